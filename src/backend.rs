@@ -0,0 +1,90 @@
+//! Abstraction over the WebGL calls made by the wrappers in this crate
+//!
+//! Every object module defines a backend trait containing the raw calls it needs, the methods are named
+//! exactly like the `wasm_bindgen` bindings they forward to. `WebGL2RenderingContext` implements all of
+//! them by calling into javascript, `RecordingBackend` implements them natively so code using the wrappers
+//! can be tested with a plain `cargo test`.
+//!
+//! The calls that only exist in WebGL2 live in separate `WebGL2*Backend` traits, so that
+//! `WebGLRenderingContext` can implement the shared surface on top of a WebGL1 context.
+use buffer::{BufferBackend, WebGL2BufferBackend};
+use framebuffer::FramebufferBackend;
+use query::QueryBackend;
+use renderbuffer::RenderbufferBackend;
+use rendering_context::{
+    BaseInstanceBackend, ContextBackend, DrawBuffersBackend, InstancingBackend,
+    WebGL2ContextBackend,
+};
+use sampler::SamplerBackend;
+use shader_program::{ProgramBackend, WebGL2ProgramBackend};
+use sync::SyncBackend;
+use texture::{TextureBackend, WebGL2TextureBackend};
+use transform_feedback::TransformFeedbackBackend;
+use uniform_location::{UniformBackend, WebGL2UniformBackend};
+use vertex_array_object::VertexArrayBackend;
+
+/// The functionality shared by WebGL1 (with the extensions that were promoted in WebGL2) and WebGL2.
+///
+/// Code that should run on both the browser and the recording backend, or on both WebGL versions, can be
+/// written generic over `B: GlBackend`. This trait is implemented automatically for every type implementing
+/// all of the backend traits.
+pub trait GlBackend:
+    ContextBackend
+    + BufferBackend
+    + FramebufferBackend
+    + RenderbufferBackend
+    + TextureBackend
+    + ProgramBackend
+    + UniformBackend
+    + VertexArrayBackend
+    + InstancingBackend
+    + DrawBuffersBackend
+{
+}
+
+impl<T> GlBackend for T where
+    T: ContextBackend
+        + BufferBackend
+        + FramebufferBackend
+        + RenderbufferBackend
+        + TextureBackend
+        + ProgramBackend
+        + UniformBackend
+        + VertexArrayBackend
+        + InstancingBackend
+        + DrawBuffersBackend
+{
+}
+
+/// A complete implementation of the WebGL2 API.
+///
+/// Implemented automatically for every `GlBackend` that also implements the WebGL2 only backend traits.
+pub trait WebGL2Backend:
+    GlBackend
+    + WebGL2ContextBackend
+    + WebGL2BufferBackend
+    + WebGL2TextureBackend
+    + WebGL2ProgramBackend
+    + WebGL2UniformBackend
+    + SamplerBackend
+    + QueryBackend
+    + SyncBackend
+    + TransformFeedbackBackend
+    + BaseInstanceBackend
+{
+}
+
+impl<T> WebGL2Backend for T where
+    T: GlBackend
+        + WebGL2ContextBackend
+        + WebGL2BufferBackend
+        + WebGL2TextureBackend
+        + WebGL2ProgramBackend
+        + WebGL2UniformBackend
+        + SamplerBackend
+        + QueryBackend
+        + SyncBackend
+        + TransformFeedbackBackend
+        + BaseInstanceBackend
+{
+}
@@ -1,32 +1,182 @@
 //! WebGLBuffer and methods
-use glenum::{BufferBase, BufferKind};
-use rendering_context::WebGL2RenderingContext;
+use error::CheckedObject;
+use glenum::{BufferBase, BufferKind, Parameter};
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing buffer objects.
+pub trait BufferBackend: Sized {
+    /// Handle to a buffer object of this backend.
+    type Buffer: Clone;
+
+    /// Binding for `WebGLRenderingContext.createBuffer()`
+    fn _create_buffer(&self) -> Self::Buffer;
+    /// Binding for `WebGLRenderingContext.deleteBuffer()`
+    fn _delete_buffer(&self, buffer: Self::Buffer);
+    /// Binding for `WebGLRenderingContext.isBuffer()`
+    fn _is_buffer(&self, buffer: &Self::Buffer) -> bool;
+    /// Binding for `WebGLRenderingContext.bindBuffer()`
+    fn _bind_buffer(&self, target: BufferKind, buffer: &Self::Buffer);
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `WebGLBuffer`
+    fn _get_parameter_buffer(&self, pname: Parameter) -> Option<Self::Buffer>;
+
     /// Creates a new `WebGLRSBuffer` object which is used for storing data such as vertices or colors.
-    pub fn create_buffer(&self) -> WebGLRSBuffer {
+    fn create_buffer(&self) -> WebGLRSBuffer<'_, Self> {
         WebGLRSBuffer {
             context: self,
             inner: self._create_buffer(),
+            leaked: false,
         }
     }
 }
 
+impl BufferBackend for WebGL2RenderingContext {
+    type Buffer = WebGLBuffer;
+
+    fn _create_buffer(&self) -> WebGLBuffer {
+        WebGL2RenderingContext::_create_buffer(self)
+    }
+    fn _delete_buffer(&self, buffer: WebGLBuffer) {
+        WebGL2RenderingContext::_delete_buffer(self, buffer)
+    }
+    fn _is_buffer(&self, buffer: &WebGLBuffer) -> bool {
+        WebGL2RenderingContext::_is_buffer(self, buffer)
+    }
+    fn _bind_buffer(&self, target: BufferKind, buffer: &WebGLBuffer) {
+        WebGL2RenderingContext::_bind_buffer(self, target, buffer)
+    }
+    fn _get_parameter_buffer(&self, pname: Parameter) -> Option<WebGLBuffer> {
+        WebGL2RenderingContext::_get_parameter_buffer(self, pname)
+    }
+}
+
+/// Backend calls for the buffer methods that were added in WebGL2.
+pub trait WebGL2BufferBackend: BufferBackend {
+    /// Binding for `WebGL2RenderingContext.bindBufferBase()`
+    fn _bind_buffer_base(&self, target: BufferBase, index: u32, buffer: &Self::Buffer);
+    /// Binding for `WebGL2RenderingContext.bindBufferRange()`
+    fn _bind_buffer_range(
+        &self,
+        target: BufferBase,
+        index: u32,
+        buffer: &Self::Buffer,
+        offset: u32,
+        size: u32,
+    );
+}
+
+impl WebGL2BufferBackend for WebGL2RenderingContext {
+    fn _bind_buffer_base(&self, target: BufferBase, index: u32, buffer: &WebGLBuffer) {
+        WebGL2RenderingContext::_bind_buffer_base(self, target, index, buffer)
+    }
+    fn _bind_buffer_range(
+        &self,
+        target: BufferBase,
+        index: u32,
+        buffer: &WebGLBuffer,
+        offset: u32,
+        size: u32,
+    ) {
+        WebGL2RenderingContext::_bind_buffer_range(self, target, index, buffer, offset, size)
+    }
+}
+
 /// Buffer which is used for storing data
 ///
 /// The `WebGLBuffer` interface is part of the WebGL API and represents an opaque buffer object
 /// storing data such as vertices or colors.
-#[derive(Clone)]
-pub struct WebGLRSBuffer<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLBuffer,
+///
+/// The object is deleted when the `WebGLRSBuffer` is dropped.
+pub struct WebGLRSBuffer<'ctx, B: 'ctx + BufferBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    pub(crate) inner: B::Buffer,
+    leaked: bool,
 }
 
-impl<'ctx> WebGLRSBuffer<'ctx> {
+impl<'ctx, B: BufferBackend> Drop for WebGLRSBuffer<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_buffer(self.inner.clone());
+        }
+    }
+}
+
+impl<'ctx, B: BufferBackend + ContextBackend> WebGLRSBuffer<'ctx, B> {
+    /// Returns a view on this buffer whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: BufferBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSBuffer<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSBuffer "buffer";
+        fn is_valid() -> bool;
+        fn bind(target: BufferKind);
+    }
+}
+
+impl<'a, 'ctx, B: WebGL2BufferBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSBuffer<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSBuffer "buffer";
+        fn bind_base(target: BufferBase, index: u32);
+        fn bind_range(target: BufferBase, index: u32, offset: u32, size: u32);
+    }
+}
+
+impl<'ctx, B: BufferBackend> WebGLRSBuffer<'ctx, B> {
     /// Deletes this `WebGLRSBuffer`
+    ///
+    /// This is the same as dropping the `WebGLRSBuffer`.
     pub fn delete(self) {
-        self.context._delete_buffer(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSBuffer` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `buffer` - the raw handle.
+    pub fn from_raw(context: &'ctx B, buffer: B::Buffer) -> Self {
+        WebGLRSBuffer {
+            context,
+            inner: buffer,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let handle = gl.create_buffer().into_raw();
+    /// assert!(!gl.calls().iter().any(|call| call.name == "deleteBuffer"));
+    ///
+    /// drop(WebGLRSBuffer::from_raw(&gl, handle));
+    /// assert_eq!(gl.calls().last().unwrap().to_string(), "deleteBuffer(Handle(1))");
+    /// ```
+    pub fn into_raw(mut self) -> B::Buffer {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSBuffer` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Returns true is the `WebGLRSBuffer` object is valid.
@@ -41,7 +191,9 @@ impl<'ctx> WebGLRSBuffer<'ctx> {
     pub fn bind(&self, target: BufferKind) {
         self.context._bind_buffer(target, &self.inner);
     }
+}
 
+impl<'ctx, B: WebGL2BufferBackend> WebGLRSBuffer<'ctx, B> {
     /// Binds the `WebGLRSBuffer` to a given binding point (target) at a given index.
     ///
     /// # Arguments
@@ -69,7 +221,7 @@ impl<'ctx> WebGLRSBuffer<'ctx> {
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLBuffer;
+    pub type WebGLBuffer;
     /// Binding for `WebGLRenderingContext.createBuffer()`
     #[wasm_bindgen(method, js_name = createBuffer)]
     fn _create_buffer(this: &WebGL2RenderingContext) -> WebGLBuffer;
@@ -86,6 +238,13 @@ extern "C" {
     #[wasm_bindgen(method, js_name = bindBuffer)]
     fn _bind_buffer(this: &WebGL2RenderingContext, target: BufferKind, buffer: &WebGLBuffer);
 
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `WebGLBuffer`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_buffer(
+        this: &WebGL2RenderingContext,
+        pname: Parameter,
+    ) -> Option<WebGLBuffer>;
+
     /// Binding for `WebGL2RenderingContext.bindBufferBase()`
     #[wasm_bindgen(method, js_name = bindBufferBase)]
     fn _bind_buffer_base(
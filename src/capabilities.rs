@@ -0,0 +1,260 @@
+//! Snapshot of the limits and features of a rendering context
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! gl.set_supported_extensions(&["EXT_color_buffer_float", "OES_texture_float_linear"]);
+//! let capabilities = Capabilities::collect(&gl);
+//! assert!(capabilities.webgl2);
+//! assert_eq!(capabilities.max_texture_size, 2048);
+//!
+//! let text = capabilities.to_string();
+//! assert_eq!(
+//!     text.lines().next(),
+//!     Some("WebGL 2.0 (webgl-rs, RecordingBackend)")
+//! );
+//! assert!(text.contains("\n  max texture size: 2048\n"));
+//! assert!(text.contains("\n  max viewport dims: 2048x2048\n"));
+//! assert!(text.contains("\n  highp float precision: vertex 23, fragment 23\n"));
+//! assert_eq!(
+//!     text.lines().last(),
+//!     Some("  extensions: EXT_color_buffer_float, OES_texture_float_linear")
+//! );
+//! ```
+use glenum::{ShaderKind, ShaderPrecision};
+use rendering_context::{ContextBackend, WebGLRSShaderPrecisionFormat};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Precision formats of the numeric types of one shader stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShaderPrecisionFormats {
+    /// Format of `lowp float`.
+    pub low_float: WebGLRSShaderPrecisionFormat,
+    /// Format of `mediump float`.
+    pub medium_float: WebGLRSShaderPrecisionFormat,
+    /// Format of `highp float`.
+    pub high_float: WebGLRSShaderPrecisionFormat,
+    /// Format of `lowp int`.
+    pub low_int: WebGLRSShaderPrecisionFormat,
+    /// Format of `mediump int`.
+    pub medium_int: WebGLRSShaderPrecisionFormat,
+    /// Format of `highp int`.
+    pub high_int: WebGLRSShaderPrecisionFormat,
+}
+
+impl ShaderPrecisionFormats {
+    fn collect<B: ContextBackend>(context: &B, shader_type: ShaderKind) -> ShaderPrecisionFormats {
+        ShaderPrecisionFormats {
+            low_float: context.get_shader_precision_format(shader_type, ShaderPrecision::LowFloat),
+            medium_float: context
+                .get_shader_precision_format(shader_type, ShaderPrecision::MediumFloat),
+            high_float: context
+                .get_shader_precision_format(shader_type, ShaderPrecision::HighFloat),
+            low_int: context.get_shader_precision_format(shader_type, ShaderPrecision::LowInt),
+            medium_int: context
+                .get_shader_precision_format(shader_type, ShaderPrecision::MediumInt),
+            high_int: context.get_shader_precision_format(shader_type, ShaderPrecision::HighInt),
+        }
+    }
+}
+
+/// Limits and features of a rendering context, collected once with `Capabilities::collect`.
+///
+/// The limits that only exist in WebGL2 are `None` on a WebGL1 context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Capabilities {
+    /// Whether the context is a WebGL2 context.
+    pub webgl2: bool,
+    /// Version string of the context, e.g. `"WebGL 2.0"`.
+    pub version: String,
+    /// Vendor of the implementation.
+    pub vendor: String,
+    /// Name of the renderer.
+    pub renderer: String,
+    /// Names of the supported extensions.
+    pub extensions: Vec<String>,
+    /// Largest width and height of a texture.
+    pub max_texture_size: u32,
+    /// Largest width and height of a cube map texture.
+    pub max_cube_map_texture_size: u32,
+    /// Largest width, height and depth of a 3D texture.
+    pub max_3d_texture_size: Option<u32>,
+    /// Maximum number of layers of an array texture.
+    pub max_array_texture_layers: Option<u32>,
+    /// Largest width and height of a renderbuffer.
+    pub max_renderbuffer_size: u32,
+    /// Largest viewport as `[width, height]`.
+    pub max_viewport_dims: [u32; 2],
+    /// Maximum number of vertex attributes.
+    pub max_vertex_attribs: u32,
+    /// Maximum number of uniform vectors in a vertex shader.
+    pub max_vertex_uniform_vectors: u32,
+    /// Maximum number of uniform vectors in a fragment shader.
+    pub max_fragment_uniform_vectors: u32,
+    /// Maximum number of varying vectors.
+    pub max_varying_vectors: u32,
+    /// Number of texture units available to a fragment shader.
+    pub max_texture_image_units: u32,
+    /// Number of texture units available to a vertex shader.
+    pub max_vertex_texture_image_units: u32,
+    /// Number of texture units available to all shaders combined.
+    pub max_combined_texture_image_units: u32,
+    /// Maximum number of uniform buffer binding points.
+    pub max_uniform_buffer_bindings: Option<u32>,
+    /// Maximum number of color attachments of a framebuffer, 1 without `WEBGL_draw_buffers` on WebGL1.
+    pub max_color_attachments: u32,
+    /// Maximum number of draw buffers, 1 without `WEBGL_draw_buffers` on WebGL1.
+    pub max_draw_buffers: u32,
+    /// Maximum number of samples of a multisample renderbuffer.
+    pub max_samples: Option<u32>,
+    /// Precision formats of the vertex shader.
+    pub vertex_shader_precision: ShaderPrecisionFormats,
+    /// Precision formats of the fragment shader.
+    pub fragment_shader_precision: ShaderPrecisionFormats,
+}
+
+/// Queries a limit that only exists in WebGL2.
+fn webgl2_limit<F: FnOnce() -> u32>(webgl2: bool, query: F) -> Option<u32> {
+    if webgl2 {
+        Some(query())
+    } else {
+        None
+    }
+}
+
+impl Capabilities {
+    /// Queries the limits and features of a context.
+    ///
+    /// The WebGL2 limits are only queried when the version string of the context starts with `WebGL 2`, so
+    /// collecting the capabilities of a WebGL1 context does not set the error flag.
+    pub fn collect<B: ContextBackend>(context: &B) -> Capabilities {
+        let parameters = context.parameters();
+        let version = parameters.version();
+        let webgl2 = version.starts_with("WebGL 2");
+        let extensions = context.get_supported_extensions();
+        let draw_buffers = webgl2 || extensions.iter().any(|name| name == "WEBGL_draw_buffers");
+        let max_viewport_dims = parameters.max_viewport_dims();
+
+        Capabilities {
+            webgl2,
+            vendor: parameters.vendor(),
+            renderer: parameters.renderer(),
+            max_texture_size: parameters.max_texture_size(),
+            max_cube_map_texture_size: parameters.max_cube_map_texture_size(),
+            max_3d_texture_size: webgl2_limit(webgl2, || parameters.max_3d_texture_size()),
+            max_array_texture_layers: webgl2_limit(webgl2, || {
+                parameters.max_array_texture_layers()
+            }),
+            max_renderbuffer_size: parameters.max_renderbuffer_size(),
+            max_viewport_dims: [max_viewport_dims[0] as u32, max_viewport_dims[1] as u32],
+            max_vertex_attribs: parameters.max_vertex_attribs(),
+            max_vertex_uniform_vectors: parameters.max_vertex_uniform_vectors(),
+            max_fragment_uniform_vectors: parameters.max_fragment_uniform_vectors(),
+            max_varying_vectors: parameters.max_varying_vectors(),
+            max_texture_image_units: parameters.max_texture_image_units(),
+            max_vertex_texture_image_units: parameters.max_vertex_texture_image_units(),
+            max_combined_texture_image_units: parameters.max_combined_texture_image_units(),
+            max_uniform_buffer_bindings: webgl2_limit(webgl2, || {
+                parameters.max_uniform_buffer_bindings()
+            }),
+            max_color_attachments: if draw_buffers {
+                parameters.max_color_attachments()
+            } else {
+                1
+            },
+            max_draw_buffers: if draw_buffers {
+                parameters.max_draw_buffers()
+            } else {
+                1
+            },
+            max_samples: webgl2_limit(webgl2, || parameters.max_samples()),
+            vertex_shader_precision: ShaderPrecisionFormats::collect(context, ShaderKind::Vertex),
+            fragment_shader_precision: ShaderPrecisionFormats::collect(
+                context,
+                ShaderKind::Fragment,
+            ),
+            version,
+            extensions,
+        }
+    }
+
+    /// Returns true if the extension with the given name is supported.
+    pub fn supports_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|extension| extension == name)
+    }
+}
+
+/// Writes one limit per line, followed by the extensions.
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn limit(f: &mut fmt::Formatter, name: &str, value: Option<u32>) -> fmt::Result {
+            match value {
+                Some(value) => writeln!(f, "  {}: {}", name, value),
+                None => writeln!(f, "  {}: unsupported", name),
+            }
+        }
+
+        writeln!(f, "{} ({}, {})", self.version, self.vendor, self.renderer)?;
+        limit(f, "max texture size", Some(self.max_texture_size))?;
+        limit(
+            f,
+            "max cube map texture size",
+            Some(self.max_cube_map_texture_size),
+        )?;
+        limit(f, "max 3D texture size", self.max_3d_texture_size)?;
+        limit(f, "max array texture layers", self.max_array_texture_layers)?;
+        limit(f, "max renderbuffer size", Some(self.max_renderbuffer_size))?;
+        writeln!(
+            f,
+            "  max viewport dims: {}x{}",
+            self.max_viewport_dims[0], self.max_viewport_dims[1]
+        )?;
+        limit(f, "max vertex attribs", Some(self.max_vertex_attribs))?;
+        limit(
+            f,
+            "max vertex uniform vectors",
+            Some(self.max_vertex_uniform_vectors),
+        )?;
+        limit(
+            f,
+            "max fragment uniform vectors",
+            Some(self.max_fragment_uniform_vectors),
+        )?;
+        limit(f, "max varying vectors", Some(self.max_varying_vectors))?;
+        limit(
+            f,
+            "max texture image units",
+            Some(self.max_texture_image_units),
+        )?;
+        limit(
+            f,
+            "max vertex texture image units",
+            Some(self.max_vertex_texture_image_units),
+        )?;
+        limit(
+            f,
+            "max combined texture image units",
+            Some(self.max_combined_texture_image_units),
+        )?;
+        limit(
+            f,
+            "max uniform buffer bindings",
+            self.max_uniform_buffer_bindings,
+        )?;
+        limit(f, "max color attachments", Some(self.max_color_attachments))?;
+        limit(f, "max draw buffers", Some(self.max_draw_buffers))?;
+        limit(f, "max samples", self.max_samples)?;
+        writeln!(
+            f,
+            "  highp float precision: vertex {}, fragment {}",
+            self.vertex_shader_precision.high_float.precision,
+            self.fragment_shader_precision.high_float.precision
+        )?;
+        write!(f, "  extensions: {}", self.extensions.join(", "))
+    }
+}
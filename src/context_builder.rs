@@ -0,0 +1,340 @@
+//! Creation of rendering contexts with custom attributes
+//!
+//! ```no_run
+//! use webgl_rs::*;
+//!
+//! let gl: WebGL2RenderingContext = ContextBuilder::new()
+//!     .antialias(false)
+//!     .power_preference(PowerPreference::HighPerformance)
+//!     .build("canvas")
+//!     .unwrap();
+//! ```
+use rendering_context::{document, HTMLCanvasElement, WebGL2RenderingContext};
+use std::error;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use webgl1::WebGLRenderingContext;
+
+/// Hint to the user agent indicating what configuration of GPU is suitable for the context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerPreference {
+    /// Let the user agent decide which GPU configuration is most suitable.
+    Default,
+    /// Prioritizes rendering performance over power consumption.
+    HighPerformance,
+    /// Prioritizes power saving over rendering performance.
+    LowPower,
+}
+
+impl PowerPreference {
+    /// Returns the value of the `powerPreference` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            PowerPreference::Default => "default",
+            PowerPreference::HighPerformance => "high-performance",
+            PowerPreference::LowPower => "low-power",
+        }
+    }
+}
+
+/// Rendering contexts that can be created by a `ContextBuilder`.
+pub trait BuildContext: Sized {
+    /// The context type that is passed to `getContext`.
+    const CONTEXT_TYPE: &'static str;
+
+    /// Creates a context on a canvas element, returns `None` if the context could not be created.
+    fn from_canvas(canvas: &HTMLCanvasElement, attributes: &WebGLContextAttributes)
+        -> Option<Self>;
+
+    /// Creates a context on an offscreen canvas, returns `None` if the context could not be created.
+    fn from_offscreen_canvas(
+        canvas: &OffscreenCanvas,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<Self>;
+}
+
+impl BuildContext for WebGL2RenderingContext {
+    const CONTEXT_TYPE: &'static str = "webgl2";
+
+    fn from_canvas(
+        canvas: &HTMLCanvasElement,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<Self> {
+        canvas._get_webgl2_context(Self::CONTEXT_TYPE, attributes)
+    }
+
+    fn from_offscreen_canvas(
+        canvas: &OffscreenCanvas,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<Self> {
+        canvas._get_webgl2_context(Self::CONTEXT_TYPE, attributes)
+    }
+}
+
+impl BuildContext for WebGLRenderingContext {
+    const CONTEXT_TYPE: &'static str = "webgl";
+
+    fn from_canvas(
+        canvas: &HTMLCanvasElement,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<Self> {
+        WebGLRenderingContext::create_on_canvas(canvas, attributes)
+    }
+
+    fn from_offscreen_canvas(
+        canvas: &OffscreenCanvas,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<Self> {
+        WebGLRenderingContext::create_on_offscreen_canvas(canvas, attributes)
+    }
+}
+
+/// Builder for `WebGL2RenderingContext` and `WebGLRenderingContext`
+///
+/// Every option corresponds with an attribute of the `WebGLContextAttributes` dictionary, the defaults are
+/// the same as the ones used by the browser.
+#[derive(Debug, Clone)]
+pub struct ContextBuilder {
+    alpha: bool,
+    depth: bool,
+    stencil: bool,
+    antialias: bool,
+    premultiplied_alpha: bool,
+    preserve_drawing_buffer: bool,
+    power_preference: PowerPreference,
+    fail_if_major_performance_caveat: bool,
+}
+
+impl Default for ContextBuilder {
+    fn default() -> Self {
+        ContextBuilder::new()
+    }
+}
+
+impl ContextBuilder {
+    /// Creates a new `ContextBuilder` with the default attributes.
+    pub fn new() -> ContextBuilder {
+        ContextBuilder {
+            alpha: true,
+            depth: true,
+            stencil: false,
+            antialias: true,
+            premultiplied_alpha: true,
+            preserve_drawing_buffer: false,
+            power_preference: PowerPreference::Default,
+            fail_if_major_performance_caveat: false,
+        }
+    }
+
+    /// Sets whether the canvas contains an alpha buffer.
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets whether the drawing buffer has a depth buffer of at least 16 bits.
+    pub fn depth(mut self, depth: bool) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets whether the drawing buffer has a stencil buffer of at least 8 bits.
+    pub fn stencil(mut self, stencil: bool) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
+    /// Sets whether or not to perform anti-aliasing.
+    pub fn antialias(mut self, antialias: bool) -> Self {
+        self.antialias = antialias;
+        self
+    }
+
+    /// Sets whether the page compositor will assume the drawing buffer contains colors with pre-multiplied
+    /// alpha.
+    pub fn premultiplied_alpha(mut self, premultiplied_alpha: bool) -> Self {
+        self.premultiplied_alpha = premultiplied_alpha;
+        self
+    }
+
+    /// Sets whether the buffers are preserved until they are cleared or overwritten by the author.
+    pub fn preserve_drawing_buffer(mut self, preserve_drawing_buffer: bool) -> Self {
+        self.preserve_drawing_buffer = preserve_drawing_buffer;
+        self
+    }
+
+    /// Sets which configuration of GPU is suitable for the context.
+    pub fn power_preference(mut self, power_preference: PowerPreference) -> Self {
+        self.power_preference = power_preference;
+        self
+    }
+
+    /// Sets whether context creation fails if the system performance is low.
+    pub fn fail_if_major_performance_caveat(
+        mut self,
+        fail_if_major_performance_caveat: bool,
+    ) -> Self {
+        self.fail_if_major_performance_caveat = fail_if_major_performance_caveat;
+        self
+    }
+
+    /// Creates a context on the canvas element with the given id.
+    ///
+    /// # Arguments
+    /// * `id` - the id of the canvas element.
+    pub fn build<C: BuildContext>(&self, id: &str) -> Result<C, ContextError> {
+        let element = document.get_element_by_id(id);
+        if element.is_null() {
+            return Err(ContextError::MissingElement { id: id.to_string() });
+        }
+        let canvas = element
+            .dyn_into::<HTMLCanvasElement>()
+            .map_err(|_| ContextError::NotACanvas { id: id.to_string() })?;
+        self.build_from_canvas(&canvas)
+    }
+
+    /// Creates a context on a canvas element.
+    pub fn build_from_canvas<C: BuildContext>(
+        &self,
+        canvas: &HTMLCanvasElement,
+    ) -> Result<C, ContextError> {
+        C::from_canvas(canvas, &self.attributes()).ok_or(ContextError::CreationFailed {
+            context_type: C::CONTEXT_TYPE,
+        })
+    }
+
+    /// Creates a context on an offscreen canvas, e.g. one that was transferred to a worker.
+    pub fn build_from_offscreen_canvas<C: BuildContext>(
+        &self,
+        canvas: &OffscreenCanvas,
+    ) -> Result<C, ContextError> {
+        C::from_offscreen_canvas(canvas, &self.attributes()).ok_or(ContextError::CreationFailed {
+            context_type: C::CONTEXT_TYPE,
+        })
+    }
+
+    fn attributes(&self) -> WebGLContextAttributes {
+        let attributes = WebGLContextAttributes::new();
+        attributes.set_alpha(self.alpha);
+        attributes.set_depth(self.depth);
+        attributes.set_stencil(self.stencil);
+        attributes.set_antialias(self.antialias);
+        attributes.set_premultiplied_alpha(self.premultiplied_alpha);
+        attributes.set_preserve_drawing_buffer(self.preserve_drawing_buffer);
+        attributes.set_power_preference(self.power_preference.as_str());
+        attributes.set_fail_if_major_performance_caveat(self.fail_if_major_performance_caveat);
+        attributes
+    }
+}
+
+/// WebGLContextAttributes bindings
+///
+/// `WebGLContextAttributes` is a dictionary and not a javascript class, so it is created as a plain object
+/// and all accessors are structural.
+#[wasm_bindgen]
+extern "C" {
+    pub type WebGLContextAttributes;
+    #[wasm_bindgen(constructor, js_class = "Object")]
+    fn new() -> WebGLContextAttributes;
+
+    #[wasm_bindgen(method, structural, getter)]
+    pub fn alpha(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = alpha)]
+    fn set_alpha(this: &WebGLContextAttributes, alpha: bool);
+    #[wasm_bindgen(method, structural, getter)]
+    pub fn antialias(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = antialias)]
+    fn set_antialias(this: &WebGLContextAttributes, antialias: bool);
+    #[wasm_bindgen(method, structural, getter)]
+    pub fn depth(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = depth)]
+    fn set_depth(this: &WebGLContextAttributes, depth: bool);
+    #[wasm_bindgen(method, structural, getter = premultipliedAlpha)]
+    pub fn premultiplied_alpha(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = premultipliedAlpha)]
+    fn set_premultiplied_alpha(this: &WebGLContextAttributes, premultiplied_alpha: bool);
+    #[wasm_bindgen(method, structural, getter = preserveDrawingBuffer)]
+    pub fn preserve_drawing_buffer(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = preserveDrawingBuffer)]
+    fn set_preserve_drawing_buffer(this: &WebGLContextAttributes, preserve_drawing_buffer: bool);
+    #[wasm_bindgen(method, structural, getter)]
+    pub fn stencil(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = stencil)]
+    fn set_stencil(this: &WebGLContextAttributes, stencil: bool);
+    #[wasm_bindgen(method, structural, getter = failIfMajorPerformanceCaveat)]
+    pub fn fail_if_major_performance_caveat(this: &WebGLContextAttributes) -> bool;
+    #[wasm_bindgen(method, structural, setter = failIfMajorPerformanceCaveat)]
+    fn set_fail_if_major_performance_caveat(
+        this: &WebGLContextAttributes,
+        fail_if_major_performance_caveat: bool,
+    );
+    #[wasm_bindgen(method, structural, getter = powerPreference)]
+    pub fn power_preference(this: &WebGLContextAttributes) -> String;
+    #[wasm_bindgen(method, structural, setter = powerPreference)]
+    fn set_power_preference(this: &WebGLContextAttributes, power_preference: &str);
+}
+
+/// OffscreenCanvas bindings
+#[wasm_bindgen]
+extern "C" {
+    pub type OffscreenCanvas;
+    /// Binding for `OffscreenCanvas.getContext()` with context type `"webgl2"`
+    #[wasm_bindgen(method, js_name = getContext)]
+    fn _get_webgl2_context(
+        this: &OffscreenCanvas,
+        context_type: &str,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<WebGL2RenderingContext>;
+}
+
+/// HTMLCanvasElement bindings
+#[wasm_bindgen]
+extern "C" {
+    /// Binding for `HTMLCanvasElement.getContext()` with context type `"webgl2"`
+    #[wasm_bindgen(method, js_name = getContext)]
+    fn _get_webgl2_context(
+        this: &HTMLCanvasElement,
+        context_type: &str,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<WebGL2RenderingContext>;
+}
+
+/// Error returned by `ContextBuilder` when a rendering context could not be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextError {
+    /// The document does not contain an element with the given id.
+    MissingElement {
+        /// The id that was looked up.
+        id: String,
+    },
+    /// The element with the given id is not a `<canvas>` element.
+    NotACanvas {
+        /// The id of the element.
+        id: String,
+    },
+    /// `getContext` returned null, the context type is not supported by the browser or the attributes can
+    /// not be satisfied.
+    CreationFailed {
+        /// The context type that was requested, `"webgl"` or `"webgl2"`.
+        context_type: &'static str,
+    },
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ContextError::MissingElement { ref id } => {
+                write!(f, "no element with id \"{}\" found", id)
+            }
+            ContextError::NotACanvas { ref id } => {
+                write!(f, "element with id \"{}\" is not a canvas", id)
+            }
+            ContextError::CreationFailed { context_type } => {
+                write!(f, "could not create a {} context", context_type)
+            }
+        }
+    }
+}
+
+impl error::Error for ContextError {}
@@ -1,594 +1,424 @@
-use glenum::{BufferKind, DataHint, PixelCopyFormat, PixelReadFormat, PixelType, TextureBindPoint};
-use rendering_context::WebGL2RenderingContext;
-use wasm_bindgen::JsValue;
+//! Typed views over the data that is uploaded to or read back from the GPU
+use formats;
+use formats::FormatError;
+use glenum::{PixelFormat, PixelType};
+use std::fmt;
+use std::mem;
+use std::slice;
 
-pub trait Buffer {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint);
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64);
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue>;
-}
-
-pub trait Image {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue>;
-    /* FIXME save for later
-    fn compressed_tex_image_2d(
-        &self,
-        context: WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: CompressedFormat,
-        width: u32,
-        height: u32,
-    ) -> Result<(), JsValue>;
-    */
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue>;
-
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue>;
+/// Borrowed view over a typed array that is passed to the backend as source data.
+///
+/// Every variant corresponds with one of the javascript typed arrays (`Uint8Array`, `Float32Array`, ...).
+#[derive(Clone, Copy)]
+pub enum ArrayData<'a> {
+    I8(&'a [i8]),
+    U8(&'a [u8]),
+    I16(&'a [i16]),
+    U16(&'a [u16]),
+    I32(&'a [i32]),
+    U32(&'a [u32]),
+    F32(&'a [f32]),
+    F64(&'a [f64]),
 }
 
-impl Buffer for Vec<u8> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_u8(target, self, usage);
-    }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_u8(target, offset, self);
+impl<'a> ArrayData<'a> {
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        match *self {
+            ArrayData::I8(data) => data.len(),
+            ArrayData::U8(data) => data.len(),
+            ArrayData::I16(data) => data.len(),
+            ArrayData::U16(data) => data.len(),
+            ArrayData::I32(data) => data.len(),
+            ArrayData::U32(data) => data.len(),
+            ArrayData::F32(data) => data.len(),
+            ArrayData::F64(data) => data.len(),
+        }
     }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_u8(target, src_offset, self, dst_offset, length)
-    }
-}
 
-impl Image for Vec<u8> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_u8(
-            target,
-            level,
-            internalformat,
-            width,
-            height,
-            0,
-            format,
-            pixel_type,
-            self,
-        )
+    /// Returns true if the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_u8(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
+
+    /// Returns the size in bytes of one element of the view.
+    pub fn element_size(&self) -> usize {
+        match *self {
+            ArrayData::I8(_) | ArrayData::U8(_) => 1,
+            ArrayData::I16(_) | ArrayData::U16(_) => 2,
+            ArrayData::I32(_) | ArrayData::U32(_) | ArrayData::F32(_) => 4,
+            ArrayData::F64(_) => 8,
+        }
     }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_u8(x, y, width, height, format, pixel_type, self)
+
+    /// Returns the name of the element type of the view.
+    pub fn element_type(&self) -> &'static str {
+        match *self {
+            ArrayData::I8(_) => "i8",
+            ArrayData::U8(_) => "u8",
+            ArrayData::I16(_) => "i16",
+            ArrayData::U16(_) => "u16",
+            ArrayData::I32(_) => "i32",
+            ArrayData::U32(_) => "u32",
+            ArrayData::F32(_) => "f32",
+            ArrayData::F64(_) => "f64",
+        }
     }
 }
 
-impl Buffer for Vec<i8> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_i8(target, self, usage);
-    }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_i8(target, offset, self);
-    }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_i8(target, src_offset, self, dst_offset, length)
+/// Only prints the element type and length, the data itself can be arbitrarily large.
+impl<'a> fmt::Debug for ArrayData<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type(), self.len())
     }
 }
 
-impl Image for Vec<i8> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_i8(
-            target,
-            level,
-            internalformat,
-            width,
-            height,
-            0,
-            format,
-            pixel_type,
-            self,
-        )
-    }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_i8(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
-    }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_i8(x, y, width, height, format, pixel_type, self)
-    }
+/// Mutable view over a typed array that is used as destination for data read back from the GPU.
+pub enum ArrayDataMut<'a> {
+    I8(&'a mut [i8]),
+    U8(&'a mut [u8]),
+    I16(&'a mut [i16]),
+    U16(&'a mut [u16]),
+    I32(&'a mut [i32]),
+    U32(&'a mut [u32]),
+    F32(&'a mut [f32]),
+    F64(&'a mut [f64]),
 }
 
-impl Buffer for Vec<u16> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_u16(target, self, usage);
-    }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_u16(target, offset, self);
-    }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_u16(target, src_offset, self, dst_offset, length)
+impl<'a> ArrayDataMut<'a> {
+    /// Returns an immutable view over the same data.
+    pub fn as_data(&self) -> ArrayData<'_> {
+        match *self {
+            ArrayDataMut::I8(ref data) => ArrayData::I8(data),
+            ArrayDataMut::U8(ref data) => ArrayData::U8(data),
+            ArrayDataMut::I16(ref data) => ArrayData::I16(data),
+            ArrayDataMut::U16(ref data) => ArrayData::U16(data),
+            ArrayDataMut::I32(ref data) => ArrayData::I32(data),
+            ArrayDataMut::U32(ref data) => ArrayData::U32(data),
+            ArrayDataMut::F32(ref data) => ArrayData::F32(data),
+            ArrayDataMut::F64(ref data) => ArrayData::F64(data),
+        }
     }
 }
 
-impl Image for Vec<u16> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_u16(
-            target,
-            level,
-            internalformat,
-            width,
-            height,
-            0,
-            format,
-            pixel_type,
-            self,
-        )
-    }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_u16(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
-    }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_u16(x, y, width, height, format, pixel_type, self)
+impl<'a> fmt::Debug for ArrayDataMut<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_data().fmt(f)
     }
 }
 
-impl Buffer for Vec<i16> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_i16(target, self, usage);
-    }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_i16(target, offset, self);
-    }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_i16(target, src_offset, self, dst_offset, length)
-    }
+/// Data that can be stored in a `WebGLRSBuffer`.
+///
+/// Implemented for vectors, slices and arrays of `Pod` values. The data is passed to WebGL as a typed array
+/// view over the wasm memory, it is not copied before the upload.
+pub trait Buffer {
+    /// Returns a view over the data.
+    fn data(&self) -> ArrayData<'_>;
 }
 
-impl Image for Vec<i16> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_i16(
-            target,
-            level,
-            internalformat,
-            width,
-            height,
-            0,
-            format,
-            pixel_type,
-            self,
-        )
+/// Data that can be used as destination for data read back from the GPU.
+pub trait BufferMut: Buffer {
+    /// Returns a mutable view over the data.
+    fn data_mut(&mut self) -> ArrayDataMut<'_>;
+}
+
+/// Data that can be used as pixel source or destination of a texture.
+///
+/// Only data of a `PixelComponent` is an image, `Float64Array` for example is not accepted by WebGL. The
+/// component type decides which `PixelType`s the data can be uploaded as.
+pub trait Image: Buffer {
+    /// The element type of the data.
+    type Component: PixelComponent;
+}
+
+/// Plain old data that can be uploaded to the GPU as is.
+///
+/// The numeric types are viewed as the typed array of the same type, other types are viewed as a
+/// `Uint8Array` over their bytes. This makes it possible to upload a slice of vertex structs directly:
+///
+/// ```
+/// use webgl_rs::*;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// unsafe impl Pod for Vertex {}
+///
+/// let vertices = [Vertex { position: [0.0; 3], uv: [0.0; 2] }; 4];
+/// assert_eq!(vertices[..].data().len(), 80);
+///
+/// let gl = RecordingBackend::new();
+/// gl.buffer_data(BufferKind::Array, &vertices[1..3], DataHint::StaticDraw);
+/// assert_eq!(gl.calls()[0].to_string(), "bufferData(Array, [u8; 40], StaticDraw)");
+/// ```
+///
+/// # Safety
+/// The type must be `#[repr(C)]` (or `#[repr(transparent)]`), contain no padding bytes, pointers or
+/// references and every bit pattern must be a valid value, reading data back writes arbitrary bytes.
+pub unsafe trait Pod: Copy {
+    /// Returns a view over a slice of this type.
+    fn array_data(data: &[Self]) -> ArrayData<'_> {
+        let len = mem::size_of_val(data);
+        ArrayData::U8(unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, len) })
+    }
+    /// Returns a mutable view over a slice of this type.
+    fn array_data_mut(data: &mut [Self]) -> ArrayDataMut<'_> {
+        let len = mem::size_of_val(data);
+        ArrayDataMut::U8(unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, len) })
     }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_i16(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
+}
+
+/// Element type of a typed array that WebGL accepts as pixel data.
+///
+/// Every element type maps to the pixel types WebGL accepts for the matching typed array, a `Uint16Array`
+/// for example holds `UNSIGNED_SHORT`, the packed 16 bit types and `HALF_FLOAT` data.
+pub trait PixelComponent: Pod {
+    /// Returns the pixel types data of this element type can be uploaded as.
+    fn pixel_types() -> &'static [PixelType];
+    /// Returns the name of the element type.
+    fn name() -> &'static str;
+}
+
+macro_rules! typed_array {
+    ($element:ty, $variant:ident) => {
+        unsafe impl Pod for $element {
+            fn array_data(data: &[Self]) -> ArrayData<'_> {
+                ArrayData::$variant(data)
+            }
+            fn array_data_mut(data: &mut [Self]) -> ArrayDataMut<'_> {
+                ArrayDataMut::$variant(data)
+            }
+        }
+    };
+    ($element:ty, $variant:ident, [$($pixel_type:ident),*]) => {
+        typed_array!($element, $variant);
+
+        impl PixelComponent for $element {
+            fn pixel_types() -> &'static [PixelType] {
+                &[$(PixelType::$pixel_type),*]
+            }
+            fn name() -> &'static str {
+                stringify!($element)
+            }
+        }
+    };
+}
+
+typed_array!(i8, I8, [Byte]);
+typed_array!(u8, U8, [UnsignedByte]);
+typed_array!(i16, I16, [Short]);
+typed_array!(
+    u16,
+    U16,
+    [
+        UnsignedShort,
+        UnsignedShort565,
+        UnsignedShort5551,
+        UnsignedShort4444,
+        HalfFloat
+    ]
+);
+typed_array!(i32, I32, [Int]);
+typed_array!(
+    u32,
+    U32,
+    [
+        UnsignedInt,
+        UnsignedInt5999Rev,
+        UnsignedInt2101010Rev,
+        UnsignedInt10f11f11fRev,
+        UnsignedInt248
+    ]
+);
+typed_array!(f32, F32, [Float]);
+typed_array!(f64, F64);
+
+/// Arrays are viewed as the typed array of their elements, a `Vec<[f32; 3]>` is uploaded as a
+/// `Float32Array`.
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {
+    fn array_data(data: &[Self]) -> ArrayData<'_> {
+        let len = data.len() * N;
+        T::array_data(unsafe { slice::from_raw_parts(data.as_ptr() as *const T, len) })
     }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_i16(x, y, width, height, format, pixel_type, self)
+    fn array_data_mut(data: &mut [Self]) -> ArrayDataMut<'_> {
+        let len = data.len() * N;
+        T::array_data_mut(unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, len) })
     }
 }
 
-impl Buffer for Vec<u32> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_u32(target, self, usage);
+impl<T: Pod> Buffer for [T] {
+    fn data(&self) -> ArrayData<'_> {
+        T::array_data(self)
     }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_u32(target, offset, self);
+}
+
+impl<T: Pod> BufferMut for [T] {
+    fn data_mut(&mut self) -> ArrayDataMut<'_> {
+        T::array_data_mut(self)
     }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_u32(target, src_offset, self, dst_offset, length)
+}
+
+impl<T: PixelComponent> Image for [T] {
+    type Component = T;
+}
+
+impl<T: Pod> Buffer for &[T] {
+    fn data(&self) -> ArrayData<'_> {
+        T::array_data(self)
     }
 }
 
-impl Image for Vec<u32> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_u32(
-            target,
-            level,
-            internalformat,
-            width,
-            height,
-            0,
-            format,
-            pixel_type,
-            self,
-        )
+impl<T: PixelComponent> Image for &[T] {
+    type Component = T;
+}
+
+impl<T: Pod> Buffer for &mut [T] {
+    fn data(&self) -> ArrayData<'_> {
+        T::array_data(self)
     }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_u32(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
+}
+
+impl<T: Pod> BufferMut for &mut [T] {
+    fn data_mut(&mut self) -> ArrayDataMut<'_> {
+        T::array_data_mut(self)
     }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_u32(x, y, width, height, format, pixel_type, self)
+}
+
+impl<T: PixelComponent> Image for &mut [T] {
+    type Component = T;
+}
+
+impl<T: Pod, const N: usize> Buffer for [T; N] {
+    fn data(&self) -> ArrayData<'_> {
+        T::array_data(self)
     }
 }
 
-impl Buffer for Vec<i32> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_i32(target, self, usage);
+impl<T: Pod, const N: usize> BufferMut for [T; N] {
+    fn data_mut(&mut self) -> ArrayDataMut<'_> {
+        T::array_data_mut(self)
     }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_i32(target, offset, self);
+}
+
+impl<T: PixelComponent, const N: usize> Image for [T; N] {
+    type Component = T;
+}
+
+impl<T: Pod> Buffer for Vec<T> {
+    fn data(&self) -> ArrayData<'_> {
+        T::array_data(self)
     }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_i32(target, src_offset, self, dst_offset, length)
+}
+
+impl<T: Pod> BufferMut for Vec<T> {
+    fn data_mut(&mut self) -> ArrayDataMut<'_> {
+        T::array_data_mut(self)
     }
 }
 
-impl Image for Vec<i32> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
+impl<T: PixelComponent> Image for Vec<T> {
+    type Component = T;
+}
+
+/// Pixel data of an image together with its dimensions, format and type.
+///
+/// The data is checked on creation, it can only hold pixel types its component type accepts and must
+/// contain at least `width` × `height` tightly packed pixels. Rows whose size is not a multiple of the
+/// `UNPACK_ALIGNMENT` (4 by default) need an alignment of 1 to be uploaded.
+///
+/// ```
+/// use webgl_rs::*;
+///
+/// let pixels = Pixels::new(2, 2, PixelFormat::Rgba, PixelType::UnsignedByte, vec![255u8; 16]).unwrap();
+/// assert_eq!(pixels.width(), 2);
+/// assert!(Pixels::new(2, 2, PixelFormat::Rgba, PixelType::Float, vec![255u8; 16]).is_err());
+/// assert!(Pixels::new(2, 2, PixelFormat::Rgba, PixelType::UnsignedByte, vec![255u8; 15]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pixels<T: PixelComponent> {
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    pixel_type: PixelType,
+    data: Vec<T>,
+}
+
+impl<T: PixelComponent> Pixels<T> {
+    /// Wraps tightly packed pixel data.
+    ///
+    /// Fails when `T` can not hold data of the pixel type or when there are less than `width` × `height`
+    /// pixels.
+    ///
+    /// # Arguments
+    /// * `width` - the width of the image in pixels.
+    /// * `height` - the height of the image in pixels.
+    /// * `format` - the format of the pixels.
+    /// * `pixel_type` - the data type of the pixels.
+    /// * `data` - the pixels, row by row.
+    pub fn new(
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_i32(
-            target,
-            level,
-            internalformat,
+        data: Vec<T>,
+    ) -> Result<Pixels<T>, FormatError> {
+        formats::validate_pixels::<T>(data.len(), width, height, format, pixel_type, 1)?;
+        Ok(Pixels {
             width,
             height,
-            0,
             format,
             pixel_type,
-            self,
-        )
+            data,
+        })
     }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_i32(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
-    }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_i32(x, y, width, height, format, pixel_type, self)
-    }
-}
 
-impl Buffer for Vec<f32> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_f32(target, self, usage);
+    /// Returns the width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
     }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_f32(target, offset, self);
+
+    /// Returns the height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
     }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_f32(target, src_offset, self, dst_offset, length)
+
+    /// Returns the format of the pixels.
+    pub fn format(&self) -> PixelFormat {
+        self.format
     }
-}
 
-impl Image for Vec<f32> {
-    fn tex_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        internalformat: PixelCopyFormat,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_image_2d_f32(
-            target,
-            level,
-            internalformat,
-            width,
-            height,
-            0,
-            format,
-            pixel_type,
-            self,
-        )
+    /// Returns the data type of the pixels.
+    pub fn pixel_type(&self) -> PixelType {
+        self.pixel_type
     }
-    fn tex_sub_image_2d(
-        &self,
-        context: &WebGL2RenderingContext,
-        target: TextureBindPoint,
-        level: u32,
-        xoffset: u32,
-        yoffset: u32,
-        width: u32,
-        height: u32,
-        format: PixelCopyFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._tex_sub_image_2d_f32(
-            target, level, xoffset, yoffset, width, height, format, pixel_type, self,
-        )
+
+    /// Returns the pixels.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
     }
-    fn read_pixels(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-    ) -> Result<(), JsValue> {
-        context._read_pixels_f32(x, y, width, height, format, pixel_type, self)
+
+    /// Returns the pixels, consuming the image.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
     }
 }
 
-impl Buffer for Vec<f64> {
-    fn buffer_data(&self, context: &WebGL2RenderingContext, target: BufferKind, usage: DataHint) {
-        context._buffer_data_f64(target, self, usage);
+impl<T: PixelComponent> Buffer for Pixels<T> {
+    fn data(&self) -> ArrayData<'_> {
+        T::array_data(&self.data)
     }
-    fn buffer_sub_data(&self, context: &WebGL2RenderingContext, target: BufferKind, offset: i64) {
-        context._buffer_sub_data_f64(target, offset, self);
-    }
-    fn get_buffer_sub_data(
-        &mut self,
-        context: &WebGL2RenderingContext,
-        target: BufferKind,
-        src_offset: i64,
-        dst_offset: u32,
-        length: u32,
-    ) -> Result<(), JsValue> {
-        context._get_buffer_sub_data_f64(target, src_offset, self, dst_offset, length)
+}
+
+impl<T: PixelComponent> BufferMut for Pixels<T> {
+    fn data_mut(&mut self) -> ArrayDataMut<'_> {
+        T::array_data_mut(&mut self.data)
     }
 }
+
+impl<T: PixelComponent> Image for Pixels<T> {
+    type Component = T;
+}
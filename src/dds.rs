@@ -0,0 +1,494 @@
+//! Loader for textures stored in DDS files
+//!
+//! `TextureData::from_dds` reads the header of a DDS file and the DX10 header that may follow it, maps the
+//! pixel format or `dxgiFormat` to an `InternalFormat` or a `TextureCompression` and splits the data into
+//! mipmap levels, see `TextureData` for uploading them. Cube maps, texture arrays and 3D textures are
+//! supported, arrays of cube maps are not because WebGL2 does not have them.
+//!
+//! DDS files store the full mipmap chain of every layer and face one after the other, the levels of a
+//! `TextureData` are reordered to hold the images of all layers and faces. Pixels in the BGR(A) and ARGB
+//! orders of Direct3D are converted to the RGBA order of WebGL while reading them.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let dxt5 = TextureData::from_dds(include_bytes!("../fixtures/dds/dxt5_mipmaps.dds")).unwrap();
+//! assert_eq!(dxt5.format(), TextureDataFormat::Compressed(TextureCompression::RgbaDxt5));
+//! assert_eq!((dxt5.width(), dxt5.height(), dxt5.levels()), (8, 8, 4));
+//! let sizes: Vec<usize> = dxt5.images().map(|(_, _, _, data)| data.len()).collect();
+//! assert_eq!(sizes, vec![64, 16, 16, 16]);
+//!
+//! // compressed formats are refused when the context does not support their extension
+//! let gl = RecordingBackend::new();
+//! assert_eq!(
+//!     dxt5.create_texture(&gl).err(),
+//!     Some(TextureFileError::MissingExtension { extension: "WEBGL_compressed_texture_s3tc" })
+//! );
+//! gl.set_supported_extensions(&["WEBGL_compressed_texture_s3tc"]);
+//! let texture = dxt5.create_texture(&gl).unwrap();
+//!
+//! // the faces of a cube map are converted from BGRA to RGBA
+//! let cube = TextureData::from_dds(include_bytes!("../fixtures/dds/bgra8_cube.dds")).unwrap();
+//! assert_eq!((cube.kind(), cube.faces(), cube.row_alignment()), (TextureKind::TextureCubeMap, 6, 1));
+//! let (_, _, face, pixel) = cube.images().nth(1).unwrap();
+//! assert_eq!((face, &pixel[..4]), (1, &[0x10, 0x20, 0x30, 0xFF][..]));
+//! let cube_map = cube.create_texture(&gl).unwrap();
+//! assert_eq!(
+//!     gl.calls().last().unwrap().to_string(),
+//!     "pixelStorei(UnpackAlignment, 4)"
+//! );
+//!
+//! // the DX10 header describes arrays, the mipmap chains of the layers are reordered into levels
+//! let bc7 = TextureData::from_dds(include_bytes!("../fixtures/dds/bc7_array.dds")).unwrap();
+//! assert_eq!(bc7.format(), TextureDataFormat::Compressed(TextureCompression::RgbaBptcUnorm));
+//! assert_eq!((bc7.kind(), bc7.layers(), bc7.levels()), (TextureKind::Texture2dArray, 2, 3));
+//! let layers: Vec<(u32, u32, u8)> = bc7.images().map(|(level, layer, _, data)| (level, layer, data[0])).collect();
+//! assert_eq!(layers, vec![(0, 0, 0), (0, 1, 1), (1, 0, 0), (1, 1, 1), (2, 0, 0), (2, 1, 1)]);
+//! assert_eq!(bc7.required_extension(), Some("EXT_texture_compression_bptc"));
+//!
+//! // the header of a 4x4 RGBA32F texture without its pixels, and with dimensions too large to address
+//! let mut header = vec![0u8; 128];
+//! let mut field = |offset: usize, value: u32| {
+//!     header[offset..offset + 4].copy_from_slice(&value.to_le_bytes())
+//! };
+//! field(0, u32::from_le_bytes(*b"DDS "));
+//! field(12, 4);
+//! field(16, 4);
+//! field(80, 0x4);
+//! field(84, 116);
+//! assert_eq!(
+//!     TextureData::from_dds(&header).err(),
+//!     Some(TextureFileError::UnexpectedEnd { required: 128 + 4 * 4 * 16, actual: 128 })
+//! );
+//! header[12..20].copy_from_slice(&[0, 0, 0, 0x80, 0, 0, 0, 0x80]);
+//! assert_eq!(
+//!     TextureData::from_dds(&header).err(),
+//!     Some(TextureFileError::UnsupportedDimensions {
+//!         width: 0x8000_0000,
+//!         height: 0x8000_0000,
+//!         depth: 0,
+//!         layers: 0,
+//!         faces: 1,
+//!     })
+//! );
+//! ```
+use formats::format_info;
+use glenum::InternalFormat as I;
+use glenum::PixelType as T;
+use glenum::TextureCompression as C;
+use glenum::{InternalFormat, PixelType, TextureCompression};
+use texture_data::TextureFileError;
+use texture_data::{Reader, TextureData, TextureDataFormat};
+
+const DDS_MAGIC: [u8; 4] = *b"DDS ";
+
+// Flags of the header
+const DDSD_DEPTH: u32 = 0x80_0000;
+const DDSD_MIPMAPCOUNT: u32 = 0x2_0000;
+const DDSCAPS2_CUBEMAP: u32 = 0x200;
+const DDSCAPS2_CUBEMAP_ALLFACES: u32 = 0xFC00;
+const DDSCAPS2_VOLUME: u32 = 0x20_0000;
+
+// Flags of the pixel format
+const DDPF_ALPHAPIXELS: u32 = 0x1;
+const DDPF_FOURCC: u32 = 0x4;
+const DDPF_RGB: u32 = 0x40;
+
+// Fields of the DX10 header
+const DX10_FOURCC: u32 = u32::from_le_bytes(*b"DX10");
+const DX10_TEXTURE3D: u32 = 4;
+const DX10_TEXTURECUBE: u32 = 0x4;
+
+/// How the pixels of a DDS file are converted to the component order of WebGL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conversion {
+    None,
+    /// Swaps the first and third byte of pixels of the given size, BGR(A) to RGB(A).
+    SwapRedBlue(usize),
+    /// Swaps the first and third byte of 4 byte pixels and makes them opaque, BGRX to RGBA.
+    SwapRedBlueOpaque,
+    /// Rotates the bits of 16 bit pixels to the left, ARGB to RGBA.
+    RotateLeft(u32),
+}
+
+/// The `FourCC`s of compressed formats, DXT1 is handled separately because the alpha flag decides
+/// between two formats.
+static FOURCC_FORMATS: &[(&[u8; 4], TextureCompression)] = &[
+    (b"DXT2", C::RgbaDxt3),
+    (b"DXT3", C::RgbaDxt3),
+    (b"DXT4", C::RgbaDxt5),
+    (b"DXT5", C::RgbaDxt5),
+    (b"ATI1", C::RedRgtc1),
+    (b"BC4U", C::RedRgtc1),
+    (b"BC4S", C::SignedRedRgtc1),
+    (b"ATI2", C::RedGreenRgtc2),
+    (b"BC5U", C::RedGreenRgtc2),
+    (b"BC5S", C::SignedRedGreenRgtc2),
+];
+
+/// The `D3DFORMAT` values that are stored in the `FourCC` field for floating point formats.
+static D3D_FORMATS: &[(u32, InternalFormat, PixelType)] = &[
+    (111, I::R16f, T::HalfFloat),
+    (112, I::Rg16f, T::HalfFloat),
+    (113, I::Rgba16f, T::HalfFloat),
+    (114, I::R32f, T::Float),
+    (115, I::Rg32f, T::Float),
+    (116, I::Rgba32f, T::Float),
+];
+
+/// The uncompressed pixel formats described by bit count and red, green, blue and alpha masks.
+static MASK_FORMATS: &[(u32, [u32; 4], InternalFormat, PixelType, Conversion)] = &[
+    (
+        32,
+        [0xFF, 0xFF00, 0xFF_0000, 0xFF00_0000],
+        I::Rgba8,
+        T::UnsignedByte,
+        Conversion::None,
+    ),
+    (
+        32,
+        [0xFF_0000, 0xFF00, 0xFF, 0xFF00_0000],
+        I::Rgba8,
+        T::UnsignedByte,
+        Conversion::SwapRedBlue(4),
+    ),
+    (
+        32,
+        [0xFF_0000, 0xFF00, 0xFF, 0],
+        I::Rgba8,
+        T::UnsignedByte,
+        Conversion::SwapRedBlueOpaque,
+    ),
+    (
+        24,
+        [0xFF_0000, 0xFF00, 0xFF, 0],
+        I::Rgb8,
+        T::UnsignedByte,
+        Conversion::SwapRedBlue(3),
+    ),
+    (
+        16,
+        [0xF800, 0x7E0, 0x1F, 0],
+        I::Rgb565,
+        T::UnsignedShort565,
+        Conversion::None,
+    ),
+    (
+        16,
+        [0x7C00, 0x3E0, 0x1F, 0x8000],
+        I::Rgb5A1,
+        T::UnsignedShort5551,
+        Conversion::RotateLeft(1),
+    ),
+    (
+        16,
+        [0xF00, 0xF0, 0xF, 0xF000],
+        I::Rgba4,
+        T::UnsignedShort4444,
+        Conversion::RotateLeft(4),
+    ),
+];
+
+/// The uncompressed `dxgiFormat`s of the DX10 header that WebGL2 supports, with the internal format,
+/// pixel type and conversion they are uploaded with.
+static DXGI_FORMATS: &[(u32, InternalFormat, PixelType, Conversion)] = &[
+    (2, I::Rgba32f, T::Float, Conversion::None),
+    (3, I::Rgba32ui, T::UnsignedInt, Conversion::None),
+    (4, I::Rgba32i, T::Int, Conversion::None),
+    (6, I::Rgb32f, T::Float, Conversion::None),
+    (7, I::Rgb32ui, T::UnsignedInt, Conversion::None),
+    (8, I::Rgb32i, T::Int, Conversion::None),
+    (10, I::Rgba16f, T::HalfFloat, Conversion::None),
+    (12, I::Rgba16ui, T::UnsignedShort, Conversion::None),
+    (14, I::Rgba16i, T::Short, Conversion::None),
+    (16, I::Rg32f, T::Float, Conversion::None),
+    (17, I::Rg32ui, T::UnsignedInt, Conversion::None),
+    (18, I::Rg32i, T::Int, Conversion::None),
+    (24, I::Rgb10A2, T::UnsignedInt2101010Rev, Conversion::None),
+    (25, I::Rgb10A2ui, T::UnsignedInt2101010Rev, Conversion::None),
+    (
+        26,
+        I::R11fG11fB10f,
+        T::UnsignedInt10f11f11fRev,
+        Conversion::None,
+    ),
+    (28, I::Rgba8, T::UnsignedByte, Conversion::None),
+    (29, I::Srgb8Alpha8, T::UnsignedByte, Conversion::None),
+    (30, I::Rgba8ui, T::UnsignedByte, Conversion::None),
+    (31, I::Rgba8Snorm, T::Byte, Conversion::None),
+    (32, I::Rgba8i, T::Byte, Conversion::None),
+    (34, I::Rg16f, T::HalfFloat, Conversion::None),
+    (36, I::Rg16ui, T::UnsignedShort, Conversion::None),
+    (38, I::Rg16i, T::Short, Conversion::None),
+    (41, I::R32f, T::Float, Conversion::None),
+    (42, I::R32ui, T::UnsignedInt, Conversion::None),
+    (43, I::R32i, T::Int, Conversion::None),
+    (49, I::Rg8, T::UnsignedByte, Conversion::None),
+    (50, I::Rg8ui, T::UnsignedByte, Conversion::None),
+    (51, I::Rg8Snorm, T::Byte, Conversion::None),
+    (52, I::Rg8i, T::Byte, Conversion::None),
+    (54, I::R16f, T::HalfFloat, Conversion::None),
+    (57, I::R16ui, T::UnsignedShort, Conversion::None),
+    (59, I::R16i, T::Short, Conversion::None),
+    (61, I::R8, T::UnsignedByte, Conversion::None),
+    (62, I::R8ui, T::UnsignedByte, Conversion::None),
+    (63, I::R8Snorm, T::Byte, Conversion::None),
+    (64, I::R8i, T::Byte, Conversion::None),
+    (67, I::Rgb9E5, T::UnsignedInt5999Rev, Conversion::None),
+    (85, I::Rgb565, T::UnsignedShort565, Conversion::None),
+    (
+        86,
+        I::Rgb5A1,
+        T::UnsignedShort5551,
+        Conversion::RotateLeft(1),
+    ),
+    (87, I::Rgba8, T::UnsignedByte, Conversion::SwapRedBlue(4)),
+    (88, I::Rgba8, T::UnsignedByte, Conversion::SwapRedBlueOpaque),
+    (
+        91,
+        I::Srgb8Alpha8,
+        T::UnsignedByte,
+        Conversion::SwapRedBlue(4),
+    ),
+    (
+        115,
+        I::Rgba4,
+        T::UnsignedShort4444,
+        Conversion::RotateLeft(4),
+    ),
+];
+
+/// The compressed `dxgiFormat`s of the DX10 header that have a WebGL extension.
+static DXGI_COMPRESSED_FORMATS: &[(u32, TextureCompression)] = &[
+    (71, C::RgbaDxt1),
+    (72, C::SrgbAlphaDxt1),
+    (74, C::RgbaDxt3),
+    (75, C::SrgbAlphaDxt3),
+    (77, C::RgbaDxt5),
+    (78, C::SrgbAlphaDxt5),
+    (80, C::RedRgtc1),
+    (81, C::SignedRedRgtc1),
+    (83, C::RedGreenRgtc2),
+    (84, C::SignedRedGreenRgtc2),
+    (95, C::RgbBptcUnsignedFloat),
+    (96, C::RgbBptcSignedFloat),
+    (98, C::RgbaBptcUnorm),
+    (99, C::SrgbAlphaBptcUnorm),
+];
+
+impl TextureData {
+    /// Parses a DDS file, with or without a DX10 header.
+    ///
+    /// Fails when the format or the dimensions are not supported by WebGL2, or when the file is shorter
+    /// than the mipmap levels its header describes. The rows of the images are tightly packed.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let file = include_bytes!("../fixtures/dds/dxt5_mipmaps.dds");
+    /// assert_eq!(
+    ///     TextureData::from_dds(b"KTX 11").err(),
+    ///     Some(TextureFileError::InvalidIdentifier { container: "DDS" })
+    /// );
+    /// assert_eq!(
+    ///     TextureData::from_dds(&file[..8]).err(),
+    ///     Some(TextureFileError::UnexpectedEnd { required: 12, actual: 8 })
+    /// );
+    ///
+    /// // the last mipmap level is cut off
+    /// let truncated = &file[..file.len() - 1];
+    /// match TextureData::from_dds(truncated) {
+    ///     Err(TextureFileError::UnexpectedEnd { actual, .. }) => assert_eq!(actual, truncated.len()),
+    ///     result => panic!("unexpected {:?}", result.map(|_| ())),
+    /// }
+    /// ```
+    pub fn from_dds(data: &[u8]) -> Result<TextureData, TextureFileError> {
+        if data.get(..4) != Some(&DDS_MAGIC[..]) {
+            return Err(TextureFileError::InvalidIdentifier { container: "DDS" });
+        }
+        let reader = Reader {
+            data,
+            big_endian: false,
+        };
+        let flags = reader.u32(8)?;
+        let caps2 = reader.u32(112)?;
+        let four_cc = reader.u32(84)?;
+        let levels = if flags & DDSD_MIPMAPCOUNT != 0 {
+            reader.u32(28)?.max(1)
+        } else {
+            1
+        };
+
+        let (format, conversion, depth, layers, faces, offset) = if four_cc == DX10_FOURCC {
+            let dxgi_format = reader.u32(128)?;
+            let (format, conversion) = dxgi_format_of(dxgi_format)
+                .ok_or(TextureFileError::UnsupportedDxgiFormat { dxgi_format })?;
+            let depth = if reader.u32(132)? == DX10_TEXTURE3D {
+                reader.u32(24)?
+            } else {
+                0
+            };
+            let faces = if reader.u32(136)? & DX10_TEXTURECUBE != 0 {
+                6
+            } else {
+                1
+            };
+            let array_size = reader.u32(140)?;
+            let layers = if array_size > 1 { array_size } else { 0 };
+            (format, conversion, depth, layers, faces, 148)
+        } else {
+            let pixel_flags = reader.u32(80)?;
+            let bit_count = reader.u32(88)?;
+            let masks = [
+                reader.u32(92)?,
+                reader.u32(96)?,
+                reader.u32(100)?,
+                reader.u32(104)?,
+            ];
+            let (format, conversion) = pixel_format_of(pixel_flags, four_cc, bit_count, masks)
+                .ok_or(TextureFileError::UnsupportedDdsFormat {
+                    flags: pixel_flags,
+                    four_cc,
+                    bit_count,
+                    masks,
+                })?;
+            let depth = if flags & DDSD_DEPTH != 0 && caps2 & DDSCAPS2_VOLUME != 0 {
+                reader.u32(24)?
+            } else {
+                0
+            };
+            // cube maps that do not have all faces are rejected as having an unsupported number of faces
+            let faces = if caps2 & DDSCAPS2_CUBEMAP != 0 {
+                (caps2 & DDSCAPS2_CUBEMAP_ALLFACES).count_ones()
+            } else {
+                1
+            };
+            (format, conversion, depth, 0, faces, 128)
+        };
+        let mut texture = TextureData::new(
+            format,
+            reader.u32(16)?,
+            reader.u32(12)?,
+            depth,
+            layers,
+            faces,
+            levels,
+            1,
+        )?;
+
+        // the file stores the mipmap chain of every layer and face, the levels gather the images of the
+        // same level from all of them
+        let images = (layers.max(1) * faces) as usize;
+        let lengths: Vec<usize> = (0..levels)
+            .map(|level| texture.image_length(level))
+            .collect();
+        let chain = lengths
+            .iter()
+            .fold(0usize, |chain, &length| chain.saturating_add(length));
+        // the file has to hold every image before the levels are allocated
+        reader.bytes(offset, chain.saturating_mul(images))?;
+        let mut level_offset = offset;
+        for &length in &lengths {
+            let mut level_data = Vec::with_capacity(length * images);
+            for image in 0..images {
+                level_data.extend_from_slice(reader.bytes(level_offset + image * chain, length)?);
+            }
+            conversion.apply(&mut level_data);
+            texture.push_level(level_data)?;
+            level_offset += length;
+        }
+        Ok(texture)
+    }
+}
+
+impl Conversion {
+    fn apply(self, data: &mut [u8]) {
+        match self {
+            Conversion::None => {}
+            Conversion::SwapRedBlue(size) => {
+                for pixel in data.chunks_mut(size) {
+                    pixel.swap(0, 2);
+                }
+            }
+            Conversion::SwapRedBlueOpaque => {
+                for pixel in data.chunks_mut(4) {
+                    pixel.swap(0, 2);
+                    pixel[3] = 0xFF;
+                }
+            }
+            Conversion::RotateLeft(bits) => {
+                for pixel in data.chunks_mut(2) {
+                    let value = u16::from_le_bytes([pixel[0], pixel[1]]).rotate_left(bits);
+                    pixel.copy_from_slice(&value.to_le_bytes());
+                }
+            }
+        }
+    }
+}
+
+/// Returns the uncompressed format of an internal format that is uploaded with the given pixel type.
+fn uncompressed(internal_format: InternalFormat, pixel_type: PixelType) -> TextureDataFormat {
+    TextureDataFormat::Uncompressed {
+        internal_format,
+        format: format_info(internal_format).format,
+        pixel_type,
+    }
+}
+
+/// Finds the format of a DDS file without a DX10 header from its pixel format.
+fn pixel_format_of(
+    flags: u32,
+    four_cc: u32,
+    bit_count: u32,
+    masks: [u32; 4],
+) -> Option<(TextureDataFormat, Conversion)> {
+    if flags & DDPF_FOURCC != 0 {
+        if four_cc == u32::from_le_bytes(*b"DXT1") {
+            let format = if flags & DDPF_ALPHAPIXELS != 0 {
+                C::RgbaDxt1
+            } else {
+                C::RgbDxt1
+            };
+            return Some((TextureDataFormat::Compressed(format), Conversion::None));
+        }
+        if let Some(&(_, format)) = FOURCC_FORMATS
+            .iter()
+            .find(|entry| u32::from_le_bytes(*entry.0) == four_cc)
+        {
+            return Some((TextureDataFormat::Compressed(format), Conversion::None));
+        }
+        return D3D_FORMATS.iter().find(|entry| entry.0 == four_cc).map(
+            |&(_, internal_format, pixel_type)| {
+                (uncompressed(internal_format, pixel_type), Conversion::None)
+            },
+        );
+    }
+    if flags & DDPF_RGB == 0 {
+        return None;
+    }
+    // the alpha mask is only meaningful when the pixels have alpha
+    let masks = if flags & DDPF_ALPHAPIXELS != 0 {
+        masks
+    } else {
+        [masks[0], masks[1], masks[2], 0]
+    };
+    MASK_FORMATS
+        .iter()
+        .find(|entry| entry.0 == bit_count && entry.1 == masks)
+        .map(|&(_, _, internal_format, pixel_type, conversion)| {
+            (uncompressed(internal_format, pixel_type), conversion)
+        })
+}
+
+/// Finds the format of a DDS file with a DX10 header.
+fn dxgi_format_of(dxgi_format: u32) -> Option<(TextureDataFormat, Conversion)> {
+    if let Some(&(_, internal_format, pixel_type, conversion)) =
+        DXGI_FORMATS.iter().find(|entry| entry.0 == dxgi_format)
+    {
+        return Some((uncompressed(internal_format, pixel_type), conversion));
+    }
+    DXGI_COMPRESSED_FORMATS
+        .iter()
+        .find(|entry| entry.0 == dxgi_format)
+        .map(|&(_, format)| (TextureDataFormat::Compressed(format), Conversion::None))
+}
@@ -0,0 +1,487 @@
+//! Draw calls that are checked against the buffers of a vertex array
+//!
+//! A `DrawCommand` collects the arguments of a draw call and issues the matching `drawArrays`,
+//! `drawElements`, `drawRangeElements` or instanced variant. Before the call is made the vertices, indices
+//! and instances it reads are compared with the current sizes of the buffers of the
+//! `WebGLRSVertexArrayObject`, a draw that would read past the end of a buffer fails with a `DrawError`
+//! instead of reaching WebGL.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let vertices = gl.create_buffer();
+//! let indices = gl.create_buffer();
+//! let vertex_array = gl.create_vertex_array();
+//! vertex_array
+//!     .attribute(0, &vertices, AttributeLayout::new(AttributeSize::Three, AttributeType::Float))
+//!     .unwrap()
+//!     .index_buffer(&indices, IndexType::UnsignedShort);
+//!
+//! // the recording backend reports empty buffers
+//! let error = vertex_array
+//!     .draw(Primitives::Triangles)
+//!     .vertices(0, 3)
+//!     .execute()
+//!     .unwrap_err();
+//! assert_eq!(
+//!     error,
+//!     DrawError::VertexOutOfRange { location: 0, required: 3, available: 0 }
+//! );
+//! assert_eq!(
+//!     vertex_array.draw(Primitives::Triangles).indices(0, 6).execute(),
+//!     Err(DrawError::IndexOutOfRange { required: 6, available: 0 })
+//! );
+//!
+//! // a vertex array without attributes can draw any number of vertices
+//! let empty = gl.create_vertex_array();
+//! empty.draw(Primitives::Points).vertices(0, 64).instances(4).execute().unwrap();
+//! assert_eq!(gl.calls().last().unwrap().to_string(), "drawArraysInstanced(Points, 0, 64, 4)");
+//! ```
+use backend::{GlBackend, WebGL2Backend};
+use glenum::{AttributeType, DataType, IndexType, Primitives};
+use std::error;
+use std::fmt;
+use vertex_array_object::{index_size, VertexArrayBindings, WebGLRSVertexArrayObject};
+
+/// Converts an index type to the type taken by `drawElements`.
+fn data_type(index_type: IndexType) -> DataType {
+    match index_type {
+        IndexType::UnsignedByte => DataType::U8,
+        IndexType::UnsignedShort => DataType::U16,
+        IndexType::UnsignedInt => DataType::U32,
+    }
+}
+
+/// Converts an index type to the type taken by the instanced and range draw calls.
+fn attribute_type(index_type: IndexType) -> AttributeType {
+    match index_type {
+        IndexType::UnsignedByte => AttributeType::UnsignedByte,
+        IndexType::UnsignedShort => AttributeType::UnsignedShort,
+        IndexType::UnsignedInt => AttributeType::UnsignedInt,
+    }
+}
+
+/// Which vertices a draw call processes.
+#[derive(Debug, Clone, Copy)]
+enum Range {
+    /// Consecutive vertices, drawn with `drawArrays`.
+    Vertices { first: u32, count: u32 },
+    /// Vertices read from the index buffer, drawn with `drawElements`.
+    Indices { first: u32, count: u32 },
+}
+
+/// The smallest and largest index of an indexed draw, only available on WebGL2.
+struct IndexRange<B> {
+    start: u32,
+    end: u32,
+    draw: fn(&B, Primitives, u32, u32, u32, AttributeType, i64),
+}
+
+/// The first instance of an instanced draw, only available on WebGL2 with an extension.
+struct BaseInstance<B> {
+    base_instance: u32,
+    supported: fn(&B) -> bool,
+    draw_arrays: fn(&B, Primitives, i32, u32, u32, u32),
+    #[allow(clippy::type_complexity)]
+    draw_elements: fn(&B, Primitives, u32, IndexType, i64, u32, i32, u32),
+}
+
+/// Builder for a draw call from a vertex array, created with `WebGLRSVertexArrayObject::draw`.
+///
+/// The vertex array is bound by `execute` and stays bound afterwards. The sizes of its buffers are read again
+/// with `WebGLRSVertexArrayObject::update_buffer_sizes` before the draw is checked, so data uploaded after
+/// the bindings were set is taken into account. The values stored in an index buffer are not known, so
+/// indexed draws only check the vertices they read when an `index_range` is given.
+pub struct DrawCommand<'a, 'ctx: 'a, B: 'ctx + GlBackend> {
+    vertex_array: &'a WebGLRSVertexArrayObject<'ctx, B>,
+    mode: Primitives,
+    range: Range,
+    index_type: Option<IndexType>,
+    instance_count: Option<u32>,
+    index_range: Option<IndexRange<B>>,
+    base_instance: Option<BaseInstance<B>>,
+}
+
+impl<'a, 'ctx, B: GlBackend> DrawCommand<'a, 'ctx, B> {
+    /// Creates a command that draws no vertices from a vertex array.
+    ///
+    /// # Arguments
+    /// * `vertex_array` - the vertex array to draw from.
+    /// * `mode` - the primitives to assemble from the vertices.
+    pub fn new(vertex_array: &'a WebGLRSVertexArrayObject<'ctx, B>, mode: Primitives) -> Self {
+        DrawCommand {
+            vertex_array,
+            mode,
+            range: Range::Vertices { first: 0, count: 0 },
+            index_type: None,
+            instance_count: None,
+            index_range: None,
+            base_instance: None,
+        }
+    }
+
+    /// Sets the primitives to assemble from the vertices.
+    pub fn mode(mut self, mode: Primitives) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Draws consecutive vertices, the command becomes a `drawArrays` call.
+    ///
+    /// # Arguments
+    /// * `first` - the first vertex to draw.
+    /// * `count` - the number of vertices to draw.
+    pub fn vertices(mut self, first: u32, count: u32) -> Self {
+        self.range = Range::Vertices { first, count };
+        self
+    }
+
+    /// Draws the vertices listed in the index buffer of the vertex array, the command becomes a
+    /// `drawElements` call.
+    ///
+    /// # Arguments
+    /// * `first` - the position of the first index in the index buffer, counted in indices.
+    /// * `count` - the number of indices to draw.
+    pub fn indices(mut self, first: u32, count: u32) -> Self {
+        self.range = Range::Indices { first, count };
+        self
+    }
+
+    /// Requires the index buffer to hold indices of the given type, only used by indexed draws.
+    ///
+    /// By default the type of the index buffer of the vertex array is used.
+    pub fn index_type(mut self, index_type: IndexType) -> Self {
+        self.index_type = Some(index_type);
+        self
+    }
+
+    /// Draws `count` instances, the command becomes an instanced draw call.
+    pub fn instances(mut self, count: u32) -> Self {
+        self.instance_count = Some(count);
+        self
+    }
+
+    /// Checks the command against the bindings of the vertex array and issues the draw call.
+    ///
+    /// Fails without drawing if the call would read past the end of a buffer, or if a base instance was
+    /// set on a context that does not support it.
+    ///
+    /// The buffers can be filled after the vertex array was configured, and respecified with less data:
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let vertices = gl.create_buffer();
+    /// let vertex_array = gl.create_vertex_array();
+    /// vertex_array
+    ///     .attribute(0, &vertices, AttributeLayout::new(AttributeSize::Two, AttributeType::Float))
+    ///     .unwrap();
+    ///
+    /// // configure first, upload later
+    /// vertices.bind(BufferKind::Array);
+    /// gl.buffer_data(BufferKind::Array, &[0.0f32; 12][..], DataHint::StaticDraw);
+    /// vertex_array.draw(Primitives::Triangles).vertices(0, 6).execute().unwrap();
+    /// assert_eq!(gl.calls().last().unwrap().to_string(), "drawArrays(Triangles, 0, 6)");
+    ///
+    /// // shrink, then draw
+    /// vertices.bind(BufferKind::Array);
+    /// gl.buffer_data(BufferKind::Array, &[0.0f32; 6][..], DataHint::StaticDraw);
+    /// assert_eq!(
+    ///     vertex_array.draw(Primitives::Triangles).vertices(0, 6).execute(),
+    ///     Err(DrawError::VertexOutOfRange { location: 0, required: 6, available: 3 })
+    /// );
+    /// ```
+    pub fn execute(self) -> Result<(), DrawError> {
+        let context = self.vertex_array.context;
+        if let Some(ref base_instance) = self.base_instance {
+            if !(base_instance.supported)(context) {
+                return Err(DrawError::BaseInstanceUnsupported);
+            }
+        }
+        self.vertex_array.update_buffer_sizes();
+        let bindings = self.vertex_array.bindings();
+        let instances = self.instance_count.unwrap_or(1);
+        let base_instance = self
+            .base_instance
+            .as_ref()
+            .map_or(0, |base_instance| base_instance.base_instance);
+        check_instances(&bindings, base_instance, instances)?;
+
+        match self.range {
+            Range::Vertices { first, count } => {
+                if count > 0 {
+                    check_vertices(&bindings, first.saturating_add(count))?;
+                }
+                drop(bindings);
+                match (self.base_instance, self.instance_count) {
+                    (Some(base_instance), _) => (base_instance.draw_arrays)(
+                        context,
+                        self.mode,
+                        first as i32,
+                        count,
+                        instances,
+                        base_instance.base_instance,
+                    ),
+                    (None, Some(instances)) => {
+                        context.draw_arrays_instanced(self.mode, first as i32, count, instances)
+                    }
+                    (None, None) => context.draw_arrays(self.mode, first, count),
+                }
+            }
+            Range::Indices { first, count } => {
+                let actual = match bindings.index_buffer {
+                    Some(ref index_buffer) => index_buffer.index_type,
+                    None => return Err(DrawError::MissingIndexBuffer),
+                };
+                if let Some(expected) = self.index_type {
+                    if expected != actual {
+                        return Err(DrawError::IndexTypeMismatch { expected, actual });
+                    }
+                }
+                let available = bindings
+                    .index_buffer
+                    .as_ref()
+                    .map_or(0, |index_buffer| index_buffer.index_count());
+                let required = first.saturating_add(count);
+                if required > available {
+                    return Err(DrawError::IndexOutOfRange {
+                        required,
+                        available,
+                    });
+                }
+                match self.index_range {
+                    Some(ref range) if count > 0 => {
+                        if range.end < range.start {
+                            return Err(DrawError::InvalidIndexRange {
+                                start: range.start,
+                                end: range.end,
+                            });
+                        }
+                        check_vertices(&bindings, range.end.saturating_add(1))?;
+                    }
+                    _ => {}
+                }
+                drop(bindings);
+
+                let offset = i64::from(first) * i64::from(index_size(actual));
+                match (self.base_instance, self.instance_count, self.index_range) {
+                    (Some(base_instance), _, _) => (base_instance.draw_elements)(
+                        context,
+                        self.mode,
+                        count,
+                        actual,
+                        offset,
+                        instances,
+                        0,
+                        base_instance.base_instance,
+                    ),
+                    (None, Some(instances), _) => context.draw_elements_instanced(
+                        self.mode,
+                        count,
+                        attribute_type(actual),
+                        offset,
+                        instances,
+                    ),
+                    (None, None, Some(range)) => (range.draw)(
+                        context,
+                        self.mode,
+                        range.start,
+                        range.end,
+                        count,
+                        attribute_type(actual),
+                        offset,
+                    ),
+                    (None, None, None) => {
+                        context.draw_elements(self.mode, count, data_type(actual), offset)
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'ctx, B: WebGL2Backend> DrawCommand<'a, 'ctx, B> {
+    /// Promises that all indices of an indexed draw lie between `start` and `end` inclusive, the command
+    /// becomes a `drawRangeElements` call.
+    ///
+    /// The range is also used to check that the indexed vertices lie within the attribute buffers. Instanced
+    /// draws are checked against the range but do not pass it to WebGL.
+    ///
+    /// # Arguments
+    /// * `start` - the smallest index.
+    /// * `end` - the largest index.
+    pub fn index_range(mut self, start: u32, end: u32) -> Self {
+        self.index_range = Some(IndexRange {
+            start,
+            end,
+            draw: B::draw_range_elements,
+        });
+        self
+    }
+
+    /// Starts numbering the instances at `base_instance`, instanced attributes skip the elements of the
+    /// instances before it.
+    ///
+    /// Requires the `WEBGL_draw_instanced_base_vertex_base_instance` extension, `execute` fails with
+    /// `DrawError::BaseInstanceUnsupported` when the context does not support it.
+    pub fn base_instance(mut self, base_instance: u32) -> Self {
+        self.base_instance = Some(BaseInstance {
+            base_instance,
+            supported: B::supports_base_instance,
+            draw_arrays: B::draw_arrays_instanced_base_instance,
+            draw_elements: B::draw_elements_instanced_base_vertex_base_instance,
+        });
+        self
+    }
+}
+
+/// Checks that every attribute that advances per vertex holds at least `required` elements.
+fn check_vertices<Handle>(
+    bindings: &VertexArrayBindings<Handle>,
+    required: u32,
+) -> Result<(), DrawError> {
+    for attribute in bindings
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.divisor == 0)
+    {
+        let available = attribute.layout.element_count(attribute.buffer_size);
+        if required > available {
+            return Err(DrawError::VertexOutOfRange {
+                location: attribute.location,
+                required,
+                available,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every instanced attribute holds the elements read by `count` instances starting at
+/// `base_instance`.
+fn check_instances<Handle>(
+    bindings: &VertexArrayBindings<Handle>,
+    base_instance: u32,
+    count: u32,
+) -> Result<(), DrawError> {
+    if count == 0 {
+        return Ok(());
+    }
+    for attribute in bindings
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.divisor != 0)
+    {
+        let required = base_instance.saturating_add(count.div_ceil(attribute.divisor));
+        let available = attribute.layout.element_count(attribute.buffer_size);
+        if required > available {
+            return Err(DrawError::InstanceOutOfRange {
+                location: attribute.location,
+                required,
+                available,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Error returned by `DrawCommand` when a draw call would read outside the buffers of its vertex array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawError {
+    /// An indexed draw was requested but the vertex array has no index buffer.
+    MissingIndexBuffer,
+    /// The indices were expected to be of a different type than the index buffer of the vertex array.
+    IndexTypeMismatch {
+        /// The type the draw call expected.
+        expected: IndexType,
+        /// The type of the index buffer of the vertex array.
+        actual: IndexType,
+    },
+    /// The draw call reads more indices than the index buffer holds.
+    IndexOutOfRange {
+        /// The number of indices the draw call needs.
+        required: u32,
+        /// The number of indices in the index buffer.
+        available: u32,
+    },
+    /// The end of an index range lies before its start.
+    InvalidIndexRange {
+        /// The smallest index of the range.
+        start: u32,
+        /// The largest index of the range.
+        end: u32,
+    },
+    /// An attribute that advances every vertex reads past the end of its buffer.
+    VertexOutOfRange {
+        /// Location of the attribute.
+        location: u32,
+        /// The number of elements the draw call needs.
+        required: u32,
+        /// The number of elements in the buffer.
+        available: u32,
+    },
+    /// An instanced attribute reads past the end of its buffer.
+    InstanceOutOfRange {
+        /// Location of the attribute.
+        location: u32,
+        /// The number of elements the draw call needs.
+        required: u32,
+        /// The number of elements in the buffer.
+        available: u32,
+    },
+    /// A base instance was set but the context does not support the
+    /// `WEBGL_draw_instanced_base_vertex_base_instance` extension.
+    BaseInstanceUnsupported,
+}
+
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DrawError::MissingIndexBuffer => {
+                f.write_str("the vertex array has no index buffer to draw from")
+            }
+            DrawError::IndexTypeMismatch { expected, actual } => write!(
+                f,
+                "expected {:?} indices but the index buffer holds {:?} indices",
+                expected, actual
+            ),
+            DrawError::IndexOutOfRange {
+                required,
+                available,
+            } => write!(
+                f,
+                "the draw reads {} indices but the index buffer holds only {}",
+                required, available
+            ),
+            DrawError::InvalidIndexRange { start, end } => {
+                write!(f, "the index range {}..={} is empty", start, end)
+            }
+            DrawError::VertexOutOfRange {
+                location,
+                required,
+                available,
+            } => write!(
+                f,
+                "the draw reads {} vertices but the buffer of attribute {} holds only {}",
+                required, location, available
+            ),
+            DrawError::InstanceOutOfRange {
+                location,
+                required,
+                available,
+            } => write!(
+                f,
+                "the draw reads {} elements but the buffer of instanced attribute {} holds only {}",
+                required, location, available
+            ),
+            DrawError::BaseInstanceUnsupported => f.write_str(
+                "drawing from a base instance requires WEBGL_draw_instanced_base_vertex_base_instance",
+            ),
+        }
+    }
+}
+
+impl error::Error for DrawError {}
@@ -0,0 +1,867 @@
+//! The WebGL error type and the checked mode of a context
+//!
+//! WebGL reports most errors by setting a flag that has to be polled with `getError`, which makes it hard
+//! to find out which call caused it. `ContextBackend::checked` returns a `Checked` view on the context
+//! whose methods poll the error flag right after the call and return a `Result` naming the operation.
+//!
+//! The polling only happens in debug builds, in release builds the checked methods compile down to the
+//! plain calls and always return `Ok` (unless the call itself throws a javascript exception).
+//!
+//! The object wrappers are checked the same way through their `checked` method:
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let buffer = gl.create_buffer();
+//! assert!(buffer.checked().bind(BufferKind::Array).is_ok());
+//!
+//! gl.push_error(Error::InvalidOperation);
+//! let result = buffer.checked().bind(BufferKind::Array);
+//! if CHECKS_ENABLED {
+//!     match result {
+//!         Err(WebGLError::Gl {
+//!             operation,
+//!             error: Error::InvalidOperation,
+//!         }) => assert_eq!(operation, "buffer.bind"),
+//!         other => panic!("unexpected result {:?}", other),
+//!     }
+//! }
+//! ```
+//!
+//! In release builds the check is skipped and the error stays queued until `get_error` is called.
+//! `CHECKS_ENABLED` tells which of the two the crate was built with:
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! gl.push_error(Error::InvalidEnum);
+//! let result = gl.checked().clear_color(0.0, 0.0, 0.0, 1.0);
+//! if CHECKS_ENABLED {
+//!     assert!(result.is_err());
+//!     assert!(match gl.get_error() {
+//!         Error::NoError => true,
+//!         _ => false,
+//!     });
+//! } else {
+//!     assert!(result.is_ok());
+//!     assert!(match gl.get_error() {
+//!         Error::InvalidEnum => true,
+//!         _ => false,
+//!     });
+//! }
+//! ```
+use data_view::{Buffer, BufferMut, Image};
+use formats::FormatError;
+use glenum::*;
+use image_source::TexImageSource;
+use rendering_context::{
+    ContextBackend, DrawBuffersBackend, InstancingBackend, WebGL2ContextBackend,
+    WebGLRSShaderPrecisionFormat,
+};
+use std::error;
+use std::fmt;
+use vertex_array_object::VertexArrayError;
+use wasm_bindgen::JsValue;
+
+/// Whether `Checked` and `CheckedObject` poll the error flag, which is only the case in debug builds.
+pub const CHECKS_ENABLED: bool = cfg!(debug_assertions);
+
+/// Error produced by a call made through a `Checked` context, or by a context method that validates its
+/// arguments.
+#[derive(Debug, Clone)]
+pub enum WebGLError {
+    /// `getError` returned an error after the operation.
+    Gl {
+        /// Name of the method that caused the error.
+        operation: &'static str,
+        /// The error reported by WebGL.
+        error: Error,
+    },
+    /// The operation threw a javascript exception.
+    Exception {
+        /// Name of the method that threw the exception.
+        operation: &'static str,
+        /// The message of the exception.
+        message: String,
+    },
+    /// The arguments were rejected before the call reached WebGL.
+    Format {
+        /// Name of the method that rejected its arguments.
+        operation: &'static str,
+        /// Why the arguments were rejected.
+        error: FormatError,
+    },
+    /// A byte range does not lie within the source data or does not start and end on one of its elements.
+    Range {
+        /// Name of the method that rejected the range.
+        operation: &'static str,
+        /// Byte offset of the range.
+        offset: u32,
+        /// Length of the range in bytes.
+        length: u32,
+        /// Size of the data in bytes.
+        size: usize,
+    },
+    /// A value was assigned to a uniform of a type it can not be assigned to.
+    UniformType {
+        /// Name of the method that rejected the value.
+        operation: &'static str,
+        /// GLSL name of the type of the value.
+        value: &'static str,
+        /// Type of the uniform.
+        uniform_type: UniformType,
+    },
+    /// An attribute of a vertex array could not be pointed at a buffer.
+    VertexArray {
+        /// Name of the method that rejected the attribute.
+        operation: &'static str,
+        /// Why the attribute was rejected.
+        error: VertexArrayError,
+    },
+}
+
+impl WebGLError {
+    /// Returns the name of the method that failed.
+    pub fn operation(&self) -> &'static str {
+        match *self {
+            WebGLError::Gl { operation, .. } => operation,
+            WebGLError::Exception { operation, .. } => operation,
+            WebGLError::Format { operation, .. } => operation,
+            WebGLError::Range { operation, .. } => operation,
+            WebGLError::UniformType { operation, .. } => operation,
+            WebGLError::VertexArray { operation, .. } => operation,
+        }
+    }
+
+    /// Wraps a javascript exception thrown by an operation.
+    pub(crate) fn exception(operation: &'static str, exception: JsValue) -> WebGLError {
+        WebGLError::Exception {
+            operation,
+            message: exception
+                .as_string()
+                .unwrap_or_else(|| format!("{:?}", exception)),
+        }
+    }
+}
+
+/// Returns the name of the WebGL constant for an error.
+fn error_name(error: Error) -> &'static str {
+    match error {
+        Error::NoError => "NO_ERROR",
+        Error::InvalidEnum => "INVALID_ENUM",
+        Error::InvalidValue => "INVALID_VALUE",
+        Error::InvalidOperation => "INVALID_OPERATION",
+        Error::InvalidFramebufferOperation => "INVALID_FRAMEBUFFER_OPERATION",
+        Error::OutOfMemory => "OUT_OF_MEMORY",
+        Error::ContextLostWebgl => "CONTEXT_LOST_WEBGL",
+    }
+}
+
+impl fmt::Display for WebGLError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WebGLError::Gl { operation, error } => {
+                write!(f, "{} failed with {}", operation, error_name(error))
+            }
+            WebGLError::Exception {
+                operation,
+                ref message,
+            } => write!(f, "{} threw an exception: {}", operation, message),
+            WebGLError::Format {
+                operation,
+                ref error,
+            } => write!(f, "{} rejected its arguments: {}", operation, error),
+            WebGLError::Range {
+                operation,
+                offset,
+                length,
+                size,
+            } => write!(
+                f,
+                "{} can not read {} bytes at offset {} from {} bytes of data",
+                operation, length, offset, size
+            ),
+            WebGLError::UniformType {
+                operation,
+                value,
+                uniform_type,
+            } => write!(
+                f,
+                "{} can not assign a value of type {} to a uniform of type {:?}",
+                operation, value, uniform_type
+            ),
+            WebGLError::VertexArray {
+                operation,
+                ref error,
+            } => write!(f, "{} rejected an attribute: {}", operation, error),
+        }
+    }
+}
+
+impl error::Error for WebGLError {}
+
+/// View on a context where every call is followed by a check of the WebGL error flag.
+///
+/// Created with `ContextBackend::checked`. The object wrappers (`WebGLRSBuffer`, `WebGLRSTexture`, ...) have
+/// their own checked view, see `CheckedObject`. Anything else can be checked by running it through `call`.
+pub struct Checked<'ctx, B: 'ctx + ContextBackend> {
+    context: &'ctx B,
+}
+
+impl<'ctx, B: ContextBackend> Clone for Checked<'ctx, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'ctx, B: ContextBackend> Copy for Checked<'ctx, B> {}
+
+impl<'ctx, B: ContextBackend> Checked<'ctx, B> {
+    pub(crate) fn new(context: &'ctx B) -> Checked<'ctx, B> {
+        Checked { context }
+    }
+
+    /// Returns the unchecked context.
+    pub fn unchecked(&self) -> &'ctx B {
+        self.context
+    }
+
+    /// Runs `f` and checks the error flag afterwards.
+    ///
+    /// # Arguments
+    /// * `operation` - name of the operation that is reported when an error occurs.
+    /// * `f` - closure performing the calls to check, e.g. `|| buffer.bind(BufferKind::Array)`.
+    pub fn call<T, F: FnOnce() -> T>(
+        &self,
+        operation: &'static str,
+        f: F,
+    ) -> Result<T, WebGLError> {
+        let value = f();
+        self.check(operation, value)
+    }
+
+    /// Drains the error flag, returns the first error if there was one.
+    ///
+    /// WebGL can record multiple errors at once, the remaining ones are discarded so they are not reported
+    /// for the next operation.
+    #[cfg(debug_assertions)]
+    fn check<T>(&self, operation: &'static str, value: T) -> Result<T, WebGLError> {
+        let mut first = None;
+        loop {
+            match self.context.get_error() {
+                Error::NoError => break,
+                Error::ContextLostWebgl => {
+                    first = first.or(Some(Error::ContextLostWebgl));
+                    break;
+                }
+                error => first = first.or(Some(error)),
+            }
+        }
+        match first {
+            Some(error) => Err(WebGLError::Gl { operation, error }),
+            None => Ok(value),
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[inline(always)]
+    fn check<T>(&self, _operation: &'static str, value: T) -> Result<T, WebGLError> {
+        Ok(value)
+    }
+
+    fn check_result(
+        &self,
+        operation: &'static str,
+        result: Result<(), JsValue>,
+    ) -> Result<(), WebGLError> {
+        match result {
+            Ok(()) => self.check(operation, ()),
+            Err(exception) => Err(WebGLError::exception(operation, exception)),
+        }
+    }
+
+    checked_methods! {
+        context;
+        fn drawing_buffer_width() -> u32;
+        fn drawing_buffer_height() -> u32;
+        fn scissor(x: i32, y: i32, width: u32, height: u32);
+        fn viewport(x: i32, y: i32, width: u32, height: u32);
+        fn active_texture(texture: TextureUnit);
+        fn blend_color(red: f32, green: f32, blue: f32, alpha: f32);
+        fn blend_equation(mode: BlendEquation);
+        fn blend_equation_separate(mode_rgb: BlendEquation, mode_alpha: BlendEquation);
+        fn blend_func(sfactor: BlendMode, dfactor: BlendMode);
+        fn blend_func_separate(
+            src_rgb: BlendMode,
+            dst_rgb: BlendMode,
+            src_alpha: BlendMode,
+            dst_alpha: BlendMode,
+        );
+        fn clear_color(red: f32, green: f32, blue: f32, alpha: f32);
+        fn clear_depth(depth: f32);
+        fn clear_stencil(s: i32);
+        fn color_mask(red: bool, green: bool, blue: bool, alpha: bool);
+        fn cull_face(mode: Culling);
+        fn depth_func(func: DepthTest);
+        fn depth_mask(flag: bool);
+        fn depth_range(z_near: f32, z_far: f32);
+        fn disable(cap: Flag);
+        fn enable(cap: Flag);
+        fn front_face(mode: FrontFaceDirection);
+        fn hint(target: HintTarget, mode: HintMode);
+        fn is_enabled(cap: Flag) -> bool;
+        fn line_width(width: f32);
+        fn pixel_storei(pname: PixelStorageMode, param: i32);
+        fn polygon_offset(factor: f32, units: f32);
+        fn sample_coverage(value: f32, invert: bool);
+        fn stencil_func(func: StencilTest, reference: i32, mask: u32);
+        fn stencil_func_separate(face: Culling, func: StencilTest, reference: i32, mask: u32);
+        fn stencil_mask(mask: u32);
+        fn stencil_mask_separate(face: Culling, mask: u32);
+        fn stencil_op(fail: StencilAction, zfail: StencilAction, zpass: StencilAction);
+        fn stencil_op_separate(
+            face: Culling,
+            fail: StencilAction,
+            zfail: StencilAction,
+            zpass: StencilAction,
+        );
+        fn check_framebuffer_status(target: FramebufferKind) -> FramebufferStatus;
+        #[validated]
+        fn renderbuffer_storage(
+            target: RenderbufferKind,
+            internal_format: InternalFormat,
+            width: u32,
+            height: u32,
+        );
+        fn generate_mipmap(target: TextureKind);
+        fn tex_parameter_f(target: TextureKind, pname: TextureParameter, param: f32);
+        fn tex_parameter_i(target: TextureKind, pname: TextureParameter, param: i32);
+        fn get_shader_precision_format(
+            shader_type: ShaderKind,
+            precision_type: ShaderPrecision,
+        ) -> WebGLRSShaderPrecisionFormat;
+        fn disable_vertex_attrib_array(index: u32);
+        fn enable_vertex_attrib_array(index: u32);
+        fn get_vertex_attrib_offset(index: u32, pname: VertexAttrib) -> i64;
+        fn vertex_attrib_1f(index: u32, v0: f32);
+        fn vertex_attrib_2f(index: u32, v0: f32, v1: f32);
+        fn vertex_attrib_3f(index: u32, v0: f32, v1: f32, v2: f32);
+        fn vertex_attrib_4f(index: u32, v0: f32, v1: f32, v2: f32, v3: f32);
+        fn vertex_attrib_1fv(index: u32, value: Vec<f32>);
+        fn vertex_attrib_2fv(index: u32, value: Vec<f32>);
+        fn vertex_attrib_3fv(index: u32, value: Vec<f32>);
+        fn vertex_attrib_4fv(index: u32, value: Vec<f32>);
+        fn vertex_attrib_pointer(
+            index: u32,
+            size: AttributeSize,
+            attribute_type: AttributeType,
+            normalized: bool,
+            stride: u8,
+            offset: i32,
+        );
+        fn clear(mask: BufferBit);
+        fn draw_arrays(mode: Primitives, first: u32, count: u32);
+        fn draw_elements(mode: Primitives, count: u32, data_type: DataType, offset: i64);
+        fn finish();
+        fn flush();
+        fn buffer_size(target: BufferKind) -> i32;
+        fn buffer_usage(target: BufferKind) -> DataHint;
+        fn renderbuffer_internal_format(target: RenderbufferKind) -> InternalFormat;
+        fn renderbuffer_width(target: RenderbufferKind) -> i32;
+        fn renderbuffer_height(target: RenderbufferKind) -> i32;
+        fn renderbuffer_green_size(target: RenderbufferKind) -> i32;
+        fn renderbuffer_blue_size(target: RenderbufferKind) -> i32;
+        fn renderbuffer_red_size(target: RenderbufferKind) -> i32;
+        fn renderbuffer_alpha_size(target: RenderbufferKind) -> i32;
+        fn renderbuffer_depth_size(target: RenderbufferKind) -> i32;
+        fn renderbuffer_stencil_size(target: RenderbufferKind) -> i32;
+        fn texture_mag_filter(target: TextureKind) -> TextureMagFilter;
+        fn texture_min_filter(target: TextureKind) -> TextureMinFilter;
+        fn texture_wrap_s(target: TextureKind) -> TextureWrap;
+        fn texture_wrap_t(target: TextureKind) -> TextureWrap;
+        fn buffer_data[T: Buffer + ?Sized](target: BufferKind, src_data: &T, usage: DataHint);
+        fn buffer_sub_data[T: Buffer + ?Sized](target: BufferKind, offset: i64, src_data: &T);
+        #[validated]
+        fn compressed_tex_image_2d(
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: TextureCompression,
+            width: u32,
+            height: u32,
+            data: &[u8],
+        );
+        #[validated]
+        fn tex_image_2d_source[S: TexImageSource + ?Sized](
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            format: PixelFormat,
+            pixel_type: PixelType,
+            source: &S,
+        );
+    }
+
+    /// Checked version of `copy_tex_image_2d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: PixelCopyFormat,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        border: u32,
+    ) -> Result<(), WebGLError> {
+        self.context
+            .copy_tex_image_2d(target, level, internalformat, x, y, width, height, border);
+        self.check("copy_tex_image_2d", ())
+    }
+
+    /// Checked version of `copy_tex_sub_image_2d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: i32,
+        yoffset: i32,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) -> Result<(), WebGLError> {
+        self.context
+            .copy_tex_sub_image_2d(target, level, xoffset, yoffset, x, y, width, height);
+        self.check("copy_tex_sub_image_2d", ())
+    }
+
+    /// Checked version of `tex_image_2d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tex_image_2d<I: Image + ?Sized>(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        src_data: &I,
+    ) -> Result<(), WebGLError> {
+        let result = self.context.tex_image_2d(
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            format,
+            pixel_type,
+            src_data,
+        );
+        result?;
+        self.check("tex_image_2d", ())
+    }
+
+    /// Checked version of `tex_sub_image_2d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tex_sub_image_2d<I: Image + ?Sized>(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &I,
+    ) -> Result<(), WebGLError> {
+        let result = self.context.tex_sub_image_2d(
+            target, level, xoffset, yoffset, width, height, format, pixel_type, pixels,
+        );
+        result?;
+        self.check("tex_sub_image_2d", ())
+    }
+
+    /// Checked version of `compressed_tex_sub_image_2d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compressed_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        self.context.compressed_tex_sub_image_2d(
+            target, level, xoffset, yoffset, width, height, format, data,
+        )?;
+        self.check("compressed_tex_sub_image_2d", ())
+    }
+
+    /// Checked version of `tex_sub_image_2d_source`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tex_sub_image_2d_source<S: TexImageSource + ?Sized>(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &S,
+    ) -> Result<(), WebGLError> {
+        self.context
+            .tex_sub_image_2d_source(target, level, xoffset, yoffset, format, pixel_type, source)?;
+        self.check("tex_sub_image_2d_source", ())
+    }
+
+    /// Checked version of `read_pixels`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn read_pixels<I: Image + BufferMut + ?Sized>(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelReadFormat,
+        pixel_type: PixelType,
+        pixels: &mut I,
+    ) -> Result<(), WebGLError> {
+        let result = self
+            .context
+            .read_pixels(x, y, width, height, format, pixel_type, pixels);
+        self.check_result("read_pixels", result)
+    }
+}
+
+impl<'ctx, B: WebGL2ContextBackend> Checked<'ctx, B> {
+    checked_methods! {
+        context;
+        fn copy_buffer_sub_data(
+            read_target: BufferKind,
+            write_target: BufferKind,
+            read_offset: i64,
+            write_offset: i64,
+            size: u32,
+        );
+        fn invalidate_framebuffer(target: FramebufferKind, attachments: &[Attachment]);
+        fn read_buffer(src: ColorBuffer);
+        fn get_internal_format_parameter(
+            target: RenderbufferKind,
+            internal_format: InternalFormat,
+            pname: InformationType,
+        ) -> Vec<i32>;
+        #[validated]
+        fn renderbuffer_storage_multisample(
+            target: RenderbufferKind,
+            samples: u32,
+            internal_format: InternalFormat,
+            width: u32,
+            height: u32,
+        );
+        #[validated]
+        fn tex_storage_2d(
+            target: Texture2DKind,
+            levels: u32,
+            internal_format: InternalFormat,
+            width: u32,
+            height: u32,
+        );
+        #[validated]
+        fn tex_storage_3d(
+            target: Texture3DKind,
+            levels: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            depth: u32,
+        );
+        fn vertex_attrib_i_4i(index: u32, v0: i32, v1: i32, v2: i32, v3: i32);
+        fn vertex_attrib_i_4ui(index: u32, v0: u32, v1: u32, v2: u32, v3: u32);
+        fn vertex_attrib_i_4iv(index: u32, value: Vec<i32>);
+        fn vertex_attrib_i_4uiv(index: u32, value: Vec<u32>);
+        fn vertex_attrib_i_pointer(
+            index: u32,
+            size: AttributeSize,
+            data_type: AttributeType,
+            stride: u32,
+            offset: i64,
+        );
+        fn draw_range_elements(
+            mode: Primitives,
+            start: u32,
+            end: u32,
+            count: u32,
+            data_type: AttributeType,
+            offset: i64,
+        );
+        fn clear_buffer_fv(buffer: BufferBit, drawbuffer: i32, values: Vec<u8>, src_offset: u32);
+        fn clear_buffer_iv(buffer: BufferBit, drawbuffer: i32, values: Vec<u8>, src_offset: u32);
+        fn clear_buffer_uiv(buffer: BufferBit, drawbuffer: i32, values: Vec<u8>, src_offset: u32);
+        fn clear_buffer_fi(buffer: BufferBit, drawbuffer: i32, depth: f32, stencil: i32);
+        fn begin_transform_feedback(primitive_mode: TransformFeedbackMode);
+        fn end_transform_feedback();
+        fn pause_transform_feedback();
+        fn resume_transform_feedback();
+        fn end_query(target: QueryTarget);
+        fn renderbuffer_samples(target: RenderbufferKind) -> i32;
+        fn texture_base_level(target: TextureKind) -> i32;
+        fn texture_compare_func(target: TextureKind) -> DepthTest;
+        fn texture_compare_mode(target: TextureKind) -> CompareMode;
+        fn texture_immutable_format(target: TextureKind) -> bool;
+        fn texture_immutable_levels(target: TextureKind) -> u32;
+        fn texture_max_level(target: TextureKind) -> i32;
+        fn texture_max_lod(target: TextureKind) -> f32;
+        fn texture_min_lod(target: TextureKind) -> f32;
+        fn texture_wrap_r(target: TextureKind) -> TextureWrap;
+        #[throwing]
+        fn get_buffer_sub_data[T: BufferMut + ?Sized](
+            target: BufferKind,
+            src_offset: i64,
+            dst_data: &mut T,
+            dst_offset: u32,
+            length: u32,
+        );
+        #[validated]
+        fn buffer_data_range[T: Buffer + ?Sized](
+            target: BufferKind,
+            src_data: &T,
+            usage: DataHint,
+            src_byte_offset: u32,
+            byte_length: u32,
+        );
+        #[validated]
+        fn buffer_sub_data_range[T: Buffer + ?Sized](
+            target: BufferKind,
+            dst_offset: i64,
+            src_data: &T,
+            src_byte_offset: u32,
+            byte_length: u32,
+        );
+    }
+
+    /// Checked version of `blit_framebuffer`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn blit_framebuffer(
+        &self,
+        src_x0: i32,
+        src_y0: i32,
+        src_x1: i32,
+        src_y1: i32,
+        dst_x0: i32,
+        dst_y0: i32,
+        dst_x1: i32,
+        dst_y1: i32,
+        mask: BufferBit,
+        filter: TextureMagFilter,
+    ) -> Result<(), WebGLError> {
+        self.context.blit_framebuffer(
+            src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+        );
+        self.check("blit_framebuffer", ())
+    }
+
+    /// Checked version of `tex_image_3d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    ) -> Result<(), WebGLError> {
+        self.context.tex_image_3d(
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            depth,
+            border,
+            format,
+            data_type,
+            src_data,
+            src_offset,
+        );
+        self.check("tex_image_3d", ())
+    }
+
+    /// Checked version of `tex_sub_image_3d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    ) -> Result<(), WebGLError> {
+        self.context.tex_sub_image_3d(
+            target, level, xoffset, yoffset, zoffset, width, height, depth, format, data_type,
+            src_data, src_offset,
+        );
+        self.check("tex_sub_image_3d", ())
+    }
+
+    /// Checked version of `compressed_tex_image_3d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compressed_tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        depth: u32,
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        self.context.compressed_tex_image_3d(
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            depth,
+            data,
+        )?;
+        self.check("compressed_tex_image_3d", ())
+    }
+
+    /// Checked version of `compressed_tex_sub_image_3d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compressed_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        self.context.compressed_tex_sub_image_3d(
+            target, level, xoffset, yoffset, zoffset, width, height, depth, format, data,
+        )?;
+        self.check("compressed_tex_sub_image_3d", ())
+    }
+
+    /// Checked version of `tex_image_3d_source`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tex_image_3d_source<S: TexImageSource + ?Sized>(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &S,
+    ) -> Result<(), WebGLError> {
+        self.context.tex_image_3d_source(
+            target,
+            level,
+            internalformat,
+            depth,
+            format,
+            pixel_type,
+            source,
+        )?;
+        self.check("tex_image_3d_source", ())
+    }
+
+    /// Checked version of `copy_tex_sub_image_3d`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Result<(), WebGLError> {
+        self.context.copy_tex_sub_image_3d(
+            target, level, xoffset, yoffset, zoffset, x, y, width, height,
+        );
+        self.check("copy_tex_sub_image_3d", ())
+    }
+}
+
+impl<'ctx, B: ContextBackend + InstancingBackend> Checked<'ctx, B> {
+    checked_methods! {
+        context;
+        fn vertex_attrib_divisor(index: u32, divisor: u32);
+        fn draw_arrays_instanced(mode: Primitives, first: i32, count: u32, instance_count: u32);
+        fn draw_elements_instanced(
+            mode: Primitives,
+            count: u32,
+            data_type: AttributeType,
+            offset: i64,
+            instance_count: u32,
+        );
+    }
+}
+
+impl<'ctx, B: ContextBackend + DrawBuffersBackend> Checked<'ctx, B> {
+    checked_methods! {
+        context;
+        fn draw_buffers(buffers: &[ColorBuffer]);
+    }
+}
+
+/// View on an object wrapper where every call is followed by a check of the WebGL error flag.
+///
+/// Created with the `checked` method of the wrappers, e.g. `WebGLRSBuffer::checked`. The errors name the
+/// operation after the wrapper and the method, e.g. `"buffer.bind"`. Like `Checked`, the flag is only polled
+/// in debug builds.
+pub struct CheckedObject<'a, 'ctx: 'a, B: 'ctx + ContextBackend, T: 'a> {
+    pub(crate) object: &'a T,
+    checked: Checked<'ctx, B>,
+}
+
+impl<'a, 'ctx, B: ContextBackend, T> CheckedObject<'a, 'ctx, B, T> {
+    pub(crate) fn new(context: &'ctx B, object: &'a T) -> CheckedObject<'a, 'ctx, B, T> {
+        CheckedObject {
+            object,
+            checked: Checked::new(context),
+        }
+    }
+
+    /// Returns the unchecked object.
+    pub fn unchecked(&self) -> &'a T {
+        self.object
+    }
+
+    /// Checks the error flag after a call to the object, see `Checked::check`.
+    pub(crate) fn check<V>(&self, operation: &'static str, value: V) -> Result<V, WebGLError> {
+        self.checked.check(operation, value)
+    }
+}
@@ -0,0 +1,926 @@
+//! Table of the internal formats of textures and renderbuffers
+//!
+//! Describes, for every `InternalFormat`, the (format, type) combinations texImage2D accepts and what the
+//! format can be used for, following the tables of the WebGL2 and OpenGL ES 3.0 specifications. The
+//! context methods that allocate storage validate their arguments against this table so that invalid
+//! combinations are rejected before they reach the driver. Pixel uploads are also checked against the
+//! element type and length of their data. Compressed formats have a separate table that gives their block
+//! size, from which the exact length of a compressed image follows.
+//!
+//! ```
+//! use webgl_rs::*;
+//! use webgl_rs::formats::*;
+//!
+//! let info = format_info(InternalFormat::Rgba16f);
+//! assert_eq!(info.bytes_per_texel, 8);
+//! assert!(info.filterable && !info.color_renderable);
+//!
+//! assert!(validate_tex_image(InternalFormat::Rgba8, PixelFormat::Rgba, PixelType::UnsignedByte).is_ok());
+//! assert!(validate_tex_image(InternalFormat::Rgba8, PixelFormat::Rgba, PixelType::Float).is_err());
+//! assert!(validate_renderbuffer_storage(InternalFormat::Rgb9E5).is_err());
+//!
+//! let gl = RecordingBackend::new();
+//! let result = gl.renderbuffer_storage(RenderbufferKind::Renderbuffer, InternalFormat::Rgb9E5, 4, 4);
+//! assert!(result.is_err());
+//! assert!(gl.calls().is_empty());
+//!
+//! // A 3x3 RGB image has 9 byte rows that are padded to 12 bytes with the default alignment of 4.
+//! assert_eq!(image_size(3, 3, PixelFormat::Rgb, PixelType::UnsignedByte, 4), Ok(33));
+//! let size = image_size(u32::MAX, u32::MAX, PixelFormat::Rgba, PixelType::Float, 4);
+//! assert_eq!(
+//!     size,
+//!     Err(FormatError::ImageTooLarge { width: u32::MAX, height: u32::MAX, depth: 1 })
+//! );
+//! let result = gl.tex_image_2d(
+//!     TextureBindPoint::Texture2d,
+//!     0,
+//!     InternalFormat::Rgb8,
+//!     3,
+//!     3,
+//!     PixelFormat::Rgb,
+//!     PixelType::UnsignedByte,
+//!     &vec![0u8; 27],
+//! );
+//! assert!(result.is_err());
+//!
+//! // A 10x10 image of 4x4 blocks is stored as 3x3 blocks.
+//! assert_eq!(compressed_image_size(TextureCompression::RgbaDxt5, 10, 10, 1), Ok(144));
+//! assert!(compressed_image_size(TextureCompression::RgbaDxt5, u32::MAX, u32::MAX, u32::MAX).is_err());
+//! assert_eq!(compressed_format_info(TextureCompression::RgbaAstc8x8).bytes_per_block, 16);
+//! ```
+use data_view::PixelComponent;
+use glenum::InternalFormat as I;
+use glenum::PixelFormat as F;
+use glenum::PixelType as T;
+use glenum::TextureCompression as C;
+use glenum::{InternalFormat, PixelFormat, PixelType, TextureCompression};
+use std::error;
+use std::fmt;
+use std::mem;
+
+/// Description of an internal format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatInfo {
+    /// The internal format that is described.
+    pub internal_format: InternalFormat,
+    /// The pixel format of the data uploaded to a texture of this format.
+    pub format: PixelFormat,
+    /// The pixel types of the data that can be uploaded to a texture of this format.
+    pub types: &'static [PixelType],
+    /// Size of one texel in bytes, for unsized formats the size with the first of `types`, which is
+    /// `PixelType::UnsignedByte` for the color formats.
+    pub bytes_per_texel: u32,
+    /// Whether the format is sized, `texStorage2D` and WebGL2 renderbuffers require a sized format.
+    pub sized: bool,
+    /// Whether the format can be attached to a framebuffer as color attachment.
+    pub color_renderable: bool,
+    /// Whether the format can be attached to a framebuffer as depth or stencil attachment.
+    pub depth_stencil_renderable: bool,
+    /// Whether textures of the format can be sampled with linear filtering.
+    pub filterable: bool,
+    /// Whether blending works when rendering to the format.
+    pub blendable: bool,
+    /// Whether the format can be used for textures, false for `StencilIndex8`.
+    pub texturable: bool,
+}
+
+// Flags of a table entry, expanded to the fields of `FormatInfo` by `info`
+const COLOR: u8 = 1;
+const FILTER: u8 = 2;
+const BLEND: u8 = 4;
+const DEPTH_STENCIL: u8 = 8;
+const UNSIZED: u8 = 16;
+const NO_TEXTURE: u8 = 32;
+
+const fn info(
+    internal_format: InternalFormat,
+    format: PixelFormat,
+    types: &'static [PixelType],
+    bytes_per_texel: u32,
+    flags: u8,
+) -> FormatInfo {
+    FormatInfo {
+        internal_format,
+        format,
+        types,
+        bytes_per_texel,
+        sized: flags & UNSIZED == 0,
+        color_renderable: flags & COLOR != 0,
+        depth_stencil_renderable: flags & DEPTH_STENCIL != 0,
+        filterable: flags & FILTER != 0,
+        blendable: flags & BLEND != 0,
+        texturable: flags & NO_TEXTURE == 0,
+    }
+}
+
+// The float types are listed for the unsized formats because WebGL1 accepts them with the
+// `OES_texture_float` and `OES_texture_half_float` extensions
+const UNSIZED_TYPES: &[PixelType] = &[T::UnsignedByte, T::HalfFloat, T::Float];
+
+/// Every internal format with its properties.
+pub static FORMATS: &[FormatInfo] = &[
+    info(I::Alpha, F::Alpha, UNSIZED_TYPES, 1, UNSIZED | FILTER),
+    info(
+        I::Luminance,
+        F::Luminance,
+        UNSIZED_TYPES,
+        1,
+        UNSIZED | FILTER,
+    ),
+    info(
+        I::LuminanceAlpha,
+        F::LuminanceAlpha,
+        UNSIZED_TYPES,
+        2,
+        UNSIZED | FILTER,
+    ),
+    info(
+        I::Rgb,
+        F::Rgb,
+        &[T::UnsignedByte, T::UnsignedShort565, T::HalfFloat, T::Float],
+        3,
+        UNSIZED | COLOR | FILTER | BLEND,
+    ),
+    info(
+        I::Rgba,
+        F::Rgba,
+        &[
+            T::UnsignedByte,
+            T::UnsignedShort4444,
+            T::UnsignedShort5551,
+            T::HalfFloat,
+            T::Float,
+        ],
+        4,
+        UNSIZED | COLOR | FILTER | BLEND,
+    ),
+    info(
+        I::DepthComponent,
+        F::DepthComponent,
+        &[T::UnsignedShort, T::UnsignedInt],
+        2,
+        UNSIZED | DEPTH_STENCIL,
+    ),
+    info(
+        I::DepthStencil,
+        F::DepthStencil,
+        &[T::UnsignedInt248],
+        4,
+        UNSIZED | DEPTH_STENCIL,
+    ),
+    info(I::R8, F::Red, &[T::UnsignedByte], 1, COLOR | FILTER | BLEND),
+    info(I::R8Snorm, F::Red, &[T::Byte], 1, FILTER),
+    info(I::R16f, F::Red, &[T::HalfFloat, T::Float], 2, FILTER),
+    info(I::R32f, F::Red, &[T::Float], 4, 0),
+    info(I::R8ui, F::RedInteger, &[T::UnsignedByte], 1, COLOR),
+    info(I::R8i, F::RedInteger, &[T::Byte], 1, COLOR),
+    info(I::R16ui, F::RedInteger, &[T::UnsignedShort], 2, COLOR),
+    info(I::R16i, F::RedInteger, &[T::Short], 2, COLOR),
+    info(I::R32ui, F::RedInteger, &[T::UnsignedInt], 4, COLOR),
+    info(I::R32i, F::RedInteger, &[T::Int], 4, COLOR),
+    info(I::Rg8, F::Rg, &[T::UnsignedByte], 2, COLOR | FILTER | BLEND),
+    info(I::Rg8Snorm, F::Rg, &[T::Byte], 2, FILTER),
+    info(I::Rg16f, F::Rg, &[T::HalfFloat, T::Float], 4, FILTER),
+    info(I::Rg32f, F::Rg, &[T::Float], 8, 0),
+    info(I::Rg8ui, F::RgInteger, &[T::UnsignedByte], 2, COLOR),
+    info(I::Rg8i, F::RgInteger, &[T::Byte], 2, COLOR),
+    info(I::Rg16ui, F::RgInteger, &[T::UnsignedShort], 4, COLOR),
+    info(I::Rg16i, F::RgInteger, &[T::Short], 4, COLOR),
+    info(I::Rg32ui, F::RgInteger, &[T::UnsignedInt], 8, COLOR),
+    info(I::Rg32i, F::RgInteger, &[T::Int], 8, COLOR),
+    info(
+        I::Rgb8,
+        F::Rgb,
+        &[T::UnsignedByte],
+        3,
+        COLOR | FILTER | BLEND,
+    ),
+    info(I::Srgb8, F::Rgb, &[T::UnsignedByte], 3, FILTER),
+    info(
+        I::Rgb565,
+        F::Rgb,
+        &[T::UnsignedByte, T::UnsignedShort565],
+        2,
+        COLOR | FILTER | BLEND,
+    ),
+    info(I::Rgb8Snorm, F::Rgb, &[T::Byte], 3, FILTER),
+    info(
+        I::R11fG11fB10f,
+        F::Rgb,
+        &[T::UnsignedInt10f11f11fRev, T::HalfFloat, T::Float],
+        4,
+        FILTER,
+    ),
+    info(
+        I::Rgb9E5,
+        F::Rgb,
+        &[T::UnsignedInt5999Rev, T::HalfFloat, T::Float],
+        4,
+        FILTER,
+    ),
+    info(I::Rgb16f, F::Rgb, &[T::HalfFloat, T::Float], 6, FILTER),
+    info(I::Rgb32f, F::Rgb, &[T::Float], 12, 0),
+    info(I::Rgb8ui, F::RgbInteger, &[T::UnsignedByte], 3, 0),
+    info(I::Rgb8i, F::RgbInteger, &[T::Byte], 3, 0),
+    info(I::Rgb16ui, F::RgbInteger, &[T::UnsignedShort], 6, 0),
+    info(I::Rgb16i, F::RgbInteger, &[T::Short], 6, 0),
+    info(I::Rgb32ui, F::RgbInteger, &[T::UnsignedInt], 12, 0),
+    info(I::Rgb32i, F::RgbInteger, &[T::Int], 12, 0),
+    info(
+        I::Rgba8,
+        F::Rgba,
+        &[T::UnsignedByte],
+        4,
+        COLOR | FILTER | BLEND,
+    ),
+    info(
+        I::Srgb8Alpha8,
+        F::Rgba,
+        &[T::UnsignedByte],
+        4,
+        COLOR | FILTER | BLEND,
+    ),
+    info(I::Rgba8Snorm, F::Rgba, &[T::Byte], 4, FILTER),
+    info(
+        I::Rgb5A1,
+        F::Rgba,
+        &[
+            T::UnsignedByte,
+            T::UnsignedShort5551,
+            T::UnsignedInt2101010Rev,
+        ],
+        2,
+        COLOR | FILTER | BLEND,
+    ),
+    info(
+        I::Rgba4,
+        F::Rgba,
+        &[T::UnsignedByte, T::UnsignedShort4444],
+        2,
+        COLOR | FILTER | BLEND,
+    ),
+    info(
+        I::Rgb10A2,
+        F::Rgba,
+        &[T::UnsignedInt2101010Rev],
+        4,
+        COLOR | FILTER | BLEND,
+    ),
+    info(I::Rgba16f, F::Rgba, &[T::HalfFloat, T::Float], 8, FILTER),
+    info(I::Rgba32f, F::Rgba, &[T::Float], 16, 0),
+    info(I::Rgba8ui, F::RgbaInteger, &[T::UnsignedByte], 4, COLOR),
+    info(I::Rgba8i, F::RgbaInteger, &[T::Byte], 4, COLOR),
+    info(
+        I::Rgb10A2ui,
+        F::RgbaInteger,
+        &[T::UnsignedInt2101010Rev],
+        4,
+        COLOR,
+    ),
+    info(I::Rgba16ui, F::RgbaInteger, &[T::UnsignedShort], 8, COLOR),
+    info(I::Rgba16i, F::RgbaInteger, &[T::Short], 8, COLOR),
+    info(I::Rgba32ui, F::RgbaInteger, &[T::UnsignedInt], 16, COLOR),
+    info(I::Rgba32i, F::RgbaInteger, &[T::Int], 16, COLOR),
+    info(
+        I::DepthComponent16,
+        F::DepthComponent,
+        &[T::UnsignedShort, T::UnsignedInt],
+        2,
+        DEPTH_STENCIL,
+    ),
+    info(
+        I::DepthComponent24,
+        F::DepthComponent,
+        &[T::UnsignedInt],
+        4,
+        DEPTH_STENCIL,
+    ),
+    info(
+        I::DepthComponent32f,
+        F::DepthComponent,
+        &[T::Float],
+        4,
+        DEPTH_STENCIL,
+    ),
+    info(
+        I::Depth24Stencil8,
+        F::DepthStencil,
+        &[T::UnsignedInt248],
+        4,
+        DEPTH_STENCIL,
+    ),
+    info(
+        I::Depth32fStencil8,
+        F::DepthStencil,
+        &[T::Float32UnsignedInt248Rev],
+        8,
+        DEPTH_STENCIL,
+    ),
+    info(
+        I::StencilIndex8,
+        F::DepthStencil,
+        &[],
+        1,
+        DEPTH_STENCIL | NO_TEXTURE,
+    ),
+];
+
+/// Returns the description of an internal format.
+pub fn format_info(internal_format: InternalFormat) -> &'static FormatInfo {
+    FORMATS
+        .iter()
+        .find(|info| info.internal_format == internal_format)
+        .expect("every internal format is part of the table")
+}
+
+/// Checks that texImage2D accepts data of the given format and type for a texture of the internal format.
+///
+/// # Arguments
+/// * `internal_format` - the internal format of the texture.
+/// * `format` - the format of the uploaded data.
+/// * `pixel_type` - the type of the uploaded data.
+pub fn validate_tex_image(
+    internal_format: InternalFormat,
+    format: PixelFormat,
+    pixel_type: PixelType,
+) -> Result<(), FormatError> {
+    let info = format_info(internal_format);
+    if !info.texturable {
+        return Err(FormatError::NotTexturable { internal_format });
+    }
+    if info.format != format || !info.types.contains(&pixel_type) {
+        return Err(FormatError::InvalidCombination {
+            internal_format,
+            format,
+            pixel_type,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that the texImage2D of a WebGL1 context accepts data of the given format and type for a texture of
+/// the internal format.
+///
+/// WebGL1 only knows the unsized formats, the format of the data has to equal the internal format. The float
+/// types and the depth formats are accepted as they are available through extensions.
+///
+/// # Arguments
+/// * `internal_format` - the internal format of the texture.
+/// * `format` - the format of the uploaded data.
+/// * `pixel_type` - the type of the uploaded data.
+pub fn validate_tex_image_webgl1(
+    internal_format: InternalFormat,
+    format: PixelFormat,
+    pixel_type: PixelType,
+) -> Result<(), FormatError> {
+    let info = format_info(internal_format);
+    if info.sized {
+        return Err(FormatError::RequiresWebGL2 { internal_format });
+    }
+    if format as u32 != internal_format as u32 || !info.types.contains(&pixel_type) {
+        return Err(FormatError::InvalidCombination {
+            internal_format,
+            format,
+            pixel_type,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that texStorage2D and texStorage3D accept the internal format, which has to be sized.
+///
+/// # Arguments
+/// * `internal_format` - the internal format of the texture.
+pub fn validate_tex_storage(internal_format: InternalFormat) -> Result<(), FormatError> {
+    let info = format_info(internal_format);
+    if !info.texturable {
+        return Err(FormatError::NotTexturable { internal_format });
+    }
+    if !info.sized {
+        return Err(FormatError::Unsized { internal_format });
+    }
+    Ok(())
+}
+
+/// Checks that renderbufferStorage accepts the internal format, which has to be color, depth or stencil
+/// renderable.
+///
+/// `DepthStencil` is the only unsized format that is accepted, WebGL1 has no sized depth and stencil format.
+///
+/// # Arguments
+/// * `internal_format` - the internal format of the renderbuffer.
+pub fn validate_renderbuffer_storage(internal_format: InternalFormat) -> Result<(), FormatError> {
+    let info = format_info(internal_format);
+    if internal_format == InternalFormat::DepthStencil
+        || (info.sized && (info.color_renderable || info.depth_stencil_renderable))
+    {
+        Ok(())
+    } else {
+        Err(FormatError::NotRenderable { internal_format })
+    }
+}
+
+/// Returns the number of channels of pixel data of the given format.
+pub fn channels(format: PixelFormat) -> usize {
+    match format {
+        F::Alpha | F::Luminance | F::Red | F::RedInteger | F::DepthComponent | F::DepthStencil => 1,
+        F::LuminanceAlpha | F::Rg | F::RgInteger => 2,
+        F::Rgb | F::RgbInteger => 3,
+        F::Rgba | F::RgbaInteger => 4,
+    }
+}
+
+/// Returns the number of bytes one pixel of data of the given format and type takes.
+///
+/// Packed types hold all channels of a pixel in one element, the other types use one element per channel.
+pub fn bytes_per_pixel(format: PixelFormat, pixel_type: PixelType) -> usize {
+    match pixel_type {
+        T::UnsignedShort565 | T::UnsignedShort5551 | T::UnsignedShort4444 => 2,
+        T::UnsignedInt2101010Rev
+        | T::UnsignedInt10f11f11fRev
+        | T::UnsignedInt5999Rev
+        | T::UnsignedInt248 => 4,
+        T::Float32UnsignedInt248Rev => 8,
+        T::Byte | T::UnsignedByte => channels(format),
+        T::Short | T::UnsignedShort | T::HalfFloat => 2 * channels(format),
+        T::Int | T::UnsignedInt | T::Float => 4 * channels(format),
+    }
+}
+
+/// Returns the number of bytes WebGL reads for an image of the given size, format and type.
+///
+/// Every row but the last is padded to a multiple of the alignment, like WebGL does for `UNPACK_ALIGNMENT`.
+///
+/// # Arguments
+/// * `width` - the width of the image in pixels.
+/// * `height` - the height of the image in pixels.
+/// * `format` - the format of the pixels.
+/// * `pixel_type` - the data type of the pixels.
+/// * `alignment` - the row alignment in bytes, 1, 2, 4 or 8.
+///
+/// Fails with `FormatError::ImageTooLarge` when the size does not fit in a `usize`, which can happen on
+/// wasm32.
+pub fn image_size(
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    pixel_type: PixelType,
+    alignment: usize,
+) -> Result<usize, FormatError> {
+    if width == 0 || height == 0 {
+        return Ok(0);
+    }
+    let too_large = FormatError::ImageTooLarge {
+        width,
+        height,
+        depth: 1,
+    };
+    let row = (width as usize)
+        .checked_mul(bytes_per_pixel(format, pixel_type))
+        .ok_or(too_large)?;
+    let alignment = alignment.max(1);
+    let padding = (alignment - row % alignment) % alignment;
+    row.checked_add(padding)
+        .and_then(|padded| padded.checked_mul(height as usize - 1))
+        .and_then(|rows| rows.checked_add(row))
+        .ok_or(too_large)
+}
+
+/// Checks that `len` elements of `C` can be uploaded as an image of the given size, format and type.
+///
+/// Fails when the element type does not match the pixel type, see `PixelComponent`, or when the data is
+/// shorter than `image_size` or its size does not fit in a `usize`. `UNPACK_ROW_LENGTH` and the skip parameters of WebGL2 are not taken into
+/// account, with those set WebGL may require more data.
+///
+/// # Arguments
+/// * `len` - the number of elements of the data.
+/// * `width` - the width of the image in pixels.
+/// * `height` - the height of the image in pixels.
+/// * `format` - the format of the pixels.
+/// * `pixel_type` - the data type of the pixels.
+/// * `alignment` - the row alignment in bytes, the value of `UNPACK_ALIGNMENT`.
+pub fn validate_pixels<C: PixelComponent>(
+    len: usize,
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    pixel_type: PixelType,
+    alignment: usize,
+) -> Result<(), FormatError> {
+    if !C::pixel_types().contains(&pixel_type) {
+        return Err(FormatError::WrongComponent {
+            pixel_type,
+            component: C::name(),
+        });
+    }
+    let required = image_size(width, height, format, pixel_type, alignment)?;
+    let actual = len * mem::size_of::<C>();
+    if actual < required {
+        return Err(FormatError::NotEnoughData { required, actual });
+    }
+    Ok(())
+}
+
+/// Description of a compressed texture format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressedFormatInfo {
+    /// The compressed format that is described.
+    pub format: TextureCompression,
+    /// Width of a block in pixels.
+    pub block_width: u32,
+    /// Height of a block in pixels.
+    pub block_height: u32,
+    /// Size of one block in bytes.
+    pub bytes_per_block: u32,
+    /// The smallest number of blocks an image is stored in along each axis, 2 for PVRTC and 1 otherwise.
+    pub min_blocks: u32,
+    /// Whether parts of a texture of this format can be replaced with `compressedTexSubImage2D`, false for
+    /// ETC1 and PVRTC.
+    pub sub_image: bool,
+    /// The WebGL extension that provides the format.
+    pub extension: &'static str,
+}
+
+// Flags of a compressed table entry, expanded by `compressed`
+const NO_SUB_IMAGE: u8 = 1;
+const TWO_BLOCKS: u8 = 2;
+
+// The extensions that provide the compressed formats
+const S3TC: &str = "WEBGL_compressed_texture_s3tc";
+const S3TC_SRGB: &str = "WEBGL_compressed_texture_s3tc_srgb";
+const ETC: &str = "WEBGL_compressed_texture_etc";
+const ETC1: &str = "WEBGL_compressed_texture_etc1";
+const ASTC: &str = "WEBGL_compressed_texture_astc";
+const BPTC: &str = "EXT_texture_compression_bptc";
+const RGTC: &str = "EXT_texture_compression_rgtc";
+const PVRTC: &str = "WEBGL_compressed_texture_pvrtc";
+
+const fn compressed(
+    format: TextureCompression,
+    block_width: u32,
+    block_height: u32,
+    bytes_per_block: u32,
+    extension: &'static str,
+    flags: u8,
+) -> CompressedFormatInfo {
+    CompressedFormatInfo {
+        format,
+        block_width,
+        block_height,
+        bytes_per_block,
+        min_blocks: if flags & TWO_BLOCKS != 0 { 2 } else { 1 },
+        sub_image: flags & NO_SUB_IMAGE == 0,
+        extension,
+    }
+}
+
+/// Every compressed format with its block size.
+pub static COMPRESSED_FORMATS: &[CompressedFormatInfo] = &[
+    compressed(C::RgbDxt1, 4, 4, 8, S3TC, 0),
+    compressed(C::RgbaDxt1, 4, 4, 8, S3TC, 0),
+    compressed(C::RgbaDxt3, 4, 4, 16, S3TC, 0),
+    compressed(C::RgbaDxt5, 4, 4, 16, S3TC, 0),
+    compressed(C::SrgbDxt1, 4, 4, 8, S3TC_SRGB, 0),
+    compressed(C::SrgbAlphaDxt1, 4, 4, 8, S3TC_SRGB, 0),
+    compressed(C::SrgbAlphaDxt3, 4, 4, 16, S3TC_SRGB, 0),
+    compressed(C::SrgbAlphaDxt5, 4, 4, 16, S3TC_SRGB, 0),
+    compressed(C::R11Eac, 4, 4, 8, ETC, 0),
+    compressed(C::SignedR11Eac, 4, 4, 8, ETC, 0),
+    compressed(C::Rg11Eac, 4, 4, 16, ETC, 0),
+    compressed(C::SignedRg11Eac, 4, 4, 16, ETC, 0),
+    compressed(C::Rgb8Etc2, 4, 4, 8, ETC, 0),
+    compressed(C::Srgb8Etc2, 4, 4, 8, ETC, 0),
+    compressed(C::Rgb8PunchthroughAlpha1Etc2, 4, 4, 8, ETC, 0),
+    compressed(C::Srgb8PunchthroughAlpha1Etc2, 4, 4, 8, ETC, 0),
+    compressed(C::Rgba8Etc2Eac, 4, 4, 16, ETC, 0),
+    compressed(C::Srgb8Alpha8Etc2Eac, 4, 4, 16, ETC, 0),
+    compressed(C::RgbEtc1, 4, 4, 8, ETC1, NO_SUB_IMAGE),
+    compressed(C::RgbaAstc4x4, 4, 4, 16, ASTC, 0),
+    compressed(C::RgbaAstc5x4, 5, 4, 16, ASTC, 0),
+    compressed(C::RgbaAstc5x5, 5, 5, 16, ASTC, 0),
+    compressed(C::RgbaAstc6x5, 6, 5, 16, ASTC, 0),
+    compressed(C::RgbaAstc6x6, 6, 6, 16, ASTC, 0),
+    compressed(C::RgbaAstc8x5, 8, 5, 16, ASTC, 0),
+    compressed(C::RgbaAstc8x6, 8, 6, 16, ASTC, 0),
+    compressed(C::RgbaAstc8x8, 8, 8, 16, ASTC, 0),
+    compressed(C::RgbaAstc10x5, 10, 5, 16, ASTC, 0),
+    compressed(C::RgbaAstc10x6, 10, 6, 16, ASTC, 0),
+    compressed(C::RgbaAstc10x8, 10, 8, 16, ASTC, 0),
+    compressed(C::RgbaAstc10x10, 10, 10, 16, ASTC, 0),
+    compressed(C::RgbaAstc12x10, 12, 10, 16, ASTC, 0),
+    compressed(C::RgbaAstc12x12, 12, 12, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc4x4, 4, 4, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc5x4, 5, 4, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc5x5, 5, 5, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc6x5, 6, 5, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc6x6, 6, 6, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc8x5, 8, 5, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc8x6, 8, 6, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc8x8, 8, 8, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc10x5, 10, 5, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc10x6, 10, 6, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc10x8, 10, 8, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc10x10, 10, 10, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc12x10, 12, 10, 16, ASTC, 0),
+    compressed(C::Srgb8Alpha8Astc12x12, 12, 12, 16, ASTC, 0),
+    compressed(C::RgbaBptcUnorm, 4, 4, 16, BPTC, 0),
+    compressed(C::SrgbAlphaBptcUnorm, 4, 4, 16, BPTC, 0),
+    compressed(C::RgbBptcSignedFloat, 4, 4, 16, BPTC, 0),
+    compressed(C::RgbBptcUnsignedFloat, 4, 4, 16, BPTC, 0),
+    compressed(C::RedRgtc1, 4, 4, 8, RGTC, 0),
+    compressed(C::SignedRedRgtc1, 4, 4, 8, RGTC, 0),
+    compressed(C::RedGreenRgtc2, 4, 4, 16, RGTC, 0),
+    compressed(C::SignedRedGreenRgtc2, 4, 4, 16, RGTC, 0),
+    compressed(C::RgbPvrtc4bppv1, 4, 4, 8, PVRTC, NO_SUB_IMAGE | TWO_BLOCKS),
+    compressed(C::RgbPvrtc2bppv1, 8, 4, 8, PVRTC, NO_SUB_IMAGE | TWO_BLOCKS),
+    compressed(
+        C::RgbaPvrtc4bppv1,
+        4,
+        4,
+        8,
+        PVRTC,
+        NO_SUB_IMAGE | TWO_BLOCKS,
+    ),
+    compressed(
+        C::RgbaPvrtc2bppv1,
+        8,
+        4,
+        8,
+        PVRTC,
+        NO_SUB_IMAGE | TWO_BLOCKS,
+    ),
+];
+
+/// Returns the description of a compressed format.
+pub fn compressed_format_info(format: TextureCompression) -> &'static CompressedFormatInfo {
+    COMPRESSED_FORMATS
+        .iter()
+        .find(|info| info.format == format)
+        .expect("every compressed format is part of the table")
+}
+
+/// Returns the number of bytes of a compressed image of the given size, the exact length
+/// `compressedTexImage2D` and `compressedTexImage3D` require.
+///
+/// # Arguments
+/// * `format` - the compressed format.
+/// * `width` - the width of the image in pixels.
+/// * `height` - the height of the image in pixels.
+/// * `depth` - the depth or number of layers of the image, 1 for 2D images.
+///
+/// Fails with `FormatError::ImageTooLarge` when the size does not fit in a `usize`, which can happen on
+/// wasm32.
+pub fn compressed_image_size(
+    format: TextureCompression,
+    width: u32,
+    height: u32,
+    depth: u32,
+) -> Result<usize, FormatError> {
+    if width == 0 || height == 0 || depth == 0 {
+        return Ok(0);
+    }
+    let info = compressed_format_info(format);
+    let columns = width.div_ceil(info.block_width).max(info.min_blocks) as usize;
+    let rows = height.div_ceil(info.block_height).max(info.min_blocks) as usize;
+    columns
+        .checked_mul(rows)
+        .and_then(|blocks| blocks.checked_mul(depth as usize))
+        .and_then(|blocks| blocks.checked_mul(info.bytes_per_block as usize))
+        .ok_or(FormatError::ImageTooLarge {
+            width,
+            height,
+            depth,
+        })
+}
+
+/// Checks that `len` bytes are exactly a compressed image of the given size.
+///
+/// # Arguments
+/// * `format` - the compressed format.
+/// * `width` - the width of the image in pixels.
+/// * `height` - the height of the image in pixels.
+/// * `depth` - the depth or number of layers of the image, 1 for 2D images.
+/// * `len` - the number of bytes of the data.
+pub fn validate_compressed_data(
+    format: TextureCompression,
+    width: u32,
+    height: u32,
+    depth: u32,
+    len: usize,
+) -> Result<(), FormatError> {
+    let expected = compressed_image_size(format, width, height, depth)?;
+    if len != expected {
+        return Err(FormatError::CompressedSizeMismatch {
+            format,
+            expected,
+            actual: len,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that a region of a compressed mipmap level can be replaced with `compressedTexSubImage2D` or
+/// `compressedTexSubImage3D`.
+///
+/// The offsets have to be multiples of the block size, and so do the width and height unless the region
+/// reaches the right or top edge of the level.
+///
+/// # Arguments
+/// * `format` - the compressed format.
+/// * `x` - the x offset of the region.
+/// * `y` - the y offset of the region.
+/// * `width` - the width of the region.
+/// * `height` - the height of the region.
+/// * `level_width` - the width of the mipmap level.
+/// * `level_height` - the height of the mipmap level.
+pub fn validate_compressed_region(
+    format: TextureCompression,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    level_width: u32,
+    level_height: u32,
+) -> Result<(), FormatError> {
+    let info = compressed_format_info(format);
+    if !info.sub_image {
+        return Err(FormatError::CompressedSubImageUnsupported { format });
+    }
+    let aligned = |offset: u32, size: u32, block: u32, level: u32| {
+        offset % block == 0 && (size % block == 0 || offset.saturating_add(size) == level)
+    };
+    if !aligned(x, width, info.block_width, level_width)
+        || !aligned(y, height, info.block_height, level_height)
+    {
+        return Err(FormatError::UnalignedCompressedRegion {
+            format,
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+    Ok(())
+}
+
+/// Error returned when an internal format is used in a way WebGL does not allow, see `formats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// Data of the given format and type can not be uploaded to a texture of the internal format.
+    InvalidCombination {
+        /// The internal format of the texture.
+        internal_format: InternalFormat,
+        /// The format of the data.
+        format: PixelFormat,
+        /// The type of the data.
+        pixel_type: PixelType,
+    },
+    /// The operation requires a sized internal format.
+    Unsized {
+        /// The unsized internal format.
+        internal_format: InternalFormat,
+    },
+    /// The internal format is not color, depth or stencil renderable and can't back a renderbuffer.
+    NotRenderable {
+        /// The internal format.
+        internal_format: InternalFormat,
+    },
+    /// The internal format can only be used for renderbuffers.
+    NotTexturable {
+        /// The internal format.
+        internal_format: InternalFormat,
+    },
+    /// The internal format is sized, WebGL1 textures only accept unsized formats.
+    RequiresWebGL2 {
+        /// The sized internal format.
+        internal_format: InternalFormat,
+    },
+    /// The element type of the pixel data can not hold data of the pixel type, see `PixelComponent`.
+    WrongComponent {
+        /// The type of the pixels.
+        pixel_type: PixelType,
+        /// The element type of the data.
+        component: &'static str,
+    },
+    /// The pixel data is shorter than the image it is uploaded as.
+    NotEnoughData {
+        /// The number of bytes the upload reads.
+        required: usize,
+        /// The number of bytes of the data.
+        actual: usize,
+    },
+    /// The size of the image in bytes does not fit in a `usize`.
+    ImageTooLarge {
+        /// The width of the image in pixels.
+        width: u32,
+        /// The height of the image in pixels.
+        height: u32,
+        /// The depth or number of layers of the image, 1 for 2D images.
+        depth: u32,
+    },
+    /// The length of compressed data does not match the size of the image.
+    CompressedSizeMismatch {
+        /// The compressed format.
+        format: TextureCompression,
+        /// The number of bytes of the image.
+        expected: usize,
+        /// The number of bytes of the data.
+        actual: usize,
+    },
+    /// Textures of the compressed format can not be updated in parts.
+    CompressedSubImageUnsupported {
+        /// The compressed format.
+        format: TextureCompression,
+    },
+    /// A region of a compressed texture does not start or end on a block boundary.
+    UnalignedCompressedRegion {
+        /// The compressed format.
+        format: TextureCompression,
+        /// The x offset of the region.
+        x: u32,
+        /// The y offset of the region.
+        y: u32,
+        /// The width of the region.
+        width: u32,
+        /// The height of the region.
+        height: u32,
+    },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FormatError::InvalidCombination {
+                internal_format,
+                format,
+                pixel_type,
+            } => write!(
+                f,
+                "{:?} data of type {:?} can not be uploaded to a {:?} texture",
+                format, pixel_type, internal_format
+            ),
+            FormatError::Unsized { internal_format } => {
+                write!(f, "{:?} is not a sized internal format", internal_format)
+            }
+            FormatError::NotRenderable { internal_format } => {
+                write!(f, "{:?} is not a renderable format", internal_format)
+            }
+            FormatError::NotTexturable { internal_format } => {
+                write!(f, "{:?} can not be used for textures", internal_format)
+            }
+            FormatError::RequiresWebGL2 { internal_format } => {
+                write!(f, "{:?} textures require WebGL2", internal_format)
+            }
+            FormatError::WrongComponent {
+                pixel_type,
+                component,
+            } => write!(
+                f,
+                "{:?} pixels can not be stored as {}",
+                pixel_type, component
+            ),
+            FormatError::NotEnoughData { required, actual } => write!(
+                f,
+                "the upload reads {} bytes but the data has only {}",
+                required, actual
+            ),
+            FormatError::ImageTooLarge {
+                width,
+                height,
+                depth,
+            } => write!(
+                f,
+                "the size of a {}x{}x{} image does not fit in a usize",
+                width, height, depth
+            ),
+            FormatError::CompressedSizeMismatch {
+                format,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "a {:?} image of this size has {} bytes but the data has {}",
+                format, expected, actual
+            ),
+            FormatError::CompressedSubImageUnsupported { format } => {
+                write!(f, "{:?} textures can not be updated in parts", format)
+            }
+            FormatError::UnalignedCompressedRegion {
+                format,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let info = compressed_format_info(format);
+                write!(
+                    f,
+                    "the region of {}x{} at ({}, {}) is not aligned to the {}x{} blocks of {:?}",
+                    width, height, x, y, info.block_width, info.block_height, format
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for FormatError {}
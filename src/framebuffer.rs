@@ -1,29 +1,263 @@
 //! WebGLFramebuffer and methods
-use glenum::FramebufferKind;
-use rendering_context::WebGL2RenderingContext;
+//!
+//! Besides the attachments recorded by `FramebufferBuilder`, the images attached to a framebuffer can be
+//! queried from WebGL:
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let framebuffer = gl.create_framebuffer();
+//! let attachment = Attachment::ColorAttachment0;
+//! match framebuffer.attached_object(attachment) {
+//!     AttachedObject::None => {}
+//!     _ => panic!("nothing is attached to a new framebuffer"),
+//! }
+//! assert_eq!(framebuffer.attachment_object_type(attachment), AttachmentObjectType::None);
+//! assert_eq!(framebuffer.attachment_texture_level(attachment), None);
+//! assert_eq!(framebuffer.attachment_color_encoding(attachment), None);
+//! assert_eq!(framebuffer.attachment_component_type(attachment), None);
+//! assert_eq!(framebuffer.attachment_bit_sizes(attachment), AttachmentBitSizes::default());
+//!
+//! // Without an attached image only the object type is queried.
+//! assert!(gl.calls().iter().all(|call| call.name != "getFramebufferAttachmentParameter"
+//!     || call.args.ends_with("ObjectType")));
+//! ```
+use error::CheckedObject;
+use glenum::{
+    Attachment, AttachmentObjectType, ColorEncoding, ComponentType, FramebufferAttachmentParameter,
+    FramebufferKind, InternalFormat, TextureBindPoint,
+};
+use renderbuffer::RenderbufferBackend;
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
+use std::fmt;
+use texture::TextureBackend;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing framebuffer objects.
+pub trait FramebufferBackend: Sized {
+    /// Handle to a framebuffer object of this backend.
+    type Framebuffer: Clone;
+
+    /// Binding for `WebGLRenderingContext.createFramebuffer()`
+    fn _create_framebuffer(&self) -> Self::Framebuffer;
+    /// Binding for `WebGLRenderingContext.bindFramebuffer()`
+    fn _bind_framebuffer(&self, target: FramebufferKind, framebuffer: &Self::Framebuffer);
+    /// Binding for `WebGLRenderingContext.deleteFramebuffer()`
+    fn _delete_framebuffer(&self, framebuffer: Self::Framebuffer);
+    /// Binding for `WebGLRenderingContext.isFramebuffer()`
+    fn _is_framebuffer(&self, framebuffer: &Self::Framebuffer) -> bool;
+    /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is `i32`
+    fn _get_framebuffer_attachment_parameter_i32(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> i32;
+
     /// Creates and initializes a new `WebGLRSFramebuffer` object
-    pub fn create_framebuffer(&self) -> WebGLRSFramebuffer {
+    fn create_framebuffer(&self) -> WebGLRSFramebuffer<'_, Self> {
         WebGLRSFramebuffer {
             context: self,
             inner: self._create_framebuffer(),
+            leaked: false,
+            attachments: Vec::new(),
+        }
+    }
+}
+
+impl FramebufferBackend for WebGL2RenderingContext {
+    type Framebuffer = WebGLFramebuffer;
+
+    fn _create_framebuffer(&self) -> WebGLFramebuffer {
+        WebGL2RenderingContext::_create_framebuffer(self)
+    }
+    fn _bind_framebuffer(&self, target: FramebufferKind, framebuffer: &WebGLFramebuffer) {
+        WebGL2RenderingContext::_bind_framebuffer(self, target, framebuffer)
+    }
+    fn _delete_framebuffer(&self, framebuffer: WebGLFramebuffer) {
+        WebGL2RenderingContext::_delete_framebuffer(self, framebuffer)
+    }
+    fn _is_framebuffer(&self, framebuffer: &WebGLFramebuffer) -> bool {
+        WebGL2RenderingContext::_is_framebuffer(self, framebuffer)
+    }
+    fn _get_framebuffer_attachment_parameter_i32(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> i32 {
+        WebGL2RenderingContext::_get_framebuffer_attachment_parameter_i32(
+            self, target, attachment, pname,
+        )
+    }
+}
+
+/// Returns the name of an attachment point as used in messages, e.g. `COLOR_ATTACHMENT1` or `DEPTH`.
+pub(crate) fn attachment_name(attachment: Attachment) -> &'static str {
+    match attachment {
+        Attachment::ColorAttachment0 => "COLOR_ATTACHMENT0",
+        Attachment::ColorAttachment1 => "COLOR_ATTACHMENT1",
+        Attachment::ColorAttachment2 => "COLOR_ATTACHMENT2",
+        Attachment::ColorAttachment3 => "COLOR_ATTACHMENT3",
+        Attachment::ColorAttachment4 => "COLOR_ATTACHMENT4",
+        Attachment::ColorAttachment5 => "COLOR_ATTACHMENT5",
+        Attachment::ColorAttachment6 => "COLOR_ATTACHMENT6",
+        Attachment::ColorAttachment7 => "COLOR_ATTACHMENT7",
+        Attachment::ColorAttachment8 => "COLOR_ATTACHMENT8",
+        Attachment::ColorAttachment9 => "COLOR_ATTACHMENT9",
+        Attachment::ColorAttachment10 => "COLOR_ATTACHMENT10",
+        Attachment::ColorAttachment11 => "COLOR_ATTACHMENT11",
+        Attachment::ColorAttachment12 => "COLOR_ATTACHMENT12",
+        Attachment::ColorAttachment13 => "COLOR_ATTACHMENT13",
+        Attachment::ColorAttachment14 => "COLOR_ATTACHMENT14",
+        Attachment::ColorAttachment15 => "COLOR_ATTACHMENT15",
+        Attachment::DepthAttachment => "DEPTH",
+        Attachment::StencilAttachment => "STENCIL",
+        Attachment::DepthStencilAttachment => "DEPTH_STENCIL",
+    }
+}
+
+/// The image attached to an attachment point of a framebuffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachedImage {
+    /// A mipmap level of a 2D texture or of a face of a cube map texture.
+    Texture {
+        /// The texture target, `TextureBindPoint::Texture2d` or a cube map face.
+        target: TextureBindPoint,
+        /// The mipmap level.
+        level: i32,
+    },
+    /// A layer of a mipmap level of a 3D or 2D array texture.
+    TextureLayer {
+        /// The mipmap level.
+        level: i32,
+        /// The layer.
+        layer: i32,
+    },
+    /// A renderbuffer.
+    Renderbuffer {
+        /// The internal format of the renderbuffer.
+        internal_format: InternalFormat,
+    },
+}
+
+/// An attachment of a framebuffer, as recorded by `FramebufferBuilder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentInfo {
+    /// The attachment point.
+    pub attachment: Attachment,
+    /// The attached image.
+    pub image: AttachedImage,
+    /// Width of the attached image.
+    pub width: u32,
+    /// Height of the attached image.
+    pub height: u32,
+}
+
+/// Formats the attachment as e.g. `COLOR_ATTACHMENT1 (texture level 0, 512x512)`.
+impl fmt::Display for AttachmentInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (", attachment_name(self.attachment))?;
+        match self.image {
+            AttachedImage::Texture {
+                target: TextureBindPoint::Texture2d,
+                level,
+            } => write!(f, "texture level {}", level)?,
+            AttachedImage::Texture { target, level } => {
+                write!(f, "texture {:?} level {}", target, level)?
+            }
+            AttachedImage::TextureLayer { level, layer } => {
+                write!(f, "texture level {} layer {}", level, layer)?
+            }
+            AttachedImage::Renderbuffer { internal_format } => {
+                write!(f, "{:?} renderbuffer", internal_format)?
+            }
         }
+        write!(f, ", {}x{})", self.width, self.height)
     }
 }
 
+/// The object attached to an attachment point, returned by `WebGLRSFramebuffer::attached_object`.
+///
+/// The object is still owned by the wrapper it was attached with, so only its raw handle is returned. It
+/// can be compared with the handle of a wrapper, wrapping it with `from_raw` would delete the object when the
+/// new wrapper is dropped.
+pub enum AttachedObject<B: TextureBackend + RenderbufferBackend> {
+    /// Nothing is attached.
+    None,
+    /// A texture is attached.
+    Texture(B::Texture),
+    /// A renderbuffer is attached.
+    Renderbuffer(B::Renderbuffer),
+}
+
+/// Number of bits of each component of an attached image, returned by
+/// `WebGLRSFramebuffer::attachment_bit_sizes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AttachmentBitSizes {
+    /// Bits of the red component.
+    pub red: u32,
+    /// Bits of the green component.
+    pub green: u32,
+    /// Bits of the blue component.
+    pub blue: u32,
+    /// Bits of the alpha component.
+    pub alpha: u32,
+    /// Bits of the depth component.
+    pub depth: u32,
+    /// Bits of the stencil component.
+    pub stencil: u32,
+}
+
 /// Collection of buffers to be used as a rendering destination
 ///
 /// The `WebGLFramebuffer` interface is part of the WebGL API and represents a collection of buffers that
 /// serve as a rendering destination.
-#[derive(Clone)]
-pub struct WebGLRSFramebuffer<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLFramebuffer,
+///
+/// The object is deleted when the `WebGLRSFramebuffer` is dropped.
+pub struct WebGLRSFramebuffer<'ctx, B: 'ctx + FramebufferBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Framebuffer,
+    leaked: bool,
+    attachments: Vec<AttachmentInfo>,
+}
+
+impl<'ctx, B: FramebufferBackend> Drop for WebGLRSFramebuffer<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_framebuffer(self.inner.clone());
+        }
+    }
+}
+
+impl<'ctx, B: FramebufferBackend + ContextBackend> WebGLRSFramebuffer<'ctx, B> {
+    /// Returns a view on this framebuffer whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
 }
 
-impl<'ctx> WebGLRSFramebuffer<'ctx> {
+impl<'a, 'ctx, B: FramebufferBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSFramebuffer<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSFramebuffer "framebuffer";
+        fn bind(target: FramebufferKind);
+        fn is_valid() -> bool;
+        fn attachment_object_type(attachment: Attachment) -> AttachmentObjectType;
+        fn attachment_texture_level(attachment: Attachment) -> Option<i32>;
+        fn attachment_texture_layer(attachment: Attachment) -> Option<i32>;
+        fn attachment_cube_face(attachment: Attachment) -> Option<TextureBindPoint>;
+        fn attachment_color_encoding(attachment: Attachment) -> Option<ColorEncoding>;
+        fn attachment_component_type(attachment: Attachment) -> Option<ComponentType>;
+        fn attachment_bit_sizes(attachment: Attachment) -> AttachmentBitSizes;
+    }
+}
+
+impl<'ctx, B: FramebufferBackend> WebGLRSFramebuffer<'ctx, B> {
     /// Binds this buffer to a given target
     ///
     /// # Arguments
@@ -38,8 +272,304 @@ impl<'ctx> WebGLRSFramebuffer<'ctx> {
     }
 
     /// Deletes this `WebGLRSFramebuffer` object
+    ///
+    /// This is the same as dropping the `WebGLRSFramebuffer`.
     pub fn delete(self) {
-        self.context._delete_framebuffer(self.inner);
+        drop(self);
+    }
+
+    /// Returns the attachments of this framebuffer, only known for framebuffers created by a
+    /// `FramebufferBuilder`.
+    pub fn attachments(&self) -> &[AttachmentInfo] {
+        &self.attachments
+    }
+
+    /// Returns the image attached to an attachment point, see `attachments`.
+    pub fn attachment(&self, attachment: Attachment) -> Option<&AttachmentInfo> {
+        self.attachments
+            .iter()
+            .find(|info| info.attachment == attachment)
+    }
+
+    /// Binds this framebuffer and queries the type of the object attached to an attachment point, the
+    /// returned view answers further questions about the attached image.
+    ///
+    /// Every `attachment_*` method of the framebuffer queries the object type again, use this to ask for
+    /// several parameters of the same attachment:
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let framebuffer = gl.create_framebuffer();
+    /// let query = framebuffer.query_attachment(Attachment::ColorAttachment0);
+    /// assert_eq!(query.object_type(), AttachmentObjectType::None);
+    /// assert_eq!(query.texture_level(), None);
+    /// assert_eq!(query.component_type(), None);
+    /// assert_eq!(query.bit_sizes(), AttachmentBitSizes::default());
+    ///
+    /// let object_type_queries = gl
+    ///     .calls()
+    ///     .iter()
+    ///     .filter(|call| call.args.ends_with("ObjectType"))
+    ///     .count();
+    /// assert_eq!(object_type_queries, 1);
+    /// ```
+    pub fn query_attachment(&self, attachment: Attachment) -> AttachmentQuery<'_, 'ctx, B> {
+        self.bind(FramebufferKind::Framebuffer);
+        let value = self.context._get_framebuffer_attachment_parameter_i32(
+            FramebufferKind::Framebuffer,
+            attachment,
+            FramebufferAttachmentParameter::ObjectType,
+        );
+        let object_type = [
+            AttachmentObjectType::Texture,
+            AttachmentObjectType::Renderbuffer,
+        ]
+        .iter()
+        .find(|&&object_type| object_type as i32 == value)
+        .cloned()
+        .unwrap_or(AttachmentObjectType::None);
+        AttachmentQuery {
+            framebuffer: self,
+            attachment,
+            object_type,
+        }
+    }
+
+    /// Returns the type of the object attached to an attachment point.
+    ///
+    /// Like all attachment queries this binds the framebuffer to `FramebufferKind::Framebuffer`.
+    pub fn attachment_object_type(&self, attachment: Attachment) -> AttachmentObjectType {
+        self.query_attachment(attachment).object_type()
+    }
+
+    /// Returns the mipmap level of the texture attached to an attachment point, `None` if no texture is
+    /// attached.
+    pub fn attachment_texture_level(&self, attachment: Attachment) -> Option<i32> {
+        self.query_attachment(attachment).texture_level()
+    }
+
+    /// Returns the layer of the 3D or 2D array texture attached to an attachment point, `None` if no
+    /// texture is attached. Requires WebGL2.
+    pub fn attachment_texture_layer(&self, attachment: Attachment) -> Option<i32> {
+        self.query_attachment(attachment).texture_layer()
+    }
+
+    /// Returns the face of the cube map texture attached to an attachment point, `None` if no cube map
+    /// texture is attached.
+    pub fn attachment_cube_face(&self, attachment: Attachment) -> Option<TextureBindPoint> {
+        self.query_attachment(attachment).cube_face()
+    }
+
+    /// Returns whether the image attached to an attachment point stores linear or sRGB values, `None` if
+    /// nothing is attached.
+    ///
+    /// Requires WebGL2 or the `EXT_sRGB` extension.
+    pub fn attachment_color_encoding(&self, attachment: Attachment) -> Option<ColorEncoding> {
+        self.query_attachment(attachment).color_encoding()
+    }
+
+    /// Returns the type of the components of the image attached to an attachment point, `None` if nothing
+    /// is attached.
+    ///
+    /// Requires WebGL2 or one of the color buffer float extensions. The components of a
+    /// `Attachment::DepthStencilAttachment` can not be queried as a whole.
+    pub fn attachment_component_type(&self, attachment: Attachment) -> Option<ComponentType> {
+        self.query_attachment(attachment).component_type()
+    }
+
+    /// Returns the number of bits of every component of the image attached to an attachment point, all
+    /// zero if nothing is attached. Requires WebGL2.
+    pub fn attachment_bit_sizes(&self, attachment: Attachment) -> AttachmentBitSizes {
+        self.query_attachment(attachment).bit_sizes()
+    }
+
+    /// Records the attachments made by a `FramebufferBuilder`.
+    pub(crate) fn set_attachments(&mut self, attachments: Vec<AttachmentInfo>) {
+        self.attachments = attachments;
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSFramebuffer` is dropped.
+    ///
+    /// Attachments made before are not known, `attachments` of the returned object is empty.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `framebuffer` - the raw handle.
+    pub fn from_raw(context: &'ctx B, framebuffer: B::Framebuffer) -> Self {
+        WebGLRSFramebuffer {
+            context,
+            inner: framebuffer,
+            leaked: false,
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Framebuffer {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSFramebuffer` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
+    }
+}
+
+impl<'ctx, B: FramebufferBackend + TextureBackend + RenderbufferBackend>
+    WebGLRSFramebuffer<'ctx, B>
+{
+    /// Returns the handle of the texture or renderbuffer attached to an attachment point.
+    pub fn attached_object(&self, attachment: Attachment) -> AttachedObject<B> {
+        self.query_attachment(attachment).object()
+    }
+}
+
+/// Queries of the image attached to one attachment point of a framebuffer, created with
+/// `WebGLRSFramebuffer::query_attachment`.
+///
+/// The framebuffer is bound and the type of the attached object is queried when the view is created, the
+/// methods only query the parameter they return. Parameters that do not exist for the attached object are
+/// `None` without asking WebGL.
+pub struct AttachmentQuery<'a, 'ctx: 'a, B: 'ctx + FramebufferBackend> {
+    framebuffer: &'a WebGLRSFramebuffer<'ctx, B>,
+    attachment: Attachment,
+    object_type: AttachmentObjectType,
+}
+
+impl<'a, 'ctx, B: FramebufferBackend> AttachmentQuery<'a, 'ctx, B> {
+    /// Returns the attachment point that is queried.
+    pub fn attachment(&self) -> Attachment {
+        self.attachment
+    }
+
+    /// Returns the type of the attached object.
+    pub fn object_type(&self) -> AttachmentObjectType {
+        self.object_type
+    }
+
+    /// Returns the mipmap level of the attached texture, `None` if no texture is attached.
+    pub fn texture_level(&self) -> Option<i32> {
+        self.texture_parameter(FramebufferAttachmentParameter::TextureLevel)
+    }
+
+    /// Returns the layer of the attached 3D or 2D array texture, `None` if no texture is attached. Requires
+    /// WebGL2.
+    pub fn texture_layer(&self) -> Option<i32> {
+        self.texture_parameter(FramebufferAttachmentParameter::TextureLayer)
+    }
+
+    /// Returns the face of the attached cube map texture, `None` if no cube map texture is attached.
+    pub fn cube_face(&self) -> Option<TextureBindPoint> {
+        let value = self.texture_parameter(FramebufferAttachmentParameter::TextureCubeMapFace)?;
+        [
+            TextureBindPoint::TextureCubeMapPositiveX,
+            TextureBindPoint::TextureCubeMapNegativeX,
+            TextureBindPoint::TextureCubeMapPositiveY,
+            TextureBindPoint::TextureCubeMapNegativeY,
+            TextureBindPoint::TextureCubeMapPositiveZ,
+            TextureBindPoint::TextureCubeMapNegativeZ,
+        ]
+        .iter()
+        .find(|&&face| face as i32 == value)
+        .cloned()
+    }
+
+    /// Returns whether the attached image stores linear or sRGB values, `None` if nothing is attached.
+    ///
+    /// Requires WebGL2 or the `EXT_sRGB` extension.
+    pub fn color_encoding(&self) -> Option<ColorEncoding> {
+        let value = self.attached_parameter(FramebufferAttachmentParameter::ColorEncoding)?;
+        [ColorEncoding::Linear, ColorEncoding::Srgb]
+            .iter()
+            .find(|&&encoding| encoding as i32 == value)
+            .cloned()
+    }
+
+    /// Returns the type of the components of the attached image, `None` if nothing is attached.
+    ///
+    /// Requires WebGL2 or one of the color buffer float extensions. The components of a
+    /// `Attachment::DepthStencilAttachment` can not be queried as a whole.
+    pub fn component_type(&self) -> Option<ComponentType> {
+        let value = self.attached_parameter(FramebufferAttachmentParameter::ComponentType)?;
+        [
+            ComponentType::Float,
+            ComponentType::Int,
+            ComponentType::UnsignedInt,
+            ComponentType::SignedNormalized,
+            ComponentType::UnsignedNormalized,
+        ]
+        .iter()
+        .find(|&&component_type| component_type as i32 == value)
+        .cloned()
+    }
+
+    /// Returns the number of bits of every component of the attached image, all zero if nothing is
+    /// attached. Requires WebGL2.
+    pub fn bit_sizes(&self) -> AttachmentBitSizes {
+        let size = |pname| self.attached_parameter(pname).unwrap_or(0).max(0) as u32;
+        AttachmentBitSizes {
+            red: size(FramebufferAttachmentParameter::RedSize),
+            green: size(FramebufferAttachmentParameter::GreenSize),
+            blue: size(FramebufferAttachmentParameter::BlueSize),
+            alpha: size(FramebufferAttachmentParameter::AlphaSize),
+            depth: size(FramebufferAttachmentParameter::DepthSize),
+            stencil: size(FramebufferAttachmentParameter::StencilSize),
+        }
+    }
+
+    /// Queries a parameter that only exists when an image is attached.
+    fn attached_parameter(&self, pname: FramebufferAttachmentParameter) -> Option<i32> {
+        if self.object_type == AttachmentObjectType::None {
+            return None;
+        }
+        Some(
+            self.framebuffer
+                .context
+                ._get_framebuffer_attachment_parameter_i32(
+                    FramebufferKind::Framebuffer,
+                    self.attachment,
+                    pname,
+                ),
+        )
+    }
+
+    /// Queries a parameter that only exists for texture attachments.
+    fn texture_parameter(&self, pname: FramebufferAttachmentParameter) -> Option<i32> {
+        if self.object_type == AttachmentObjectType::Texture {
+            self.attached_parameter(pname)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, 'ctx, B: FramebufferBackend + TextureBackend + RenderbufferBackend>
+    AttachmentQuery<'a, 'ctx, B>
+{
+    /// Returns the handle of the attached texture or renderbuffer.
+    pub fn object(&self) -> AttachedObject<B> {
+        let context = self.framebuffer.context;
+        let target = FramebufferKind::Framebuffer;
+        let pname = FramebufferAttachmentParameter::ObjectName;
+        let object = match self.object_type {
+            AttachmentObjectType::Texture => context
+                ._get_framebuffer_attachment_parameter_texture(target, self.attachment, pname)
+                .map(AttachedObject::Texture),
+            AttachmentObjectType::Renderbuffer => context
+                ._get_framebuffer_attachment_parameter_renderbuffer(target, self.attachment, pname)
+                .map(AttachedObject::Renderbuffer),
+            AttachmentObjectType::None => None,
+        };
+        object.unwrap_or(AttachedObject::None)
     }
 }
 
@@ -48,7 +578,7 @@ impl<'ctx> WebGLRSFramebuffer<'ctx> {
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLFramebuffer;
+    pub type WebGLFramebuffer;
     /// Binding for `WebGLRenderingContext.createFramebuffer()`
     #[wasm_bindgen(method, js_name = createFramebuffer)]
     fn _create_framebuffer(this: &WebGL2RenderingContext) -> WebGLFramebuffer;
@@ -68,4 +598,13 @@ extern "C" {
     /// Binding for `WebGLRenderingContext.isFramebuffer()`
     #[wasm_bindgen(method, js_name = isFramebuffer)]
     fn _is_framebuffer(this: &WebGL2RenderingContext, framebuffer: &WebGLFramebuffer) -> bool;
+
+    /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is `i32`
+    #[wasm_bindgen(method, js_name = getFramebufferAttachmentParameter)]
+    fn _get_framebuffer_attachment_parameter_i32(
+        this: &WebGL2RenderingContext,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> i32;
 }
@@ -0,0 +1,473 @@
+//! Builder that creates a `WebGLRSFramebuffer` with all of its attachments in one go
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let color = gl.create_texture();
+//! let normals = gl.create_texture();
+//! let framebuffer = FramebufferBuilder::new(&gl)
+//!     .color(0, AttachmentImage::texture(&color, 0, 512, 512))
+//!     .color(1, AttachmentImage::texture(&normals, 0, 512, 512))
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(framebuffer.attachments().len(), 2);
+//! assert!(gl.calls().iter().any(|call| call.to_string()
+//!     == "drawBuffers([ColorAttachment0, ColorAttachment1])"));
+//!
+//! // the recording backend reports renderbuffers of 0x0 pixels
+//! let depth = gl.create_renderbuffer();
+//! let error = FramebufferBuilder::new(&gl)
+//!     .color(1, AttachmentImage::texture(&normals, 0, 512, 512))
+//!     .depth(AttachmentImage::renderbuffer(&depth))
+//!     .build()
+//!     .err()
+//!     .unwrap();
+//! assert_eq!(error.to_string(), "COLOR_ATTACHMENT1 is 512x512 but DEPTH is 0x0");
+//! ```
+use backend::GlBackend;
+use formats::FormatError;
+use framebuffer::attachment_name;
+use framebuffer::{AttachedImage, AttachmentInfo, WebGLRSFramebuffer};
+use glenum::{
+    Attachment, ColorBuffer, FramebufferKind, FramebufferStatus, RenderbufferKind, TextureBindPoint,
+};
+use renderbuffer::WebGLRSRenderbuffer;
+use std::error;
+use std::fmt;
+use texture::{WebGL2TextureBackend, WebGLRSTexture};
+
+/// The color attachment points, indexed by their number.
+pub(crate) const COLOR_ATTACHMENTS: [Attachment; 16] = [
+    Attachment::ColorAttachment0,
+    Attachment::ColorAttachment1,
+    Attachment::ColorAttachment2,
+    Attachment::ColorAttachment3,
+    Attachment::ColorAttachment4,
+    Attachment::ColorAttachment5,
+    Attachment::ColorAttachment6,
+    Attachment::ColorAttachment7,
+    Attachment::ColorAttachment8,
+    Attachment::ColorAttachment9,
+    Attachment::ColorAttachment10,
+    Attachment::ColorAttachment11,
+    Attachment::ColorAttachment12,
+    Attachment::ColorAttachment13,
+    Attachment::ColorAttachment14,
+    Attachment::ColorAttachment15,
+];
+
+/// The draw buffers writing to the color attachments, indexed by their number.
+pub(crate) const DRAW_BUFFERS: [ColorBuffer; 16] = [
+    ColorBuffer::ColorAttachment0,
+    ColorBuffer::ColorAttachment1,
+    ColorBuffer::ColorAttachment2,
+    ColorBuffer::ColorAttachment3,
+    ColorBuffer::ColorAttachment4,
+    ColorBuffer::ColorAttachment5,
+    ColorBuffer::ColorAttachment6,
+    ColorBuffer::ColorAttachment7,
+    ColorBuffer::ColorAttachment8,
+    ColorBuffer::ColorAttachment9,
+    ColorBuffer::ColorAttachment10,
+    ColorBuffer::ColorAttachment11,
+    ColorBuffer::ColorAttachment12,
+    ColorBuffer::ColorAttachment13,
+    ColorBuffer::ColorAttachment14,
+    ColorBuffer::ColorAttachment15,
+];
+
+/// Attaches a texture layer, only available on WebGL2.
+type AttachLayer<'ctx, B> = fn(&WebGLRSTexture<'ctx, B>, FramebufferKind, Attachment, i32, i32);
+
+/// Where the image of an attachment comes from.
+enum Source<'a, 'ctx: 'a, B: 'ctx + GlBackend> {
+    Texture {
+        texture: &'a WebGLRSTexture<'ctx, B>,
+        target: TextureBindPoint,
+        level: i32,
+    },
+    TextureLayer {
+        texture: &'a WebGLRSTexture<'ctx, B>,
+        level: i32,
+        layer: i32,
+        attach: AttachLayer<'ctx, B>,
+    },
+    Renderbuffer(&'a WebGLRSRenderbuffer<'ctx, B>),
+}
+
+/// An image that can be attached to a framebuffer by a `FramebufferBuilder`.
+///
+/// WebGL can not query the size of a texture, so the size of texture images has to be given. The size of a
+/// renderbuffer is queried when the framebuffer is built.
+pub struct AttachmentImage<'a, 'ctx: 'a, B: 'ctx + GlBackend> {
+    source: Source<'a, 'ctx, B>,
+    width: u32,
+    height: u32,
+}
+
+impl<'a, 'ctx, B: GlBackend> AttachmentImage<'a, 'ctx, B> {
+    /// A mipmap level of a 2D texture.
+    ///
+    /// # Arguments
+    /// * `texture` - the texture.
+    /// * `level` - the mipmap level.
+    /// * `width` - the width of the mipmap level.
+    /// * `height` - the height of the mipmap level.
+    pub fn texture(
+        texture: &'a WebGLRSTexture<'ctx, B>,
+        level: i32,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        AttachmentImage {
+            source: Source::Texture {
+                texture,
+                target: TextureBindPoint::Texture2d,
+                level,
+            },
+            width,
+            height,
+        }
+    }
+
+    /// A mipmap level of a face of a cube map texture.
+    ///
+    /// # Arguments
+    /// * `texture` - the cube map texture.
+    /// * `face` - the face, one of the `TextureBindPoint::TextureCubeMap*` targets.
+    /// * `level` - the mipmap level.
+    /// * `size` - the width and height of the mipmap level.
+    pub fn cube_face(
+        texture: &'a WebGLRSTexture<'ctx, B>,
+        face: TextureBindPoint,
+        level: i32,
+        size: u32,
+    ) -> Self {
+        AttachmentImage {
+            source: Source::Texture {
+                texture,
+                target: face,
+                level,
+            },
+            width: size,
+            height: size,
+        }
+    }
+
+    /// A renderbuffer, its size is queried when the framebuffer is built.
+    pub fn renderbuffer(renderbuffer: &'a WebGLRSRenderbuffer<'ctx, B>) -> Self {
+        AttachmentImage {
+            source: Source::Renderbuffer(renderbuffer),
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+impl<'a, 'ctx, B: GlBackend + WebGL2TextureBackend> AttachmentImage<'a, 'ctx, B> {
+    /// A layer of a mipmap level of a 3D or 2D array texture.
+    ///
+    /// # Arguments
+    /// * `texture` - the texture.
+    /// * `level` - the mipmap level.
+    /// * `layer` - the layer.
+    /// * `width` - the width of the mipmap level.
+    /// * `height` - the height of the mipmap level.
+    pub fn layer(
+        texture: &'a WebGLRSTexture<'ctx, B>,
+        level: i32,
+        layer: i32,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        AttachmentImage {
+            source: Source::TextureLayer {
+                texture,
+                level,
+                layer,
+                attach: WebGLRSTexture::attach_layer_framebuffer,
+            },
+            width,
+            height,
+        }
+    }
+}
+
+/// Creates a framebuffer, attaches its images and reports why it is incomplete as a `FramebufferError`.
+///
+/// The framebuffer stays bound to `FramebufferKind::Framebuffer` after `build`, renderbuffers that were
+/// queried for their size stay bound as well.
+pub struct FramebufferBuilder<'a, 'ctx: 'a, B: 'ctx + GlBackend> {
+    context: &'ctx B,
+    attachments: Vec<(Attachment, AttachmentImage<'a, 'ctx, B>)>,
+}
+
+impl<'a, 'ctx, B: GlBackend> FramebufferBuilder<'a, 'ctx, B> {
+    /// Creates a builder for a framebuffer of the given context.
+    pub fn new(context: &'ctx B) -> Self {
+        FramebufferBuilder {
+            context,
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Attaches an image to a color attachment point, replacing the image attached to it before.
+    ///
+    /// The draw buffers of the framebuffer are set to write to every attached color attachment.
+    ///
+    /// # Arguments
+    /// * `index` - the number of the color attachment, 0 to 15.
+    /// * `image` - the image to attach.
+    ///
+    /// # Panics
+    /// Panics if the index is larger than 15.
+    pub fn color(self, index: u32, image: AttachmentImage<'a, 'ctx, B>) -> Self {
+        assert!(index < 16, "color attachment {} does not exist", index);
+        self.attach(COLOR_ATTACHMENTS[index as usize], image)
+    }
+
+    /// Attaches an image to the depth attachment point.
+    pub fn depth(self, image: AttachmentImage<'a, 'ctx, B>) -> Self {
+        self.attach(Attachment::DepthAttachment, image)
+    }
+
+    /// Attaches an image to the stencil attachment point.
+    pub fn stencil(self, image: AttachmentImage<'a, 'ctx, B>) -> Self {
+        self.attach(Attachment::StencilAttachment, image)
+    }
+
+    /// Attaches an image to the combined depth and stencil attachment point.
+    pub fn depth_stencil(self, image: AttachmentImage<'a, 'ctx, B>) -> Self {
+        self.attach(Attachment::DepthStencilAttachment, image)
+    }
+
+    /// Attaches an image to an attachment point, replacing the image attached to it before.
+    pub fn attach(mut self, attachment: Attachment, image: AttachmentImage<'a, 'ctx, B>) -> Self {
+        self.attachments.retain(|&(other, _)| other != attachment);
+        self.attachments.push((attachment, image));
+        self
+    }
+
+    /// Creates the framebuffer and attaches the images.
+    ///
+    /// Fails when no image is attached, when a depth or stencil image is combined with a depth-stencil
+    /// image, when the attached images differ in size or when `checkFramebufferStatus` reports the
+    /// framebuffer as incomplete. The framebuffer is deleted again on failure.
+    pub fn build(self) -> Result<WebGLRSFramebuffer<'ctx, B>, FramebufferError> {
+        if self.attachments.is_empty() {
+            return Err(FramebufferError::MissingAttachment);
+        }
+        if self.has(Attachment::DepthStencilAttachment) {
+            for &attachment in &[Attachment::DepthAttachment, Attachment::StencilAttachment] {
+                if self.has(attachment) {
+                    return Err(FramebufferError::DepthStencilConflict { attachment });
+                }
+            }
+        }
+
+        let target = FramebufferKind::Framebuffer;
+        let mut framebuffer = self.context.create_framebuffer();
+        framebuffer.bind(target);
+        let mut infos = Vec::with_capacity(self.attachments.len());
+        for &(attachment, ref image) in &self.attachments {
+            let info = match image.source {
+                Source::Texture {
+                    texture,
+                    target: texture_target,
+                    level,
+                } => {
+                    texture.attach_framebuffer(target, attachment, texture_target, level);
+                    AttachmentInfo {
+                        attachment,
+                        image: AttachedImage::Texture {
+                            target: texture_target,
+                            level,
+                        },
+                        width: image.width,
+                        height: image.height,
+                    }
+                }
+                Source::TextureLayer {
+                    texture,
+                    level,
+                    layer,
+                    attach,
+                } => {
+                    attach(texture, target, attachment, level, layer);
+                    AttachmentInfo {
+                        attachment,
+                        image: AttachedImage::TextureLayer { level, layer },
+                        width: image.width,
+                        height: image.height,
+                    }
+                }
+                Source::Renderbuffer(renderbuffer) => {
+                    let kind = RenderbufferKind::Renderbuffer;
+                    renderbuffer.attach_framebuffer(target, attachment, kind);
+                    renderbuffer.bind(kind);
+                    AttachmentInfo {
+                        attachment,
+                        image: AttachedImage::Renderbuffer {
+                            internal_format: self.context.renderbuffer_internal_format(kind),
+                        },
+                        width: self.context.renderbuffer_width(kind).max(0) as u32,
+                        height: self.context.renderbuffer_height(kind).max(0) as u32,
+                    }
+                }
+            };
+            infos.push(info);
+        }
+
+        if let Some(other) = infos
+            .iter()
+            .find(|info| (info.width, info.height) != (infos[0].width, infos[0].height))
+        {
+            return Err(FramebufferError::DimensionMismatch {
+                attachment: infos[0],
+                other: *other,
+            });
+        }
+
+        let draw_buffers = self.draw_buffers();
+        if !draw_buffers.is_empty() && draw_buffers != [ColorBuffer::ColorAttachment0] {
+            self.context.draw_buffers(&draw_buffers);
+        }
+
+        let status = self.context.check_framebuffer_status(target);
+        if status != FramebufferStatus::FramebufferComplete {
+            return Err(FramebufferError::Incomplete {
+                status,
+                attachments: infos,
+            });
+        }
+        framebuffer.set_attachments(infos);
+        Ok(framebuffer)
+    }
+
+    /// Returns true if an image is attached to the attachment point.
+    fn has(&self, attachment: Attachment) -> bool {
+        self.attachments
+            .iter()
+            .any(|&(other, _)| other == attachment)
+    }
+
+    /// Returns the draw buffers writing to the attached color attachments, up to the last attached one.
+    fn draw_buffers(&self) -> Vec<ColorBuffer> {
+        let count = (0..16)
+            .rev()
+            .find(|&index| self.has(COLOR_ATTACHMENTS[index]))
+            .map_or(0, |index| index + 1);
+        (0..count)
+            .map(|index| {
+                if self.has(COLOR_ATTACHMENTS[index]) {
+                    DRAW_BUFFERS[index]
+                } else {
+                    ColorBuffer::None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Error returned by `FramebufferBuilder::build` when the framebuffer can not be used as a render target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FramebufferError {
+    /// No images were attached.
+    MissingAttachment,
+    /// A depth or stencil attachment was combined with a depth-stencil attachment.
+    DepthStencilConflict {
+        /// The depth or stencil attachment point.
+        attachment: Attachment,
+    },
+    /// Two attached images differ in size.
+    DimensionMismatch {
+        /// The first attachment.
+        attachment: AttachmentInfo,
+        /// The attachment with a different size.
+        other: AttachmentInfo,
+    },
+    /// `checkFramebufferStatus` reported the framebuffer as incomplete.
+    Incomplete {
+        /// The status returned by `checkFramebufferStatus`.
+        status: FramebufferStatus,
+        /// The attached images.
+        attachments: Vec<AttachmentInfo>,
+    },
+    /// The internal format requested for an attachment can not be rendered to.
+    Format {
+        /// The attachment point.
+        attachment: Attachment,
+        /// Why the format was rejected.
+        error: FormatError,
+    },
+}
+
+/// Explains why a framebuffer with the given status is incomplete.
+fn status_reason(status: FramebufferStatus) -> &'static str {
+    match status {
+        FramebufferStatus::FramebufferComplete => "it is complete",
+        FramebufferStatus::FramebufferIncompleteAttachment => {
+            "an attached image is not renderable or has a size of zero"
+        }
+        FramebufferStatus::FramebufferIncompleteMissingAttachment => "no image is attached",
+        FramebufferStatus::FramebufferIncompleteDimensions => "the attached images differ in size",
+        FramebufferStatus::FramebufferUnsupported => {
+            "the combination of attached formats is not supported"
+        }
+        FramebufferStatus::FramebufferIncompleteMultisample => {
+            "the attached images differ in the number of samples"
+        }
+    }
+}
+
+impl fmt::Display for FramebufferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FramebufferError::MissingAttachment => {
+                f.write_str("the framebuffer has no attachments")
+            }
+            FramebufferError::DepthStencilConflict { attachment } => write!(
+                f,
+                "{} can not be attached together with DEPTH_STENCIL",
+                attachment_name(attachment)
+            ),
+            FramebufferError::DimensionMismatch {
+                ref attachment,
+                ref other,
+            } => write!(
+                f,
+                "{} is {}x{} but {} is {}x{}",
+                attachment_name(attachment.attachment),
+                attachment.width,
+                attachment.height,
+                attachment_name(other.attachment),
+                other.width,
+                other.height
+            ),
+            FramebufferError::Incomplete {
+                status,
+                ref attachments,
+            } => {
+                write!(
+                    f,
+                    "the framebuffer is incomplete because {}",
+                    status_reason(status)
+                )?;
+                for (index, attachment) in attachments.iter().enumerate() {
+                    f.write_str(if index == 0 { ", attachments: " } else { ", " })?;
+                    write!(f, "{}", attachment)?;
+                }
+                Ok(())
+            }
+            FramebufferError::Format { attachment, error } => write!(
+                f,
+                "{} can not be created: {}",
+                attachment_name(attachment),
+                error
+            ),
+        }
+    }
+}
+
+impl error::Error for FramebufferError {}
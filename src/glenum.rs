@@ -5,11 +5,13 @@
     
     Documentation taken from https://developer.mozilla.org/en-US/docs/Web/API/WebGL_API/Constants
 */
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 /// Constants passed to WebGLRenderingContext.vertexAttribPointer()
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AttributeSize {
     One = 1,
     Two = 2,
@@ -284,6 +286,8 @@ pub enum Parameter {
     ArrayBufferBinding = 0x8894,
     /// Passed to getParameter to get the current element array buffer.
     ElementArrayBufferBinding = 0x8895,
+    /// Passed to getParameter to get the current vertex array object, same as `VERTEX_ARRAY_BINDING_OES`.
+    VertexArrayBinding = 0x85B5,
     /// Passed to getParameter to get the current lineWidth (set by the lineWidth method).
     LineWidth = 0x0B21,
     /// Passed to getParameter to get the current size of a point drawn with gl.POINTS
@@ -344,6 +348,10 @@ pub enum Parameter {
     UnpackAlignment = 0x0CF5,
     ///
     PackAlignment = 0x0D05,
+    /// Whether pixels from DOM sources are flipped vertically when they are uploaded.
+    UnpackFlipYWebgl = 0x9240,
+    /// Whether the colors of DOM sources are multiplied by their alpha when they are uploaded.
+    UnpackPremultiplyAlphaWebgl = 0x9241,
     ///
     MaxTextureSize = 0x0D33,
     ///
@@ -396,6 +404,37 @@ pub enum Parameter {
 
     ///
     MaxCubeMapTextureSize = 0x851C,
+    /// Passed to getParameter to get the largest width, height and depth of a 3D texture. Requires WebGL2.
+    Max3dTextureSize = 0x8073,
+    /// Passed to getParameter to get the maximum number of layers of an array texture. Requires WebGL2.
+    MaxArrayTextureLayers = 0x88FF,
+    /// Passed to getParameter to get the largest width and height of a renderbuffer.
+    MaxRenderbufferSize = 0x84E8,
+    /// Passed to getParameter to get the maximum number of vertex attributes.
+    MaxVertexAttribs = 0x8869,
+    /// Passed to getParameter to get the maximum number of uniform vectors in a vertex shader.
+    MaxVertexUniformVectors = 0x8DFB,
+    /// Passed to getParameter to get the maximum number of uniform vectors in a fragment shader.
+    MaxFragmentUniformVectors = 0x8DFD,
+    /// Passed to getParameter to get the maximum number of varying vectors.
+    MaxVaryingVectors = 0x8DFC,
+    /// Passed to getParameter to get the number of texture units available to a fragment shader.
+    MaxTextureImageUnits = 0x8872,
+    /// Passed to getParameter to get the number of texture units available to a vertex shader.
+    MaxVertexTextureImageUnits = 0x8B4C,
+    /// Passed to getParameter to get the number of texture units available to all shaders combined.
+    MaxCombinedTextureImageUnits = 0x8B4D,
+    /// Passed to getParameter to get the maximum number of uniform buffer binding points. Requires WebGL2.
+    MaxUniformBufferBindings = 0x8A2F,
+    /// Passed to getParameter to get the maximum number of color attachments of a framebuffer.
+    /// Requires WebGL2 or the `WEBGL_draw_buffers` extension.
+    MaxColorAttachments = 0x8CDF,
+    /// Passed to getParameter to get the maximum number of draw buffers.
+    /// Requires WebGL2 or the `WEBGL_draw_buffers` extension.
+    MaxDrawBuffers = 0x8824,
+    /// Passed to getParameter to get the maximum number of samples of a multisample renderbuffer.
+    /// Requires WebGL2.
+    MaxSamples = 0x8D57,
 }
 
 /// Constants passed to WebGLRenderingContext.getVertexAttrib().
@@ -528,7 +567,7 @@ pub enum StencilAction {
 }
 
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PixelType {
     ///
     UnsignedByte = 0x1401,
@@ -540,10 +579,32 @@ pub enum PixelType {
     UnsignedShort565 = 0x8363,
     ///
     Float = 0x1406,
+    /// Requires WebGL2 or the `OES_texture_half_float` extension
+    HalfFloat = 0x140B,
+    /// Requires WebGL2
+    Byte = 0x1400,
+    /// Requires WebGL2 or the `WEBGL_depth_texture` extension
+    UnsignedShort = 0x1403,
+    /// Requires WebGL2
+    Short = 0x1402,
+    /// Requires WebGL2 or the `WEBGL_depth_texture` extension
+    UnsignedInt = 0x1405,
+    /// Requires WebGL2
+    Int = 0x1404,
+    /// Requires WebGL2
+    UnsignedInt2101010Rev = 0x8368,
+    /// Requires WebGL2
+    UnsignedInt10f11f11fRev = 0x8C3B,
+    /// Requires WebGL2
+    UnsignedInt5999Rev = 0x8C3E,
+    /// Requires WebGL2 or the `WEBGL_depth_texture` extension
+    UnsignedInt248 = 0x84FA,
+    /// Requires WebGL2
+    Float32UnsignedInt248Rev = 0x8DAD,
 }
 
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PixelFormat {
     ///
     DepthComponent = 0x1902,
@@ -557,6 +618,20 @@ pub enum PixelFormat {
     Luminance = 0x1909,
     ///
     LuminanceAlpha = 0x190A,
+    /// Requires WebGL2
+    Red = 0x1903,
+    /// Requires WebGL2
+    Rg = 0x8227,
+    /// Requires WebGL2
+    RedInteger = 0x8D94,
+    /// Requires WebGL2
+    RgInteger = 0x8228,
+    /// Requires WebGL2
+    RgbInteger = 0x8D98,
+    /// Requires WebGL2
+    RgbaInteger = 0x8D99,
+    /// Requires WebGL2 or the `WEBGL_depth_texture` extension
+    DepthStencil = 0x84F9,
 }
 
 #[wasm_bindgen]
@@ -609,7 +684,7 @@ pub enum HintTarget {
 
 /// WebGLRenderingContext.texParameter[fi]() or WebGLRenderingContext.bindTexture() "target" parameter
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextureKind {
     ///
     Texture2d = 0x0DE1,
@@ -675,7 +750,7 @@ pub enum TextureParameter {
 
 /// WebGLRenderingContext.texImage2D() "target" parameter
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextureBindPoint {
     ///
     Texture2d = 0x0DE1,
@@ -863,10 +938,14 @@ pub enum ShaderPrecision {
     HighInt = 0x8DF5,
 }
 
-/// Constants passed to WebGLRenderingContext.hint()
+/// Types of active attributes, uniforms and transform feedback varyings, returned by getActiveAttrib,
+/// getActiveUniform and getTransformFeedbackVarying.
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UniformType {
+    ///
+    Float = 0x1406,
     ///
     FloatVec2 = 0x8B50,
     ///
@@ -874,12 +953,22 @@ pub enum UniformType {
     ///
     FloatVec4 = 0x8B52,
     ///
+    Int = 0x1404,
+    ///
     IntVec2 = 0x8B53,
     ///
     IntVec3 = 0x8B54,
     ///
     IntVec4 = 0x8B55,
     ///
+    UnsignedInt = 0x1405,
+    ///
+    UnsignedIntVec2 = 0x8DC6,
+    ///
+    UnsignedIntVec3 = 0x8DC7,
+    ///
+    UnsignedIntVec4 = 0x8DC8,
+    ///
     Bool = 0x8B56,
     ///
     BoolVec2 = 0x8B57,
@@ -894,14 +983,93 @@ pub enum UniformType {
     ///
     FloatMat4 = 0x8B5C,
     ///
+    FloatMat2x3 = 0x8B65,
+    ///
+    FloatMat2x4 = 0x8B66,
+    ///
+    FloatMat3x2 = 0x8B67,
+    ///
+    FloatMat3x4 = 0x8B68,
+    ///
+    FloatMat4x2 = 0x8B69,
+    ///
+    FloatMat4x3 = 0x8B6A,
+    ///
     Sampler2d = 0x8B5E,
     ///
+    Sampler3d = 0x8B5F,
+    ///
     SamplerCube = 0x8B60,
+    ///
+    Sampler2dShadow = 0x8B62,
+    ///
+    Sampler2dArray = 0x8DC1,
+    ///
+    Sampler2dArrayShadow = 0x8DC4,
+    ///
+    SamplerCubeShadow = 0x8DC5,
+    ///
+    IntSampler2d = 0x8DCA,
+    ///
+    IntSampler3d = 0x8DCB,
+    ///
+    IntSamplerCube = 0x8DCC,
+    ///
+    IntSampler2dArray = 0x8DCF,
+    ///
+    UnsignedIntSampler2d = 0x8DD2,
+    ///
+    UnsignedIntSampler3d = 0x8DD3,
+    ///
+    UnsignedIntSamplerCube = 0x8DD4,
+    ///
+    UnsignedIntSampler2dArray = 0x8DD7,
 }
 
-///
+/// Constants passed to WebGL2RenderingContext.getActiveUniforms()
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy)]
+pub enum UniformParameter {
+    /// Passed to getActiveUniforms to get the types of the uniforms.
+    UniformType = 0x8A37,
+    /// Passed to getActiveUniforms to get the array sizes of the uniforms.
+    UniformSize = 0x8A38,
+    /// Passed to getActiveUniforms to get the indices of the uniform blocks of the uniforms, -1 for uniforms
+    /// outside a block.
+    UniformBlockIndex = 0x8A3A,
+    /// Passed to getActiveUniforms to get the byte offsets of the uniforms in their block.
+    UniformOffset = 0x8A3B,
+    /// Passed to getActiveUniforms to get the byte strides between the elements of array uniforms.
+    UniformArrayStride = 0x8A3C,
+    /// Passed to getActiveUniforms to get the byte strides between the columns or rows of matrix uniforms.
+    UniformMatrixStride = 0x8A3D,
+    /// Passed to getActiveUniforms to get whether the matrix uniforms are stored in row major order.
+    UniformIsRowMajor = 0x8A3E,
+}
+
+/// Constants passed to WebGL2RenderingContext.getActiveUniformBlockParameter()
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub enum UniformBlockParameter {
+    /// Passed to getActiveUniformBlockParameter to get the binding point of the block.
+    UniformBlockBinding = 0x8A3F,
+    /// Passed to getActiveUniformBlockParameter to get the minimum buffer size in bytes for the block.
+    UniformBlockDataSize = 0x8A40,
+    /// Passed to getActiveUniformBlockParameter to get the number of active uniforms in the block.
+    UniformBlockActiveUniforms = 0x8A42,
+    /// Passed to getActiveUniformBlockParameter to get the indices of the active uniforms in the block.
+    UniformBlockActiveUniformIndices = 0x8A43,
+    /// Passed to getActiveUniformBlockParameter to get whether the block is used by the vertex shader.
+    UniformBlockReferencedByVertexShader = 0x8A44,
+    /// Passed to getActiveUniformBlockParameter to get whether the block is used by the fragment shader.
+    UniformBlockReferencedByFragmentShader = 0x8A46,
+}
+
+/// Compressed texture formats, each family is provided by a WebGL extension.
+///
+/// See `formats::compressed_format_info` for the block size and extension of every format.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextureCompression {
     /// A DXT1-compressed image in an RGB image format.
     RgbDxt1 = 0x83F0,
@@ -914,6 +1082,116 @@ pub enum TextureCompression {
     /// It also provides a 4:1 compression,
     /// but differs to the DXT3 compression in how the alpha compression is done.
     RgbaDxt5 = 0x83F3,
+    /// A DXT1-compressed image in an sRGB image format.
+    SrgbDxt1 = 0x8C4C,
+    /// A DXT1-compressed image in an sRGB image format with a simple on/off alpha value.
+    SrgbAlphaDxt1 = 0x8C4D,
+    /// A DXT3-compressed image in an sRGB image format with alpha.
+    SrgbAlphaDxt3 = 0x8C4E,
+    /// A DXT5-compressed image in an sRGB image format with alpha.
+    SrgbAlphaDxt5 = 0x8C4F,
+    /// One channel unsigned EAC format.
+    R11Eac = 0x9270,
+    /// One channel signed EAC format.
+    SignedR11Eac = 0x9271,
+    /// Two channel unsigned EAC format.
+    Rg11Eac = 0x9272,
+    /// Two channel signed EAC format.
+    SignedRg11Eac = 0x9273,
+    /// RGB ETC2 format.
+    Rgb8Etc2 = 0x9274,
+    /// sRGB ETC2 format.
+    Srgb8Etc2 = 0x9275,
+    /// RGB ETC2 format with a simple on/off alpha value.
+    Rgb8PunchthroughAlpha1Etc2 = 0x9276,
+    /// sRGB ETC2 format with a simple on/off alpha value.
+    Srgb8PunchthroughAlpha1Etc2 = 0x9277,
+    /// RGBA ETC2 format with EAC compressed alpha.
+    Rgba8Etc2Eac = 0x9278,
+    /// sRGB ETC2 format with EAC compressed alpha.
+    Srgb8Alpha8Etc2Eac = 0x9279,
+    /// RGB ETC1 format, can not be updated with `compressedTexSubImage2D`.
+    RgbEtc1 = 0x8D64,
+    /// RGBA ASTC format with 4x4 blocks.
+    RgbaAstc4x4 = 0x93B0,
+    /// RGBA ASTC format with 5x4 blocks.
+    RgbaAstc5x4 = 0x93B1,
+    /// RGBA ASTC format with 5x5 blocks.
+    RgbaAstc5x5 = 0x93B2,
+    /// RGBA ASTC format with 6x5 blocks.
+    RgbaAstc6x5 = 0x93B3,
+    /// RGBA ASTC format with 6x6 blocks.
+    RgbaAstc6x6 = 0x93B4,
+    /// RGBA ASTC format with 8x5 blocks.
+    RgbaAstc8x5 = 0x93B5,
+    /// RGBA ASTC format with 8x6 blocks.
+    RgbaAstc8x6 = 0x93B6,
+    /// RGBA ASTC format with 8x8 blocks.
+    RgbaAstc8x8 = 0x93B7,
+    /// RGBA ASTC format with 10x5 blocks.
+    RgbaAstc10x5 = 0x93B8,
+    /// RGBA ASTC format with 10x6 blocks.
+    RgbaAstc10x6 = 0x93B9,
+    /// RGBA ASTC format with 10x8 blocks.
+    RgbaAstc10x8 = 0x93BA,
+    /// RGBA ASTC format with 10x10 blocks.
+    RgbaAstc10x10 = 0x93BB,
+    /// RGBA ASTC format with 12x10 blocks.
+    RgbaAstc12x10 = 0x93BC,
+    /// RGBA ASTC format with 12x12 blocks.
+    RgbaAstc12x12 = 0x93BD,
+    /// sRGB ASTC format with alpha and 4x4 blocks.
+    Srgb8Alpha8Astc4x4 = 0x93D0,
+    /// sRGB ASTC format with alpha and 5x4 blocks.
+    Srgb8Alpha8Astc5x4 = 0x93D1,
+    /// sRGB ASTC format with alpha and 5x5 blocks.
+    Srgb8Alpha8Astc5x5 = 0x93D2,
+    /// sRGB ASTC format with alpha and 6x5 blocks.
+    Srgb8Alpha8Astc6x5 = 0x93D3,
+    /// sRGB ASTC format with alpha and 6x6 blocks.
+    Srgb8Alpha8Astc6x6 = 0x93D4,
+    /// sRGB ASTC format with alpha and 8x5 blocks.
+    Srgb8Alpha8Astc8x5 = 0x93D5,
+    /// sRGB ASTC format with alpha and 8x6 blocks.
+    Srgb8Alpha8Astc8x6 = 0x93D6,
+    /// sRGB ASTC format with alpha and 8x8 blocks.
+    Srgb8Alpha8Astc8x8 = 0x93D7,
+    /// sRGB ASTC format with alpha and 10x5 blocks.
+    Srgb8Alpha8Astc10x5 = 0x93D8,
+    /// sRGB ASTC format with alpha and 10x6 blocks.
+    Srgb8Alpha8Astc10x6 = 0x93D9,
+    /// sRGB ASTC format with alpha and 10x8 blocks.
+    Srgb8Alpha8Astc10x8 = 0x93DA,
+    /// sRGB ASTC format with alpha and 10x10 blocks.
+    Srgb8Alpha8Astc10x10 = 0x93DB,
+    /// sRGB ASTC format with alpha and 12x10 blocks.
+    Srgb8Alpha8Astc12x10 = 0x93DC,
+    /// sRGB ASTC format with alpha and 12x12 blocks.
+    Srgb8Alpha8Astc12x12 = 0x93DD,
+    /// RGBA BPTC format with unsigned normalized values.
+    RgbaBptcUnorm = 0x8E8C,
+    /// sRGB BPTC format with alpha.
+    SrgbAlphaBptcUnorm = 0x8E8D,
+    /// RGB BPTC format with signed float values.
+    RgbBptcSignedFloat = 0x8E8E,
+    /// RGB BPTC format with unsigned float values.
+    RgbBptcUnsignedFloat = 0x8E8F,
+    /// One channel unsigned RGTC format.
+    RedRgtc1 = 0x8DBB,
+    /// One channel signed RGTC format.
+    SignedRedRgtc1 = 0x8DBC,
+    /// Two channel unsigned RGTC format.
+    RedGreenRgtc2 = 0x8DBD,
+    /// Two channel signed RGTC format.
+    SignedRedGreenRgtc2 = 0x8DBE,
+    /// RGB PVRTC format with 4 bits per pixel.
+    RgbPvrtc4bppv1 = 0x8C00,
+    /// RGB PVRTC format with 2 bits per pixel.
+    RgbPvrtc2bppv1 = 0x8C01,
+    /// RGBA PVRTC format with 4 bits per pixel.
+    RgbaPvrtc4bppv1 = 0x8C02,
+    /// RGBA PVRTC format with 2 bits per pixel.
+    RgbaPvrtc2bppv1 = 0x8C03,
 }
 
 /// A texture unit
@@ -968,7 +1246,7 @@ pub enum FramebufferKind {
 
 /// Constants passed to `WebGLRenderingContext.checkFramebufferStatus()`
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FramebufferStatus {
     /// The framebuffer is ready to display.
     FramebufferComplete = 0x8CD5,
@@ -985,6 +1263,79 @@ pub enum FramebufferStatus {
     FramebufferIncompleteMultisample = 0x8D56,
 }
 
+/// Constants passed to `WebGLRenderingContext.getFramebufferAttachmentParameter()`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub enum FramebufferAttachmentParameter {
+    /// The type of the attached object, see `AttachmentObjectType`.
+    ObjectType = 0x8CD0,
+    /// The attached texture or renderbuffer.
+    ObjectName = 0x8CD1,
+    /// The mipmap level of the attached texture.
+    TextureLevel = 0x8CD2,
+    /// The cube map face of the attached texture, 0 if the texture is not a cube map.
+    TextureCubeMapFace = 0x8CD3,
+    /// The layer of the attached 3D or 2D array texture, requires WebGL2.
+    TextureLayer = 0x8CD4,
+    /// The color encoding of the attached image, see `ColorEncoding`. Requires WebGL2 or the `EXT_sRGB`
+    /// extension.
+    ColorEncoding = 0x8210,
+    /// The type of the components of the attached image, see `ComponentType`. Requires WebGL2 or one of
+    /// the color buffer float extensions.
+    ComponentType = 0x8211,
+    /// The number of bits of the red component, requires WebGL2.
+    RedSize = 0x8212,
+    /// The number of bits of the green component, requires WebGL2.
+    GreenSize = 0x8213,
+    /// The number of bits of the blue component, requires WebGL2.
+    BlueSize = 0x8214,
+    /// The number of bits of the alpha component, requires WebGL2.
+    AlphaSize = 0x8215,
+    /// The number of bits of the depth component, requires WebGL2.
+    DepthSize = 0x8216,
+    /// The number of bits of the stencil component, requires WebGL2.
+    StencilSize = 0x8217,
+}
+
+/// Type of the object attached to a framebuffer, returned by `getFramebufferAttachmentParameter()`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentObjectType {
+    /// Nothing is attached.
+    None = 0,
+    /// A texture is attached.
+    Texture = 0x1702,
+    /// A renderbuffer is attached.
+    Renderbuffer = 0x8D41,
+}
+
+/// Encoding of the color values of a framebuffer attachment, returned by
+/// `getFramebufferAttachmentParameter()`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorEncoding {
+    /// The values are stored linearly.
+    Linear = 0x2601,
+    /// The values are stored in the sRGB color space.
+    Srgb = 0x8C40,
+}
+
+/// Type of the components of a framebuffer attachment, returned by `getFramebufferAttachmentParameter()`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentType {
+    /// Floating point components.
+    Float = 0x1406,
+    /// Signed integer components.
+    Int = 0x1404,
+    /// Unsigned integer components.
+    UnsignedInt = 0x1405,
+    /// Signed normalized fixed point components.
+    SignedNormalized = 0x8F9C,
+    /// Unsigned normalized fixed point components.
+    UnsignedNormalized = 0x8C17,
+}
+
 /// Constants passed to `WebGLRenderingContext.framebufferRenderbuffer()`
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy)]
@@ -995,7 +1346,7 @@ pub enum RenderbufferKind {
 
 /// Constants passed to `WebGLRenderingContext.framebufferRenderbuffer()`
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Attachment {
     /// color buffer.
     ColorAttachment0 = 0x8CE0,
@@ -1047,26 +1398,147 @@ pub enum RenderbufferParameter {
     Format = 0x8D44,
 }
 
-// TODO extend with https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext/renderbufferStorage
-/// Constants returned from `WebGLRenderingContext.getRenderbufferParameter()`
+/// Internal formats of textures and renderbuffers, passed to texImage2D, texStorage2D, renderbufferStorage
+/// and returned from `WebGLRenderingContext.getRenderbufferParameter()`.
+///
+/// The unsized formats are the only ones WebGL1 accepts for textures, WebGL2 adds the sized formats.
+/// `formats::format_info` describes the valid uses of each format.
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
-pub enum RenderbufferFormat {
-    /// 4 red bits, 4 green bits, 4 blue bits 4 alpha bits.
-    Rgba4 = 0x8056,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InternalFormat {
+    /// Unsized alpha format.
+    Alpha = 0x1906,
+    /// Unsized luminance format.
+    Luminance = 0x1909,
+    /// Unsized luminance and alpha format.
+    LuminanceAlpha = 0x190A,
+    /// Unsized RGB format.
+    Rgb = 0x1907,
+    /// Unsized RGBA format.
+    Rgba = 0x1908,
+    /// Unsized depth format, requires WebGL1 with the `WEBGL_depth_texture` extension.
+    DepthComponent = 0x1902,
+    /// Unsized depth and stencil format, for renderbuffers and WebGL1 textures with the `WEBGL_depth_texture`
+    /// extension.
+    DepthStencil = 0x84F9,
+    /// 8 red bits.
+    R8 = 0x8229,
+    /// 8 signed normalized red bits.
+    R8Snorm = 0x8F94,
+    /// 16 bit float red.
+    R16f = 0x822D,
+    /// 32 bit float red.
+    R32f = 0x822E,
+    /// 8 bit unsigned integer red.
+    R8ui = 0x8232,
+    /// 8 bit signed integer red.
+    R8i = 0x8231,
+    /// 16 bit unsigned integer red.
+    R16ui = 0x8234,
+    /// 16 bit signed integer red.
+    R16i = 0x8233,
+    /// 32 bit unsigned integer red.
+    R32ui = 0x8236,
+    /// 32 bit signed integer red.
+    R32i = 0x8235,
+    /// 8 red bits, 8 green bits.
+    Rg8 = 0x822B,
+    /// 8 signed normalized red and green bits.
+    Rg8Snorm = 0x8F95,
+    /// 16 bit float red and green.
+    Rg16f = 0x822F,
+    /// 32 bit float red and green.
+    Rg32f = 0x8230,
+    /// 8 bit unsigned integer red and green.
+    Rg8ui = 0x8238,
+    /// 8 bit signed integer red and green.
+    Rg8i = 0x8237,
+    /// 16 bit unsigned integer red and green.
+    Rg16ui = 0x823A,
+    /// 16 bit signed integer red and green.
+    Rg16i = 0x8239,
+    /// 32 bit unsigned integer red and green.
+    Rg32ui = 0x823C,
+    /// 32 bit signed integer red and green.
+    Rg32i = 0x823B,
+    /// 8 red bits, 8 green bits, 8 blue bits.
+    Rgb8 = 0x8051,
+    /// 8 bit red, green and blue in the sRGB color space.
+    Srgb8 = 0x8C41,
     /// 5 red bits, 6 green bits, 5 blue bits.
     Rgb565 = 0x8D62,
+    /// 8 signed normalized red, green and blue bits.
+    Rgb8Snorm = 0x8F96,
+    /// 11 bit float red and green, 10 bit float blue.
+    R11fG11fB10f = 0x8C3A,
+    /// 9 bit red, green and blue with a shared 5 bit exponent.
+    Rgb9E5 = 0x8C3D,
+    /// 16 bit float red, green and blue.
+    Rgb16f = 0x881B,
+    /// 32 bit float red, green and blue.
+    Rgb32f = 0x8815,
+    /// 8 bit unsigned integer red, green and blue.
+    Rgb8ui = 0x8D7D,
+    /// 8 bit signed integer red, green and blue.
+    Rgb8i = 0x8D8F,
+    /// 16 bit unsigned integer red, green and blue.
+    Rgb16ui = 0x8D77,
+    /// 16 bit signed integer red, green and blue.
+    Rgb16i = 0x8D89,
+    /// 32 bit unsigned integer red, green and blue.
+    Rgb32ui = 0x8D71,
+    /// 32 bit signed integer red, green and blue.
+    Rgb32i = 0x8D83,
+    /// 8 red bits, 8 green bits, 8 blue bits, 8 alpha bits.
+    Rgba8 = 0x8058,
+    /// 8 bit red, green and blue in the sRGB color space, 8 alpha bits.
+    Srgb8Alpha8 = 0x8C43,
+    /// 8 signed normalized red, green, blue and alpha bits.
+    Rgba8Snorm = 0x8F97,
     /// 5 red bits, 5 green bits, 5 blue bits, 1 alpha bit.
     Rgb5A1 = 0x8057,
+    /// 4 red bits, 4 green bits, 4 blue bits 4 alpha bits.
+    Rgba4 = 0x8056,
+    /// 10 red bits, 10 green bits, 10 blue bits, 2 alpha bits.
+    Rgb10A2 = 0x8059,
+    /// 16 bit float red, green, blue and alpha.
+    Rgba16f = 0x881A,
+    /// 32 bit float red, green, blue and alpha.
+    Rgba32f = 0x8814,
+    /// 8 bit unsigned integer red, green, blue and alpha.
+    Rgba8ui = 0x8D7C,
+    /// 8 bit signed integer red, green, blue and alpha.
+    Rgba8i = 0x8D8E,
+    /// 10 bit unsigned integer red, green and blue, 2 bit unsigned integer alpha.
+    Rgb10A2ui = 0x906F,
+    /// 16 bit unsigned integer red, green, blue and alpha.
+    Rgba16ui = 0x8D76,
+    /// 16 bit signed integer red, green, blue and alpha.
+    Rgba16i = 0x8D88,
+    /// 32 bit unsigned integer red, green, blue and alpha.
+    Rgba32ui = 0x8D70,
+    /// 32 bit signed integer red, green, blue and alpha.
+    Rgba32i = 0x8D82,
     /// 16 depth bits.
     DepthComponent16 = 0x81A5,
-    /// 8 stencil bits.
+    /// 24 depth bits.
+    DepthComponent24 = 0x81A6,
+    /// 32 bit float depth.
+    DepthComponent32f = 0x8CAC,
+    /// 24 depth bits, 8 stencil bits.
+    Depth24Stencil8 = 0x88F0,
+    /// 32 bit float depth, 8 stencil bits.
+    Depth32fStencil8 = 0x8CAD,
+    /// 8 stencil bits, only for renderbuffers.
     StencilIndex8 = 0x8D48,
 }
 
+/// Former name of `InternalFormat`, from when it only listed the WebGL1 renderbuffer formats.
+pub type RenderbufferFormat = InternalFormat;
+
 /// Constants passed to vertexAttribPointer
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AttributeType {
     /// signed 8-bit integer, with values in [-128, 127]
     Byte = 0x1400,
@@ -1080,11 +1552,27 @@ pub enum AttributeType {
     Float = 0x1406,
     /// 16-bit IEEE floating point number
     HalfFloat = 0x140B,
+    /// signed 32-bit integer, requires WebGL2
+    Int = 0x1404,
+    /// unsigned 32-bit integer, requires WebGL2
+    UnsignedInt = 0x1405,
+}
+
+/// Types of the indices in an element array buffer, passed to drawElements
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexType {
+    /// unsigned 8-bit indices
+    UnsignedByte = 0x1401,
+    /// unsigned 16-bit indices
+    UnsignedShort = 0x1403,
+    /// unsigned 32-bit indices, requires WebGL2 or the `OES_element_index_uint` extension
+    UnsignedInt = 0x1405,
 }
 
 /// Constants passed to readBuffer
 #[wasm_bindgen]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorBuffer {
     None = 0,
     Back = 0x0405,
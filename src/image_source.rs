@@ -0,0 +1,214 @@
+//! DOM objects that can be uploaded to a texture without copying their pixels into wasm memory
+//!
+//! `HTMLImageElement`, `HTMLCanvasElement`, `HTMLVideoElement`, `ImageBitmap`, `ImageData` and
+//! `OffscreenCanvas` implement `TexImageSource`, they are passed to `texImage2D`, `texSubImage2D`,
+//! `texImage3D` and `texSubImage3D` as they are and decoded by the browser. The size of the upload is the
+//! size of the source, the natural size of an image and the intrinsic size of a video frame.
+//!
+//! The pixels are flipped vertically when `UNPACK_FLIP_Y_WEBGL` is set and their colors are multiplied
+//! by their alpha when `UNPACK_PREMULTIPLY_ALPHA_WEBGL` is set, `TextureUpload::flip_y` and
+//! `TextureUpload::premultiply_alpha` set them for the duration of a single upload.
+//!
+//! ```no_run
+//! use webgl_rs::*;
+//!
+//! fn upload_image(gl: &WebGL2RenderingContext, image: &HTMLImageElement) -> Result<(), TextureError> {
+//!     let texture = TextureBuilder::texture_2d(gl, InternalFormat::Rgba8, image.width(), image.height())
+//!         .mipmaps()
+//!         .build()?;
+//!     texture
+//!         .upload(0)
+//!         .flip_y(true)
+//!         .source(PixelFormat::Rgba, PixelType::UnsignedByte, image)?;
+//!     texture.generate_mipmaps()
+//! }
+//! ```
+use context_builder::OffscreenCanvas;
+use rendering_context::HTMLCanvasElement;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Image source bindings
+#[wasm_bindgen]
+extern "C" {
+    /// An `<img>` element.
+    pub type HTMLImageElement;
+    /// Binding for `HTMLImageElement.naturalWidth`
+    #[wasm_bindgen(method, getter = naturalWidth)]
+    fn natural_width(this: &HTMLImageElement) -> u32;
+    /// Binding for `HTMLImageElement.naturalHeight`
+    #[wasm_bindgen(method, getter = naturalHeight)]
+    fn natural_height(this: &HTMLImageElement) -> u32;
+
+    /// A `<video>` element, its current frame is uploaded.
+    pub type HTMLVideoElement;
+    /// Binding for `HTMLVideoElement.videoWidth`
+    #[wasm_bindgen(method, getter = videoWidth)]
+    fn video_width(this: &HTMLVideoElement) -> u32;
+    /// Binding for `HTMLVideoElement.videoHeight`
+    #[wasm_bindgen(method, getter = videoHeight)]
+    fn video_height(this: &HTMLVideoElement) -> u32;
+
+    /// A decoded image created by `createImageBitmap`.
+    pub type ImageBitmap;
+    /// Binding for `ImageBitmap.width`
+    #[wasm_bindgen(method, getter = width)]
+    fn bitmap_width(this: &ImageBitmap) -> u32;
+    /// Binding for `ImageBitmap.height`
+    #[wasm_bindgen(method, getter = height)]
+    fn bitmap_height(this: &ImageBitmap) -> u32;
+
+    /// Unpremultiplied RGBA pixels, e.g. read back from a 2D canvas.
+    pub type ImageData;
+    /// Binding for `ImageData.width`
+    #[wasm_bindgen(method, getter = width)]
+    fn data_width(this: &ImageData) -> u32;
+    /// Binding for `ImageData.height`
+    #[wasm_bindgen(method, getter = height)]
+    fn data_height(this: &ImageData) -> u32;
+
+    /// Binding for `HTMLCanvasElement.width`
+    #[wasm_bindgen(method, getter = width)]
+    fn canvas_width(this: &HTMLCanvasElement) -> u32;
+    /// Binding for `HTMLCanvasElement.height`
+    #[wasm_bindgen(method, getter = height)]
+    fn canvas_height(this: &HTMLCanvasElement) -> u32;
+
+    /// Binding for `OffscreenCanvas.width`
+    #[wasm_bindgen(method, getter = width)]
+    fn offscreen_width(this: &OffscreenCanvas) -> u32;
+    /// Binding for `OffscreenCanvas.height`
+    #[wasm_bindgen(method, getter = height)]
+    fn offscreen_height(this: &OffscreenCanvas) -> u32;
+}
+
+/// Borrowed DOM object that is passed to the backend as pixel source.
+///
+/// Every variant corresponds with one of the types of the `TexImageSource` union of WebGL.
+#[derive(Clone, Copy)]
+pub enum ImageSource<'a> {
+    Image(&'a HTMLImageElement),
+    Canvas(&'a HTMLCanvasElement),
+    Video(&'a HTMLVideoElement),
+    ImageBitmap(&'a ImageBitmap),
+    ImageData(&'a ImageData),
+    OffscreenCanvas(&'a OffscreenCanvas),
+}
+
+impl<'a> ImageSource<'a> {
+    /// Returns the name of the javascript type of the source.
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            ImageSource::Image(_) => "HTMLImageElement",
+            ImageSource::Canvas(_) => "HTMLCanvasElement",
+            ImageSource::Video(_) => "HTMLVideoElement",
+            ImageSource::ImageBitmap(_) => "ImageBitmap",
+            ImageSource::ImageData(_) => "ImageData",
+            ImageSource::OffscreenCanvas(_) => "OffscreenCanvas",
+        }
+    }
+
+    /// Returns the source as the javascript value that is passed to WebGL.
+    pub(crate) fn as_js_value(&self) -> &'a JsValue {
+        match *self {
+            ImageSource::Image(source) => source,
+            ImageSource::Canvas(source) => source,
+            ImageSource::Video(source) => source,
+            ImageSource::ImageBitmap(source) => source,
+            ImageSource::ImageData(source) => source,
+            ImageSource::OffscreenCanvas(source) => source,
+        }
+    }
+}
+
+/// Only prints the type of the source, like `ArrayData` its contents are not shown.
+impl<'a> fmt::Debug for ImageSource<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.type_name())
+    }
+}
+
+/// DOM object that can be used as pixel source of a texture.
+///
+/// The width and height are the size of the image the browser uploads, they are read when the upload is
+/// checked so the source has to be loaded by then.
+pub trait TexImageSource {
+    /// Returns a view over the source.
+    fn source(&self) -> ImageSource<'_>;
+    /// Returns the width of the uploaded image in pixels.
+    fn width(&self) -> u32;
+    /// Returns the height of the uploaded image in pixels.
+    fn height(&self) -> u32;
+}
+
+impl TexImageSource for HTMLImageElement {
+    fn source(&self) -> ImageSource<'_> {
+        ImageSource::Image(self)
+    }
+    fn width(&self) -> u32 {
+        self.natural_width()
+    }
+    fn height(&self) -> u32 {
+        self.natural_height()
+    }
+}
+
+impl TexImageSource for HTMLCanvasElement {
+    fn source(&self) -> ImageSource<'_> {
+        ImageSource::Canvas(self)
+    }
+    fn width(&self) -> u32 {
+        self.canvas_width()
+    }
+    fn height(&self) -> u32 {
+        self.canvas_height()
+    }
+}
+
+impl TexImageSource for HTMLVideoElement {
+    fn source(&self) -> ImageSource<'_> {
+        ImageSource::Video(self)
+    }
+    fn width(&self) -> u32 {
+        self.video_width()
+    }
+    fn height(&self) -> u32 {
+        self.video_height()
+    }
+}
+
+impl TexImageSource for ImageBitmap {
+    fn source(&self) -> ImageSource<'_> {
+        ImageSource::ImageBitmap(self)
+    }
+    fn width(&self) -> u32 {
+        self.bitmap_width()
+    }
+    fn height(&self) -> u32 {
+        self.bitmap_height()
+    }
+}
+
+impl TexImageSource for ImageData {
+    fn source(&self) -> ImageSource<'_> {
+        ImageSource::ImageData(self)
+    }
+    fn width(&self) -> u32 {
+        self.data_width()
+    }
+    fn height(&self) -> u32 {
+        self.data_height()
+    }
+}
+
+impl TexImageSource for OffscreenCanvas {
+    fn source(&self) -> ImageSource<'_> {
+        ImageSource::OffscreenCanvas(self)
+    }
+    fn width(&self) -> u32 {
+        self.offscreen_width()
+    }
+    fn height(&self) -> u32 {
+        self.offscreen_height()
+    }
+}
@@ -0,0 +1,450 @@
+//! Loader for textures stored in KTX 1 and KTX 2 files
+//!
+//! `TextureData::from_ktx` reads the header of a KTX file, maps its format to an `InternalFormat` or a
+//! `TextureCompression` and splits the data into mipmap levels, see `TextureData` for uploading them.
+//!
+//! Mipmap levels of KTX 2 files that are supercompressed with zstd are decompressed when the `zstd` feature
+//! is enabled, with the pure Rust `ruzstd` decoder. BasisLZ and zlib supercompression are not supported,
+//! and neither are files that have to be transcoded (a `vkFormat` of 0).
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let ktx = TextureData::from_ktx(include_bytes!("../fixtures/ktx/rgba8_mipmaps.ktx")).unwrap();
+//! assert_eq!(
+//!     ktx.format(),
+//!     TextureDataFormat::Uncompressed {
+//!         internal_format: InternalFormat::Rgba8,
+//!         format: PixelFormat::Rgba,
+//!         pixel_type: PixelType::UnsignedByte,
+//!     }
+//! );
+//! assert_eq!((ktx.width(), ktx.height(), ktx.levels()), (4, 2, 3));
+//! let images: Vec<_> = ktx
+//!     .images()
+//!     .map(|(level, layer, face, data)| (level, layer, face, data.len()))
+//!     .collect();
+//! assert_eq!(images, vec![(0, 0, 0, 32), (1, 0, 0, 8), (2, 0, 0, 4)]);
+//!
+//! let gl = RecordingBackend::new();
+//! let texture = ktx.create_texture(&gl).unwrap();
+//! assert_eq!(texture.storage().unwrap().levels, 3);
+//! assert_eq!(
+//!     gl.calls().last().unwrap().to_string(),
+//!     "texSubImage2D(Texture2d, 2, 0, 0, 1, 1, Rgba, UnsignedByte, [u8; 4])"
+//! );
+//!
+//! // the rows of KTX 1 files are padded to 4 bytes, which is the default unpack alignment
+//! let cube = TextureData::from_ktx(include_bytes!("../fixtures/ktx/rgb8_cube.ktx")).unwrap();
+//! assert_eq!((cube.kind(), cube.row_alignment()), (TextureKind::TextureCubeMap, 4));
+//! assert_eq!(cube.images().count(), 6);
+//! let cube_map = cube.create_texture(&gl).unwrap();
+//! assert_eq!(
+//!     gl.calls().last().unwrap().to_string(),
+//!     "texSubImage2D(TextureCubeMapNegativeZ, 0, 0, 0, 2, 2, Rgb, UnsignedByte, [u8; 16])"
+//! );
+//!
+//! // the rows of KTX 2 files are tightly packed, the unpack alignment is restored after the upload
+//! let array = TextureData::from_ktx(include_bytes!("../fixtures/ktx/r16f_array.ktx2")).unwrap();
+//! assert_eq!((array.kind(), array.layers(), array.row_alignment()), (TextureKind::Texture2dArray, 2, 1));
+//! let array_texture = array.create_texture(&gl).unwrap();
+//! let calls: Vec<String> = gl.calls().iter().map(|call| call.to_string()).collect();
+//! assert!(calls.contains(
+//!     &"texSubImage3D(Texture2dArray, 0, 0, 0, 1, 3, 3, 1, Red, HalfFloat, [u16; 9])".to_string()
+//! ));
+//! assert_eq!(calls.last().unwrap(), "pixelStorei(UnpackAlignment, 4)");
+//!
+//! let bc1 = TextureData::from_ktx(include_bytes!("../fixtures/ktx/bc1_zstd.ktx2"));
+//! #[cfg(feature = "zstd")]
+//! {
+//!     let bc1 = bc1.unwrap();
+//!     assert_eq!(bc1.format(), TextureDataFormat::Compressed(TextureCompression::RgbaDxt1));
+//!     let sizes: Vec<usize> = bc1.images().map(|(_, _, _, data)| data.len()).collect();
+//!     assert_eq!(sizes, vec![32, 8, 8, 8]);
+//!     assert_eq!(bc1.required_extension(), Some("WEBGL_compressed_texture_s3tc"));
+//!     assert!(bc1.create_texture(&gl).is_err());
+//!     gl.set_supported_extensions(&["WEBGL_compressed_texture_s3tc"]);
+//!     bc1.create_texture(&gl).unwrap();
+//!     let calls: Vec<String> = gl.calls().iter().map(|call| call.to_string()).collect();
+//!     assert!(calls.contains(
+//!         &"compressedTexImage2D(Texture2d, 3, RgbaDxt1, 1, 1, 0, [u8; 8])".to_string()
+//!     ));
+//! }
+//! #[cfg(not(feature = "zstd"))]
+//! assert_eq!(bc1.unwrap_err(), TextureFileError::UnsupportedSupercompression { scheme: 2 });
+//! ```
+use formats::{format_info, COMPRESSED_FORMATS, FORMATS};
+use glenum::InternalFormat as I;
+use glenum::PixelType as T;
+use glenum::TextureCompression as C;
+use glenum::{InternalFormat, PixelType, TextureCompression};
+use texture_data::TextureFileError;
+use texture_data::{Reader, TextureData, TextureDataFormat};
+
+const KTX1_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x31, 0x31, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+const KTX2_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+// Supercompression schemes of KTX 2
+const SUPERCOMPRESSION_NONE: u32 = 0;
+const SUPERCOMPRESSION_ZSTD: u32 = 2;
+
+/// The uncompressed `vkFormat`s of KTX 2 that WebGL2 supports, with the internal format and pixel type
+/// they are uploaded as. The packed formats store their components in the same bits as the matching
+/// packed pixel types.
+static VK_FORMATS: &[(u32, InternalFormat, PixelType)] = &[
+    (2, I::Rgba4, T::UnsignedShort4444),
+    (4, I::Rgb565, T::UnsignedShort565),
+    (6, I::Rgb5A1, T::UnsignedShort5551),
+    (9, I::R8, T::UnsignedByte),
+    (10, I::R8Snorm, T::Byte),
+    (13, I::R8ui, T::UnsignedByte),
+    (14, I::R8i, T::Byte),
+    (16, I::Rg8, T::UnsignedByte),
+    (17, I::Rg8Snorm, T::Byte),
+    (20, I::Rg8ui, T::UnsignedByte),
+    (21, I::Rg8i, T::Byte),
+    (23, I::Rgb8, T::UnsignedByte),
+    (24, I::Rgb8Snorm, T::Byte),
+    (27, I::Rgb8ui, T::UnsignedByte),
+    (28, I::Rgb8i, T::Byte),
+    (29, I::Srgb8, T::UnsignedByte),
+    (37, I::Rgba8, T::UnsignedByte),
+    (38, I::Rgba8Snorm, T::Byte),
+    (41, I::Rgba8ui, T::UnsignedByte),
+    (42, I::Rgba8i, T::Byte),
+    (43, I::Srgb8Alpha8, T::UnsignedByte),
+    (64, I::Rgb10A2, T::UnsignedInt2101010Rev),
+    (68, I::Rgb10A2ui, T::UnsignedInt2101010Rev),
+    (74, I::R16ui, T::UnsignedShort),
+    (75, I::R16i, T::Short),
+    (76, I::R16f, T::HalfFloat),
+    (81, I::Rg16ui, T::UnsignedShort),
+    (82, I::Rg16i, T::Short),
+    (83, I::Rg16f, T::HalfFloat),
+    (88, I::Rgb16ui, T::UnsignedShort),
+    (89, I::Rgb16i, T::Short),
+    (90, I::Rgb16f, T::HalfFloat),
+    (95, I::Rgba16ui, T::UnsignedShort),
+    (96, I::Rgba16i, T::Short),
+    (97, I::Rgba16f, T::HalfFloat),
+    (98, I::R32ui, T::UnsignedInt),
+    (99, I::R32i, T::Int),
+    (100, I::R32f, T::Float),
+    (101, I::Rg32ui, T::UnsignedInt),
+    (102, I::Rg32i, T::Int),
+    (103, I::Rg32f, T::Float),
+    (104, I::Rgb32ui, T::UnsignedInt),
+    (105, I::Rgb32i, T::Int),
+    (106, I::Rgb32f, T::Float),
+    (107, I::Rgba32ui, T::UnsignedInt),
+    (108, I::Rgba32i, T::Int),
+    (109, I::Rgba32f, T::Float),
+    (122, I::R11fG11fB10f, T::UnsignedInt10f11f11fRev),
+    (123, I::Rgb9E5, T::UnsignedInt5999Rev),
+];
+
+/// The compressed `vkFormat`s of KTX 2 that have a WebGL extension.
+static VK_COMPRESSED_FORMATS: &[(u32, TextureCompression)] = &[
+    (131, C::RgbDxt1),
+    (132, C::SrgbDxt1),
+    (133, C::RgbaDxt1),
+    (134, C::SrgbAlphaDxt1),
+    (135, C::RgbaDxt3),
+    (136, C::SrgbAlphaDxt3),
+    (137, C::RgbaDxt5),
+    (138, C::SrgbAlphaDxt5),
+    (139, C::RedRgtc1),
+    (140, C::SignedRedRgtc1),
+    (141, C::RedGreenRgtc2),
+    (142, C::SignedRedGreenRgtc2),
+    (143, C::RgbBptcUnsignedFloat),
+    (144, C::RgbBptcSignedFloat),
+    (145, C::RgbaBptcUnorm),
+    (146, C::SrgbAlphaBptcUnorm),
+    (147, C::Rgb8Etc2),
+    (148, C::Srgb8Etc2),
+    (149, C::Rgb8PunchthroughAlpha1Etc2),
+    (150, C::Srgb8PunchthroughAlpha1Etc2),
+    (151, C::Rgba8Etc2Eac),
+    (152, C::Srgb8Alpha8Etc2Eac),
+    (153, C::R11Eac),
+    (154, C::SignedR11Eac),
+    (155, C::Rg11Eac),
+    (156, C::SignedRg11Eac),
+    (157, C::RgbaAstc4x4),
+    (158, C::Srgb8Alpha8Astc4x4),
+    (159, C::RgbaAstc5x4),
+    (160, C::Srgb8Alpha8Astc5x4),
+    (161, C::RgbaAstc5x5),
+    (162, C::Srgb8Alpha8Astc5x5),
+    (163, C::RgbaAstc6x5),
+    (164, C::Srgb8Alpha8Astc6x5),
+    (165, C::RgbaAstc6x6),
+    (166, C::Srgb8Alpha8Astc6x6),
+    (167, C::RgbaAstc8x5),
+    (168, C::Srgb8Alpha8Astc8x5),
+    (169, C::RgbaAstc8x6),
+    (170, C::Srgb8Alpha8Astc8x6),
+    (171, C::RgbaAstc8x8),
+    (172, C::Srgb8Alpha8Astc8x8),
+    (173, C::RgbaAstc10x5),
+    (174, C::Srgb8Alpha8Astc10x5),
+    (175, C::RgbaAstc10x6),
+    (176, C::Srgb8Alpha8Astc10x6),
+    (177, C::RgbaAstc10x8),
+    (178, C::Srgb8Alpha8Astc10x8),
+    (179, C::RgbaAstc10x10),
+    (180, C::Srgb8Alpha8Astc10x10),
+    (181, C::RgbaAstc12x10),
+    (182, C::Srgb8Alpha8Astc12x10),
+    (183, C::RgbaAstc12x12),
+    (184, C::Srgb8Alpha8Astc12x12),
+    (1_000_054_000, C::RgbaPvrtc2bppv1),
+    (1_000_054_001, C::RgbaPvrtc4bppv1),
+];
+
+impl TextureData {
+    /// Parses a KTX 1 or KTX 2 file.
+    ///
+    /// Fails when the format or the dimensions are not supported by WebGL2, or when the levels in the file
+    /// do not have the size that follows from the dimensions and format. The rows of the images of KTX 1
+    /// files are aligned to 4 bytes, those of KTX 2 files are tightly packed. Rows go from bottom to top when
+    /// the file was written for OpenGL.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let mut ktx1 = include_bytes!("../fixtures/ktx/rgba8_mipmaps.ktx").to_vec();
+    /// assert_eq!(
+    ///     TextureData::from_ktx(&ktx1[1..]).err(),
+    ///     Some(TextureFileError::InvalidIdentifier { container: "KTX" })
+    /// );
+    /// assert_eq!(
+    ///     TextureData::from_ktx(&ktx1[..20]).err(),
+    ///     Some(TextureFileError::UnexpectedEnd { required: 24, actual: 20 })
+    /// );
+    /// ktx1[12..16].copy_from_slice(&[1, 2, 3, 5]);
+    /// assert_eq!(
+    ///     TextureData::from_ktx(&ktx1).err(),
+    ///     Some(TextureFileError::InvalidEndianness { value: 0x0503_0201 })
+    /// );
+    ///
+    /// // a key/value data length that runs past the end of a KTX 1 file
+    /// ktx1[12..16].copy_from_slice(&[1, 2, 3, 4]);
+    /// ktx1[60..64].copy_from_slice(&u32::MAX.to_le_bytes());
+    /// match TextureData::from_ktx(&ktx1) {
+    ///     Err(TextureFileError::UnexpectedEnd { actual, .. }) => assert_eq!(actual, ktx1.len()),
+    ///     result => panic!("unexpected {:?}", result.map(|_| ())),
+    /// }
+    ///
+    /// // the uncompressed length of a KTX 2 level has to match its size, before it is decompressed
+    /// let mut ktx2 = include_bytes!("../fixtures/ktx/bc1_zstd.ktx2").to_vec();
+    /// ktx2[96..104].copy_from_slice(&(1u64 << 60).to_le_bytes());
+    /// let error = TextureData::from_ktx(&ktx2).err().unwrap();
+    /// #[cfg(feature = "zstd")]
+    /// assert_eq!(
+    ///     error,
+    ///     TextureFileError::LevelSizeMismatch { level: 0, expected: 32, actual: (1u64 << 60) as usize }
+    /// );
+    /// #[cfg(not(feature = "zstd"))]
+    /// assert_eq!(error, TextureFileError::UnsupportedSupercompression { scheme: 2 });
+    ///
+    /// let mut array = include_bytes!("../fixtures/ktx/r16f_array.ktx2").to_vec();
+    /// array[96..104].copy_from_slice(&u64::MAX.to_le_bytes());
+    /// match TextureData::from_ktx(&array) {
+    ///     Err(TextureFileError::LevelSizeMismatch { level: 0, .. }) => {}
+    ///     result => panic!("unexpected {:?}", result.map(|_| ())),
+    /// }
+    /// ```
+    pub fn from_ktx(data: &[u8]) -> Result<TextureData, TextureFileError> {
+        match data.get(..12) {
+            Some(identifier) if identifier == KTX1_IDENTIFIER => parse_ktx1(data),
+            Some(identifier) if identifier == KTX2_IDENTIFIER => parse_ktx2(data),
+            _ => Err(TextureFileError::InvalidIdentifier { container: "KTX" }),
+        }
+    }
+}
+
+fn parse_ktx1(data: &[u8]) -> Result<TextureData, TextureFileError> {
+    let mut reader = Reader {
+        data,
+        big_endian: false,
+    };
+    reader.big_endian = match reader.u32(12)? {
+        0x0403_0201 => false,
+        0x0102_0304 => true,
+        value => return Err(TextureFileError::InvalidEndianness { value }),
+    };
+    let field = |index: usize| reader.u32(12 + 4 * index);
+    let (gl_type, type_size, gl_format, gl_internal_format) =
+        (field(1)?, field(2)?, field(3)?, field(4)?);
+    let format = ktx1_format(gl_type, gl_format, gl_internal_format).ok_or(
+        TextureFileError::UnsupportedGlFormat {
+            internal_format: gl_internal_format,
+            format: gl_format,
+            pixel_type: gl_type,
+        },
+    )?;
+    let (layers, faces, levels) = (field(9)?, field(10)?, field(11)?);
+    let mut texture = TextureData::new(
+        format,
+        field(6)?,
+        field(7)?,
+        field(8)?,
+        layers,
+        faces,
+        levels,
+        4,
+    )?;
+
+    // the faces of a cube map that is not an array are stored one by one, with the size of a face
+    let chunks = if faces == 6 && layers == 0 { 6 } else { 1 };
+    let mut offset =
+        64usize
+            .checked_add(field(12)? as usize)
+            .ok_or(TextureFileError::UnexpectedEnd {
+                required: usize::MAX,
+                actual: data.len(),
+            })?;
+    for level in 0..levels.max(1) {
+        let image_size = reader.u32(offset)? as usize;
+        offset += 4;
+        let expected = texture.level_length(level);
+        if image_size.saturating_mul(chunks) != expected {
+            return Err(TextureFileError::LevelSizeMismatch {
+                level,
+                expected,
+                actual: image_size.saturating_mul(chunks),
+            });
+        }
+        reader.bytes(offset, expected)?;
+        let mut level_data = Vec::with_capacity(expected);
+        for _ in 0..chunks {
+            level_data.extend_from_slice(reader.bytes(offset, image_size)?);
+            offset += image_size.div_ceil(4) * 4;
+        }
+        if reader.big_endian && type_size > 1 {
+            for component in level_data.chunks_mut(type_size as usize) {
+                component.reverse();
+            }
+        }
+        texture.push_level(level_data)?;
+    }
+    Ok(texture)
+}
+
+fn parse_ktx2(data: &[u8]) -> Result<TextureData, TextureFileError> {
+    let reader = Reader {
+        data,
+        big_endian: false,
+    };
+    let field = |index: usize| reader.u32(12 + 4 * index);
+    let vk_format = field(0)?;
+    let format =
+        ktx2_format(vk_format).ok_or(TextureFileError::UnsupportedVkFormat { vk_format })?;
+    let levels = field(7)?;
+    let scheme = field(8)?;
+    if scheme != SUPERCOMPRESSION_NONE
+        && (scheme != SUPERCOMPRESSION_ZSTD || !cfg!(feature = "zstd"))
+    {
+        return Err(TextureFileError::UnsupportedSupercompression { scheme });
+    }
+    let mut texture = TextureData::new(
+        format,
+        field(2)?,
+        field(3)?,
+        field(4)?,
+        field(5)?,
+        field(6)?,
+        levels,
+        1,
+    )?;
+
+    // the level index follows the header and the offsets of the data format descriptor, the key/value
+    // data and the supercompression global data
+    for level in 0..levels.max(1) {
+        let index = 80 + 24 * level as usize;
+        let stored = reader.bytes(reader.usize(index)?, reader.usize(index + 8)?)?;
+        // the uncompressed length comes from the file, it is checked before anything is allocated for it
+        let expected = texture.level_length(level);
+        let length = reader.usize(index + 16)?;
+        if length != expected {
+            return Err(TextureFileError::LevelSizeMismatch {
+                level,
+                expected,
+                actual: length,
+            });
+        }
+        let level_data = if scheme == SUPERCOMPRESSION_ZSTD {
+            decompress(stored, length)
+                .map_err(|message| TextureFileError::Decompression { level, message })?
+        } else {
+            stored.to_vec()
+        };
+        texture.push_level(level_data)?;
+    }
+    Ok(texture)
+}
+
+/// Finds the format of a KTX 1 file, a `glType` of 0 marks a compressed format.
+fn ktx1_format(gl_type: u32, gl_format: u32, gl_internal_format: u32) -> Option<TextureDataFormat> {
+    if gl_type == 0 {
+        return COMPRESSED_FORMATS
+            .iter()
+            .find(|info| info.format as u32 == gl_internal_format)
+            .map(|info| TextureDataFormat::Compressed(info.format));
+    }
+    let info = FORMATS.iter().find(|info| {
+        info.internal_format as u32 == gl_internal_format && info.format as u32 == gl_format
+    })?;
+    info.types
+        .iter()
+        .find(|&&pixel_type| pixel_type as u32 == gl_type)
+        .map(|&pixel_type| TextureDataFormat::Uncompressed {
+            internal_format: info.internal_format,
+            format: info.format,
+            pixel_type,
+        })
+}
+
+/// Finds the format of a KTX 2 file.
+fn ktx2_format(vk_format: u32) -> Option<TextureDataFormat> {
+    if let Some(&(_, internal_format, pixel_type)) =
+        VK_FORMATS.iter().find(|entry| entry.0 == vk_format)
+    {
+        return Some(TextureDataFormat::Uncompressed {
+            internal_format,
+            format: format_info(internal_format).format,
+            pixel_type,
+        });
+    }
+    VK_COMPRESSED_FORMATS
+        .iter()
+        .find(|entry| entry.0 == vk_format)
+        .map(|&(_, format)| TextureDataFormat::Compressed(format))
+}
+
+/// Decompresses a zstd supercompressed mipmap level of `length` bytes.
+#[cfg(feature = "zstd")]
+fn decompress(data: &[u8], length: usize) -> Result<Vec<u8>, String> {
+    let mut level = vec![0; length];
+    let written = ::ruzstd::decoding::FrameDecoder::new()
+        .decode_all(data, &mut level)
+        .map_err(|error| error.to_string())?;
+    if written != length {
+        return Err(format!(
+            "{} bytes were expected but {} were decompressed",
+            length, written
+        ));
+    }
+    Ok(level)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn decompress(_data: &[u8], _length: usize) -> Result<Vec<u8>, String> {
+    unreachable!("zstd supercompression is rejected without the zstd feature")
+}
@@ -1,35 +1,101 @@
+#[cfg(feature = "zstd")]
+extern crate ruzstd;
+#[cfg(feature = "serde")]
+extern crate serde;
 extern crate wasm_bindgen;
+#[cfg(feature = "derive")]
+extern crate webgl_rs_derive;
 
-//TODO: possible solution of different getParameter methods is to add accessors on different types
 //TODO: safety with methods that can throw
 //TODO: JsString?
 //TODO: js-sys objects?
+#[macro_use]
+mod macros;
+
+pub mod backend;
 pub mod buffer;
+pub mod capabilities;
+pub mod context_builder;
 pub mod data_view;
+pub mod dds;
+pub mod draw;
+pub mod error;
+pub mod formats;
 pub mod framebuffer;
+pub mod framebuffer_builder;
 pub mod glenum;
+pub mod image_source;
+pub mod ktx;
+pub mod multisample;
+pub mod parameters;
+pub mod program_builder;
 pub mod query;
+pub mod recording;
+pub mod reflection;
 pub mod renderbuffer;
 pub mod rendering_context;
 pub mod sampler;
 pub mod shader_program;
+pub mod shared;
 pub mod sync;
 pub mod texture;
+pub mod texture_builder;
+pub mod texture_data;
+pub mod texture_loader;
 pub mod transform_feedback;
 pub mod uniform_location;
+pub mod vertex;
 pub mod vertex_array_object;
+pub mod webgl1;
 
-pub use buffer::WebGLRSBuffer;
-pub use data_view::Buffer;
-pub use framebuffer::WebGLRSFramebuffer;
+pub use backend::{GlBackend, WebGL2Backend};
+pub use buffer::{BufferBackend, WebGL2BufferBackend, WebGLRSBuffer};
+pub use capabilities::{Capabilities, ShaderPrecisionFormats};
+pub use context_builder::{BuildContext, ContextBuilder, ContextError, PowerPreference};
+pub use data_view::{
+    ArrayData, ArrayDataMut, Buffer, BufferMut, Image, PixelComponent, Pixels, Pod,
+};
+pub use draw::{DrawCommand, DrawError};
+pub use error::{Checked, CheckedObject, WebGLError, CHECKS_ENABLED};
+pub use formats::{CompressedFormatInfo, FormatError, FormatInfo};
+pub use framebuffer::{
+    AttachedImage, AttachedObject, AttachmentBitSizes, AttachmentInfo, AttachmentQuery,
+    FramebufferBackend, WebGLRSFramebuffer,
+};
+pub use framebuffer_builder::{AttachmentImage, FramebufferBuilder, FramebufferError};
 pub use glenum::*;
-pub use query::WebGLRSQuery;
-pub use renderbuffer::WebGLRSRenderbuffer;
+pub use image_source::{
+    HTMLImageElement, HTMLVideoElement, ImageBitmap, ImageData, ImageSource, TexImageSource,
+};
+pub use multisample::MultisampleTarget;
+pub use parameters::Parameters;
+pub use program_builder::{ProgramBuilder, ProgramError, Severity, ShaderDiagnostic};
+pub use query::{QueryBackend, WebGLRSQuery};
+pub use recording::RecordingBackend;
+pub use reflection::{
+    AttributeInfo, ProgramReflection, UniformBlockInfo, UniformInfo, UniformLayout, VaryingInfo,
+};
+pub use renderbuffer::{RenderbufferBackend, WebGLRSRenderbuffer};
 pub use rendering_context::*;
-pub use sampler::WebGLRSSampler;
-pub use shader_program::{WebGLRSProgram, WebGLRSShader};
-pub use sync::WebGLRSSync;
-pub use texture::WebGLRSTexture;
-pub use transform_feedback::WebGLRSTransformFeedback;
-pub use uniform_location::WebGLRSUniformLocation;
-pub use vertex_array_object::WebGLRSVertexArrayObject;
+pub use sampler::{SamplerBackend, WebGLRSSampler};
+pub use shader_program::{
+    ProgramBackend, WebGL2ProgramBackend, WebGLRSActiveInfo, WebGLRSProgram, WebGLRSShader,
+};
+pub use shared::Shared;
+pub use sync::{SyncBackend, WebGLRSSync};
+pub use texture::{TextureBackend, TextureStorage, WebGL2TextureBackend, WebGLRSTexture};
+pub use texture_builder::{TextureBuilder, TextureError, TextureUpload};
+pub use texture_data::{TextureData, TextureDataFormat, TextureFileError, TextureImages};
+pub use texture_loader::{
+    Blob, BrowserFetch, Fetch, FetchFuture, LoadError, LoadOptions, MemoryFetch, TextureLoader,
+};
+pub use transform_feedback::{TransformFeedbackBackend, WebGLRSTransformFeedback};
+pub use uniform_location::{Uniform, UniformBackend, WebGL2UniformBackend, WebGLRSUniformLocation};
+pub use vertex::{Vertex, VertexAttribute, VertexField};
+pub use vertex_array_object::{
+    AttributeBinding, AttributeLayout, IndexBinding, VertexArrayBackend, VertexArrayBindings,
+    VertexArrayError, WebGLRSVertexArrayObject,
+};
+pub use webgl1::{Extension, WebGLRenderingContext};
+#[cfg(feature = "derive")]
+pub use webgl_rs_derive::Vertex;
@@ -0,0 +1,103 @@
+//! Macros shared by the modules of the crate
+
+/// Generates the methods of `Checked` and `CheckedObject` that forward a call and check the WebGL error flag
+/// afterwards.
+///
+/// The list starts with `context;` for `Checked`, or with `object`, the name of the wrapper and the prefix of
+/// its operations for `CheckedObject`, e.g. `object WebGLRSBuffer "buffer";`. Every method is given by its
+/// signature without `&self`, generic parameters go between brackets instead of angle brackets:
+///
+/// * `fn name(args) -> T;` returns the value of the call.
+/// * `#[validated] fn name(args);` calls a method that validates its arguments and returns a
+///   `Result<(), WebGLError>`, its error is returned before the flag is checked.
+/// * `#[throwing] fn name(args);` calls a method that returns the javascript exception it threw.
+/// * `#[chained] fn name(args);` returns the checked view itself, for methods that can be chained.
+/// * `#[rejecting] fn name(args);` returns the checked view like `#[chained]`, for chained methods that return
+///   their own error type. The error is wrapped by the `check_rejected` method of the view.
+macro_rules! checked_methods {
+    (context; $($methods:tt)*) => {
+        checked_methods!(@each [context] $($methods)*);
+    };
+    (object $object:ident $prefix:literal; $($methods:tt)*) => {
+        checked_methods!(@each [object $object $prefix] $($methods)*);
+    };
+
+    (@each [$($mode:tt)*]) => {};
+    (@each [context]
+        $(#[$kind:ident])? fn $name:ident $([$($generics:tt)*])? ($($args:tt)*) $(-> $ret:ty)?;
+        $($rest:tt)*
+    ) => {
+        checked_methods!(@method [$($kind)?] context,
+            stringify!($name),
+            concat!("Checked version of `", stringify!($name), "`."),
+            $name [$($($generics)*)?] ($($args)*) [$($ret)?]);
+        checked_methods!(@each [context] $($rest)*);
+    };
+    (@each [object $object:ident $prefix:literal]
+        $(#[$kind:ident])? fn $name:ident $([$($generics:tt)*])? ($($args:tt)*) $(-> $ret:ty)?;
+        $($rest:tt)*
+    ) => {
+        checked_methods!(@method [$($kind)?] object,
+            concat!($prefix, ".", stringify!($name)),
+            concat!("Checked version of `", stringify!($object), "::", stringify!($name), "`."),
+            $name [$($($generics)*)?] ($($args)*) [$($ret)?]);
+        checked_methods!(@each [object $object $prefix] $($rest)*);
+    };
+
+    (@return) => { () };
+    (@return $ret:ty) => { $ret };
+
+    (@method [] $target:ident, $operation:expr, $doc:expr,
+        $name:ident [$($generics:tt)*] ($($arg:ident: $ty:ty),* $(,)?) [$($ret:ty)?]
+    ) => {
+        #[doc = $doc]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<$($generics)*>(
+            &self,
+            $($arg: $ty),*
+        ) -> Result<checked_methods!(@return $($ret)?), $crate::error::WebGLError> {
+            let value = self.$target.$name($($arg),*);
+            self.check($operation, value)
+        }
+    };
+    (@method [validated] $target:ident, $operation:expr, $doc:expr,
+        $name:ident [$($generics:tt)*] ($($arg:ident: $ty:ty),* $(,)?) []
+    ) => {
+        #[doc = $doc]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<$($generics)*>(&self, $($arg: $ty),*) -> Result<(), $crate::error::WebGLError> {
+            self.$target.$name($($arg),*)?;
+            self.check($operation, ())
+        }
+    };
+    (@method [throwing] $target:ident, $operation:expr, $doc:expr,
+        $name:ident [$($generics:tt)*] ($($arg:ident: $ty:ty),* $(,)?) []
+    ) => {
+        #[doc = $doc]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<$($generics)*>(&self, $($arg: $ty),*) -> Result<(), $crate::error::WebGLError> {
+            let result = self.$target.$name($($arg),*);
+            self.check_result($operation, result)
+        }
+    };
+    (@method [chained] $target:ident, $operation:expr, $doc:expr,
+        $name:ident [$($generics:tt)*] ($($arg:ident: $ty:ty),* $(,)?) []
+    ) => {
+        #[doc = $doc]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<$($generics)*>(&self, $($arg: $ty),*) -> Result<&Self, $crate::error::WebGLError> {
+            self.$target.$name($($arg),*);
+            self.check($operation, self)
+        }
+    };
+    (@method [rejecting] $target:ident, $operation:expr, $doc:expr,
+        $name:ident [$($generics:tt)*] ($($arg:ident: $ty:ty),* $(,)?) []
+    ) => {
+        #[doc = $doc]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<$($generics)*>(&self, $($arg: $ty),*) -> Result<&Self, $crate::error::WebGLError> {
+            let result = self.$target.$name($($arg),*).map(|_| ());
+            self.check_rejected($operation, result)
+        }
+    };
+}
@@ -0,0 +1,357 @@
+//! Multisampled offscreen render target that is resolved into textures
+//!
+//! A `MultisampleTarget` renders into multisampled renderbuffers and copies the result into single sampled
+//! textures with `blitFramebuffer` when `resolve` is called, the textures can then be sampled like any
+//! other texture.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let target = MultisampleTarget::new(
+//!     &gl,
+//!     640,
+//!     480,
+//!     8,
+//!     &[InternalFormat::Rgba8],
+//!     Some(InternalFormat::Depth24Stencil8),
+//! )
+//! .unwrap();
+//! // the recording backend supports 4 samples for every renderable format
+//! assert_eq!(target.samples(), 4);
+//!
+//! target.bind();
+//! gl.clear(BufferBit::Color);
+//! target.resolve();
+//! let calls: Vec<String> = gl.calls().iter().map(|call| call.to_string()).collect();
+//! assert!(calls.contains(&"blitFramebuffer(0, 0, 640, 480, 0, 0, 640, 480, Color, Nearest)".to_string()));
+//! assert_eq!(
+//!     calls.last().unwrap(),
+//!     "invalidateFramebuffer(ReadFramebuffer, [ColorAttachment0, DepthStencilAttachment])"
+//! );
+//!
+//! let error = MultisampleTarget::new(&gl, 640, 480, 4, &[InternalFormat::Rgb32f], None)
+//!     .err()
+//!     .unwrap();
+//! assert_eq!(error.to_string(), "COLOR_ATTACHMENT0 can not be created: Rgb32f is not a renderable format");
+//! ```
+use backend::WebGL2Backend;
+use formats::FormatError;
+use formats::{format_info, validate_renderbuffer_storage, validate_tex_storage};
+use framebuffer::WebGLRSFramebuffer;
+use framebuffer_builder::FramebufferError;
+use framebuffer_builder::{AttachmentImage, FramebufferBuilder, COLOR_ATTACHMENTS, DRAW_BUFFERS};
+use glenum::{
+    Attachment, BufferBit, ColorBuffer, FramebufferKind, InformationType, InternalFormat,
+    PixelFormat, RenderbufferKind, Texture2DKind, TextureKind, TextureMagFilter, TextureMinFilter,
+    TextureParameter,
+};
+use renderbuffer::WebGLRSRenderbuffer;
+use texture::WebGLRSTexture;
+
+/// Returns the attachment point of a depth and/or stencil format.
+fn depth_attachment(internal_format: InternalFormat) -> Attachment {
+    match format_info(internal_format).format {
+        PixelFormat::DepthComponent => Attachment::DepthAttachment,
+        _ if internal_format == InternalFormat::StencilIndex8 => Attachment::StencilAttachment,
+        _ => Attachment::DepthStencilAttachment,
+    }
+}
+
+/// Checks that a renderbuffer of the internal format can be attached to the attachment point, color
+/// attachments also need a texture of the format to be resolved into.
+fn validate_attachment(
+    attachment: Attachment,
+    internal_format: InternalFormat,
+    color: bool,
+) -> Result<(), FramebufferError> {
+    let info = format_info(internal_format);
+    let renderable = if color {
+        info.color_renderable
+    } else {
+        info.depth_stencil_renderable
+    };
+    let result = if !renderable {
+        Err(FormatError::NotRenderable { internal_format })
+    } else if color {
+        validate_tex_storage(internal_format)
+    } else {
+        validate_renderbuffer_storage(internal_format)
+    };
+    result.map_err(|error| FramebufferError::Format { attachment, error })
+}
+
+/// A multisampled framebuffer together with a framebuffer of textures it is resolved into.
+///
+/// Every color attachment is a multisampled renderbuffer with a matching single sampled texture, the depth
+/// and stencil attachment only exists in the multisampled framebuffer. The textures have a single mipmap
+/// level and are filtered linearly when their format allows it.
+pub struct MultisampleTarget<'ctx, B: 'ctx + WebGL2Backend> {
+    context: &'ctx B,
+    width: u32,
+    height: u32,
+    samples: u32,
+    framebuffer: WebGLRSFramebuffer<'ctx, B>,
+    renderbuffers: Vec<WebGLRSRenderbuffer<'ctx, B>>,
+    resolve_framebuffer: WebGLRSFramebuffer<'ctx, B>,
+    textures: Vec<WebGLRSTexture<'ctx, B>>,
+    attachments: Vec<Attachment>,
+}
+
+impl<'ctx, B: WebGL2Backend> MultisampleTarget<'ctx, B> {
+    /// Creates the multisampled and the resolve framebuffer.
+    ///
+    /// The number of samples is clamped to the largest count at most `samples` that
+    /// `getInternalformatParameter` reports for all of the formats, or 0 when there is none.
+    ///
+    /// # Arguments
+    /// * `context` - the context to create the target in.
+    /// * `width` - the width of the attachments.
+    /// * `height` - the height of the attachments.
+    /// * `samples` - the requested number of samples.
+    /// * `color_formats` - the formats of the color attachments, at least one and at most 16.
+    /// * `depth_format` - the format of the depth and/or stencil attachment, if any.
+    ///
+    /// # Panics
+    /// Panics if more than 16 color formats are given.
+    pub fn new(
+        context: &'ctx B,
+        width: u32,
+        height: u32,
+        samples: u32,
+        color_formats: &[InternalFormat],
+        depth_format: Option<InternalFormat>,
+    ) -> Result<Self, FramebufferError> {
+        assert!(
+            color_formats.len() <= 16,
+            "{} color attachments do not exist",
+            color_formats.len()
+        );
+        if color_formats.is_empty() {
+            return Err(FramebufferError::MissingAttachment);
+        }
+        for (index, &internal_format) in color_formats.iter().enumerate() {
+            validate_attachment(COLOR_ATTACHMENTS[index], internal_format, true)?;
+        }
+        if let Some(internal_format) = depth_format {
+            validate_attachment(depth_attachment(internal_format), internal_format, false)?;
+        }
+        let samples = MultisampleTarget::supported_samples(
+            context,
+            samples,
+            color_formats.iter().chain(depth_format.iter()),
+        );
+
+        let kind = RenderbufferKind::Renderbuffer;
+        let renderbuffer = |internal_format| {
+            let renderbuffer = context.create_renderbuffer();
+            renderbuffer.bind(kind);
+            context._renderbuffer_storage_multisample(
+                kind,
+                samples,
+                internal_format,
+                width,
+                height,
+            );
+            renderbuffer
+        };
+        let mut renderbuffers: Vec<_> = color_formats
+            .iter()
+            .map(|&format| renderbuffer(format))
+            .collect();
+        let mut attachments = COLOR_ATTACHMENTS[..color_formats.len()].to_vec();
+        if let Some(internal_format) = depth_format {
+            renderbuffers.push(renderbuffer(internal_format));
+            attachments.push(depth_attachment(internal_format));
+        }
+
+        let textures: Vec<_> = color_formats
+            .iter()
+            .map(|&internal_format| {
+                let texture = context.create_texture();
+                texture.bind(TextureKind::Texture2d);
+                context._tex_storage_2d(
+                    Texture2DKind::Texture2d,
+                    1,
+                    internal_format,
+                    width,
+                    height,
+                );
+                let (min_filter, mag_filter) = if format_info(internal_format).filterable {
+                    (TextureMinFilter::Linear, TextureMagFilter::Linear)
+                } else {
+                    (TextureMinFilter::Nearest, TextureMagFilter::Nearest)
+                };
+                context.tex_parameter_i(
+                    TextureKind::Texture2d,
+                    TextureParameter::MinFilter,
+                    min_filter as i32,
+                );
+                context.tex_parameter_i(
+                    TextureKind::Texture2d,
+                    TextureParameter::MagFilter,
+                    mag_filter as i32,
+                );
+                texture
+            })
+            .collect();
+
+        let framebuffer = attachments
+            .iter()
+            .zip(&renderbuffers)
+            .fold(
+                FramebufferBuilder::new(context),
+                |builder, (&attachment, renderbuffer)| {
+                    builder.attach(attachment, AttachmentImage::renderbuffer(renderbuffer))
+                },
+            )
+            .build()?;
+        let resolve_framebuffer = textures
+            .iter()
+            .enumerate()
+            .fold(
+                FramebufferBuilder::new(context),
+                |builder, (index, texture)| {
+                    builder.color(
+                        index as u32,
+                        AttachmentImage::texture(texture, 0, width, height),
+                    )
+                },
+            )
+            .build()?;
+
+        Ok(MultisampleTarget {
+            context,
+            width,
+            height,
+            samples,
+            framebuffer,
+            renderbuffers,
+            resolve_framebuffer,
+            textures,
+            attachments,
+        })
+    }
+
+    /// Returns the largest sample count at most `requested` that is supported by all formats.
+    fn supported_samples<'a, I: Iterator<Item = &'a InternalFormat>>(
+        context: &B,
+        requested: u32,
+        formats: I,
+    ) -> u32 {
+        let mut supported: Option<Vec<u32>> = None;
+        for &internal_format in formats {
+            let counts: Vec<u32> = context
+                .get_internal_format_parameter(
+                    RenderbufferKind::Renderbuffer,
+                    internal_format,
+                    InformationType::Samples,
+                )
+                .into_iter()
+                .filter(|&count| count > 0)
+                .map(|count| count as u32)
+                .collect();
+            supported = Some(match supported {
+                Some(supported) => supported
+                    .into_iter()
+                    .filter(|count| counts.contains(count))
+                    .collect(),
+                None => counts,
+            });
+        }
+        supported
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|&count| count <= requested)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of samples of the multisampled attachments, 0 if multisampling is not supported.
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Returns the width of the attachments.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the attachments.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the multisampled framebuffer that is rendered into.
+    pub fn framebuffer(&self) -> &WebGLRSFramebuffer<'ctx, B> {
+        &self.framebuffer
+    }
+
+    /// Returns the multisampled renderbuffers, the color attachments followed by the depth attachment.
+    pub fn renderbuffers(&self) -> &[WebGLRSRenderbuffer<'ctx, B>] {
+        &self.renderbuffers
+    }
+
+    /// Returns the framebuffer of the textures the multisampled framebuffer is resolved into.
+    pub fn resolve_framebuffer(&self) -> &WebGLRSFramebuffer<'ctx, B> {
+        &self.resolve_framebuffer
+    }
+
+    /// Returns the textures the color attachments are resolved into, indexed by their color attachment.
+    pub fn textures(&self) -> &[WebGLRSTexture<'ctx, B>] {
+        &self.textures
+    }
+
+    /// Returns the texture color attachment `index` is resolved into.
+    pub fn texture(&self, index: usize) -> Option<&WebGLRSTexture<'ctx, B>> {
+        self.textures.get(index)
+    }
+
+    /// Binds the multisampled framebuffer to `FramebufferKind::Framebuffer` so it can be rendered into.
+    pub fn bind(&self) {
+        self.framebuffer.bind(FramebufferKind::Framebuffer);
+    }
+
+    /// Copies every color attachment into its texture and invalidates the multisampled attachments.
+    ///
+    /// The contents of the multisampled framebuffer are undefined afterwards, it has to be cleared before
+    /// it is rendered into again. The multisampled framebuffer stays bound to
+    /// `FramebufferKind::ReadFramebuffer` and the resolve framebuffer to `FramebufferKind::DrawFramebuffer`.
+    pub fn resolve(&self) {
+        let context = self.context;
+        let (width, height) = (self.width as i32, self.height as i32);
+        self.framebuffer.bind(FramebufferKind::ReadFramebuffer);
+        self.resolve_framebuffer
+            .bind(FramebufferKind::DrawFramebuffer);
+        let blit = || {
+            context.blit_framebuffer(
+                0,
+                0,
+                width,
+                height,
+                0,
+                0,
+                width,
+                height,
+                BufferBit::Color,
+                TextureMagFilter::Nearest,
+            )
+        };
+        if self.textures.len() == 1 {
+            blit();
+        } else {
+            // a blit writes the read buffer to every draw buffer, so they are selected one at a time
+            let mut draw_buffers = vec![ColorBuffer::None; self.textures.len()];
+            for index in 0..self.textures.len() {
+                context.read_buffer(DRAW_BUFFERS[index]);
+                draw_buffers[index] = DRAW_BUFFERS[index];
+                context.draw_buffers(&draw_buffers);
+                blit();
+                draw_buffers[index] = ColorBuffer::None;
+            }
+            context.read_buffer(ColorBuffer::ColorAttachment0);
+            context.draw_buffers(&DRAW_BUFFERS[..self.textures.len()]);
+        }
+        context.invalidate_framebuffer(FramebufferKind::ReadFramebuffer, &self.attachments);
+    }
+}
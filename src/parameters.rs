@@ -0,0 +1,524 @@
+//! Typed accessors for the state of a rendering context
+//!
+//! `WebGLRenderingContext.getParameter()` returns a different javascript type for every parameter name, so
+//! instead of one binding returning a `JsValue` every parameter gets its own method on the `Parameters`
+//! view that is returned by `ContextBackend::parameters`.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! assert_eq!(gl.parameters().viewport(), [0, 0, 300, 150]);
+//! assert!(gl.parameters().array_buffer_binding().is_none());
+//! ```
+use buffer::{BufferBackend, WebGLRSBuffer};
+use glenum::*;
+use rendering_context::ContextBackend;
+use std::mem::ManuallyDrop;
+use texture::{TextureBackend, WebGLRSTexture};
+
+/// View on a context for querying its current state.
+///
+/// Created with `ContextBackend::parameters`.
+pub struct Parameters<'ctx, B: 'ctx + ContextBackend> {
+    context: &'ctx B,
+}
+
+impl<'ctx, B: ContextBackend> Clone for Parameters<'ctx, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'ctx, B: ContextBackend> Copy for Parameters<'ctx, B> {}
+
+/// Copies the values returned for an array parameter into a fixed size array.
+///
+/// Missing values are left at their default, superfluous values are ignored.
+fn to_array<A: Default + AsMut<[T]>, T>(values: Vec<T>) -> A {
+    let mut array = A::default();
+    for (slot, value) in array.as_mut().iter_mut().zip(values) {
+        *slot = value;
+    }
+    array
+}
+
+impl<'ctx, B: ContextBackend> Parameters<'ctx, B> {
+    pub(crate) fn new(context: &'ctx B) -> Parameters<'ctx, B> {
+        Parameters { context }
+    }
+
+    /// Returns the RGB blend equation.
+    pub fn blend_equation_rgb(&self) -> BlendEquation {
+        self.context._get_parameter_enum1(Parameter::BlendEquation)
+    }
+
+    /// Returns the alpha blend equation.
+    pub fn blend_equation_alpha(&self) -> BlendEquation {
+        self.context
+            ._get_parameter_enum1(Parameter::BlendEquationAlpha)
+    }
+
+    /// Returns the destination RGB blend function.
+    pub fn blend_dst_rgb(&self) -> BlendMode {
+        self.context._get_parameter_enum2(Parameter::BlendDstRgb)
+    }
+
+    /// Returns the source RGB blend function.
+    pub fn blend_src_rgb(&self) -> BlendMode {
+        self.context._get_parameter_enum2(Parameter::BlendSrcRgb)
+    }
+
+    /// Returns the destination alpha blend function.
+    pub fn blend_dst_alpha(&self) -> BlendMode {
+        self.context._get_parameter_enum2(Parameter::BlendDstAlpha)
+    }
+
+    /// Returns the source alpha blend function.
+    pub fn blend_src_alpha(&self) -> BlendMode {
+        self.context._get_parameter_enum2(Parameter::BlendSrcAlpha)
+    }
+
+    /// Returns the blend color as `[red, green, blue, alpha]`.
+    pub fn blend_color(&self) -> [f32; 4] {
+        to_array(self.context._get_parameter_f32_array(Parameter::BlendColor))
+    }
+
+    /// Returns the width of rasterized lines.
+    pub fn line_width(&self) -> f32 {
+        self.context._get_parameter_f32(Parameter::LineWidth)
+    }
+
+    /// Returns the range of supported point sizes as `[min, max]`.
+    pub fn aliased_point_size_range(&self) -> [f32; 2] {
+        to_array(
+            self.context
+                ._get_parameter_f32_array(Parameter::AliasedPointSizeRange),
+        )
+    }
+
+    /// Returns the range of supported line widths as `[min, max]`.
+    pub fn aliased_line_width_range(&self) -> [f32; 2] {
+        to_array(
+            self.context
+                ._get_parameter_f32_array(Parameter::AliasedLineWidthRange),
+        )
+    }
+
+    /// Returns which faces are culled.
+    pub fn cull_face_mode(&self) -> Culling {
+        self.context._get_parameter_enum3(Parameter::CullFaceMode)
+    }
+
+    /// Returns the winding orientation of front-facing polygons.
+    pub fn front_face(&self) -> FrontFaceDirection {
+        self.context._get_parameter_enum4(Parameter::FrontFace)
+    }
+
+    /// Returns the depth range as `[z_near, z_far]`.
+    pub fn depth_range(&self) -> [f32; 2] {
+        to_array(self.context._get_parameter_f32_array(Parameter::DepthRange))
+    }
+
+    /// Returns whether writing into the depth buffer is enabled.
+    pub fn depth_writemask(&self) -> bool {
+        self.context._get_parameter_bool(Parameter::DepthWritemask)
+    }
+
+    /// Returns the value the depth buffer is cleared to.
+    pub fn depth_clear_value(&self) -> f32 {
+        self.context._get_parameter_f32(Parameter::DepthClearValue)
+    }
+
+    /// Returns the depth comparison function.
+    pub fn depth_func(&self) -> DepthTest {
+        self.context._get_parameter_enum5(Parameter::DepthFunc)
+    }
+
+    /// Returns the value the stencil buffer is cleared to.
+    pub fn stencil_clear_value(&self) -> i32 {
+        self.context
+            ._get_parameter_i32(Parameter::StencilClearValue)
+    }
+
+    /// Returns the stencil test function for front faces.
+    pub fn stencil_func(&self) -> StencilTest {
+        self.context._get_parameter_enum6(Parameter::StencilFunc)
+    }
+
+    /// Returns the action taken for front faces when the stencil test fails.
+    pub fn stencil_fail(&self) -> StencilAction {
+        self.context._get_parameter_enum7(Parameter::StencilFail)
+    }
+
+    /// Returns the action taken for front faces when the stencil test passes and the depth test fails.
+    pub fn stencil_pass_depth_fail(&self) -> StencilAction {
+        self.context
+            ._get_parameter_enum7(Parameter::StencilPassDepthFail)
+    }
+
+    /// Returns the action taken for front faces when both the stencil test and the depth test pass.
+    pub fn stencil_pass_depth_pass(&self) -> StencilAction {
+        self.context
+            ._get_parameter_enum7(Parameter::StencilPassDepthPass)
+    }
+
+    /// Returns the reference value of the stencil test for front faces.
+    pub fn stencil_ref(&self) -> i32 {
+        self.context._get_parameter_i32(Parameter::StencilRef)
+    }
+
+    /// Returns the mask that is applied to the stencil values of front faces before comparing them.
+    pub fn stencil_value_mask(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::StencilValueMask)
+    }
+
+    /// Returns the mask of the stencil bits that are written for front faces.
+    pub fn stencil_writemask(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::StencilWritemask)
+    }
+
+    /// Returns the stencil test function for back faces.
+    pub fn stencil_back_func(&self) -> StencilTest {
+        self.context
+            ._get_parameter_enum6(Parameter::StencilBackFunc)
+    }
+
+    /// Returns the action taken for back faces when the stencil test fails.
+    pub fn stencil_back_fail(&self) -> StencilAction {
+        self.context
+            ._get_parameter_enum7(Parameter::StencilBackFail)
+    }
+
+    /// Returns the action taken for back faces when the stencil test passes and the depth test fails.
+    pub fn stencil_back_pass_depth_fail(&self) -> StencilAction {
+        self.context
+            ._get_parameter_enum7(Parameter::StencilBackPassDepthFail)
+    }
+
+    /// Returns the action taken for back faces when both the stencil test and the depth test pass.
+    pub fn stencil_back_pass_depth_pass(&self) -> StencilAction {
+        self.context
+            ._get_parameter_enum7(Parameter::StencilBackPassDepthPass)
+    }
+
+    /// Returns the reference value of the stencil test for back faces.
+    pub fn stencil_back_ref(&self) -> i32 {
+        self.context._get_parameter_i32(Parameter::StencilBackRef)
+    }
+
+    /// Returns the mask that is applied to the stencil values of back faces before comparing them.
+    pub fn stencil_back_value_mask(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::StencilBackValueMask)
+    }
+
+    /// Returns the mask of the stencil bits that are written for back faces.
+    pub fn stencil_back_writemask(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::StencilBackWritemask)
+    }
+
+    /// Returns the viewport as `[x, y, width, height]`.
+    pub fn viewport(&self) -> [i32; 4] {
+        to_array(self.context._get_parameter_i32_array(Parameter::Viewport))
+    }
+
+    /// Returns the scissor box as `[x, y, width, height]`.
+    pub fn scissor_box(&self) -> [i32; 4] {
+        to_array(self.context._get_parameter_i32_array(Parameter::ScissorBox))
+    }
+
+    /// Returns the color the color buffer is cleared to as `[red, green, blue, alpha]`.
+    pub fn color_clear_value(&self) -> [f32; 4] {
+        to_array(
+            self.context
+                ._get_parameter_f32_array(Parameter::ColorClearValue),
+        )
+    }
+
+    /// Returns which color components are written as `[red, green, blue, alpha]`.
+    pub fn color_writemask(&self) -> [bool; 4] {
+        to_array(
+            self.context
+                ._get_parameter_bool_array(Parameter::ColorWritemask),
+        )
+    }
+
+    /// Returns the row alignment used when unpacking pixel data from memory.
+    pub fn unpack_alignment(&self) -> i32 {
+        self.context._get_parameter_i32(Parameter::UnpackAlignment)
+    }
+
+    /// Returns the row alignment used when packing pixel data into memory.
+    pub fn pack_alignment(&self) -> i32 {
+        self.context._get_parameter_i32(Parameter::PackAlignment)
+    }
+
+    /// Returns whether DOM sources are flipped vertically when they are uploaded to a texture.
+    pub fn unpack_flip_y(&self) -> bool {
+        self.context
+            ._get_parameter_bool(Parameter::UnpackFlipYWebgl)
+    }
+
+    /// Returns whether the colors of DOM sources are multiplied by their alpha when they are uploaded.
+    pub fn unpack_premultiply_alpha(&self) -> bool {
+        self.context
+            ._get_parameter_bool(Parameter::UnpackPremultiplyAlphaWebgl)
+    }
+
+    /// Returns the largest width and height of a texture.
+    pub fn max_texture_size(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::MaxTextureSize)
+    }
+
+    /// Returns the largest width and height of a cube map texture.
+    pub fn max_cube_map_texture_size(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxCubeMapTextureSize)
+    }
+
+    /// Returns the largest width, height and depth of a 3D texture. Requires WebGL2.
+    pub fn max_3d_texture_size(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::Max3dTextureSize)
+    }
+
+    /// Returns the maximum number of layers of an array texture. Requires WebGL2.
+    pub fn max_array_texture_layers(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxArrayTextureLayers)
+    }
+
+    /// Returns the largest width and height of a renderbuffer.
+    pub fn max_renderbuffer_size(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxRenderbufferSize)
+    }
+
+    /// Returns the largest viewport as `[width, height]`.
+    pub fn max_viewport_dims(&self) -> [i32; 2] {
+        to_array(
+            self.context
+                ._get_parameter_i32_array(Parameter::MaxViewportDims),
+        )
+    }
+
+    /// Returns the maximum number of vertex attributes.
+    pub fn max_vertex_attribs(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::MaxVertexAttribs)
+    }
+
+    /// Returns the maximum number of uniform vectors in a vertex shader.
+    pub fn max_vertex_uniform_vectors(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxVertexUniformVectors)
+    }
+
+    /// Returns the maximum number of uniform vectors in a fragment shader.
+    pub fn max_fragment_uniform_vectors(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxFragmentUniformVectors)
+    }
+
+    /// Returns the maximum number of varying vectors shared by the shaders of a program.
+    pub fn max_varying_vectors(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxVaryingVectors)
+    }
+
+    /// Returns the number of texture units available to a fragment shader.
+    pub fn max_texture_image_units(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxTextureImageUnits)
+    }
+
+    /// Returns the number of texture units available to a vertex shader.
+    pub fn max_vertex_texture_image_units(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxVertexTextureImageUnits)
+    }
+
+    /// Returns the number of texture units available to all shaders combined.
+    pub fn max_combined_texture_image_units(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxCombinedTextureImageUnits)
+    }
+
+    /// Returns the maximum number of uniform buffer binding points. Requires WebGL2.
+    pub fn max_uniform_buffer_bindings(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxUniformBufferBindings)
+    }
+
+    /// Returns the maximum number of color attachments of a framebuffer.
+    ///
+    /// Requires WebGL2 or the `WEBGL_draw_buffers` extension.
+    pub fn max_color_attachments(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::MaxColorAttachments)
+    }
+
+    /// Returns the maximum number of draw buffers.
+    ///
+    /// Requires WebGL2 or the `WEBGL_draw_buffers` extension.
+    pub fn max_draw_buffers(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::MaxDrawBuffers)
+    }
+
+    /// Returns the maximum number of samples of a multisample renderbuffer. Requires WebGL2.
+    pub fn max_samples(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::MaxSamples)
+    }
+
+    /// Returns the number of bits of subpixel precision used to position rasterized geometry.
+    pub fn subpixel_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::SubpixelBits)
+    }
+
+    /// Returns the number of red bits in the color buffer of the current framebuffer.
+    pub fn red_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::RedBits)
+    }
+
+    /// Returns the number of green bits in the color buffer of the current framebuffer.
+    pub fn green_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::GreenBits)
+    }
+
+    /// Returns the number of blue bits in the color buffer of the current framebuffer.
+    pub fn blue_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::BlueBits)
+    }
+
+    /// Returns the number of alpha bits in the color buffer of the current framebuffer.
+    pub fn alpha_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::AlphaBits)
+    }
+
+    /// Returns the number of bits in the depth buffer of the current framebuffer.
+    pub fn depth_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::DepthBits)
+    }
+
+    /// Returns the number of bits in the stencil buffer of the current framebuffer.
+    pub fn stencil_bits(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::StencilBits)
+    }
+
+    /// Returns the units of the polygon offset.
+    pub fn polygon_offset_units(&self) -> f32 {
+        self.context
+            ._get_parameter_f32(Parameter::PolygonOffsetUnits)
+    }
+
+    /// Returns the scale factor of the polygon offset.
+    pub fn polygon_offset_factor(&self) -> f32 {
+        self.context
+            ._get_parameter_f32(Parameter::PolygonOffsetFactor)
+    }
+
+    /// Returns the number of multisample buffers of the current framebuffer.
+    pub fn sample_buffers(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::SampleBuffers)
+    }
+
+    /// Returns the number of samples per pixel of the current framebuffer.
+    pub fn samples(&self) -> u32 {
+        self.context._get_parameter_u32(Parameter::Samples)
+    }
+
+    /// Returns the value of the sample coverage.
+    pub fn sample_coverage_value(&self) -> f32 {
+        self.context
+            ._get_parameter_f32(Parameter::SampleCoverageValue)
+    }
+
+    /// Returns whether the sample coverage mask is inverted.
+    pub fn sample_coverage_invert(&self) -> bool {
+        self.context
+            ._get_parameter_bool(Parameter::SampleCoverageInvert)
+    }
+
+    /// Returns the compressed texture formats that are supported by the context.
+    ///
+    /// The formats are returned as raw values because they depend on the enabled extensions.
+    pub fn compressed_texture_formats(&self) -> Vec<u32> {
+        self.context
+            ._get_parameter_u32_array(Parameter::CompressedTextureFormats)
+    }
+
+    /// Returns the vendor of the implementation.
+    pub fn vendor(&self) -> String {
+        self.context._get_parameter_string(Parameter::Vendor)
+    }
+
+    /// Returns the name of the renderer.
+    pub fn renderer(&self) -> String {
+        self.context._get_parameter_string(Parameter::Renderer)
+    }
+
+    /// Returns the version of the implementation, e.g. `"WebGL 2.0"`.
+    pub fn version(&self) -> String {
+        self.context._get_parameter_string(Parameter::Version)
+    }
+
+    /// Returns the pixel type that is preferred by `read_pixels` for the current framebuffer.
+    ///
+    /// Returned as raw value because it is not limited to the types accepted by the other pixel methods.
+    pub fn implementation_color_read_type(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::ImplementationColorReadType)
+    }
+
+    /// Returns the pixel format that is preferred by `read_pixels` for the current framebuffer.
+    ///
+    /// Returned as raw value because it is not limited to the formats accepted by the other pixel methods.
+    pub fn implementation_color_read_format(&self) -> u32 {
+        self.context
+            ._get_parameter_u32(Parameter::ImplementationColorReadFormat)
+    }
+}
+
+impl<'ctx, B: ContextBackend + BufferBackend> Parameters<'ctx, B> {
+    /// Returns the buffer that is bound to `BufferKind::Array`.
+    ///
+    /// The buffer is still owned by the `WebGLRSBuffer` that was bound, so the returned wrapper does not
+    /// delete it when it goes out of scope.
+    pub fn array_buffer_binding(&self) -> Option<ManuallyDrop<WebGLRSBuffer<'ctx, B>>> {
+        self.context
+            ._get_parameter_buffer(Parameter::ArrayBufferBinding)
+            .map(|buffer| ManuallyDrop::new(WebGLRSBuffer::from_raw(self.context, buffer)))
+    }
+
+    /// Returns the buffer that is bound to `BufferKind::ElementArray`.
+    ///
+    /// The buffer is still owned by the `WebGLRSBuffer` that was bound, so the returned wrapper does not
+    /// delete it when it goes out of scope.
+    pub fn element_array_buffer_binding(&self) -> Option<ManuallyDrop<WebGLRSBuffer<'ctx, B>>> {
+        self.context
+            ._get_parameter_buffer(Parameter::ElementArrayBufferBinding)
+            .map(|buffer| ManuallyDrop::new(WebGLRSBuffer::from_raw(self.context, buffer)))
+    }
+}
+
+impl<'ctx, B: ContextBackend + TextureBackend> Parameters<'ctx, B> {
+    /// Returns the texture that is bound to `TextureKind::Texture2d` of the active texture unit.
+    ///
+    /// The texture is still owned by the `WebGLRSTexture` that was bound, so the returned wrapper does not
+    /// delete it when it goes out of scope.
+    pub fn texture_binding_2d(&self) -> Option<ManuallyDrop<WebGLRSTexture<'ctx, B>>> {
+        self.context
+            ._get_parameter_texture(Parameter::TextureBinding2d)
+            .map(|texture| ManuallyDrop::new(WebGLRSTexture::from_raw(self.context, texture)))
+    }
+
+    /// Returns the texture that is bound to `TextureKind::TextureCubeMap` of the active texture unit.
+    ///
+    /// The texture is still owned by the `WebGLRSTexture` that was bound, so the returned wrapper does not
+    /// delete it when it goes out of scope.
+    pub fn texture_binding_cube_map(&self) -> Option<ManuallyDrop<WebGLRSTexture<'ctx, B>>> {
+        self.context
+            ._get_parameter_texture(Parameter::TextureBindingCubeMap)
+            .map(|texture| ManuallyDrop::new(WebGLRSTexture::from_raw(self.context, texture)))
+    }
+}
@@ -0,0 +1,374 @@
+//! Builder that compiles and links a `WebGLRSProgram` in one go
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let program = ProgramBuilder::new(&gl)
+//!     .vertex("attribute vec2 pos; void main() { gl_Position = vec4(pos, 0.0, 1.0); }")
+//!     .fragment("void main() { gl_FragColor = vec4(1.0); }")
+//!     .bind_attrib(0, "pos")
+//!     .build()
+//!     .unwrap();
+//! assert!(program.link_status());
+//! assert_eq!(gl.calls().iter().filter(|call| call.name == "deleteShader").count(), 2);
+//! ```
+use glenum::{ShaderKind, TransformFeedbackBufferMode};
+use shader_program::{ProgramBackend, WebGL2ProgramBackend, WebGLRSProgram, WebGLRSShader};
+use std::error;
+use std::fmt;
+
+/// Varyings recorded by transform feedback, set before linking.
+struct TransformFeedbackVaryings<'ctx, B: 'ctx + ProgramBackend> {
+    varyings: Vec<String>,
+    buffer_mode: TransformFeedbackBufferMode,
+    set: fn(&WebGLRSProgram<'ctx, B>, &[&str], TransformFeedbackBufferMode),
+}
+
+/// Compiles the shaders of a program, links it and reports failures as a `ProgramError`.
+///
+/// The shaders are detached and deleted once the program is linked, only the program is returned.
+pub struct ProgramBuilder<'ctx, B: 'ctx + ProgramBackend> {
+    context: &'ctx B,
+    vertex: Option<String>,
+    fragment: Option<String>,
+    attrib_locations: Vec<(u32, String)>,
+    transform_feedback: Option<TransformFeedbackVaryings<'ctx, B>>,
+}
+
+impl<'ctx, B: ProgramBackend> ProgramBuilder<'ctx, B> {
+    /// Creates a builder for a program of the given context.
+    pub fn new(context: &'ctx B) -> Self {
+        ProgramBuilder {
+            context,
+            vertex: None,
+            fragment: None,
+            attrib_locations: Vec::new(),
+            transform_feedback: None,
+        }
+    }
+
+    /// Sets the source of the vertex shader.
+    pub fn vertex(mut self, source: &str) -> Self {
+        self.vertex = Some(source.to_string());
+        self
+    }
+
+    /// Sets the source of the fragment shader.
+    pub fn fragment(mut self, source: &str) -> Self {
+        self.fragment = Some(source.to_string());
+        self
+    }
+
+    /// Binds a vertex attribute to a location before linking.
+    ///
+    /// # Arguments
+    /// * `index` - the location to bind the attribute to.
+    /// * `name` - the name of the attribute.
+    pub fn bind_attrib(mut self, index: u32, name: &str) -> Self {
+        self.attrib_locations.push((index, name.to_string()));
+        self
+    }
+
+    /// Compiles the shaders and links the program.
+    ///
+    /// Fails with `ProgramError::Compile` holding the parsed info log when a shader does not compile and
+    /// with `ProgramError::Link` when the program does not link.
+    pub fn build(self) -> Result<WebGLRSProgram<'ctx, B>, ProgramError> {
+        let vertex = self.compile(ShaderKind::Vertex, &self.vertex)?;
+        let fragment = self.compile(ShaderKind::Fragment, &self.fragment)?;
+
+        let program = self.context.create_program();
+        program.attach_shader(&vertex);
+        program.attach_shader(&fragment);
+        for &(index, ref name) in &self.attrib_locations {
+            program.bind_attrib_location(index, name);
+        }
+        if let Some(ref transform_feedback) = self.transform_feedback {
+            let varyings: Vec<&str> = transform_feedback
+                .varyings
+                .iter()
+                .map(|varying| varying.as_str())
+                .collect();
+            (transform_feedback.set)(&program, &varyings, transform_feedback.buffer_mode);
+        }
+        program.link();
+        if !program.link_status() {
+            return Err(ProgramError::Link {
+                log: program.info_log(),
+            });
+        }
+
+        program.detach_shader(&vertex);
+        program.detach_shader(&fragment);
+        Ok(program)
+    }
+
+    /// Compiles the shader of one stage.
+    fn compile(
+        &self,
+        stage: ShaderKind,
+        source: &Option<String>,
+    ) -> Result<WebGLRSShader<'ctx, B>, ProgramError> {
+        let source = match *source {
+            Some(ref source) => source,
+            None => return Err(ProgramError::MissingShader { stage }),
+        };
+        let shader = self.context.create_shader(stage);
+        shader.set_shader_source(source);
+        shader.compile();
+        if !shader.compile_status() {
+            let log = shader.info_log();
+            return Err(ProgramError::Compile {
+                stage,
+                diagnostics: ShaderDiagnostic::parse_log(stage, &log, source),
+                log,
+            });
+        }
+        Ok(shader)
+    }
+}
+
+impl<'ctx, B: WebGL2ProgramBackend> ProgramBuilder<'ctx, B> {
+    /// Sets the varyings that are recorded in transform feedback buffers.
+    ///
+    /// # Arguments
+    /// * `varyings` - the names of the varyings to record.
+    /// * `buffer_mode` - whether the varyings are interleaved in one buffer or written to separate buffers.
+    pub fn transform_feedback(
+        mut self,
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    ) -> Self {
+        self.transform_feedback = Some(TransformFeedbackVaryings {
+            varyings: varyings.iter().map(|varying| varying.to_string()).collect(),
+            buffer_mode,
+            set: WebGLRSProgram::set_transform_feedback_varyings,
+        });
+        self
+    }
+}
+
+/// Returns the name of a shader stage as used in messages.
+fn stage_name(stage: ShaderKind) -> &'static str {
+    match stage {
+        ShaderKind::Vertex => "vertex",
+        ShaderKind::Fragment => "fragment",
+    }
+}
+
+/// Severity of a `ShaderDiagnostic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The shader failed to compile because of this diagnostic.
+    Error,
+    /// A problem that does not prevent compilation.
+    Warning,
+    /// Additional information from the compiler.
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        })
+    }
+}
+
+/// One message of the info log of a shader, as reported by the driver.
+#[derive(Debug, Clone)]
+pub struct ShaderDiagnostic {
+    /// The shader stage that produced the message.
+    pub stage: ShaderKind,
+    /// Line in the shader source the message refers to, starting at 1.
+    pub line: Option<u32>,
+    /// Column in the line, only reported by some drivers.
+    pub column: Option<u32>,
+    /// Severity of the message.
+    pub severity: Severity,
+    /// The message itself, without the location prefix.
+    pub message: String,
+    /// The source line the message refers to.
+    pub source_line: Option<String>,
+}
+
+/// Splits the `0:12: ` or `0:12(5): ` location prefix off a log line.
+fn parse_location(text: &str) -> Option<(u32, Option<u32>, &str)> {
+    let mut parts = text.splitn(3, ':');
+    let _source_string: u32 = parts.next()?.trim().parse().ok()?;
+    let position = parts.next()?.trim();
+    let rest = parts.next()?.trim();
+    match position.find('(') {
+        Some(index) => {
+            let column = position[index + 1..].trim_end_matches(')').parse().ok()?;
+            Some((position[..index].parse().ok()?, Some(column), rest))
+        }
+        None => Some((position.parse().ok()?, None, rest)),
+    }
+}
+
+/// Splits a `ERROR:` or `error:` severity prefix off a log line.
+fn parse_severity(text: &str) -> Option<(Severity, &str)> {
+    let prefixes = [
+        ("error:", Severity::Error),
+        ("warning:", Severity::Warning),
+        ("info:", Severity::Info),
+    ];
+    for &(prefix, severity) in &prefixes {
+        if text
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+        {
+            return Some((severity, text[prefix.len()..].trim()));
+        }
+    }
+    None
+}
+
+impl ShaderDiagnostic {
+    /// Parses the info log of a shader into diagnostics.
+    ///
+    /// Understands the log formats of ANGLE (`ERROR: 0:12: message`) and Mesa (`0:12(5): error: message`),
+    /// lines in other formats are kept as errors without a location. The summary line ANGLE appends to the
+    /// log is skipped.
+    ///
+    /// # Arguments
+    /// * `stage` - the stage of the shader.
+    /// * `log` - the info log of the shader.
+    /// * `source` - the source code of the shader, used to look up the offending lines.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let source = "void main() {\n    gl_FragColor = color;\n}";
+    /// let log = "ERROR: 0:2: 'color' : undeclared identifier\nERROR: 1 compilation errors.  No code generated.";
+    /// let diagnostics = ShaderDiagnostic::parse_log(ShaderKind::Fragment, log, source);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].line, Some(2));
+    /// assert_eq!(diagnostics[0].severity, Severity::Error);
+    /// assert_eq!(diagnostics[0].source_line.as_ref().unwrap(), "    gl_FragColor = color;");
+    ///
+    /// let log = "0:2(20): error: `color' undeclared";
+    /// let diagnostics = ShaderDiagnostic::parse_log(ShaderKind::Fragment, log, source);
+    /// assert_eq!(diagnostics[0].column, Some(20));
+    /// assert_eq!(diagnostics[0].message, "`color' undeclared");
+    ///
+    /// // drivers may log in other languages, lines that do not start with ASCII are kept whole
+    /// let diagnostics = ShaderDiagnostic::parse_log(ShaderKind::Fragment, "変数が未定義です", "");
+    /// assert_eq!((diagnostics[0].line, diagnostics[0].severity), (None, Severity::Error));
+    /// assert_eq!(diagnostics[0].message, "変数が未定義です");
+    /// ```
+    pub fn parse_log(stage: ShaderKind, log: &str, source: &str) -> Vec<ShaderDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for entry in log.lines() {
+            let entry = entry.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+            if entry.is_empty() {
+                continue;
+            }
+            let (severity, line, column, message) = match parse_severity(entry) {
+                // ANGLE puts the severity first
+                Some((severity, rest)) => match parse_location(rest) {
+                    Some((line, column, message)) => (severity, Some(line), column, message),
+                    None if rest.contains("compilation error") => continue,
+                    None => (severity, None, None, rest),
+                },
+                // Mesa puts the location first
+                None => match parse_location(entry) {
+                    Some((line, column, rest)) => {
+                        let (severity, message) =
+                            parse_severity(rest).unwrap_or((Severity::Error, rest));
+                        (severity, Some(line), column, message)
+                    }
+                    None => (Severity::Error, None, None, entry),
+                },
+            };
+            let source_line = line
+                .and_then(|line| (line as usize).checked_sub(1))
+                .and_then(|index| source.lines().nth(index))
+                .map(|line| line.to_string());
+            diagnostics.push(ShaderDiagnostic {
+                stage,
+                line,
+                column,
+                severity,
+                message: message.to_string(),
+                source_line,
+            });
+        }
+        diagnostics
+    }
+}
+
+/// Writes `vertex:12:5: error: message`, followed by the offending source line when it is known.
+impl fmt::Display for ShaderDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", stage_name(self.stage))?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, ":{}", column)?;
+        }
+        write!(f, ": {}: {}", self.severity, self.message)?;
+        if let (Some(line), Some(source_line)) = (self.line, self.source_line.as_ref()) {
+            write!(f, "\n{:>5} | {}", line, source_line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by `ProgramBuilder::build`.
+#[derive(Debug, Clone)]
+pub enum ProgramError {
+    /// No source was given for a shader stage.
+    MissingShader {
+        /// The stage without source.
+        stage: ShaderKind,
+    },
+    /// A shader failed to compile.
+    Compile {
+        /// The stage that failed to compile.
+        stage: ShaderKind,
+        /// The info log of the shader.
+        log: String,
+        /// The info log parsed into diagnostics.
+        diagnostics: Vec<ShaderDiagnostic>,
+    },
+    /// The program failed to link.
+    Link {
+        /// The info log of the program.
+        log: String,
+    },
+}
+
+impl fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProgramError::MissingShader { stage } => {
+                write!(f, "no source given for the {} shader", stage_name(stage))
+            }
+            ProgramError::Compile {
+                stage,
+                ref log,
+                ref diagnostics,
+            } => {
+                write!(f, "could not compile the {} shader", stage_name(stage))?;
+                if diagnostics.is_empty() {
+                    return write!(f, ": {}", log.trim());
+                }
+                for diagnostic in diagnostics {
+                    write!(f, "\n{}", diagnostic)?;
+                }
+                Ok(())
+            }
+            ProgramError::Link { ref log } => {
+                write!(f, "could not link the program: {}", log.trim())
+            }
+        }
+    }
+}
+
+impl error::Error for ProgramError {}
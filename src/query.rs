@@ -1,40 +1,154 @@
 //! WebGLQuery and methods
+use error::CheckedObject;
 use glenum::{Query, QueryParameter, QueryTarget};
-use rendering_context::WebGL2RenderingContext;
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
+use std::mem::ManuallyDrop;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing query objects.
+pub trait QueryBackend: Sized {
+    /// Handle to a query object of this backend.
+    type Query: Clone;
+
+    /// Binding for `WebGL2RenderingContext.createQuery()`
+    fn _create_query(&self) -> Self::Query;
+    /// Binding for `WebGL2RenderingContext.deleteQuery()`
+    fn _delete_query(&self, query: Self::Query);
+    /// Binding for `WebGL2RenderingContext.isQuery()`
+    fn _is_query(&self, query: &Self::Query) -> bool;
+    /// Binding for `WebGL2RenderingContext.beginQuery()`
+    fn _begin_query(&self, target: QueryTarget, query: &Self::Query);
+    /// Binding for `WebGL2RenderingContext.getQuery()`
+    fn _get_query(&self, target: QueryTarget, pname: Query) -> Self::Query;
+    /// Binding for `WebGL2RenderingContext.getQueryParameter()` if return type is bool
+    fn _get_query_parameter_bool(&self, query: &Self::Query, pname: QueryParameter) -> bool;
+    /// Binding for `WebGL2RenderingContext.getQueryParameter()` if return type is u32
+    fn _get_query_parameter_u32(&self, query: &Self::Query, pname: QueryParameter) -> u32;
+
     /// Creates and initializes a `WebGLRSQuery` object, that provide ways to asynchronously query for information.
-    pub fn create_query(&self) -> WebGLRSQuery {
+    fn create_query(&self) -> WebGLRSQuery<'_, Self> {
         WebGLRSQuery {
             context: self,
             inner: self._create_query(),
+            leaked: false,
         }
     }
 
     /// Returns the currently active WebGLQuery for the target, or null.
     ///
+    /// The query is still owned by the `WebGLRSQuery` that was used to begin it, so the returned wrapper
+    /// does not delete it when it goes out of scope.
+    ///
     /// # Arguments
     /// * `target` - specifying the target of the query
     /// TODO nullable -> Option
-    pub fn query(&self, target: QueryTarget) -> WebGLRSQuery {
-        WebGLRSQuery {
+    fn query(&self, target: QueryTarget) -> ManuallyDrop<WebGLRSQuery<'_, Self>> {
+        ManuallyDrop::new(WebGLRSQuery {
             context: self,
             inner: self._get_query(target, Query::Current),
+            leaked: false,
+        })
+    }
+}
+
+impl QueryBackend for WebGL2RenderingContext {
+    type Query = WebGLQuery;
+
+    fn _create_query(&self) -> WebGLQuery {
+        WebGL2RenderingContext::_create_query(self)
+    }
+    fn _delete_query(&self, query: WebGLQuery) {
+        WebGL2RenderingContext::_delete_query(self, query)
+    }
+    fn _is_query(&self, query: &WebGLQuery) -> bool {
+        WebGL2RenderingContext::_is_query(self, query)
+    }
+    fn _begin_query(&self, target: QueryTarget, query: &WebGLQuery) {
+        WebGL2RenderingContext::_begin_query(self, target, query)
+    }
+    fn _get_query(&self, target: QueryTarget, pname: Query) -> WebGLQuery {
+        WebGL2RenderingContext::_get_query(self, target, pname)
+    }
+    fn _get_query_parameter_bool(&self, query: &WebGLQuery, pname: QueryParameter) -> bool {
+        WebGL2RenderingContext::_get_query_parameter_bool(self, query, pname)
+    }
+    fn _get_query_parameter_u32(&self, query: &WebGLQuery, pname: QueryParameter) -> u32 {
+        WebGL2RenderingContext::_get_query_parameter_u32(self, query, pname)
+    }
+}
+
+/// Query object which is used to asynchronously query for information.
+///
+/// The object is deleted when the `WebGLRSQuery` is dropped.
+pub struct WebGLRSQuery<'ctx, B: 'ctx + QueryBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Query,
+    leaked: bool,
+}
+
+impl<'ctx, B: QueryBackend> Drop for WebGLRSQuery<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_query(self.inner.clone());
         }
     }
 }
 
-#[derive(Clone)]
-pub struct WebGLRSQuery<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLQuery,
+impl<'ctx, B: QueryBackend + ContextBackend> WebGLRSQuery<'ctx, B> {
+    /// Returns a view on this query whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
 }
 
-impl<'ctx> WebGLRSQuery<'ctx> {
+impl<'a, 'ctx, B: QueryBackend + ContextBackend> CheckedObject<'a, 'ctx, B, WebGLRSQuery<'ctx, B>> {
+    checked_methods! {
+        object WebGLRSQuery "query";
+        fn is_valid() -> bool;
+        fn begin(target: QueryTarget);
+        fn result_available() -> bool;
+        fn result() -> u32;
+    }
+}
+
+impl<'ctx, B: QueryBackend> WebGLRSQuery<'ctx, B> {
     /// Deletes this `WebGLRSQuery` object.
+    ///
+    /// This is the same as dropping the `WebGLRSQuery`.
     pub fn delete(self) {
-        self.context._delete_query(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSQuery` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `query` - the raw handle.
+    pub fn from_raw(context: &'ctx B, query: B::Query) -> Self {
+        WebGLRSQuery {
+            context,
+            inner: query,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Query {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSQuery` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Returns true if this is a valid `WebGLRSQuery` object.
@@ -68,7 +182,7 @@ impl<'ctx> WebGLRSQuery<'ctx> {
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLQuery;
+    pub type WebGLQuery;
 
     /// Binding for `WebGL2RenderingContext.createQuery()`
     #[wasm_bindgen(method, js_name = createQuery)]
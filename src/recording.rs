@@ -0,0 +1,2707 @@
+//! Native backend that records every call instead of executing it
+//!
+//! The `RecordingBackend` implements all of the backend traits without needing a browser, which makes it
+//! possible to test code written against the wrappers of this crate with a plain `cargo test`:
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let buffer = gl.create_buffer();
+//! buffer.bind(BufferKind::Array);
+//! gl.buffer_data(BufferKind::Array, &vec![0.0f32, 1.0, 2.0], DataHint::StaticDraw);
+//!
+//! let calls: Vec<String> = gl.calls().iter().map(|call| call.to_string()).collect();
+//! assert_eq!(
+//!     calls,
+//!     vec![
+//!         "createBuffer()",
+//!         "bindBuffer(Array, Handle(1))",
+//!         "bufferData(Array, [f32; 3], StaticDraw)",
+//!     ]
+//! );
+//! ```
+//!
+//! Queries return the default values of a freshly created WebGL2 context, or WebGL1 context when created
+//! with `RecordingBackend::webgl1`. The exceptions are the pixel storage modes which follow `pixel_storei`,
+//! the buffer sizes which follow `bufferData`, the supported extensions which are set with `set_supported_extensions` and the active variables of
+//! programs which are scripted with `set_program_interface`. Errors that should be returned by `get_error`
+//! can be queued with `push_error`.
+use buffer::{BufferBackend, WebGL2BufferBackend};
+use data_view::{ArrayData, ArrayDataMut};
+use formats::FormatError;
+use formats::{self, format_info};
+use framebuffer::FramebufferBackend;
+use glenum::*;
+use image_source::ImageSource;
+use query::QueryBackend;
+use renderbuffer::RenderbufferBackend;
+use rendering_context::{
+    BaseInstanceBackend, ContextBackend, DrawBuffersBackend, InstancingBackend,
+    WebGL2ContextBackend, WebGLRSShaderPrecisionFormat,
+};
+use sampler::SamplerBackend;
+use shader_program::{ProgramBackend, WebGL2ProgramBackend, WebGLRSActiveInfo};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::mem;
+use sync::SyncBackend;
+use texture::{TextureBackend, WebGL2TextureBackend};
+use transform_feedback::TransformFeedbackBackend;
+use uniform_location::{UniformBackend, WebGL2UniformBackend};
+use vertex_array_object::VertexArrayBackend;
+use wasm_bindgen::JsValue;
+use webgl1::Extension;
+
+/// Opaque handle to an object created by the `RecordingBackend`.
+///
+/// Handles are numbered in order of creation starting from 1, a handle of 0 represents `null`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle(pub u32);
+
+/// A single call made to the `RecordingBackend`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    /// Name of the javascript method that would have been called, e.g. `bufferData`.
+    pub name: &'static str,
+    /// The arguments of the call, formatted with `Debug` and separated by commas.
+    pub args: String,
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", self.name, self.args)
+    }
+}
+
+/// An active attribute reported by `getActiveAttrib`, see `ProgramInterface`.
+#[derive(Debug, Clone)]
+pub struct ActiveAttribute {
+    /// Name, size and type of the attribute.
+    pub info: WebGLRSActiveInfo,
+    /// Location returned by `getAttribLocation`, -1 for built-in attributes.
+    pub location: i32,
+}
+
+/// An active uniform reported by `getActiveUniform` and `getActiveUniforms`, see `ProgramInterface`.
+#[derive(Debug, Clone)]
+pub struct ActiveUniform {
+    /// Name, size and type of the uniform.
+    pub info: WebGLRSActiveInfo,
+    /// Index of the uniform block containing the uniform, -1 for uniforms outside a block.
+    pub block_index: i32,
+    /// Byte offset in the uniform block, -1 for uniforms outside a block.
+    pub offset: i32,
+    /// Byte stride between array elements.
+    pub array_stride: i32,
+    /// Byte stride between matrix columns (or rows).
+    pub matrix_stride: i32,
+    /// Whether a matrix is stored in row major order.
+    pub row_major: bool,
+}
+
+impl ActiveUniform {
+    /// Describes a uniform outside of a uniform block.
+    pub fn new(name: &str, uniform_type: UniformType, size: u32) -> ActiveUniform {
+        ActiveUniform {
+            info: WebGLRSActiveInfo {
+                name: name.to_string(),
+                size,
+                data_type: uniform_type as u32,
+            },
+            block_index: -1,
+            offset: -1,
+            array_stride: -1,
+            matrix_stride: -1,
+            row_major: false,
+        }
+    }
+}
+
+/// An active uniform block reported by the `getActiveUniformBlock*` queries, see `ProgramInterface`.
+#[derive(Debug, Clone, Default)]
+pub struct ActiveUniformBlock {
+    /// Name of the block.
+    pub name: String,
+    /// Binding point of the block.
+    pub binding: u32,
+    /// Size in bytes of the block.
+    pub data_size: u32,
+    /// Indices of the uniforms in the block.
+    pub uniforms: Vec<u32>,
+    /// Whether the block is used by the vertex shader.
+    pub referenced_by_vertex_shader: bool,
+    /// Whether the block is used by the fragment shader.
+    pub referenced_by_fragment_shader: bool,
+}
+
+/// The active variables the `RecordingBackend` reports for a program, set with
+/// `RecordingBackend::set_program_interface`.
+#[derive(Debug, Clone, Default)]
+pub struct ProgramInterface {
+    /// The active attributes, ordered by index.
+    pub attributes: Vec<ActiveAttribute>,
+    /// The active uniforms, ordered by index.
+    pub uniforms: Vec<ActiveUniform>,
+    /// The active uniform blocks, ordered by index.
+    pub uniform_blocks: Vec<ActiveUniformBlock>,
+    /// The varyings recorded by transform feedback, ordered by index.
+    pub transform_feedback_varyings: Vec<WebGLRSActiveInfo>,
+}
+
+/// Value of `INVALID_INDEX`, returned by `getUniformBlockIndex` for unknown names.
+const INVALID_INDEX: u32 = 0xFFFF_FFFF;
+
+/// Returned for indices without a scripted variable.
+fn unknown_active_info() -> WebGLRSActiveInfo {
+    WebGLRSActiveInfo {
+        name: String::new(),
+        size: 1,
+        data_type: UniformType::Float as u32,
+    }
+}
+
+/// Backend that records all calls made to it, for testing code without a browser.
+pub struct RecordingBackend {
+    calls: RefCell<Vec<Call>>,
+    errors: RefCell<VecDeque<Error>>,
+    next_handle: Cell<u32>,
+    live: RefCell<HashSet<Handle>>,
+    shader_kinds: RefCell<HashMap<Handle, ShaderKind>>,
+    shader_sources: RefCell<HashMap<Handle, String>>,
+    bound_buffers: RefCell<HashMap<u32, Handle>>,
+    buffer_sizes: RefCell<HashMap<Handle, i32>>,
+    drawing_buffer_size: Cell<(u32, u32)>,
+    alignments: Cell<(i32, i32)>,
+    unpack_modes: Cell<(bool, bool)>,
+    extensions: RefCell<Vec<String>>,
+    program_interface: RefCell<ProgramInterface>,
+    webgl1: bool,
+    missing_extension: Cell<bool>,
+}
+
+impl RecordingBackend {
+    /// Creates a new `RecordingBackend` with a drawing buffer of 300 by 150 pixels, the default size of a canvas.
+    pub fn new() -> RecordingBackend {
+        RecordingBackend {
+            calls: RefCell::new(Vec::new()),
+            errors: RefCell::new(VecDeque::new()),
+            next_handle: Cell::new(1),
+            live: RefCell::new(HashSet::new()),
+            shader_kinds: RefCell::new(HashMap::new()),
+            shader_sources: RefCell::new(HashMap::new()),
+            bound_buffers: RefCell::new(HashMap::new()),
+            buffer_sizes: RefCell::new(HashMap::new()),
+            drawing_buffer_size: Cell::new((300, 150)),
+            alignments: Cell::new((4, 4)),
+            unpack_modes: Cell::new((false, false)),
+            extensions: RefCell::new(Vec::new()),
+            program_interface: RefCell::new(ProgramInterface::default()),
+            webgl1: false,
+            missing_extension: Cell::new(false),
+        }
+    }
+
+    /// Creates a new `RecordingBackend` that follows the rules of a WebGL1 context.
+    ///
+    /// The version is reported as `WebGL 1.0` and texture uploads only accept the unsized formats, see
+    /// `formats::validate_tex_image_webgl1`. The WebGL2 backend traits are still implemented.
+    ///
+    /// Like `WebGLRenderingContext`, the instancing, vertex array and draw buffers calls are only recorded when
+    /// their extension is in `set_supported_extensions`, otherwise the next `get_error` returns
+    /// `INVALID_OPERATION`.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::webgl1();
+    /// let buffers = [ColorBuffer::ColorAttachment0, ColorBuffer::ColorAttachment1];
+    /// gl.draw_buffers(&buffers);
+    /// gl.draw_arrays_instanced(Primitives::Triangles, 0, 3, 2);
+    /// assert_eq!(gl.create_vertex_array().is_valid(), false);
+    /// assert!(gl.calls().is_empty());
+    /// assert!(matches!(gl.get_error(), Error::InvalidOperation));
+    /// assert!(matches!(gl.get_error(), Error::NoError));
+    ///
+    /// let result = gl.checked().draw_buffers(&buffers);
+    /// assert!(result.is_err() || !CHECKS_ENABLED);
+    ///
+    /// gl.set_supported_extensions(&["WEBGL_draw_buffers"]);
+    /// gl.take_calls();
+    /// assert!(gl.checked().draw_buffers(&buffers).is_ok());
+    /// assert_eq!(gl.calls()[0].name, "drawBuffers");
+    /// ```
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::webgl1();
+    /// assert!(!Capabilities::collect(&gl).webgl2);
+    ///
+    /// let upload = |internal_format, pixel_type, pixels: &[u8]| {
+    ///     gl.tex_image_2d(
+    ///         TextureBindPoint::Texture2d,
+    ///         0,
+    ///         internal_format,
+    ///         1,
+    ///         1,
+    ///         PixelFormat::Rgba,
+    ///         pixel_type,
+    ///         pixels,
+    ///     )
+    /// };
+    /// assert!(upload(InternalFormat::Rgba, PixelType::UnsignedByte, &[0; 4]).is_ok());
+    /// match upload(InternalFormat::Rgba8, PixelType::UnsignedByte, &[0; 4]) {
+    ///     Err(WebGLError::Format {
+    ///         error: FormatError::RequiresWebGL2 { .. },
+    ///         ..
+    ///     }) => {}
+    ///     other => panic!("unexpected result {:?}", other),
+    /// }
+    /// assert!(upload(InternalFormat::Rgb, PixelType::UnsignedByte, &[0; 4]).is_err());
+    ///
+    /// // float data is accepted for unsized formats, as with `OES_texture_float`
+    /// let result = gl.tex_image_2d(
+    ///     TextureBindPoint::Texture2d,
+    ///     0,
+    ///     InternalFormat::Rgba,
+    ///     1,
+    ///     1,
+    ///     PixelFormat::Rgba,
+    ///     PixelType::Float,
+    ///     &[0.0f32; 4][..],
+    /// );
+    /// assert!(result.is_ok());
+    ///
+    /// let uploads = gl.calls().iter().filter(|call| call.name == "texImage2D").count();
+    /// assert_eq!(uploads, 2);
+    /// ```
+    pub fn webgl1() -> RecordingBackend {
+        RecordingBackend {
+            webgl1: true,
+            ..RecordingBackend::new()
+        }
+    }
+
+    /// Returns a copy of all calls recorded so far.
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls.borrow().clone()
+    }
+
+    /// Returns all calls recorded so far and clears the log.
+    pub fn take_calls(&self) -> Vec<Call> {
+        mem::take(&mut *self.calls.borrow_mut())
+    }
+
+    /// Queues an error that will be returned by the next call to `get_error`.
+    ///
+    /// When no errors are queued `get_error` returns `Error::NoError`.
+    pub fn push_error(&self, error: Error) {
+        self.errors.borrow_mut().push_back(error);
+    }
+
+    /// Sets the size returned by `drawing_buffer_width` and `drawing_buffer_height`.
+    pub fn set_drawing_buffer_size(&self, width: u32, height: u32) {
+        self.drawing_buffer_size.set((width, height));
+    }
+
+    /// Sets the extensions returned by `get_supported_extensions`, none are supported by default.
+    pub fn set_supported_extensions(&self, extensions: &[&str]) {
+        *self.extensions.borrow_mut() = extensions.iter().map(|&name| name.to_owned()).collect();
+    }
+
+    /// Sets the active variables that are reported for every program, programs have none by default.
+    pub fn set_program_interface(&self, interface: ProgramInterface) {
+        *self.program_interface.borrow_mut() = interface;
+    }
+
+    /// Returns true if the extension is supported, records an `INVALID_OPERATION` error otherwise. WebGL2
+    /// contexts support all extensions that were promoted to the core.
+    fn extension(&self, extension: Extension) -> bool {
+        let supported = !self.webgl1
+            || self
+                .extensions
+                .borrow()
+                .iter()
+                .any(|name| name == extension.name());
+        if !supported {
+            self.missing_extension.set(true);
+        }
+        supported
+    }
+
+    fn record(&self, name: &'static str, args: String) {
+        self.calls.borrow_mut().push(Call { name, args });
+    }
+
+    fn create_handle(&self) -> Handle {
+        let handle = Handle(self.next_handle.get());
+        self.next_handle.set(handle.0 + 1);
+        self.live.borrow_mut().insert(handle);
+        handle
+    }
+
+    fn delete_handle(&self, handle: Handle) {
+        self.live.borrow_mut().remove(&handle);
+    }
+
+    fn is_live(&self, handle: &Handle) -> bool {
+        self.live.borrow().contains(handle)
+    }
+
+    /// Records the size in bytes of the buffer bound to `target`.
+    fn set_buffer_size(&self, target: BufferKind, size: usize) {
+        if let Some(&buffer) = self.bound_buffers.borrow().get(&(target as u32)) {
+            self.buffer_sizes.borrow_mut().insert(buffer, size as i32);
+        }
+    }
+}
+
+/// Returns the size in bytes of one element of the data.
+fn element_size(data: &ArrayData) -> usize {
+    match *data {
+        ArrayData::I8(_) | ArrayData::U8(_) => 1,
+        ArrayData::I16(_) | ArrayData::U16(_) => 2,
+        ArrayData::I32(_) | ArrayData::U32(_) | ArrayData::F32(_) => 4,
+        ArrayData::F64(_) => 8,
+    }
+}
+
+impl Default for RecordingBackend {
+    fn default() -> Self {
+        RecordingBackend::new()
+    }
+}
+
+/// Mirrors the javascript backend, which can not use `f64` data for pixels.
+fn pixel_data_result(data: &ArrayData, message: &str) -> Result<(), JsValue> {
+    match *data {
+        ArrayData::F64(_) => Err(JsValue::from_str(message)),
+        _ => Ok(()),
+    }
+}
+
+impl ContextBackend for RecordingBackend {
+    fn validate_tex_image(
+        &self,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+    ) -> Result<(), FormatError> {
+        if self.webgl1 {
+            formats::validate_tex_image_webgl1(internalformat, format, pixel_type)
+        } else {
+            formats::validate_tex_image(internalformat, format, pixel_type)
+        }
+    }
+    fn drawing_buffer_width(&self) -> u32 {
+        self.record("drawingBufferWidth", String::new());
+        self.drawing_buffer_size.get().0
+    }
+    fn drawing_buffer_height(&self) -> u32 {
+        self.record("drawingBufferHeight", String::new());
+        self.drawing_buffer_size.get().1
+    }
+    fn is_context_lost(&self) -> bool {
+        self.record("isContextLost", String::new());
+        false
+    }
+    fn get_supported_extensions(&self) -> Vec<String> {
+        self.record("getSupportedExtensions", String::new());
+        self.extensions.borrow().clone()
+    }
+    fn scissor(&self, x: i32, y: i32, width: u32, height: u32) {
+        self.record(
+            "scissor",
+            format!("{:?}, {:?}, {:?}, {:?}", x, y, width, height),
+        );
+    }
+    fn viewport(&self, x: i32, y: i32, width: u32, height: u32) {
+        self.record(
+            "viewport",
+            format!("{:?}, {:?}, {:?}, {:?}", x, y, width, height),
+        );
+    }
+    fn active_texture(&self, texture: TextureUnit) {
+        self.record("activeTexture", format!("{:?}", texture));
+    }
+    fn blend_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        self.record(
+            "blendColor",
+            format!("{:?}, {:?}, {:?}, {:?}", red, green, blue, alpha),
+        );
+    }
+    fn blend_equation(&self, mode: BlendEquation) {
+        self.record("blendEquation", format!("{:?}", mode));
+    }
+    fn blend_equation_separate(&self, mode_rgb: BlendEquation, mode_alpha: BlendEquation) {
+        self.record(
+            "blendEquationSeparate",
+            format!("{:?}, {:?}", mode_rgb, mode_alpha),
+        );
+    }
+    fn blend_func(&self, sfactor: BlendMode, dfactor: BlendMode) {
+        self.record("blendFunc", format!("{:?}, {:?}", sfactor, dfactor));
+    }
+    fn blend_func_separate(
+        &self,
+        src_rgb: BlendMode,
+        dst_rgb: BlendMode,
+        src_alpha: BlendMode,
+        dst_alpha: BlendMode,
+    ) {
+        self.record(
+            "blendFuncSeparate",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                src_rgb, dst_rgb, src_alpha, dst_alpha
+            ),
+        );
+    }
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        self.record(
+            "clearColor",
+            format!("{:?}, {:?}, {:?}, {:?}", red, green, blue, alpha),
+        );
+    }
+    fn clear_depth(&self, depth: f32) {
+        self.record("clearDepth", format!("{:?}", depth));
+    }
+    fn clear_stencil(&self, s: i32) {
+        self.record("clearStencil", format!("{:?}", s));
+    }
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        self.record(
+            "colorMask",
+            format!("{:?}, {:?}, {:?}, {:?}", red, green, blue, alpha),
+        );
+    }
+    fn cull_face(&self, mode: Culling) {
+        self.record("cullFace", format!("{:?}", mode));
+    }
+    fn depth_func(&self, func: DepthTest) {
+        self.record("depthFunc", format!("{:?}", func));
+    }
+    fn depth_mask(&self, flag: bool) {
+        self.record("depthMask", format!("{:?}", flag));
+    }
+    fn depth_range(&self, z_near: f32, z_far: f32) {
+        self.record("depthRange", format!("{:?}, {:?}", z_near, z_far));
+    }
+    fn disable(&self, cap: Flag) {
+        self.record("disable", format!("{:?}", cap));
+    }
+    fn enable(&self, cap: Flag) {
+        self.record("enable", format!("{:?}", cap));
+    }
+    fn front_face(&self, mode: FrontFaceDirection) {
+        self.record("frontFace", format!("{:?}", mode));
+    }
+    fn get_error(&self) -> Error {
+        self.record("getError", String::new());
+        if self.missing_extension.replace(false) {
+            return Error::InvalidOperation;
+        }
+        self.errors
+            .borrow_mut()
+            .pop_front()
+            .unwrap_or(Error::NoError)
+    }
+    fn hint(&self, target: HintTarget, mode: HintMode) {
+        self.record("hint", format!("{:?}, {:?}", target, mode));
+    }
+    fn is_enabled(&self, cap: Flag) -> bool {
+        self.record("isEnabled", format!("{:?}", cap));
+        cap as u32 == Flag::Dither as u32
+    }
+    fn line_width(&self, width: f32) {
+        self.record("lineWidth", format!("{:?}", width));
+    }
+    fn pixel_storei(&self, pname: PixelStorageMode, param: i32) {
+        self.record("pixelStorei", format!("{:?}, {:?}", pname, param));
+        let (pack, unpack) = self.alignments.get();
+        let (flip_y, premultiply_alpha) = self.unpack_modes.get();
+        match pname {
+            PixelStorageMode::PackAlignment => self.alignments.set((param, unpack)),
+            PixelStorageMode::UnpackAlignment => self.alignments.set((pack, param)),
+            PixelStorageMode::UnpackFlipYWebgl => {
+                self.unpack_modes.set((param != 0, premultiply_alpha))
+            }
+            PixelStorageMode::UnpackPremultiplyAlphaWebgl => {
+                self.unpack_modes.set((flip_y, param != 0))
+            }
+            _ => {}
+        }
+    }
+    fn polygon_offset(&self, factor: f32, units: f32) {
+        self.record("polygonOffset", format!("{:?}, {:?}", factor, units));
+    }
+    fn sample_coverage(&self, value: f32, invert: bool) {
+        self.record("sampleCoverage", format!("{:?}, {:?}", value, invert));
+    }
+    fn stencil_func(&self, func: StencilTest, reference: i32, mask: u32) {
+        self.record(
+            "stencilFunc",
+            format!("{:?}, {:?}, {:?}", func, reference, mask),
+        );
+    }
+    fn stencil_func_separate(&self, face: Culling, func: StencilTest, reference: i32, mask: u32) {
+        self.record(
+            "stencilFuncSeparate",
+            format!("{:?}, {:?}, {:?}, {:?}", face, func, reference, mask),
+        );
+    }
+    fn stencil_mask(&self, mask: u32) {
+        self.record("stencilMask", format!("{:?}", mask));
+    }
+    fn stencil_mask_separate(&self, face: Culling, mask: u32) {
+        self.record("stencilMaskSeparate", format!("{:?}, {:?}", face, mask));
+    }
+    fn stencil_op(&self, fail: StencilAction, zfail: StencilAction, zpass: StencilAction) {
+        self.record("stencilOp", format!("{:?}, {:?}, {:?}", fail, zfail, zpass));
+    }
+    fn stencil_op_separate(
+        &self,
+        face: Culling,
+        fail: StencilAction,
+        zfail: StencilAction,
+        zpass: StencilAction,
+    ) {
+        self.record(
+            "stencilOpSeparate",
+            format!("{:?}, {:?}, {:?}, {:?}", face, fail, zfail, zpass),
+        );
+    }
+    fn _get_buffer_parameter_i32(&self, target: BufferKind, pname: BufferParameter) -> i32 {
+        self.record("getBufferParameter", format!("{:?}, {:?}", target, pname));
+        match pname {
+            BufferParameter::Size => self
+                .bound_buffers
+                .borrow()
+                .get(&(target as u32))
+                .and_then(|buffer| self.buffer_sizes.borrow().get(buffer).cloned())
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+    fn _get_buffer_parameter_enum(&self, target: BufferKind, pname: BufferParameter) -> DataHint {
+        self.record("getBufferParameter", format!("{:?}, {:?}", target, pname));
+        DataHint::StaticDraw
+    }
+    fn check_framebuffer_status(&self, target: FramebufferKind) -> FramebufferStatus {
+        self.record("checkFramebufferStatus", format!("{:?}", target));
+        FramebufferStatus::FramebufferComplete
+    }
+    fn _get_renderbuffer_parameter_i32(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> i32 {
+        self.record(
+            "getRenderbufferParameter",
+            format!("{:?}, {:?}", target, pname),
+        );
+        0
+    }
+    fn _get_renderbuffer_parameter_enum(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> InternalFormat {
+        self.record(
+            "getRenderbufferParameter",
+            format!("{:?}, {:?}", target, pname),
+        );
+        InternalFormat::Rgba4
+    }
+    fn _renderbuffer_storage(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        self.record(
+            "renderbufferStorage",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                target, internal_format, width, height
+            ),
+        );
+    }
+    fn copy_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: PixelCopyFormat,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        border: u32,
+    ) {
+        self.record(
+            "copyTexImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, internalformat, x, y, width, height, border
+            ),
+        );
+    }
+    fn copy_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: i32,
+        yoffset: i32,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) {
+        self.record(
+            "copyTexSubImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, xoffset, yoffset, x, y, width, height
+            ),
+        );
+    }
+    fn generate_mipmap(&self, target: TextureKind) {
+        self.record("generateMipmap", format!("{:?}", target));
+    }
+    fn _get_tex_parameter_i32(&self, target: TextureKind, pname: TextureParameter) -> i32 {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        0
+    }
+    fn _get_tex_parameter_u32(&self, target: TextureKind, pname: TextureParameter) -> u32 {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        0
+    }
+    fn _get_tex_parameter_f32(&self, target: TextureKind, pname: TextureParameter) -> f32 {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        0.0
+    }
+    fn _get_tex_parameter_bool(&self, target: TextureKind, pname: TextureParameter) -> bool {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        false
+    }
+    fn _get_tex_parameter_enum1(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMagFilter {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        TextureMagFilter::Linear
+    }
+    fn _get_tex_parameter_enum2(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMinFilter {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        TextureMinFilter::NearestMipmapLinear
+    }
+    fn _get_tex_parameter_enum3(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureWrap {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        TextureWrap::Repeat
+    }
+    fn _get_tex_parameter_enum4(&self, target: TextureKind, pname: TextureParameter) -> DepthTest {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        DepthTest::Lequal
+    }
+    fn _get_tex_parameter_enum5(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> CompareMode {
+        self.record("getTexParameter", format!("{:?}, {:?}", target, pname));
+        CompareMode::None
+    }
+    fn _get_parameter_bool(&self, pname: Parameter) -> bool {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::UnpackFlipYWebgl => self.unpack_modes.get().0,
+            Parameter::UnpackPremultiplyAlphaWebgl => self.unpack_modes.get().1,
+            // the depth write mask is the only boolean state that is enabled by default
+            _ => pname as u32 == Parameter::DepthWritemask as u32,
+        }
+    }
+    fn _get_parameter_i32(&self, pname: Parameter) -> i32 {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::PackAlignment => self.alignments.get().0,
+            Parameter::UnpackAlignment => self.alignments.get().1,
+            _ => 0,
+        }
+    }
+    fn _get_parameter_u32(&self, pname: Parameter) -> u32 {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::StencilValueMask
+            | Parameter::StencilWritemask
+            | Parameter::StencilBackValueMask
+            | Parameter::StencilBackWritemask => u32::MAX,
+            Parameter::MaxTextureSize
+            | Parameter::MaxCubeMapTextureSize
+            | Parameter::MaxRenderbufferSize => 2048,
+            Parameter::Max3dTextureSize | Parameter::MaxArrayTextureLayers => 256,
+            Parameter::MaxVertexAttribs
+            | Parameter::MaxTextureImageUnits
+            | Parameter::MaxVertexTextureImageUnits => 16,
+            Parameter::MaxVertexUniformVectors => 256,
+            Parameter::MaxFragmentUniformVectors => 224,
+            Parameter::MaxVaryingVectors => 15,
+            Parameter::MaxCombinedTextureImageUnits => 32,
+            Parameter::MaxUniformBufferBindings => 24,
+            Parameter::MaxColorAttachments | Parameter::MaxDrawBuffers | Parameter::MaxSamples => 4,
+            Parameter::SubpixelBits => 4,
+            Parameter::RedBits
+            | Parameter::GreenBits
+            | Parameter::BlueBits
+            | Parameter::AlphaBits => 8,
+            Parameter::DepthBits => 24,
+            Parameter::SampleBuffers => 1,
+            Parameter::Samples => 4,
+            Parameter::ImplementationColorReadType => PixelType::UnsignedByte as u32,
+            Parameter::ImplementationColorReadFormat => PixelReadFormat::Rgba as u32,
+            _ => 0,
+        }
+    }
+    fn _get_parameter_f32(&self, pname: Parameter) -> f32 {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::LineWidth | Parameter::DepthClearValue | Parameter::SampleCoverageValue => {
+                1.0
+            }
+            _ => 0.0,
+        }
+    }
+    fn _get_parameter_string(&self, pname: Parameter) -> String {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::Vendor => "webgl-rs".to_string(),
+            Parameter::Renderer => "RecordingBackend".to_string(),
+            Parameter::Version if self.webgl1 => "WebGL 1.0".to_string(),
+            Parameter::Version => "WebGL 2.0".to_string(),
+            _ => String::new(),
+        }
+    }
+    fn _get_parameter_i32_array(&self, pname: Parameter) -> Vec<i32> {
+        self.record("getParameter", format!("{:?}", pname));
+        let (width, height) = self.drawing_buffer_size.get();
+        match pname {
+            Parameter::Viewport | Parameter::ScissorBox => vec![0, 0, width as i32, height as i32],
+            Parameter::MaxViewportDims => vec![2048, 2048],
+            _ => Vec::new(),
+        }
+    }
+    fn _get_parameter_u32_array(&self, pname: Parameter) -> Vec<u32> {
+        self.record("getParameter", format!("{:?}", pname));
+        Vec::new()
+    }
+    fn _get_parameter_f32_array(&self, pname: Parameter) -> Vec<f32> {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::BlendColor | Parameter::ColorClearValue => vec![0.0; 4],
+            Parameter::AliasedPointSizeRange | Parameter::AliasedLineWidthRange => vec![1.0, 1.0],
+            Parameter::DepthRange => vec![0.0, 1.0],
+            _ => Vec::new(),
+        }
+    }
+    fn _get_parameter_bool_array(&self, pname: Parameter) -> Vec<bool> {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::ColorWritemask => vec![true; 4],
+            _ => Vec::new(),
+        }
+    }
+    fn _get_parameter_enum1(&self, pname: Parameter) -> BlendEquation {
+        self.record("getParameter", format!("{:?}", pname));
+        BlendEquation::FuncAdd
+    }
+    fn _get_parameter_enum2(&self, pname: Parameter) -> BlendMode {
+        self.record("getParameter", format!("{:?}", pname));
+        match pname {
+            Parameter::BlendDstRgb | Parameter::BlendDstAlpha => BlendMode::Zero,
+            _ => BlendMode::One,
+        }
+    }
+    fn _get_parameter_enum3(&self, pname: Parameter) -> Culling {
+        self.record("getParameter", format!("{:?}", pname));
+        Culling::Back
+    }
+    fn _get_parameter_enum4(&self, pname: Parameter) -> FrontFaceDirection {
+        self.record("getParameter", format!("{:?}", pname));
+        FrontFaceDirection::CCW
+    }
+    fn _get_parameter_enum5(&self, pname: Parameter) -> DepthTest {
+        self.record("getParameter", format!("{:?}", pname));
+        DepthTest::Less
+    }
+    fn _get_parameter_enum6(&self, pname: Parameter) -> StencilTest {
+        self.record("getParameter", format!("{:?}", pname));
+        StencilTest::Always
+    }
+    fn _get_parameter_enum7(&self, pname: Parameter) -> StencilAction {
+        self.record("getParameter", format!("{:?}", pname));
+        StencilAction::Keep
+    }
+    fn tex_parameter_f(&self, target: TextureKind, pname: TextureParameter, param: f32) {
+        self.record(
+            "texParameterf",
+            format!("{:?}, {:?}, {:?}", target, pname, param),
+        );
+    }
+    fn tex_parameter_i(&self, target: TextureKind, pname: TextureParameter, param: i32) {
+        self.record(
+            "texParameteri",
+            format!("{:?}, {:?}, {:?}", target, pname, param),
+        );
+    }
+    fn get_shader_precision_format(
+        &self,
+        shader_type: ShaderKind,
+        precision_type: ShaderPrecision,
+    ) -> WebGLRSShaderPrecisionFormat {
+        self.record(
+            "getShaderPrecisionFormat",
+            format!("{:?}, {:?}", shader_type, precision_type),
+        );
+        WebGLRSShaderPrecisionFormat {
+            range_min: 127,
+            range_max: 127,
+            precision: 23,
+        }
+    }
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        self.record("disableVertexAttribArray", format!("{:?}", index));
+    }
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        self.record("enableVertexAttribArray", format!("{:?}", index));
+    }
+    fn get_vertex_attrib_offset(&self, index: u32, pname: VertexAttrib) -> i64 {
+        self.record("getVertexAttribOffset", format!("{:?}, {:?}", index, pname));
+        0
+    }
+    fn vertex_attrib_1f(&self, index: u32, v0: f32) {
+        self.record("vertexAttrib1f", format!("{:?}, {:?}", index, v0));
+    }
+    fn vertex_attrib_2f(&self, index: u32, v0: f32, v1: f32) {
+        self.record("vertexAttrib2f", format!("{:?}, {:?}, {:?}", index, v0, v1));
+    }
+    fn vertex_attrib_3f(&self, index: u32, v0: f32, v1: f32, v2: f32) {
+        self.record(
+            "vertexAttrib3f",
+            format!("{:?}, {:?}, {:?}, {:?}", index, v0, v1, v2),
+        );
+    }
+    fn vertex_attrib_4f(&self, index: u32, v0: f32, v1: f32, v2: f32, v3: f32) {
+        self.record(
+            "vertexAttrib4f",
+            format!("{:?}, {:?}, {:?}, {:?}, {:?}", index, v0, v1, v2, v3),
+        );
+    }
+    fn vertex_attrib_1fv(&self, index: u32, value: Vec<f32>) {
+        self.record("vertexAttrib1fv", format!("{:?}, {:?}", index, value));
+    }
+    fn vertex_attrib_2fv(&self, index: u32, value: Vec<f32>) {
+        self.record("vertexAttrib2fv", format!("{:?}, {:?}", index, value));
+    }
+    fn vertex_attrib_3fv(&self, index: u32, value: Vec<f32>) {
+        self.record("vertexAttrib3fv", format!("{:?}, {:?}", index, value));
+    }
+    fn vertex_attrib_4fv(&self, index: u32, value: Vec<f32>) {
+        self.record("vertexAttrib4fv", format!("{:?}, {:?}", index, value));
+    }
+    fn vertex_attrib_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        attribute_type: AttributeType,
+        normalized: bool,
+        stride: u8,
+        offset: i32,
+    ) {
+        self.record(
+            "vertexAttribPointer",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                index, size, attribute_type, normalized, stride, offset
+            ),
+        );
+    }
+    fn clear(&self, mask: BufferBit) {
+        self.record("clear", format!("{:?}", mask));
+    }
+    fn draw_arrays(&self, mode: Primitives, first: u32, count: u32) {
+        self.record(
+            "drawArrays",
+            format!("{:?}, {:?}, {:?}", mode, first, count),
+        );
+    }
+    fn draw_elements(&self, mode: Primitives, count: u32, data_type: DataType, offset: i64) {
+        self.record(
+            "drawElements",
+            format!("{:?}, {:?}, {:?}, {:?}", mode, count, data_type, offset),
+        );
+    }
+    fn finish(&self) {
+        self.record("finish", String::new());
+    }
+    fn flush(&self) {
+        self.record("flush", String::new());
+    }
+    fn _buffer_data(&self, target: BufferKind, src_data: ArrayData, usage: DataHint) {
+        self.record(
+            "bufferData",
+            format!("{:?}, {:?}, {:?}", target, src_data, usage),
+        );
+        self.set_buffer_size(target, src_data.len() * element_size(&src_data));
+    }
+    fn _buffer_sub_data(&self, target: BufferKind, offset: i64, src_data: ArrayData) {
+        self.record(
+            "bufferSubData",
+            format!("{:?}, {:?}, {:?}", target, offset, src_data),
+        );
+    }
+    fn _tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        src_data: ArrayData,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, internalformat, width, height, border, format, pixel_type, src_data
+            ),
+        );
+        pixel_data_result(&src_data, "f64 data can not be used as pixel source")
+    }
+    fn _tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texSubImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, xoffset, yoffset, width, height, format, pixel_type, pixels
+            ),
+        );
+        pixel_data_result(&pixels, "f64 data can not be used as pixel source")
+    }
+    fn _compressed_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        self.record(
+            "compressedTexImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                ArrayData::U8(data)
+            ),
+        );
+        Ok(())
+    }
+    fn _compressed_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        self.record(
+            "compressedTexSubImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                xoffset,
+                yoffset,
+                width,
+                height,
+                format,
+                ArrayData::U8(data)
+            ),
+        );
+        Ok(())
+    }
+    fn _tex_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, internalformat, format, pixel_type, source
+            ),
+        );
+        Ok(())
+    }
+    fn _tex_sub_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texSubImage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, xoffset, yoffset, format, pixel_type, source
+            ),
+        );
+        Ok(())
+    }
+    fn _read_pixels(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelReadFormat,
+        pixel_type: PixelType,
+        pixels: ArrayDataMut,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "readPixels",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                x, y, width, height, format, pixel_type, pixels
+            ),
+        );
+        pixel_data_result(
+            &pixels.as_data(),
+            "f64 data can not be used as pixel destination",
+        )
+    }
+}
+
+impl InstancingBackend for RecordingBackend {
+    fn vertex_attrib_divisor(&self, index: u32, divisor: u32) {
+        if !self.extension(Extension::AngleInstancedArrays) {
+            return;
+        }
+        self.record("vertexAttribDivisor", format!("{:?}, {:?}", index, divisor));
+    }
+    fn draw_arrays_instanced(&self, mode: Primitives, first: i32, count: u32, instance_count: u32) {
+        if !self.extension(Extension::AngleInstancedArrays) {
+            return;
+        }
+        self.record(
+            "drawArraysInstanced",
+            format!("{:?}, {:?}, {:?}, {:?}", mode, first, count, instance_count),
+        );
+    }
+    fn draw_elements_instanced(
+        &self,
+        mode: Primitives,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+        instance_count: u32,
+    ) {
+        if !self.extension(Extension::AngleInstancedArrays) {
+            return;
+        }
+        self.record(
+            "drawElementsInstanced",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                mode, count, data_type, offset, instance_count
+            ),
+        );
+    }
+}
+
+impl BaseInstanceBackend for RecordingBackend {
+    fn supports_base_instance(&self) -> bool {
+        self.record(
+            "getExtension",
+            format!("{:?}", "WEBGL_draw_instanced_base_vertex_base_instance"),
+        );
+        true
+    }
+    fn draw_arrays_instanced_base_instance(
+        &self,
+        mode: Primitives,
+        first: i32,
+        count: u32,
+        instance_count: u32,
+        base_instance: u32,
+    ) {
+        self.record(
+            "drawArraysInstancedBaseInstanceWEBGL",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                mode, first, count, instance_count, base_instance
+            ),
+        );
+    }
+    fn draw_elements_instanced_base_vertex_base_instance(
+        &self,
+        mode: Primitives,
+        count: u32,
+        index_type: IndexType,
+        offset: i64,
+        instance_count: u32,
+        base_vertex: i32,
+        base_instance: u32,
+    ) {
+        self.record(
+            "drawElementsInstancedBaseVertexBaseInstanceWEBGL",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                mode, count, index_type, offset, instance_count, base_vertex, base_instance
+            ),
+        );
+    }
+}
+
+impl DrawBuffersBackend for RecordingBackend {
+    fn draw_buffers(&self, buffers: &[ColorBuffer]) {
+        if !self.extension(Extension::WebglDrawBuffers) {
+            return;
+        }
+        self.record("drawBuffers", format!("{:?}", buffers));
+    }
+}
+
+impl WebGL2ContextBackend for RecordingBackend {
+    fn copy_buffer_sub_data(
+        &self,
+        read_target: BufferKind,
+        write_target: BufferKind,
+        read_offset: i64,
+        write_offset: i64,
+        size: u32,
+    ) {
+        self.record(
+            "copyBufferSubData",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                read_target, write_target, read_offset, write_offset, size
+            ),
+        );
+    }
+    fn blit_framebuffer(
+        &self,
+        src_x0: i32,
+        src_y0: i32,
+        src_x1: i32,
+        src_y1: i32,
+        dst_x0: i32,
+        dst_y0: i32,
+        dst_x1: i32,
+        dst_y1: i32,
+        mask: BufferBit,
+        filter: TextureMagFilter,
+    ) {
+        self.record(
+            "blitFramebuffer",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter
+            ),
+        );
+    }
+    fn invalidate_framebuffer(&self, target: FramebufferKind, attachments: &[Attachment]) {
+        self.record(
+            "invalidateFramebuffer",
+            format!("{:?}, {:?}", target, attachments),
+        );
+    }
+    fn read_buffer(&self, src: ColorBuffer) {
+        self.record("readBuffer", format!("{:?}", src));
+    }
+    fn get_internal_format_parameter(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        pname: InformationType,
+    ) -> Vec<i32> {
+        self.record(
+            "getInternalformatParameter",
+            format!("{:?}, {:?}, {:?}", target, internal_format, pname),
+        );
+        // the sample count reported as `MaxSamples`, for every format that can back a renderbuffer
+        let info = format_info(internal_format);
+        match pname {
+            InformationType::Samples
+                if info.sized && (info.color_renderable || info.depth_stencil_renderable) =>
+            {
+                vec![4]
+            }
+            InformationType::Samples => Vec::new(),
+        }
+    }
+    fn _renderbuffer_storage_multisample(
+        &self,
+        target: RenderbufferKind,
+        samples: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        self.record(
+            "renderbufferStorageMultisample",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, samples, internal_format, width, height
+            ),
+        );
+    }
+    fn _tex_storage_2d(
+        &self,
+        target: Texture2DKind,
+        levels: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        self.record(
+            "texStorage2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, levels, internal_format, width, height
+            ),
+        );
+    }
+    fn _tex_storage_3d(
+        &self,
+        target: Texture3DKind,
+        levels: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) {
+        self.record(
+            "texStorage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, levels, internalformat, width, height, depth
+            ),
+        );
+    }
+    fn tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    ) {
+        self.record(
+            "texImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                depth,
+                border,
+                format,
+                data_type,
+                src_data,
+                src_offset
+            ),
+        );
+    }
+    fn tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    ) {
+        self.record(
+            "texSubImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                xoffset,
+                yoffset,
+                zoffset,
+                width,
+                height,
+                depth,
+                format,
+                data_type,
+                src_data,
+                src_offset
+            ),
+        );
+    }
+    fn _tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texSubImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                xoffset,
+                yoffset,
+                zoffset,
+                width,
+                height,
+                depth,
+                format,
+                pixel_type,
+                pixels
+            ),
+        );
+        pixel_data_result(&pixels, "f64 data can not be used as pixel source")
+    }
+    fn _compressed_tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        self.record(
+            "compressedTexImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                depth,
+                border,
+                ArrayData::U8(data)
+            ),
+        );
+        Ok(())
+    }
+    fn _compressed_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        self.record(
+            "compressedTexSubImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                xoffset,
+                yoffset,
+                zoffset,
+                width,
+                height,
+                depth,
+                format,
+                ArrayData::U8(data)
+            ),
+        );
+        Ok(())
+    }
+    fn _tex_image_3d_source(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                depth,
+                border,
+                format,
+                pixel_type,
+                source
+            ),
+        );
+        Ok(())
+    }
+    fn _tex_sub_image_3d_source(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "texSubImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target,
+                level,
+                xoffset,
+                yoffset,
+                zoffset,
+                width,
+                height,
+                depth,
+                format,
+                pixel_type,
+                source
+            ),
+        );
+        Ok(())
+    }
+    fn copy_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) {
+        self.record(
+            "copyTexSubImage3D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                target, level, xoffset, yoffset, zoffset, x, y, width, height
+            ),
+        );
+    }
+    fn vertex_attrib_i_4i(&self, index: u32, v0: i32, v1: i32, v2: i32, v3: i32) {
+        self.record(
+            "vertexAttribI4i",
+            format!("{:?}, {:?}, {:?}, {:?}, {:?}", index, v0, v1, v2, v3),
+        );
+    }
+    fn vertex_attrib_i_4ui(&self, index: u32, v0: u32, v1: u32, v2: u32, v3: u32) {
+        self.record(
+            "vertexAttribI4ui",
+            format!("{:?}, {:?}, {:?}, {:?}, {:?}", index, v0, v1, v2, v3),
+        );
+    }
+    fn vertex_attrib_i_4iv(&self, index: u32, value: Vec<i32>) {
+        self.record("vertexAttribI4iv", format!("{:?}, {:?}", index, value));
+    }
+    fn vertex_attrib_i_4uiv(&self, index: u32, value: Vec<u32>) {
+        self.record("vertexAttribI4uiv", format!("{:?}, {:?}", index, value));
+    }
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        data_type: AttributeType,
+        stride: u32,
+        offset: i64,
+    ) {
+        self.record(
+            "vertexAttribIPointer",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                index, size, data_type, stride, offset
+            ),
+        );
+    }
+    fn draw_range_elements(
+        &self,
+        mode: Primitives,
+        start: u32,
+        end: u32,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+    ) {
+        self.record(
+            "drawRangeElements",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+                mode, start, end, count, data_type, offset
+            ),
+        );
+    }
+    fn clear_buffer_fv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    ) {
+        self.record(
+            "clearBufferfv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                buffer, drawbuffer, values, src_offset
+            ),
+        );
+    }
+    fn clear_buffer_iv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    ) {
+        self.record(
+            "clearBufferiv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                buffer, drawbuffer, values, src_offset
+            ),
+        );
+    }
+    fn clear_buffer_uiv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    ) {
+        self.record(
+            "clearBufferuiv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                buffer, drawbuffer, values, src_offset
+            ),
+        );
+    }
+    fn clear_buffer_fi(&self, buffer: BufferBit, drawbuffer: i32, depth: f32, stencil: i32) {
+        self.record(
+            "clearBufferfv",
+            format!("{:?}, {:?}, {:?}, {:?}", buffer, drawbuffer, depth, stencil),
+        );
+    }
+    fn begin_transform_feedback(&self, primitive_mode: TransformFeedbackMode) {
+        self.record("beginTransformFeedback", format!("{:?}", primitive_mode));
+    }
+    fn end_transform_feedback(&self) {
+        self.record("endTransformFeedback", String::new());
+    }
+    fn pause_transform_feedback(&self) {
+        self.record("pauseTransformFeedback", String::new());
+    }
+    fn resume_transform_feedback(&self) {
+        self.record("resumeTransformFeedback", String::new());
+    }
+    fn end_query(&self, target: QueryTarget) {
+        self.record("endQuery", format!("{:?}", target));
+    }
+    fn _buffer_data_range(
+        &self,
+        target: BufferKind,
+        src_data: ArrayData,
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    ) {
+        self.record(
+            "bufferData",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, src_data, usage, src_offset, length
+            ),
+        );
+        let elements = match length {
+            0 => src_data.len().saturating_sub(src_offset as usize),
+            length => length as usize,
+        };
+        self.set_buffer_size(target, elements * element_size(&src_data));
+    }
+    fn _buffer_sub_data_range(
+        &self,
+        target: BufferKind,
+        dst_offset: i64,
+        src_data: ArrayData,
+        src_offset: u32,
+        length: u32,
+    ) {
+        self.record(
+            "bufferSubData",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, dst_offset, src_data, src_offset, length
+            ),
+        );
+    }
+    fn _get_buffer_sub_data(
+        &self,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: ArrayDataMut,
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue> {
+        self.record(
+            "getBufferSubData",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, src_offset, dst_data, dst_offset, length
+            ),
+        );
+        Ok(())
+    }
+}
+
+impl BufferBackend for RecordingBackend {
+    type Buffer = Handle;
+
+    fn _create_buffer(&self) -> Handle {
+        self.record("createBuffer", String::new());
+        self.create_handle()
+    }
+    fn _delete_buffer(&self, buffer: Handle) {
+        self.record("deleteBuffer", format!("{:?}", buffer));
+        self.buffer_sizes.borrow_mut().remove(&buffer);
+        self.bound_buffers
+            .borrow_mut()
+            .retain(|_, bound| *bound != buffer);
+        self.delete_handle(buffer);
+    }
+    fn _is_buffer(&self, buffer: &Handle) -> bool {
+        self.record("isBuffer", format!("{:?}", buffer));
+        self.is_live(buffer)
+    }
+    fn _bind_buffer(&self, target: BufferKind, buffer: &Handle) {
+        self.record("bindBuffer", format!("{:?}, {:?}", target, buffer));
+        self.bound_buffers
+            .borrow_mut()
+            .insert(target as u32, *buffer);
+    }
+    fn _get_parameter_buffer(&self, pname: Parameter) -> Option<Handle> {
+        self.record("getParameter", format!("{:?}", pname));
+        None
+    }
+}
+
+impl WebGL2BufferBackend for RecordingBackend {
+    fn _bind_buffer_base(&self, target: BufferBase, index: u32, buffer: &Handle) {
+        self.record(
+            "bindBufferBase",
+            format!("{:?}, {:?}, {:?}", target, index, buffer),
+        );
+    }
+    fn _bind_buffer_range(
+        &self,
+        target: BufferBase,
+        index: u32,
+        buffer: &Handle,
+        offset: u32,
+        size: u32,
+    ) {
+        self.record(
+            "bindBufferRange",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, index, buffer, offset, size
+            ),
+        );
+    }
+}
+
+impl FramebufferBackend for RecordingBackend {
+    type Framebuffer = Handle;
+
+    fn _create_framebuffer(&self) -> Handle {
+        self.record("createFramebuffer", String::new());
+        self.create_handle()
+    }
+    fn _bind_framebuffer(&self, target: FramebufferKind, framebuffer: &Handle) {
+        self.record(
+            "bindFramebuffer",
+            format!("{:?}, {:?}", target, framebuffer),
+        );
+    }
+    fn _delete_framebuffer(&self, framebuffer: Handle) {
+        self.record("deleteFramebuffer", format!("{:?}", framebuffer));
+        self.delete_handle(framebuffer);
+    }
+    fn _is_framebuffer(&self, framebuffer: &Handle) -> bool {
+        self.record("isFramebuffer", format!("{:?}", framebuffer));
+        self.is_live(framebuffer)
+    }
+    fn _get_framebuffer_attachment_parameter_i32(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> i32 {
+        self.record(
+            "getFramebufferAttachmentParameter",
+            format!("{:?}, {:?}, {:?}", target, attachment, pname),
+        );
+        0
+    }
+}
+
+impl RenderbufferBackend for RecordingBackend {
+    type Renderbuffer = Handle;
+
+    fn _create_renderbuffer(&self) -> Handle {
+        self.record("createRenderbuffer", String::new());
+        self.create_handle()
+    }
+    fn _framebuffer_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        renderbuffertarget: RenderbufferKind,
+        renderbuffer: &Handle,
+    ) {
+        self.record(
+            "framebufferRenderbuffer",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}",
+                target, attachment, renderbuffertarget, renderbuffer
+            ),
+        );
+    }
+    fn _bind_renderbuffer(&self, target: RenderbufferKind, renderbuffer: &Handle) {
+        self.record(
+            "bindRenderbuffer",
+            format!("{:?}, {:?}", target, renderbuffer),
+        );
+    }
+    fn _delete_renderbuffer(&self, renderbuffer: Handle) {
+        self.record("deleteRenderbuffer", format!("{:?}", renderbuffer));
+        self.delete_handle(renderbuffer);
+    }
+    fn _is_renderbuffer(&self, renderbuffer: &Handle) -> bool {
+        self.record("isRenderbuffer", format!("{:?}", renderbuffer));
+        self.is_live(renderbuffer)
+    }
+    fn _get_framebuffer_attachment_parameter_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<Handle> {
+        self.record(
+            "getFramebufferAttachmentParameter",
+            format!("{:?}, {:?}, {:?}", target, attachment, pname),
+        );
+        None
+    }
+}
+
+impl TextureBackend for RecordingBackend {
+    type Texture = Handle;
+
+    fn _create_texture(&self) -> Handle {
+        self.record("createTexture", String::new());
+        self.create_handle()
+    }
+    fn _bind_texture(&self, target: TextureKind, texture: &Handle) {
+        self.record("bindTexture", format!("{:?}, {:?}", target, texture));
+    }
+    fn _delete_texture(&self, texture: Handle) {
+        self.record("deleteTexture", format!("{:?}", texture));
+        self.delete_handle(texture);
+    }
+    fn _is_texture(&self, texture: &Handle) -> bool {
+        self.record("isTexture", format!("{:?}", texture));
+        self.is_live(texture)
+    }
+    fn _get_parameter_texture(&self, pname: Parameter) -> Option<Handle> {
+        self.record("getParameter", format!("{:?}", pname));
+        None
+    }
+    fn _get_framebuffer_attachment_parameter_texture(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<Handle> {
+        self.record(
+            "getFramebufferAttachmentParameter",
+            format!("{:?}, {:?}, {:?}", target, attachment, pname),
+        );
+        None
+    }
+    fn _framebuffer_texture_2d(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        textarget: TextureBindPoint,
+        texture: &Handle,
+        level: i32,
+    ) {
+        self.record(
+            "framebufferTexture2D",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, attachment, textarget, texture, level
+            ),
+        );
+    }
+}
+
+impl WebGL2TextureBackend for RecordingBackend {
+    fn _framebuffer_texture_layer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        texture: &Handle,
+        level: i32,
+        layer: i32,
+    ) {
+        self.record(
+            "framebufferTextureLayer",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                target, attachment, texture, level, layer
+            ),
+        );
+    }
+}
+
+impl ProgramBackend for RecordingBackend {
+    type Program = Handle;
+    type Shader = Handle;
+
+    fn _create_program(&self) -> Handle {
+        self.record("createProgram", String::new());
+        self.create_handle()
+    }
+    fn _delete_program(&self, program: Handle) {
+        self.record("deleteProgram", format!("{:?}", program));
+        self.delete_handle(program);
+    }
+    fn _detach_shader(&self, program: &Handle, shader: &Handle) {
+        self.record("detachShader", format!("{:?}, {:?}", program, shader));
+    }
+    fn _attach_shader(&self, program: &Handle, shader: &Handle) {
+        self.record("attachShader", format!("{:?}, {:?}", program, shader));
+    }
+    fn _bind_attrib_location(&self, program: &Handle, index: u32, name: &str) {
+        self.record(
+            "bindAttribLocation",
+            format!("{:?}, {:?}, {:?}", program, index, name),
+        );
+    }
+    fn _get_program_info_log(&self, program: &Handle) -> String {
+        self.record("getProgramInfoLog", format!("{:?}", program));
+        String::new()
+    }
+    fn _is_program(&self, program: &Handle) -> bool {
+        self.record("isProgram", format!("{:?}", program));
+        self.is_live(program)
+    }
+    fn _link_program(&self, program: &Handle) {
+        self.record("linkProgram", format!("{:?}", program));
+    }
+    fn _use_program(&self, program: &Handle) {
+        self.record("useProgram", format!("{:?}", program));
+    }
+    fn _validate_program(&self, program: &Handle) {
+        self.record("validateProgram", format!("{:?}", program));
+    }
+    fn _get_active_attrib(&self, program: &Handle, index: u32) -> WebGLRSActiveInfo {
+        self.record("getActiveAttrib", format!("{:?}, {:?}", program, index));
+        self.program_interface
+            .borrow()
+            .attributes
+            .get(index as usize)
+            .map_or_else(unknown_active_info, |attribute| attribute.info.clone())
+    }
+    fn _get_active_uniform(&self, program: &Handle, index: u32) -> WebGLRSActiveInfo {
+        self.record("getActiveUniform", format!("{:?}, {:?}", program, index));
+        self.program_interface
+            .borrow()
+            .uniforms
+            .get(index as usize)
+            .map_or_else(unknown_active_info, |uniform| uniform.info.clone())
+    }
+    fn _get_attrib_location(&self, program: &Handle, name: &str) -> i32 {
+        self.record("getAttribLocation", format!("{:?}, {:?}", program, name));
+        self.program_interface
+            .borrow()
+            .attributes
+            .iter()
+            .find(|attribute| attribute.info.name == name)
+            .map_or(-1, |attribute| attribute.location)
+    }
+    fn _get_uniform_location(&self, program: &Handle, name: &str) -> Handle {
+        self.record("getUniformLocation", format!("{:?}, {:?}", program, name));
+        self.create_handle()
+    }
+    fn _get_program_parameter_i32(&self, program: &Handle, pname: ProgramParameter) -> i32 {
+        self.record("getProgramParameter", format!("{:?}, {:?}", program, pname));
+        let interface = self.program_interface.borrow();
+        let count = match pname {
+            ProgramParameter::ActiveAttributes => interface.attributes.len(),
+            ProgramParameter::ActiveUniforms => interface.uniforms.len(),
+            ProgramParameter::ActiveUniformBlocks => interface.uniform_blocks.len(),
+            ProgramParameter::TransformFeedbackVaryings => {
+                interface.transform_feedback_varyings.len()
+            }
+            _ => 0,
+        };
+        count as i32
+    }
+    fn _get_program_parameter_bool(&self, program: &Handle, pname: ProgramParameter) -> bool {
+        self.record("getProgramParameter", format!("{:?}, {:?}", program, pname));
+        pname as u32 != ProgramParameter::DeleteStatus as u32
+    }
+    fn _create_shader(&self, kind: ShaderKind) -> Handle {
+        self.record("createShader", format!("{:?}", kind));
+        let shader = self.create_handle();
+        self.shader_kinds.borrow_mut().insert(shader, kind);
+        shader
+    }
+    fn _compile_shader(&self, shader: &Handle) {
+        self.record("compileShader", format!("{:?}", shader));
+    }
+    fn _delete_shader(&self, shader: Handle) {
+        self.record("deleteShader", format!("{:?}", shader));
+        self.shader_kinds.borrow_mut().remove(&shader);
+        self.shader_sources.borrow_mut().remove(&shader);
+        self.delete_handle(shader);
+    }
+    fn _get_shader_info_log(&self, shader: &Handle) -> String {
+        self.record("getShaderInfoLog", format!("{:?}", shader));
+        String::new()
+    }
+    fn _get_shader_source(&self, shader: &Handle) -> String {
+        self.record("getShaderSource", format!("{:?}", shader));
+        self.shader_sources
+            .borrow()
+            .get(shader)
+            .cloned()
+            .unwrap_or_default()
+    }
+    fn _is_shader(&self, shader: &Handle) -> bool {
+        self.record("isShader", format!("{:?}", shader));
+        self.is_live(shader)
+    }
+    fn _shader_source(&self, shader: &Handle, source: &str) {
+        self.record("shaderSource", format!("{:?}, {:?}", shader, source));
+        self.shader_sources
+            .borrow_mut()
+            .insert(*shader, source.to_string());
+    }
+    fn _get_shader_parameter_bool(&self, shader: &Handle, pname: ShaderParameter) -> bool {
+        self.record("getShaderParameter", format!("{:?}, {:?}", shader, pname));
+        pname as u32 != ShaderParameter::DeleteStatus as u32
+    }
+    fn _get_shader_parameter_enum(&self, shader: &Handle, pname: ShaderParameter) -> ShaderKind {
+        self.record("getShaderParameter", format!("{:?}, {:?}", shader, pname));
+        self.shader_kinds.borrow()[shader]
+    }
+}
+
+impl WebGL2ProgramBackend for RecordingBackend {
+    fn _get_frag_data_location(&self, program: &Handle, name: &str) -> i32 {
+        self.record("getFragDataLocation", format!("{:?}, {:?}", program, name));
+        0
+    }
+    fn _get_transform_feedback_varying(&self, program: &Handle, index: u32) -> WebGLRSActiveInfo {
+        self.record(
+            "getTransformFeedbackVarying",
+            format!("{:?}, {:?}", program, index),
+        );
+        self.program_interface
+            .borrow()
+            .transform_feedback_varyings
+            .get(index as usize)
+            .map_or_else(unknown_active_info, |varying| varying.clone())
+    }
+    fn _get_uniform_block_index(&self, program: &Handle, uniform_block_name: &str) -> u32 {
+        self.record(
+            "getUniformBlockIndex",
+            format!("{:?}, {:?}", program, uniform_block_name),
+        );
+        self.program_interface
+            .borrow()
+            .uniform_blocks
+            .iter()
+            .position(|block| block.name == uniform_block_name)
+            .map_or(INVALID_INDEX, |index| index as u32)
+    }
+    fn _get_active_uniform_block_name(&self, program: &Handle, uniform_block_index: u32) -> String {
+        self.record(
+            "getActiveUniformBlockName",
+            format!("{:?}, {:?}", program, uniform_block_index),
+        );
+        self.program_interface
+            .borrow()
+            .uniform_blocks
+            .get(uniform_block_index as usize)
+            .map(|block| block.name.clone())
+            .unwrap_or_default()
+    }
+    fn _uniform_block_binding(
+        &self,
+        program: &Handle,
+        uniform_block_index: u32,
+        uniform_block_binding: u32,
+    ) {
+        self.record(
+            "uniformBlockBinding",
+            format!(
+                "{:?}, {:?}, {:?}",
+                program, uniform_block_index, uniform_block_binding
+            ),
+        );
+    }
+    fn _get_program_parameter_enum(
+        &self,
+        program: &Handle,
+        pname: ProgramParameter,
+    ) -> TransformFeedbackBufferMode {
+        self.record("getProgramParameter", format!("{:?}, {:?}", program, pname));
+        TransformFeedbackBufferMode::InterleavedAttribs
+    }
+    fn _transform_feedback_varyings(
+        &self,
+        program: &Handle,
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    ) {
+        self.record(
+            "transformFeedbackVaryings",
+            format!("{:?}, {:?}, {:?}", program, varyings, buffer_mode),
+        );
+    }
+    fn _get_active_uniforms_i32(
+        &self,
+        program: &Handle,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<i32> {
+        self.record(
+            "getActiveUniforms",
+            format!("{:?}, {:?}, {:?}", program, uniform_indices, pname),
+        );
+        let interface = self.program_interface.borrow();
+        uniform_indices
+            .iter()
+            .map(|&index| match interface.uniforms.get(index as usize) {
+                Some(uniform) => match pname {
+                    UniformParameter::UniformType => uniform.info.data_type as i32,
+                    UniformParameter::UniformSize => uniform.info.size as i32,
+                    UniformParameter::UniformBlockIndex => uniform.block_index,
+                    UniformParameter::UniformOffset => uniform.offset,
+                    UniformParameter::UniformArrayStride => uniform.array_stride,
+                    UniformParameter::UniformMatrixStride => uniform.matrix_stride,
+                    UniformParameter::UniformIsRowMajor => uniform.row_major as i32,
+                },
+                None => match pname {
+                    UniformParameter::UniformType => UniformType::Float as i32,
+                    UniformParameter::UniformSize => 1,
+                    _ => -1,
+                },
+            })
+            .collect()
+    }
+    fn _get_active_uniforms_bool(
+        &self,
+        program: &Handle,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<bool> {
+        self.record(
+            "getActiveUniforms",
+            format!("{:?}, {:?}, {:?}", program, uniform_indices, pname),
+        );
+        let interface = self.program_interface.borrow();
+        uniform_indices
+            .iter()
+            .map(
+                |&index| match (pname, interface.uniforms.get(index as usize)) {
+                    (UniformParameter::UniformIsRowMajor, Some(uniform)) => uniform.row_major,
+                    _ => false,
+                },
+            )
+            .collect()
+    }
+    fn _get_active_uniform_block_parameter_u32(
+        &self,
+        program: &Handle,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> u32 {
+        self.record(
+            "getActiveUniformBlockParameter",
+            format!("{:?}, {:?}, {:?}", program, uniform_block_index, pname),
+        );
+        let interface = self.program_interface.borrow();
+        let block = match interface.uniform_blocks.get(uniform_block_index as usize) {
+            Some(block) => block,
+            None => return 0,
+        };
+        match pname {
+            UniformBlockParameter::UniformBlockBinding => block.binding,
+            UniformBlockParameter::UniformBlockDataSize => block.data_size,
+            UniformBlockParameter::UniformBlockActiveUniforms => block.uniforms.len() as u32,
+            _ => 0,
+        }
+    }
+    fn _get_active_uniform_block_parameter_bool(
+        &self,
+        program: &Handle,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> bool {
+        self.record(
+            "getActiveUniformBlockParameter",
+            format!("{:?}, {:?}, {:?}", program, uniform_block_index, pname),
+        );
+        let interface = self.program_interface.borrow();
+        let block = match interface.uniform_blocks.get(uniform_block_index as usize) {
+            Some(block) => block,
+            None => return false,
+        };
+        match pname {
+            UniformBlockParameter::UniformBlockReferencedByVertexShader => {
+                block.referenced_by_vertex_shader
+            }
+            UniformBlockParameter::UniformBlockReferencedByFragmentShader => {
+                block.referenced_by_fragment_shader
+            }
+            _ => false,
+        }
+    }
+    fn _get_active_uniform_block_parameter_u32_array(
+        &self,
+        program: &Handle,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> Vec<u32> {
+        self.record(
+            "getActiveUniformBlockParameter",
+            format!("{:?}, {:?}, {:?}", program, uniform_block_index, pname),
+        );
+        match pname {
+            UniformBlockParameter::UniformBlockActiveUniformIndices => self
+                .program_interface
+                .borrow()
+                .uniform_blocks
+                .get(uniform_block_index as usize)
+                .map(|block| block.uniforms.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl UniformBackend for RecordingBackend {
+    type UniformLocation = Handle;
+
+    fn uniform_1f(&self, location: &Handle, v0: f32) {
+        self.record("uniform1f", format!("{:?}, {:?}", location, v0));
+    }
+    fn uniform_2f(&self, location: &Handle, v0: f32, v1: f32) {
+        self.record("uniform2f", format!("{:?}, {:?}, {:?}", location, v0, v1));
+    }
+    fn uniform_3f(&self, location: &Handle, v0: f32, v1: f32, v2: f32) {
+        self.record(
+            "uniform3f",
+            format!("{:?}, {:?}, {:?}, {:?}", location, v0, v1, v2),
+        );
+    }
+    fn uniform_4f(&self, location: &Handle, v0: f32, v1: f32, v2: f32, v3: f32) {
+        self.record(
+            "uniform4f",
+            format!("{:?}, {:?}, {:?}, {:?}, {:?}", location, v0, v1, v2, v3),
+        );
+    }
+    fn uniform_1i(&self, location: &Handle, v0: i32) {
+        self.record("uniform1i", format!("{:?}, {:?}", location, v0));
+    }
+    fn uniform_2i(&self, location: &Handle, v0: i32, v1: i32) {
+        self.record("uniform2i", format!("{:?}, {:?}, {:?}", location, v0, v1));
+    }
+    fn uniform_3i(&self, location: &Handle, v0: i32, v1: i32, v2: i32) {
+        self.record(
+            "uniform3i",
+            format!("{:?}, {:?}, {:?}, {:?}", location, v0, v1, v2),
+        );
+    }
+    fn uniform_4i(&self, location: &Handle, v0: i32, v1: i32, v2: i32, v3: i32) {
+        self.record(
+            "uniform4i",
+            format!("{:?}, {:?}, {:?}, {:?}, {:?}", location, v0, v1, v2, v3),
+        );
+    }
+    fn uniform_1fv(&self, location: &Handle, value: Vec<f32>) {
+        self.record("uniform1fv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_2fv(&self, location: &Handle, value: Vec<f32>) {
+        self.record("uniform2fv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_3fv(&self, location: &Handle, value: Vec<f32>) {
+        self.record("uniform3fv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_4fv(&self, location: &Handle, value: Vec<f32>) {
+        self.record("uniform4fv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_1iv(&self, location: &Handle, value: Vec<i32>) {
+        self.record("uniform1iv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_2iv(&self, location: &Handle, value: Vec<i32>) {
+        self.record("uniform2iv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_3iv(&self, location: &Handle, value: Vec<i32>) {
+        self.record("uniform3iv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_4iv(&self, location: &Handle, value: Vec<i32>) {
+        self.record("uniform4iv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_matrix_2fv(&self, location: &Handle, transpose: bool, value: Vec<f32>) {
+        self.record(
+            "uniformMatrix2fv",
+            format!("{:?}, {:?}, {:?}", location, transpose, value),
+        );
+    }
+    fn uniform_matrix_3fv(&self, location: &Handle, transpose: bool, value: Vec<f32>) {
+        self.record(
+            "uniformMatrix3fv",
+            format!("{:?}, {:?}, {:?}", location, transpose, value),
+        );
+    }
+    fn uniform_matrix_4fv(&self, location: &Handle, transpose: bool, value: Vec<f32>) {
+        self.record(
+            "uniformMatrix4fv",
+            format!("{:?}, {:?}, {:?}", location, transpose, value),
+        );
+    }
+}
+
+impl WebGL2UniformBackend for RecordingBackend {
+    fn uniform_1ui(&self, location: &Handle, v0: u32) {
+        self.record("uniform1ui", format!("{:?}, {:?}", location, v0));
+    }
+    fn uniform_2ui(&self, location: &Handle, v0: u32, v1: u32) {
+        self.record("uniform2ui", format!("{:?}, {:?}, {:?}", location, v0, v1));
+    }
+    fn uniform_3ui(&self, location: &Handle, v0: u32, v1: u32, v2: u32) {
+        self.record(
+            "uniform3ui",
+            format!("{:?}, {:?}, {:?}, {:?}", location, v0, v1, v2),
+        );
+    }
+    fn uniform_4ui(&self, location: &Handle, v0: u32, v1: u32, v2: u32, v3: u32) {
+        self.record(
+            "uniform4ui",
+            format!("{:?}, {:?}, {:?}, {:?}, {:?}", location, v0, v1, v2, v3),
+        );
+    }
+    fn uniform_1uiv(&self, location: &Handle, value: Vec<u32>) {
+        self.record("uniform1uiv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_2uiv(&self, location: &Handle, value: Vec<u32>) {
+        self.record("uniform2uiv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_3uiv(&self, location: &Handle, value: Vec<u32>) {
+        self.record("uniform3uiv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_4uiv(&self, location: &Handle, value: Vec<u32>) {
+        self.record("uniform4uiv", format!("{:?}, {:?}", location, value));
+    }
+    fn uniform_matrix_2x3fv(
+        &self,
+        location: &Handle,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        self.record(
+            "uniformMatrix2x3fv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                location, transpose, data, src_offset, src_length
+            ),
+        );
+    }
+    fn uniform_matrix_2x4fv(
+        &self,
+        location: &Handle,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        self.record(
+            "uniformMatrix2x4fv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                location, transpose, data, src_offset, src_length
+            ),
+        );
+    }
+    fn uniform_matrix_3x2fv(
+        &self,
+        location: &Handle,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        self.record(
+            "uniformMatrix3x2fv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                location, transpose, data, src_offset, src_length
+            ),
+        );
+    }
+    fn uniform_matrix_3x4fv(
+        &self,
+        location: &Handle,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        self.record(
+            "uniformMatrix3x4fv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                location, transpose, data, src_offset, src_length
+            ),
+        );
+    }
+    fn uniform_matrix_4x2fv(
+        &self,
+        location: &Handle,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        self.record(
+            "uniformMatrix4x2fv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                location, transpose, data, src_offset, src_length
+            ),
+        );
+    }
+    fn uniform_matrix_4x3fv(
+        &self,
+        location: &Handle,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        self.record(
+            "uniformMatrix4x3fv",
+            format!(
+                "{:?}, {:?}, {:?}, {:?}, {:?}",
+                location, transpose, data, src_offset, src_length
+            ),
+        );
+    }
+}
+
+impl SamplerBackend for RecordingBackend {
+    type Sampler = Handle;
+
+    fn _create_sampler(&self) -> Handle {
+        self.record("createSampler", String::new());
+        self.create_handle()
+    }
+    fn _delete_sampler(&self, sampler: Handle) {
+        self.record("deleteSampler", format!("{:?}", sampler));
+        self.delete_handle(sampler);
+    }
+    fn _bind_sampler(&self, unit: u32, sampler: &Handle) {
+        self.record("bindSampler", format!("{:?}, {:?}", unit, sampler));
+    }
+    fn _is_sampler(&self, sampler: &Handle) -> bool {
+        self.record("isSampler", format!("{:?}", sampler));
+        self.is_live(sampler)
+    }
+    fn _get_sampler_parameter_f32(&self, sampler: &Handle, pname: TextureParameter) -> f32 {
+        self.record("getSamplerParameter", format!("{:?}, {:?}", sampler, pname));
+        0.0
+    }
+    fn _get_sampler_parameter_enum1(&self, sampler: &Handle, pname: TextureParameter) -> DepthTest {
+        self.record("getSamplerParameter", format!("{:?}, {:?}", sampler, pname));
+        DepthTest::Lequal
+    }
+    fn _get_sampler_parameter_enum2(
+        &self,
+        sampler: &Handle,
+        pname: TextureParameter,
+    ) -> CompareMode {
+        self.record("getSamplerParameter", format!("{:?}, {:?}", sampler, pname));
+        CompareMode::None
+    }
+    fn _get_sampler_parameter_enum3(
+        &self,
+        sampler: &Handle,
+        pname: TextureParameter,
+    ) -> TextureMagFilter {
+        self.record("getSamplerParameter", format!("{:?}, {:?}", sampler, pname));
+        TextureMagFilter::Linear
+    }
+    fn _get_sampler_parameter_enum4(
+        &self,
+        sampler: &Handle,
+        pname: TextureParameter,
+    ) -> TextureMinFilter {
+        self.record("getSamplerParameter", format!("{:?}, {:?}", sampler, pname));
+        TextureMinFilter::NearestMipmapLinear
+    }
+    fn _get_sampler_parameter_enum5(
+        &self,
+        sampler: &Handle,
+        pname: TextureParameter,
+    ) -> TextureWrap {
+        self.record("getSamplerParameter", format!("{:?}, {:?}", sampler, pname));
+        TextureWrap::Repeat
+    }
+    fn _sampler_parameter_i(&self, sampler: &Handle, pname: TextureParameter, param: i32) {
+        self.record(
+            "samplerParameteri",
+            format!("{:?}, {:?}, {:?}", sampler, pname, param),
+        );
+    }
+    fn _sampler_parameter_f(&self, sampler: &Handle, pname: TextureParameter, param: f32) {
+        self.record(
+            "samplerParameterf",
+            format!("{:?}, {:?}, {:?}", sampler, pname, param),
+        );
+    }
+}
+
+impl QueryBackend for RecordingBackend {
+    type Query = Handle;
+
+    fn _create_query(&self) -> Handle {
+        self.record("createQuery", String::new());
+        self.create_handle()
+    }
+    fn _delete_query(&self, query: Handle) {
+        self.record("deleteQuery", format!("{:?}", query));
+        self.delete_handle(query);
+    }
+    fn _is_query(&self, query: &Handle) -> bool {
+        self.record("isQuery", format!("{:?}", query));
+        self.is_live(query)
+    }
+    fn _begin_query(&self, target: QueryTarget, query: &Handle) {
+        self.record("beginQuery", format!("{:?}, {:?}", target, query));
+    }
+    fn _get_query(&self, target: QueryTarget, pname: Query) -> Handle {
+        self.record("getQuery", format!("{:?}, {:?}", target, pname));
+        Handle(0)
+    }
+    fn _get_query_parameter_bool(&self, query: &Handle, pname: QueryParameter) -> bool {
+        self.record("getQueryParameter", format!("{:?}, {:?}", query, pname));
+        true
+    }
+    fn _get_query_parameter_u32(&self, query: &Handle, pname: QueryParameter) -> u32 {
+        self.record("getQueryParameter", format!("{:?}, {:?}", query, pname));
+        0
+    }
+}
+
+impl SyncBackend for RecordingBackend {
+    type Sync = Handle;
+
+    fn _fence_sync(&self, conditions: GPUState, flags: u32) -> Handle {
+        self.record("fenceSync", format!("{:?}, {:?}", conditions, flags));
+        self.create_handle()
+    }
+    fn _is_sync(&self, sync: &Handle) -> bool {
+        self.record("isSync", format!("{:?}", sync));
+        self.is_live(sync)
+    }
+    fn _delete_sync(&self, sync: Handle) {
+        self.record("deleteSync", format!("{:?}", sync));
+        self.delete_handle(sync);
+    }
+    fn _client_wait_sync(&self, sync: &Handle, flags: u32, timeout: i64) -> WaitStatus {
+        self.record(
+            "clientWaitSync",
+            format!("{:?}, {:?}, {:?}", sync, flags, timeout),
+        );
+        WaitStatus::AlreadySignaled
+    }
+    fn _wait_sync(&self, sync: &Handle, flags: u32, timeout: i64) {
+        self.record(
+            "waitSync",
+            format!("{:?}, {:?}, {:?}", sync, flags, timeout),
+        );
+    }
+    fn _get_sync_parameter_status(&self, sync: &Handle, pname: SyncParameter) -> SyncStatus {
+        self.record("getSyncParameter", format!("{:?}, {:?}", sync, pname));
+        SyncStatus::Signaled
+    }
+}
+
+impl TransformFeedbackBackend for RecordingBackend {
+    type TransformFeedback = Handle;
+
+    fn _create_transform_feedback(&self) -> Handle {
+        self.record("createTransformFeedback", String::new());
+        self.create_handle()
+    }
+    fn _delete_transform_feedback(&self, transform_feedback: Handle) {
+        self.record(
+            "deleteTransformFeedback",
+            format!("{:?}", transform_feedback),
+        );
+        self.delete_handle(transform_feedback);
+    }
+    fn _is_transform_feedback(&self, transform_feedback: &Handle) -> bool {
+        self.record("isTransformFeedback", format!("{:?}", transform_feedback));
+        self.is_live(transform_feedback)
+    }
+    fn _bind_transform_feedback(&self, target: TransformFeedback, transform_feedback: &Handle) {
+        self.record(
+            "bindTransformFeedback",
+            format!("{:?}, {:?}", target, transform_feedback),
+        );
+    }
+}
+
+impl VertexArrayBackend for RecordingBackend {
+    type VertexArray = Handle;
+
+    fn _create_vertex_array(&self) -> Handle {
+        if !self.extension(Extension::OesVertexArrayObject) {
+            return Handle(0);
+        }
+        self.record("createVertexArray", String::new());
+        self.create_handle()
+    }
+    fn _delete_vertex_array(&self, vertex_array: Handle) {
+        if !self.extension(Extension::OesVertexArrayObject) {
+            return;
+        }
+        self.record("deleteVertexArray", format!("{:?}", vertex_array));
+        self.delete_handle(vertex_array);
+    }
+    fn _is_vertex_array(&self, vertex_array: &Handle) -> bool {
+        if !self.extension(Extension::OesVertexArrayObject) {
+            return false;
+        }
+        self.record("isVertexArray", format!("{:?}", vertex_array));
+        self.is_live(vertex_array)
+    }
+    fn _bind_vertex_array(&self, vertex_array: &Handle) {
+        if !self.extension(Extension::OesVertexArrayObject) {
+            return;
+        }
+        self.record("bindVertexArray", format!("{:?}", vertex_array));
+    }
+    fn _unbind_vertex_array(&self) {
+        if !self.extension(Extension::OesVertexArrayObject) {
+            return;
+        }
+        self.record("bindVertexArray", "null".to_string());
+    }
+    fn _get_parameter_vertex_array(&self, pname: Parameter) -> Option<Handle> {
+        self.record("getParameter", format!("{:?}", pname));
+        None
+    }
+}
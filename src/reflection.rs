@@ -0,0 +1,343 @@
+//! Description of the active variables of a linked program
+//!
+//! The recording backend reports the active variables that are scripted with `set_program_interface`:
+//!
+//! ```
+//! use webgl_rs::recording::{ActiveAttribute, ActiveUniform, ActiveUniformBlock, ProgramInterface};
+//! use webgl_rs::*;
+//!
+//! let attribute = |name: &str, uniform_type: UniformType, location| ActiveAttribute {
+//!     info: WebGLRSActiveInfo {
+//!         name: name.to_string(),
+//!         size: 1,
+//!         data_type: uniform_type as u32,
+//!     },
+//!     location,
+//! };
+//! let block_member = |name: &str, uniform_type: UniformType, offset| ActiveUniform {
+//!     block_index: 0,
+//!     offset,
+//!     array_stride: 0,
+//!     matrix_stride: if offset == 0 { 16 } else { 0 },
+//!     ..ActiveUniform::new(name, uniform_type, 1)
+//! };
+//! let gl = RecordingBackend::new();
+//! gl.set_program_interface(ProgramInterface {
+//!     attributes: vec![
+//!         attribute("position", UniformType::FloatVec2, 0),
+//!         attribute("gl_VertexID", UniformType::Int, -1),
+//!     ],
+//!     uniforms: vec![
+//!         ActiveUniform::new("lights[0]", UniformType::FloatVec4, 4),
+//!         block_member("model", UniformType::FloatMat4, 0),
+//!         block_member("tint", UniformType::FloatVec4, 64),
+//!     ],
+//!     uniform_blocks: vec![ActiveUniformBlock {
+//!         name: "Object".to_string(),
+//!         binding: 1,
+//!         data_size: 80,
+//!         uniforms: vec![1, 2],
+//!         referenced_by_vertex_shader: true,
+//!         referenced_by_fragment_shader: false,
+//!     }],
+//!     transform_feedback_varyings: Vec::new(),
+//! });
+//!
+//! let program = ProgramBuilder::new(&gl)
+//!     .vertex("#version 300 es\nin vec2 position; void main() { gl_Position = vec4(position, 0.0, 1.0); }")
+//!     .fragment("#version 300 es\nprecision mediump float; out vec4 color; void main() { color = vec4(1.0); }")
+//!     .build()
+//!     .unwrap();
+//! let reflection = ProgramReflection::collect_webgl2(&program);
+//!
+//! let position = reflection.attribute("position").unwrap();
+//! assert_eq!(position.location, Some(0));
+//! assert_eq!(position.attribute_type, Some(UniformType::FloatVec2));
+//! assert_eq!(reflection.attribute("gl_VertexID").unwrap().location, None);
+//! assert_eq!(program.attrib_location("normal"), None);
+//!
+//! // arrays are found without their `[0]` suffix and uniforms outside a block have no layout
+//! let lights = reflection.uniform("lights").unwrap();
+//! assert_eq!((lights.name.as_str(), lights.size), ("lights[0]", 4));
+//! assert_eq!(lights.layout, None);
+//! assert!(reflection.uniform("light").is_none());
+//!
+//! let tint = reflection.uniform("tint").unwrap();
+//! assert_eq!(
+//!     tint.layout,
+//!     Some(UniformLayout {
+//!         block_index: 0,
+//!         offset: 64,
+//!         array_stride: 0,
+//!         matrix_stride: 0,
+//!         row_major: false,
+//!     })
+//! );
+//! assert_eq!(reflection.uniform("model").unwrap().layout.unwrap().matrix_stride, 16);
+//!
+//! let block = reflection.uniform_block("Object").unwrap();
+//! assert_eq!((block.index, block.binding, block.data_size), (0, 1, 80));
+//! assert!(block.referenced_by_vertex_shader && !block.referenced_by_fragment_shader);
+//! let members: Vec<&str> = reflection
+//!     .block_uniforms(block)
+//!     .iter()
+//!     .map(|uniform| uniform.name.as_str())
+//!     .collect();
+//! assert_eq!(members, vec!["model", "tint"]);
+//! ```
+use glenum::UniformType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use shader_program::{ProgramBackend, WebGL2ProgramBackend, WebGLRSProgram};
+use uniform_location::WebGLRSUniformLocation;
+
+/// An active vertex attribute of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AttributeInfo {
+    /// Name of the attribute.
+    pub name: String,
+    /// Type of the attribute, `None` if the type is not a WebGL2 type.
+    pub attribute_type: Option<UniformType>,
+    /// Number of elements of the attribute, 1 if it is not an array.
+    pub size: u32,
+    /// Location of the attribute, `None` for built-in attributes such as `gl_VertexID`.
+    pub location: Option<u32>,
+}
+
+/// Memory layout of a uniform that is part of a uniform block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniformLayout {
+    /// Index of the uniform block, also an index into `ProgramReflection::uniform_blocks`.
+    pub block_index: u32,
+    /// Byte offset of the uniform from the start of the block.
+    pub offset: u32,
+    /// Byte stride between the elements of an array, 0 if the uniform is not an array.
+    pub array_stride: u32,
+    /// Byte stride between the columns (or rows) of a matrix, 0 if the uniform is not a matrix.
+    pub matrix_stride: u32,
+    /// Whether a matrix is stored in row major order.
+    pub row_major: bool,
+}
+
+/// An active uniform of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniformInfo {
+    /// Name of the uniform, arrays have a `[0]` suffix.
+    pub name: String,
+    /// Type of the uniform, `None` if the type is not a WebGL2 type.
+    pub uniform_type: Option<UniformType>,
+    /// Number of elements of the uniform, 1 if it is not an array.
+    pub size: u32,
+    /// Layout of the uniform in its uniform block, `None` for uniforms outside a block and on WebGL1.
+    pub layout: Option<UniformLayout>,
+}
+
+impl UniformInfo {
+    /// Returns the location of the uniform in the program it was collected from, values set through the
+    /// location are checked against the type of the uniform.
+    ///
+    /// Locations are javascript objects so they are looked up on demand instead of being part of the
+    /// reflection. Uniforms in a uniform block do not have a location.
+    pub fn location<'a, B: ProgramBackend>(
+        &self,
+        program: &'a WebGLRSProgram<B>,
+    ) -> WebGLRSUniformLocation<'a, B> {
+        let location = program.untyped_uniform_location(&self.name);
+        match self.uniform_type {
+            Some(uniform_type) => location.with_type(uniform_type),
+            None => location,
+        }
+    }
+}
+
+/// An active uniform block of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniformBlockInfo {
+    /// Name of the block.
+    pub name: String,
+    /// Index of the block in the program.
+    pub index: u32,
+    /// Binding point the block is assigned to.
+    pub binding: u32,
+    /// Minimum size in bytes of the buffer that backs the block.
+    pub data_size: u32,
+    /// Indices of the uniforms of the block in `ProgramReflection::uniforms`.
+    pub uniforms: Vec<u32>,
+    /// Whether the block is used by the vertex shader.
+    pub referenced_by_vertex_shader: bool,
+    /// Whether the block is used by the fragment shader.
+    pub referenced_by_fragment_shader: bool,
+}
+
+/// A varying that is recorded by transform feedback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaryingInfo {
+    /// Name of the varying.
+    pub name: String,
+    /// Type of the varying, `None` if the type is not a WebGL2 type.
+    pub varying_type: Option<UniformType>,
+    /// Number of elements of the varying, 1 if it is not an array.
+    pub size: u32,
+}
+
+/// The active attributes, uniforms, uniform blocks and transform feedback varyings of a linked program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProgramReflection {
+    /// The active attributes, ordered by index.
+    pub attributes: Vec<AttributeInfo>,
+    /// The active uniforms, ordered by index.
+    pub uniforms: Vec<UniformInfo>,
+    /// The active uniform blocks, ordered by index. Always empty on WebGL1.
+    pub uniform_blocks: Vec<UniformBlockInfo>,
+    /// The varyings recorded by transform feedback, ordered by index. Always empty on WebGL1.
+    pub transform_feedback_varyings: Vec<VaryingInfo>,
+}
+
+/// Converts a count returned by getProgramParameter into a range end.
+fn count(value: i32) -> u32 {
+    if value > 0 {
+        value as u32
+    } else {
+        0
+    }
+}
+
+impl ProgramReflection {
+    /// Collects the active attributes and uniforms of a linked program.
+    ///
+    /// Only queries what WebGL1 supports, use `collect_webgl2` to include uniform blocks and transform
+    /// feedback varyings.
+    pub fn collect<B: ProgramBackend>(program: &WebGLRSProgram<B>) -> ProgramReflection {
+        let attributes = (0..count(program.active_attributes()))
+            .map(|index| {
+                let info = program.active_attrib(index);
+                AttributeInfo {
+                    location: program.attrib_location(&info.name),
+                    attribute_type: info.uniform_type(),
+                    size: info.size,
+                    name: info.name,
+                }
+            })
+            .collect();
+        let uniforms = (0..count(program.active_uniforms()))
+            .map(|index| {
+                let info = program.active_uniform(index);
+                UniformInfo {
+                    uniform_type: info.uniform_type(),
+                    size: info.size,
+                    name: info.name,
+                    layout: None,
+                }
+            })
+            .collect();
+
+        ProgramReflection {
+            attributes,
+            uniforms,
+            uniform_blocks: Vec::new(),
+            transform_feedback_varyings: Vec::new(),
+        }
+    }
+
+    /// Collects the active attributes, uniforms, uniform blocks and transform feedback varyings of a linked
+    /// program.
+    pub fn collect_webgl2<B: WebGL2ProgramBackend>(
+        program: &WebGLRSProgram<B>,
+    ) -> ProgramReflection {
+        let mut reflection = ProgramReflection::collect(program);
+
+        let indices: Vec<u32> = (0..reflection.uniforms.len() as u32).collect();
+        let block_indices = program.uniform_block_indices(&indices);
+        let offsets = program.uniform_offsets(&indices);
+        let array_strides = program.uniform_array_strides(&indices);
+        let matrix_strides = program.uniform_matrix_strides(&indices);
+        let row_major = program.uniform_is_row_major(&indices);
+        for (index, uniform) in reflection.uniforms.iter_mut().enumerate() {
+            let block_index = block_indices.get(index).cloned().unwrap_or(-1);
+            if block_index < 0 {
+                continue;
+            }
+            uniform.layout = Some(UniformLayout {
+                block_index: block_index as u32,
+                offset: count(offsets.get(index).cloned().unwrap_or(0)),
+                array_stride: count(array_strides.get(index).cloned().unwrap_or(0)),
+                matrix_stride: count(matrix_strides.get(index).cloned().unwrap_or(0)),
+                row_major: row_major.get(index).cloned().unwrap_or(false),
+            });
+        }
+
+        reflection.uniform_blocks = (0..count(program.active_uniform_blocks()))
+            .map(|index| UniformBlockInfo {
+                name: program.active_uniform_block_name(index),
+                index,
+                binding: program.uniform_block_binding(index),
+                data_size: program.uniform_block_data_size(index),
+                uniforms: program.uniform_block_active_uniform_indices(index),
+                referenced_by_vertex_shader: program
+                    .uniform_block_referenced_by_vertex_shader(index),
+                referenced_by_fragment_shader: program
+                    .uniform_block_referenced_by_fragment_shader(index),
+            })
+            .collect();
+        reflection.transform_feedback_varyings = (0..count(program.transform_feedback_varyings()))
+            .map(|index| {
+                let info = program.transform_feedback_varying(index);
+                VaryingInfo {
+                    varying_type: info.uniform_type(),
+                    size: info.size,
+                    name: info.name,
+                }
+            })
+            .collect();
+        reflection
+    }
+
+    /// Returns the attribute with the given name.
+    pub fn attribute(&self, name: &str) -> Option<&AttributeInfo> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+
+    /// Returns the uniform with the given name, the `[0]` suffix of arrays can be left out.
+    pub fn uniform(&self, name: &str) -> Option<&UniformInfo> {
+        self.uniforms.iter().find(|uniform| {
+            uniform.name == name
+                || (uniform.name.ends_with("[0]")
+                    && &uniform.name[..uniform.name.len() - 3] == name)
+        })
+    }
+
+    /// Returns the typed location of the uniform with the given name, see `UniformInfo::location`.
+    ///
+    /// # Arguments
+    /// * `program` - the program the reflection was collected from.
+    /// * `name` - the name of the uniform, the `[0]` suffix of arrays can be left out.
+    pub fn uniform_location<'a, B: ProgramBackend>(
+        &self,
+        program: &'a WebGLRSProgram<B>,
+        name: &str,
+    ) -> Option<WebGLRSUniformLocation<'a, B>> {
+        self.uniform(name).map(|uniform| uniform.location(program))
+    }
+
+    /// Returns the uniform block with the given name.
+    pub fn uniform_block(&self, name: &str) -> Option<&UniformBlockInfo> {
+        self.uniform_blocks.iter().find(|block| block.name == name)
+    }
+
+    /// Returns the uniforms of a uniform block.
+    pub fn block_uniforms(&self, block: &UniformBlockInfo) -> Vec<&UniformInfo> {
+        block
+            .uniforms
+            .iter()
+            .filter_map(|&index| self.uniforms.get(index as usize))
+            .collect()
+    }
+}
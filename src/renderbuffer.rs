@@ -1,30 +1,169 @@
 //! WebGLRenderbuffer and methods
-use glenum::{Attachment, FramebufferKind, RenderbufferKind};
-use rendering_context::WebGL2RenderingContext;
+use error::CheckedObject;
+use glenum::{Attachment, FramebufferAttachmentParameter, FramebufferKind, RenderbufferKind};
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing renderbuffer objects.
+pub trait RenderbufferBackend: Sized {
+    /// Handle to a renderbuffer object of this backend.
+    type Renderbuffer: Clone;
+
+    /// Binding for `WebGLRenderingContext.createRenderbuffer()`
+    fn _create_renderbuffer(&self) -> Self::Renderbuffer;
+    /// Binding for `WebGLRenderingContext.framebufferRenderbuffer()`
+    fn _framebuffer_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        renderbuffertarget: RenderbufferKind,
+        renderbuffer: &Self::Renderbuffer,
+    );
+    /// Binding for `WebGLRenderingContext.bindRenderbuffer()`
+    fn _bind_renderbuffer(&self, target: RenderbufferKind, renderbuffer: &Self::Renderbuffer);
+    /// Binding for `WebGLRenderingContext.deleteRenderbuffer()`
+    fn _delete_renderbuffer(&self, renderbuffer: Self::Renderbuffer);
+    /// Binding for `WebGLRenderingContext.isRenderbuffer()`
+    fn _is_renderbuffer(&self, renderbuffer: &Self::Renderbuffer) -> bool;
+    /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is
+    /// `WebGLRenderbuffer`
+    fn _get_framebuffer_attachment_parameter_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<Self::Renderbuffer>;
+
     /// Creates and initializes WebGLRSRenderbuffer object.
-    pub fn create_renderbuffer(&self) -> WebGLRSRenderbuffer {
+    fn create_renderbuffer(&self) -> WebGLRSRenderbuffer<'_, Self> {
         WebGLRSRenderbuffer {
             context: self,
             inner: self._create_renderbuffer(),
+            leaked: false,
         }
     }
 }
 
+impl RenderbufferBackend for WebGL2RenderingContext {
+    type Renderbuffer = WebGLRenderbuffer;
+
+    fn _create_renderbuffer(&self) -> WebGLRenderbuffer {
+        WebGL2RenderingContext::_create_renderbuffer(self)
+    }
+    fn _framebuffer_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        renderbuffertarget: RenderbufferKind,
+        renderbuffer: &WebGLRenderbuffer,
+    ) {
+        WebGL2RenderingContext::_framebuffer_renderbuffer(
+            self,
+            target,
+            attachment,
+            renderbuffertarget,
+            renderbuffer,
+        )
+    }
+    fn _bind_renderbuffer(&self, target: RenderbufferKind, renderbuffer: &WebGLRenderbuffer) {
+        WebGL2RenderingContext::_bind_renderbuffer(self, target, renderbuffer)
+    }
+    fn _delete_renderbuffer(&self, renderbuffer: WebGLRenderbuffer) {
+        WebGL2RenderingContext::_delete_renderbuffer(self, renderbuffer)
+    }
+    fn _is_renderbuffer(&self, renderbuffer: &WebGLRenderbuffer) -> bool {
+        WebGL2RenderingContext::_is_renderbuffer(self, renderbuffer)
+    }
+    fn _get_framebuffer_attachment_parameter_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<WebGLRenderbuffer> {
+        WebGL2RenderingContext::_get_framebuffer_attachment_parameter_renderbuffer(
+            self, target, attachment, pname,
+        )
+    }
+}
+
 /// The `WebGLRenderbuffer` interface is represents a buffer that can contain an image, or can be source
 /// or target of an rendering operation.
-#[derive(Clone)]
-pub struct WebGLRSRenderbuffer<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLRenderbuffer,
+///
+/// The object is deleted when the `WebGLRSRenderbuffer` is dropped.
+pub struct WebGLRSRenderbuffer<'ctx, B: 'ctx + RenderbufferBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Renderbuffer,
+    leaked: bool,
+}
+
+impl<'ctx, B: RenderbufferBackend> Drop for WebGLRSRenderbuffer<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_renderbuffer(self.inner.clone());
+        }
+    }
+}
+
+impl<'ctx, B: RenderbufferBackend + ContextBackend> WebGLRSRenderbuffer<'ctx, B> {
+    /// Returns a view on this renderbuffer whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
 }
 
-impl<'ctx> WebGLRSRenderbuffer<'ctx> {
+impl<'a, 'ctx, B: RenderbufferBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSRenderbuffer<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSRenderbuffer "renderbuffer";
+        fn is_valid() -> bool;
+        fn bind(target: RenderbufferKind);
+        fn attach_framebuffer(
+            fb_target: FramebufferKind,
+            attachment: Attachment,
+            rb_target: RenderbufferKind,
+        );
+    }
+}
+
+impl<'ctx, B: RenderbufferBackend> WebGLRSRenderbuffer<'ctx, B> {
     /// Deletes the `WebGLRSRenderbuffer` object
+    ///
+    /// This is the same as dropping the `WebGLRSRenderbuffer`.
     pub fn delete(self) {
-        self.context._delete_renderbuffer(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSRenderbuffer` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `renderbuffer` - the raw handle.
+    pub fn from_raw(context: &'ctx B, renderbuffer: B::Renderbuffer) -> Self {
+        WebGLRSRenderbuffer {
+            context,
+            inner: renderbuffer,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Renderbuffer {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSRenderbuffer` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Returns true if the `WebGLRSRenderbuffer` object is valid and false otherwise.
@@ -62,7 +201,7 @@ impl<'ctx> WebGLRSRenderbuffer<'ctx> {
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLRenderbuffer;
+    pub type WebGLRenderbuffer;
     /// Binding for `WebGLRenderingContext.createRenderbuffer()`
     #[wasm_bindgen(method, js_name = createRenderbuffer)]
     fn _create_renderbuffer(this: &WebGL2RenderingContext) -> WebGLRenderbuffer;
@@ -97,4 +236,13 @@ extern "C" {
     #[wasm_bindgen(method, js_name = isRenderbuffer)]
     fn _is_renderbuffer(this: &WebGL2RenderingContext, renderbuffer: &WebGLRenderbuffer) -> bool;
 
+    /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is
+    /// `WebGLRenderbuffer`
+    #[wasm_bindgen(method, js_name = getFramebufferAttachmentParameter)]
+    fn _get_framebuffer_attachment_parameter_renderbuffer(
+        this: &WebGL2RenderingContext,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<WebGLRenderbuffer>;
 }
@@ -2,34 +2,470 @@
 //!
 //! Documentation taken straight from https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext
 //! and https://developer.mozilla.org/en-US/docs/Web/API/WebGL2RenderingContext
-use data_view::{Buffer, Image};
+use context_builder::ContextBuilder;
+use data_view::{ArrayData, ArrayDataMut, Buffer, BufferMut, Image};
+use error::{Checked, WebGLError};
+use formats;
+use formats::FormatError;
 use glenum::*;
+use image_source::{ImageSource, TexImageSource};
+use parameters::Parameters;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 #[derive(Clone, Copy)]
 extern "C" {
     pub type HTMLDocument;
-    static document: HTMLDocument;
+    pub(crate) static document: HTMLDocument;
 
     #[wasm_bindgen(method, js_name = getElementById)]
-    fn get_element_by_id(this: &HTMLDocument, id: &str) -> HTMLCanvasElement;
+    pub(crate) fn get_element_by_id(this: &HTMLDocument, id: &str) -> JsValue;
 
     pub type HTMLCanvasElement;
-    #[wasm_bindgen(method)]
-    fn get_context(this: &HTMLCanvasElement, context: &str) -> WebGL2RenderingContext;
+
+    /// Javascript array of strings
+    pub(crate) type StringArray;
+
+    /// Binding for the `Array()` constructor
+    #[wasm_bindgen(constructor, js_class = "Array")]
+    pub(crate) fn new() -> StringArray;
+
+    /// Binding for `Array.prototype.push()`
+    #[wasm_bindgen(method, structural)]
+    pub(crate) fn push(this: &StringArray, value: &str) -> u32;
+
+    /// Binding for `Array.prototype.join()`
+    #[wasm_bindgen(method, structural)]
+    fn join(this: &StringArray, separator: &str) -> String;
+}
+
+/// Copies a javascript array of strings, a missing array is treated as an empty one.
+///
+/// The array is joined into one string and split again, so the strings can not contain commas.
+pub(crate) fn string_list(array: Option<StringArray>) -> Vec<String> {
+    match array {
+        Some(array) => array
+            .join(",")
+            .split(',')
+            .filter(|string| !string.is_empty())
+            .map(String::from)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Checks that the data of an upload matches the pixel type and holds the whole image, given the current
+/// `UNPACK_ALIGNMENT` of the context.
+fn validate_pixels<B: ContextBackend, I: Image + ?Sized>(
+    context: &B,
+    operation: &'static str,
+    data: &I,
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    pixel_type: PixelType,
+) -> Result<(), WebGLError> {
+    let alignment = context._get_parameter_i32(Parameter::UnpackAlignment) as usize;
+    formats::validate_pixels::<I::Component>(
+        data.data().len(),
+        width,
+        height,
+        format,
+        pixel_type,
+        alignment,
+    )
+    .map_err(|error| WebGLError::Format { operation, error })
+}
+
+/// Converts a byte range of the data into the element offset and length taken by the WebGL calls.
+fn element_range(
+    operation: &'static str,
+    data: &ArrayData,
+    byte_offset: u32,
+    byte_length: u32,
+) -> Result<(u32, u32), WebGLError> {
+    let element_size = data.element_size() as u32;
+    let size = data.len() * element_size as usize;
+    // element sizes are powers of two, so the low bits tell whether the range is aligned to the elements
+    let misaligned = (byte_offset | byte_length) & (element_size - 1) != 0;
+    if misaligned || byte_offset as u64 + byte_length as u64 > size as u64 {
+        return Err(WebGLError::Range {
+            operation,
+            offset: byte_offset,
+            length: byte_length,
+            size,
+        });
+    }
+    Ok((byte_offset / element_size, byte_length / element_size))
 }
 
 impl WebGL2RenderingContext {
+    /// Creates a WebGL2 context with the default attributes on the canvas with the given id.
+    ///
+    /// # Panics
+    /// Panics if the canvas does not exist or the context could not be created, use `ContextBuilder` to
+    /// handle these errors.
     pub fn new(id: &str) -> WebGL2RenderingContext {
-        document.get_element_by_id(id).get_context("webgl2")
+        ContextBuilder::new()
+            .build(id)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
+}
+
+/// Backend calls for all context state and drawing methods that are not tied to a specific WebGL object.
+///
+/// The methods that take typed arrays receive them as `ArrayData` views so that a backend does not have
+/// to implement every typed variant separately.
+pub trait ContextBackend: Sized {
+    /// Binding for `WebGLRenderingContext.drawingBufferWidth`
+    fn drawing_buffer_width(&self) -> u32;
+    /// Binding for `WebGLRenderingContext.drawingBufferHeight`
+    fn drawing_buffer_height(&self) -> u32;
+    /// Binding for `WebGLRenderingContext.isContextLost()`
+    fn is_context_lost(&self) -> bool;
+    /// Binding for `WebGLRenderingContext.getSupportedExtensions()`
+    fn get_supported_extensions(&self) -> Vec<String>;
+    /// Binding for `WebGLRenderingContext.scissor()`
+    fn scissor(&self, x: i32, y: i32, width: u32, height: u32);
+    /// Binding for `WebGLRenderingContext.viewport()`
+    fn viewport(&self, x: i32, y: i32, width: u32, height: u32);
+    /// Binding for `WebGLRenderingContext.activeTexture()`
+    fn active_texture(&self, texture: TextureUnit);
+    /// Binding for `WebGLRenderingContext.blendColor()`
+    fn blend_color(&self, red: f32, green: f32, blue: f32, alpha: f32);
+    /// Binding for `WebGLRenderingContext.blendEquation()`
+    fn blend_equation(&self, mode: BlendEquation);
+    /// Binding for `WebGLRenderingContext.blendEquationSeparate()`
+    fn blend_equation_separate(&self, mode_rgb: BlendEquation, mode_alpha: BlendEquation);
+    /// Binding for `WebGLRenderingContext.blendFunc()`
+    fn blend_func(&self, sfactor: BlendMode, dfactor: BlendMode);
+    /// Binding for `WebGLRenderingContext.blendFuncSeparate()`
+    fn blend_func_separate(
+        &self,
+        src_rgb: BlendMode,
+        dst_rgb: BlendMode,
+        src_alpha: BlendMode,
+        dst_alpha: BlendMode,
+    );
+    /// Binding for `WebGLRenderingContext.clearColor()`
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32);
+    /// Binding for `WebGLRenderingContext.clearDepth()`
+    fn clear_depth(&self, depth: f32);
+    /// Binding for `WebGLRenderingContext.clearStencil()`
+    fn clear_stencil(&self, s: i32);
+    /// Binding for `WebGLRenderingContext.colorMask()`
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool);
+    /// Binding for `WebGLRenderingContext.cullFace()`
+    fn cull_face(&self, mode: Culling);
+    /// Binding for `WebGLRenderingContext.depthFunc()`
+    fn depth_func(&self, func: DepthTest);
+    /// Binding for `WebGLRenderingContext.depthMask()`
+    fn depth_mask(&self, flag: bool);
+    /// Binding for `WebGLRenderingContext.depthRange()`
+    fn depth_range(&self, z_near: f32, z_far: f32);
+    /// Binding for `WebGLRenderingContext.disable()`
+    fn disable(&self, cap: Flag);
+    /// Binding for `WebGLRenderingContext.enable()`
+    fn enable(&self, cap: Flag);
+    /// Binding for `WebGLRenderingContext.frontFace()`
+    fn front_face(&self, mode: FrontFaceDirection);
+    /// Binding for `WebGLRenderingContext.getError()`
+    fn get_error(&self) -> Error;
+    /// Binding for `WebGLRenderingContext.hint()`
+    fn hint(&self, target: HintTarget, mode: HintMode);
+    /// Binding for `WebGLRenderingContext.isEnabled()`
+    fn is_enabled(&self, cap: Flag) -> bool;
+    /// Binding for `WebGLRenderingContext.lineWidth()`
+    fn line_width(&self, width: f32);
+    /// Binding for `WebGLRenderingContext.pixelStorei()`
+    fn pixel_storei(&self, pname: PixelStorageMode, param: i32);
+    /// Binding for `WebGLRenderingContext.polygonOffset()`
+    fn polygon_offset(&self, factor: f32, units: f32);
+    /// Binding for `WebGLRenderingContext.sampleCoverage()`
+    fn sample_coverage(&self, value: f32, invert: bool);
+    /// Binding for `WebGLRenderingContext.stencilFunc()`
+    fn stencil_func(&self, func: StencilTest, reference: i32, mask: u32);
+    /// Binding for `WebGLRenderingContext.stencilFuncSeparate()`
+    fn stencil_func_separate(&self, face: Culling, func: StencilTest, reference: i32, mask: u32);
+    /// Binding for `WebGLRenderingContext.stencilMask()`
+    fn stencil_mask(&self, mask: u32);
+    /// Binding for `WebGLRenderingContext.stencilMaskSeparate()`
+    fn stencil_mask_separate(&self, face: Culling, mask: u32);
+    /// Binding for `WebGLRenderingContext.stencilOp()`
+    fn stencil_op(&self, fail: StencilAction, zfail: StencilAction, zpass: StencilAction);
+    /// Binding for `WebGLRenderingContext.stencilOpSeparate()`
+    fn stencil_op_separate(
+        &self,
+        face: Culling,
+        fail: StencilAction,
+        zfail: StencilAction,
+        zpass: StencilAction,
+    );
+    /// Binding for `WebGLRenderingContext.getBufferParameter()`
+    fn _get_buffer_parameter_i32(&self, target: BufferKind, pname: BufferParameter) -> i32;
+    /// Binding for `WebGLRenderingContext.getBufferParameter()`
+    fn _get_buffer_parameter_enum(&self, target: BufferKind, pname: BufferParameter) -> DataHint;
+    /// Binding for `WebGLRenderingContext.checkFramebufferStatus()`
+    fn check_framebuffer_status(&self, target: FramebufferKind) -> FramebufferStatus;
+    /// Binding for `WebGLRenderingContext.getRenderbufferParameter()`
+    fn _get_renderbuffer_parameter_i32(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> i32;
+    /// Binding for `WebGLRenderingContext.getRenderbufferParameter()`
+    fn _get_renderbuffer_parameter_enum(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> InternalFormat;
+    /// Binding for `WebGLRenderingContext.renderbufferStorage()`
+    fn _renderbuffer_storage(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    );
+    /// Binding for `WebGLRenderingContext.copyTexImage2D()`
+    #[allow(clippy::too_many_arguments)]
+    fn copy_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: PixelCopyFormat,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        border: u32,
+    );
+    /// Binding for `WebGLRenderingContext.copyTexSubImage2D()`
+    #[allow(clippy::too_many_arguments)]
+    fn copy_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: i32,
+        yoffset: i32,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    );
+    /// Binding for `WebGLRenderingContext.generateMipmap()`
+    fn generate_mipmap(&self, target: TextureKind);
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_i32(&self, target: TextureKind, pname: TextureParameter) -> i32;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_u32(&self, target: TextureKind, pname: TextureParameter) -> u32;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_f32(&self, target: TextureKind, pname: TextureParameter) -> f32;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_bool(&self, target: TextureKind, pname: TextureParameter) -> bool;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_enum1(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMagFilter;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_enum2(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMinFilter;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_enum3(&self, target: TextureKind, pname: TextureParameter)
+        -> TextureWrap;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_enum4(&self, target: TextureKind, pname: TextureParameter) -> DepthTest;
+    /// Binding for `WebGLRenderingContext.getTexParameter()`
+    fn _get_tex_parameter_enum5(&self, target: TextureKind, pname: TextureParameter)
+        -> CompareMode;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_bool(&self, pname: Parameter) -> bool;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_i32(&self, pname: Parameter) -> i32;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_u32(&self, pname: Parameter) -> u32;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_f32(&self, pname: Parameter) -> f32;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_string(&self, pname: Parameter) -> String;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_i32_array(&self, pname: Parameter) -> Vec<i32>;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_u32_array(&self, pname: Parameter) -> Vec<u32>;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_f32_array(&self, pname: Parameter) -> Vec<f32>;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_bool_array(&self, pname: Parameter) -> Vec<bool>;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum1(&self, pname: Parameter) -> BlendEquation;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum2(&self, pname: Parameter) -> BlendMode;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum3(&self, pname: Parameter) -> Culling;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum4(&self, pname: Parameter) -> FrontFaceDirection;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum5(&self, pname: Parameter) -> DepthTest;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum6(&self, pname: Parameter) -> StencilTest;
+    /// Binding for `WebGLRenderingContext.getParameter()`
+    fn _get_parameter_enum7(&self, pname: Parameter) -> StencilAction;
+    /// Binding for `WebGLRenderingContext.texParameterf()`
+    fn tex_parameter_f(&self, target: TextureKind, pname: TextureParameter, param: f32);
+    /// Binding for `WebGLRenderingContext.texParameteri()`
+    fn tex_parameter_i(&self, target: TextureKind, pname: TextureParameter, param: i32);
+    /// Binding for `WebGLRenderingContext.getShaderPrecisionFormat()`
+    fn get_shader_precision_format(
+        &self,
+        shader_type: ShaderKind,
+        precision_type: ShaderPrecision,
+    ) -> WebGLRSShaderPrecisionFormat;
+    /// Binding for `WebGLRenderingContext.disableVertexAttribArray()`
+    fn disable_vertex_attrib_array(&self, index: u32);
+    /// Binding for `WebGLRenderingContext.enableVertexAttribArray()`
+    fn enable_vertex_attrib_array(&self, index: u32);
+    /// Binding for `WebGLRenderingContext.getVertexAttribOffset()`
+    fn get_vertex_attrib_offset(&self, index: u32, pname: VertexAttrib) -> i64;
+    /// Binding for `WebGLRenderingContext.vertexAttrib1f()`
+    fn vertex_attrib_1f(&self, index: u32, v0: f32);
+    /// Binding for `WebGLRenderingContext.vertexAttrib2f()`
+    fn vertex_attrib_2f(&self, index: u32, v0: f32, v1: f32);
+    /// Binding for `WebGLRenderingContext.vertexAttrib3f()`
+    fn vertex_attrib_3f(&self, index: u32, v0: f32, v1: f32, v2: f32);
+    /// Binding for `WebGLRenderingContext.vertexAttrib4f()`
+    fn vertex_attrib_4f(&self, index: u32, v0: f32, v1: f32, v2: f32, v3: f32);
+    /// Binding for `WebGLRenderingContext.vertexAttrib1fv()`
+    fn vertex_attrib_1fv(&self, index: u32, value: Vec<f32>);
+    /// Binding for `WebGLRenderingContext.vertexAttrib2fv()`
+    fn vertex_attrib_2fv(&self, index: u32, value: Vec<f32>);
+    /// Binding for `WebGLRenderingContext.vertexAttrib3fv()`
+    fn vertex_attrib_3fv(&self, index: u32, value: Vec<f32>);
+    /// Binding for `WebGLRenderingContext.vertexAttrib4fv()`
+    fn vertex_attrib_4fv(&self, index: u32, value: Vec<f32>);
+    /// Binding for `WebGLRenderingContext.vertexAttribPointer()`
+    fn vertex_attrib_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        attribute_type: AttributeType,
+        normalized: bool,
+        stride: u8,
+        offset: i32,
+    );
+    /// Binding for `WebGLRenderingContext.clear()`
+    fn clear(&self, mask: BufferBit);
+    /// Binding for `WebGLRenderingContext.drawArrays()`
+    fn draw_arrays(&self, mode: Primitives, first: u32, count: u32);
+    /// Binding for `WebGLRenderingContext.drawElements()`
+    fn draw_elements(&self, mode: Primitives, count: u32, data_type: DataType, offset: i64);
+    /// Binding for `WebGLRenderingContext.finish()`
+    fn finish(&self);
+    /// Binding for `WebGLRenderingContext.flush()`
+    fn flush(&self);
+    /// Binding for `WebGLRenderingContext.bufferData()`
+    fn _buffer_data(&self, target: BufferKind, src_data: ArrayData, usage: DataHint);
+    /// Binding for `WebGLRenderingContext.bufferSubData()`
+    fn _buffer_sub_data(&self, target: BufferKind, offset: i64, src_data: ArrayData);
+    /// Binding for `WebGLRenderingContext.texImage2D()`
+    #[allow(clippy::too_many_arguments)]
+    fn _tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        src_data: ArrayData,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGLRenderingContext.texSubImage2D()`
+    #[allow(clippy::too_many_arguments)]
+    fn _tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGLRenderingContext.compressedTexImage2D()`
+    #[allow(clippy::too_many_arguments)]
+    fn _compressed_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGLRenderingContext.compressedTexSubImage2D()`
+    #[allow(clippy::too_many_arguments)]
+    fn _compressed_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGLRenderingContext.texImage2D()` with a `TexImageSource`
+    fn _tex_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGLRenderingContext.texSubImage2D()` with a `TexImageSource`
+    #[allow(clippy::too_many_arguments)]
+    fn _tex_sub_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGLRenderingContext.readPixels()`
+    #[allow(clippy::too_many_arguments)]
+    fn _read_pixels(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelReadFormat,
+        pixel_type: PixelType,
+        pixels: ArrayDataMut,
+    ) -> Result<(), JsValue>;
 
     /// Returns the size of the currently bound buffer in bytes
     ///
     /// # Arguments
     /// * `target` - specifying the target buffer object.
-    pub fn buffer_size(&self, target: BufferKind) -> i32 {
+    fn buffer_size(&self, target: BufferKind) -> i32 {
         self._get_buffer_parameter_i32(target, BufferParameter::Size)
     }
 
@@ -37,7 +473,7 @@ impl WebGL2RenderingContext {
     ///     
     /// # Arguments
     /// * `target` - specifying the target buffer object.
-    pub fn buffer_usage(&self, target: BufferKind) -> DataHint {
+    fn buffer_usage(&self, target: BufferKind) -> DataHint {
         self._get_buffer_parameter_enum(target, BufferParameter::Usage)
     }
 
@@ -45,7 +481,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_internal_format(&self, target: RenderbufferKind) -> RenderbufferFormat {
+    fn renderbuffer_internal_format(&self, target: RenderbufferKind) -> InternalFormat {
         self._get_renderbuffer_parameter_enum(target, RenderbufferParameter::Format)
     }
 
@@ -53,7 +489,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_width(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_width(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::Width)
     }
 
@@ -61,7 +497,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_height(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_height(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::Height)
     }
 
@@ -69,7 +505,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_green_size(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_green_size(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::GreenSize)
     }
 
@@ -77,7 +513,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_blue_size(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_blue_size(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::BlueSize)
     }
 
@@ -85,7 +521,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_red_size(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_red_size(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::RedSize)
     }
 
@@ -93,7 +529,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_alpha_size(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_alpha_size(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::AlphaSize)
     }
 
@@ -101,7 +537,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_depth_size(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_depth_size(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::DepthSize)
     }
 
@@ -109,23 +545,15 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_stencil_size(&self, target: RenderbufferKind) -> i32 {
+    fn renderbuffer_stencil_size(&self, target: RenderbufferKind) -> i32 {
         self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::StencilSize)
     }
 
-    /// Returns the number of samples of the image of the currently bound renderbuffer.
-    ///
-    /// # Arguments
-    /// * `target` - specifying the target renderbuffer object.
-    pub fn renderbuffer_samples(&self, target: RenderbufferKind) -> i32 {
-        self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::Samples)
-    }
-
     /// Returns the texture magnification filter
     ///
     /// # Arguments
     /// * `target` - specifying the binding point (target).
-    pub fn texture_mag_filter(&self, target: TextureKind) -> TextureMagFilter {
+    fn texture_mag_filter(&self, target: TextureKind) -> TextureMagFilter {
         self._get_tex_parameter_enum1(target, TextureParameter::MagFilter)
     }
 
@@ -133,7 +561,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the binding point (target).
-    pub fn texture_min_filter(&self, target: TextureKind) -> TextureMinFilter {
+    fn texture_min_filter(&self, target: TextureKind) -> TextureMinFilter {
         self._get_tex_parameter_enum2(target, TextureParameter::MinFilter)
     }
 
@@ -141,7 +569,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the binding point (target).
-    pub fn texture_wrap_s(&self, target: TextureKind) -> TextureWrap {
+    fn texture_wrap_s(&self, target: TextureKind) -> TextureWrap {
         self._get_tex_parameter_enum3(target, TextureParameter::WrapS)
     }
 
@@ -149,79 +577,7 @@ impl WebGL2RenderingContext {
     ///
     /// # Arguments
     /// * `target` - specifying the binding point (target).
-    pub fn texture_wrap_t(&self, target: TextureKind) -> TextureWrap {
-        self._get_tex_parameter_enum3(target, TextureParameter::WrapS)
-    }
-
-    /// Returns the texture mipmap level
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_base_level(&self, target: TextureKind) -> i32 {
-        self._get_tex_parameter_i32(target, TextureParameter::BaseLevel)
-    }
-
-    /// Returns the texture comparison function
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_compare_func(&self, target: TextureKind) -> DepthTest {
-        self._get_tex_parameter_enum4(target, TextureParameter::CompareFunc)
-    }
-
-    /// Returns the texture comparison mode
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_compare_mode(&self, target: TextureKind) -> CompareMode {
-        self._get_tex_parameter_enum5(target, TextureParameter::CompareMode)
-    }
-
-    /// Returns whether the texture format and size is immutable
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_immutable_format(&self, target: TextureKind) -> bool {
-        self._get_tex_parameter_bool(target, TextureParameter::ImmutableFormat)
-    }
-
-    /// Returns the immutable levels for a texture
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_immutable_levels(&self, target: TextureKind) -> u32 {
-        self._get_tex_parameter_u32(target, TextureParameter::ImmutableLevels)
-    }
-
-    /// Returns the maximum texture mipmap array level
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_max_level(&self, target: TextureKind) -> i32 {
-        self._get_tex_parameter_i32(target, TextureParameter::MaxLevel)
-    }
-
-    /// Returns the texture maximum level-of-detail value
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_max_lod(&self, target: TextureKind) -> f32 {
-        self._get_tex_parameter_f32(target, TextureParameter::MaxLod)
-    }
-
-    /// Returns the texture minimum level-of-detail value
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_min_lod(&self, target: TextureKind) -> f32 {
-        self._get_tex_parameter_f32(target, TextureParameter::MinLod)
-    }
-
-    /// Returns the wrapping function for texture coordinate r
-    ///
-    /// # Arguments
-    /// * `target` - specifying the binding point (target).
-    pub fn texture_wrap_r(&self, target: TextureKind) -> TextureWrap {
+    fn texture_wrap_t(&self, target: TextureKind) -> TextureWrap {
         self._get_tex_parameter_enum3(target, TextureParameter::WrapS)
     }
 
@@ -231,8 +587,8 @@ impl WebGL2RenderingContext {
     /// * `target` - specifying the binding point (target)
     /// * `src_data` - the source data to be stored in the buffer
     /// * `usage` - specifying the usage pattern of the data store.
-    pub fn buffer_data<B: Buffer>(&self, target: BufferKind, src_data: &B, usage: DataHint) {
-        src_data.buffer_data(self, target, usage);
+    fn buffer_data<B: Buffer + ?Sized>(&self, target: BufferKind, src_data: &B, usage: DataHint) {
+        self._buffer_data(target, src_data.data(), usage);
     }
 
     /// Updates a subset of a buffer object's data store.
@@ -241,8 +597,22 @@ impl WebGL2RenderingContext {
     /// * `target` - specifying the binding point (target)
     /// * `offset` - specifying an offset in bytes where the data replacement will start.
     /// * `src_data` - the source data to be stored in the buffer
-    pub fn buffer_sub_data<B: Buffer>(&self, target: BufferKind, offset: i64, src_data: &B) {
-        src_data.buffer_sub_data(self, target, offset);
+    fn buffer_sub_data<B: Buffer + ?Sized>(&self, target: BufferKind, offset: i64, src_data: &B) {
+        self._buffer_sub_data(target, offset, src_data.data());
+    }
+
+    /// Checks that `tex_image_2d` accepts data of the given format and type for a texture of the internal
+    /// format, following the rules of the WebGL version of the context.
+    ///
+    /// Follows WebGL2 by default, see `formats::validate_tex_image`. WebGL1 contexts only accept unsized
+    /// formats, see `formats::validate_tex_image_webgl1`.
+    fn validate_tex_image(
+        &self,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+    ) -> Result<(), FormatError> {
+        formats::validate_tex_image(internalformat, format, pixel_type)
     }
 
     /// Specifies and loads a two-dimensional texture image.
@@ -258,41 +628,157 @@ impl WebGL2RenderingContext {
     ///         https://www.khronos.org/registry/OpenGL-Refpages/es3.0/html/glTexImage2D.xhtml
     /// * `pixel_type` - specifying the data type of the texel data.
     /// * `src_data` - pixel source for the texture
-    pub fn tex_image_2d<I: Image>(
+    ///
+    /// Fails without calling WebGL when the format and type can not be uploaded to the internal format, see
+    /// `validate_tex_image`, or when the data does not match the type or is too short, see
+    /// `formats::validate_pixels`.
+    #[allow(clippy::too_many_arguments)]
+    fn tex_image_2d<I: Image + ?Sized>(
         &self,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &I,
-    ) -> Result<(), JsValue> {
-        src_data.tex_image_2d(
+    ) -> Result<(), WebGLError> {
+        self.validate_tex_image(internalformat, format, pixel_type)
+            .map_err(|error| WebGLError::Format {
+                operation: "tex_image_2d",
+                error,
+            })?;
+        validate_pixels(
             self,
+            "tex_image_2d",
+            src_data,
+            width,
+            height,
+            format,
+            pixel_type,
+        )?;
+        self._tex_image_2d(
             target,
             level,
             internalformat,
             width,
             height,
+            0,
             format,
             pixel_type,
+            src_data.data(),
         )
+        .map_err(|exception| WebGLError::exception("tex_image_2d", exception))
+    }
+
+    /// Creates and initializes the data store of the currently bound renderbuffer.
+    ///
+    /// Fails without calling WebGL when the internal format is not renderable, see
+    /// `formats::validate_renderbuffer_storage`.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the renderbuffer.
+    /// * `internal_format` - specifying the internal format of the renderbuffer.
+    /// * `width` - specifying the width of the renderbuffer in pixels.
+    /// * `height` - specifying the height of the renderbuffer in pixels.
+    fn renderbuffer_storage(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<(), WebGLError> {
+        formats::validate_renderbuffer_storage(internal_format).map_err(|error| {
+            WebGLError::Format {
+                operation: "renderbuffer_storage",
+                error,
+            }
+        })?;
+        self._renderbuffer_storage(target, internal_format, width, height);
+        Ok(())
     }
 
-    /* FIXME save for later
-    pub fn compressed_tex_image_2d(
+    /// Specifies a two-dimensional texture image in a compressed format.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail. Level 0 is the base image level and level n is the nth
+    ///   mipmap reduction level.
+    /// * `internalformat` - specifying the compressed format of the image.
+    /// * `width` - specifying the width of the texture.
+    /// * `height` - specifying the height of the texture.
+    /// * `data` - the compressed image.
+    ///
+    /// Fails without calling WebGL when the length of the data is not the size of the compressed image, see
+    /// `formats::validate_compressed_data`.
+    fn compressed_tex_image_2d(
         &self,
         target: TextureBindPoint,
         level: u32,
-        internalformat: CompressedFormat,
+        internalformat: TextureCompression,
         width: u32,
         height: u32,
-        pixels: I,
-    ) -> Result<(), JsValue> {
-        pixels.compressed_tex_image_2d(self, target, level, internalformat, width, height)
-    }*/
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        formats::validate_compressed_data(internalformat, width, height, 1, data.len()).map_err(
+            |error| WebGLError::Format {
+                operation: "compressed_tex_image_2d",
+                error,
+            },
+        )?;
+        self._compressed_tex_image_2d(target, level, internalformat, width, height, 0, data)
+            .map_err(|exception| WebGLError::exception("compressed_tex_image_2d", exception))
+    }
+
+    /// Replaces a sub-rectangle of a texture with compressed data.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail. Level 0 is the base image level and level n is the nth
+    ///   mipmap reduction level.
+    /// * `xoffset` - specifying the x offset of the sub-rectangle, a multiple of the block width.
+    /// * `yoffset` - specifying the y offset of the sub-rectangle, a multiple of the block height.
+    /// * `width` - specifying the width of the sub-rectangle.
+    /// * `height` - specifying the height of the sub-rectangle.
+    /// * `format` - specifying the compressed format of the texture.
+    /// * `data` - the compressed sub-rectangle.
+    ///
+    /// Fails without calling WebGL when the format can not be updated in parts, when the offsets are not
+    /// aligned to the blocks of the format or when the length of the data is not the size of the compressed
+    /// sub-rectangle. The size of the mipmap level is not known here, so a width or height that is not a
+    /// multiple of the block size is left for WebGL to check.
+    #[allow(clippy::too_many_arguments)]
+    fn compressed_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        formats::validate_compressed_region(
+            format,
+            xoffset,
+            yoffset,
+            width,
+            height,
+            xoffset.saturating_add(width),
+            yoffset.saturating_add(height),
+        )
+        .and_then(|_| formats::validate_compressed_data(format, width, height, 1, data.len()))
+        .map_err(|error| WebGLError::Format {
+            operation: "compressed_tex_sub_image_2d",
+            error,
+        })?;
+        self._compressed_tex_sub_image_2d(
+            target, level, xoffset, yoffset, width, height, format, data,
+        )
+        .map_err(|exception| WebGLError::exception("compressed_tex_sub_image_2d", exception))
+    }
 
     /// Specifies a sub-rectangle of the current texture.
     ///
@@ -308,58 +794,2122 @@ impl WebGL2RenderingContext {
     ///         https://www.khronos.org/registry/OpenGL-Refpages/es3.0/html/glTexImage2D.xhtml
     /// * `pixel_type` - specifying the data type of the texel data.
     /// * `pixels` - pixel source for the texture
-    pub fn tex_sub_image_2d<I: Image>(
+    ///
+    /// Fails without calling WebGL when the data does not match the type or is too short, see
+    /// `formats::validate_pixels`.
+    #[allow(clippy::too_many_arguments)]
+    fn tex_sub_image_2d<I: Image + ?Sized>(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &I,
+    ) -> Result<(), WebGLError> {
+        validate_pixels(
+            self,
+            "tex_sub_image_2d",
+            pixels,
+            width,
+            height,
+            format,
+            pixel_type,
+        )?;
+        self._tex_sub_image_2d(
+            target,
+            level,
+            xoffset,
+            yoffset,
+            width,
+            height,
+            format,
+            pixel_type,
+            pixels.data(),
+        )
+        .map_err(|exception| WebGLError::exception("tex_sub_image_2d", exception))
+    }
+
+    /// Specifies a two-dimensional texture image from a DOM object, the image has the size of the source.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail. Level 0 is the base image level and level n is the nth
+    ///   mipmap reduction level.
+    /// * `internalformat` - specifying the color components in the texture.
+    /// * `format` - specifying the format of the texel data.
+    /// * `pixel_type` - specifying the data type of the texel data.
+    /// * `source` - the image, canvas, video frame, bitmap or image data that is uploaded. It is flipped
+    ///   and premultiplied according to `UNPACK_FLIP_Y_WEBGL` and `UNPACK_PREMULTIPLY_ALPHA_WEBGL`.
+    ///
+    /// Fails without calling WebGL when the format and type can not be uploaded to the internal format, see
+    /// `validate_tex_image`.
+    fn tex_image_2d_source<S: TexImageSource + ?Sized>(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &S,
+    ) -> Result<(), WebGLError> {
+        self.validate_tex_image(internalformat, format, pixel_type)
+            .map_err(|error| WebGLError::Format {
+                operation: "tex_image_2d_source",
+                error,
+            })?;
+        self._tex_image_2d_source(
+            target,
+            level,
+            internalformat,
+            format,
+            pixel_type,
+            source.source(),
+        )
+        .map_err(|exception| WebGLError::exception("tex_image_2d_source", exception))
+    }
+
+    /// Replaces a sub-rectangle of the current texture with a DOM object, the rectangle has the size of the
+    /// source.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail. Level 0 is the base image level and level n is the nth
+    ///   mipmap reduction level.
+    /// * `xoffset` - specifying the x offset of the sub-rectangle.
+    /// * `yoffset` - specifying the y offset of the sub-rectangle.
+    /// * `format` - specifying the format of the texel data.
+    /// * `pixel_type` - specifying the data type of the texel data.
+    /// * `source` - the image, canvas, video frame, bitmap or image data that is uploaded. It is flipped
+    ///   and premultiplied according to `UNPACK_FLIP_Y_WEBGL` and `UNPACK_PREMULTIPLY_ALPHA_WEBGL`.
+    #[allow(clippy::too_many_arguments)]
+    fn tex_sub_image_2d_source<S: TexImageSource + ?Sized>(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &S,
+    ) -> Result<(), WebGLError> {
+        self._tex_sub_image_2d_source(
+            target,
+            level,
+            xoffset,
+            yoffset,
+            format,
+            pixel_type,
+            source.source(),
+        )
+        .map_err(|exception| WebGLError::exception("tex_sub_image_2d_source", exception))
+    }
+
+    /// Reads a block of pixels from a specified rectangle of the current color framebuffer into an array object.
+    ///
+    /// # Arguments
+    /// * `x` - specifying the first horizontal pixel that is read from the lower left corner of a rectangular block of pixels.
+    /// * `y` - specifying the first vertical pixel that is read from the lower left corner of a rectangular block of pixels.
+    /// * `width` - specifying the width of the rectangle.
+    /// * `height` - specifying the height of the rectangle.
+    /// * `format` - specifying the format of the pixel data.
+    /// * `pixel_type` - specifying the data type of the pixel data.
+    /// * `pixels` - An array object to read data into. The array type must match the type of the type parameter.
+    #[allow(clippy::too_many_arguments)]
+    fn read_pixels<I: Image + BufferMut + ?Sized>(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelReadFormat,
+        pixel_type: PixelType,
+        pixels: &mut I,
+    ) -> Result<(), JsValue> {
+        self._read_pixels(x, y, width, height, format, pixel_type, pixels.data_mut())
+    }
+
+    /// Returns a view on this context where every call checks the WebGL error flag afterwards.
+    ///
+    /// The checks are only performed in debug builds, see the `error` module.
+    fn checked(&self) -> Checked<'_, Self> {
+        Checked::new(self)
+    }
+
+    /// Returns a view on this context for querying its current state, e.g. the viewport or blend state.
+    fn parameters(&self) -> Parameters<'_, Self> {
+        Parameters::new(self)
+    }
+
+    // TODO loading -> tex_image_3d, tex_sub_image_3d, clear_buffer_uiv, clear_buffer_iv, clear_buffer_fv
+}
+
+impl ContextBackend for WebGL2RenderingContext {
+    fn drawing_buffer_width(&self) -> u32 {
+        WebGL2RenderingContext::drawing_buffer_width(self)
+    }
+    fn drawing_buffer_height(&self) -> u32 {
+        WebGL2RenderingContext::drawing_buffer_height(self)
+    }
+    fn is_context_lost(&self) -> bool {
+        WebGL2RenderingContext::is_context_lost(self)
+    }
+    fn get_supported_extensions(&self) -> Vec<String> {
+        string_list(WebGL2RenderingContext::_get_supported_extensions(self))
+    }
+    fn scissor(&self, x: i32, y: i32, width: u32, height: u32) {
+        WebGL2RenderingContext::scissor(self, x, y, width, height)
+    }
+    fn viewport(&self, x: i32, y: i32, width: u32, height: u32) {
+        WebGL2RenderingContext::viewport(self, x, y, width, height)
+    }
+    fn active_texture(&self, texture: TextureUnit) {
+        WebGL2RenderingContext::active_texture(self, texture)
+    }
+    fn blend_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        WebGL2RenderingContext::blend_color(self, red, green, blue, alpha)
+    }
+    fn blend_equation(&self, mode: BlendEquation) {
+        WebGL2RenderingContext::blend_equation(self, mode)
+    }
+    fn blend_equation_separate(&self, mode_rgb: BlendEquation, mode_alpha: BlendEquation) {
+        WebGL2RenderingContext::blend_equation_separate(self, mode_rgb, mode_alpha)
+    }
+    fn blend_func(&self, sfactor: BlendMode, dfactor: BlendMode) {
+        WebGL2RenderingContext::blend_func(self, sfactor, dfactor)
+    }
+    fn blend_func_separate(
+        &self,
+        src_rgb: BlendMode,
+        dst_rgb: BlendMode,
+        src_alpha: BlendMode,
+        dst_alpha: BlendMode,
+    ) {
+        WebGL2RenderingContext::blend_func_separate(self, src_rgb, dst_rgb, src_alpha, dst_alpha)
+    }
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        WebGL2RenderingContext::clear_color(self, red, green, blue, alpha)
+    }
+    fn clear_depth(&self, depth: f32) {
+        WebGL2RenderingContext::clear_depth(self, depth)
+    }
+    fn clear_stencil(&self, s: i32) {
+        WebGL2RenderingContext::clear_stencil(self, s)
+    }
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        WebGL2RenderingContext::color_mask(self, red, green, blue, alpha)
+    }
+    fn cull_face(&self, mode: Culling) {
+        WebGL2RenderingContext::cull_face(self, mode)
+    }
+    fn depth_func(&self, func: DepthTest) {
+        WebGL2RenderingContext::depth_func(self, func)
+    }
+    fn depth_mask(&self, flag: bool) {
+        WebGL2RenderingContext::depth_mask(self, flag)
+    }
+    fn depth_range(&self, z_near: f32, z_far: f32) {
+        WebGL2RenderingContext::depth_range(self, z_near, z_far)
+    }
+    fn disable(&self, cap: Flag) {
+        WebGL2RenderingContext::disable(self, cap)
+    }
+    fn enable(&self, cap: Flag) {
+        WebGL2RenderingContext::enable(self, cap)
+    }
+    fn front_face(&self, mode: FrontFaceDirection) {
+        WebGL2RenderingContext::front_face(self, mode)
+    }
+    fn get_error(&self) -> Error {
+        WebGL2RenderingContext::get_error(self)
+    }
+    fn hint(&self, target: HintTarget, mode: HintMode) {
+        WebGL2RenderingContext::hint(self, target, mode)
+    }
+    fn is_enabled(&self, cap: Flag) -> bool {
+        WebGL2RenderingContext::is_enabled(self, cap)
+    }
+    fn line_width(&self, width: f32) {
+        WebGL2RenderingContext::line_width(self, width)
+    }
+    fn pixel_storei(&self, pname: PixelStorageMode, param: i32) {
+        WebGL2RenderingContext::pixel_storei(self, pname, param)
+    }
+    fn polygon_offset(&self, factor: f32, units: f32) {
+        WebGL2RenderingContext::polygon_offset(self, factor, units)
+    }
+    fn sample_coverage(&self, value: f32, invert: bool) {
+        WebGL2RenderingContext::sample_coverage(self, value, invert)
+    }
+    fn stencil_func(&self, func: StencilTest, reference: i32, mask: u32) {
+        WebGL2RenderingContext::stencil_func(self, func, reference, mask)
+    }
+    fn stencil_func_separate(&self, face: Culling, func: StencilTest, reference: i32, mask: u32) {
+        WebGL2RenderingContext::stencil_func_separate(self, face, func, reference, mask)
+    }
+    fn stencil_mask(&self, mask: u32) {
+        WebGL2RenderingContext::stencil_mask(self, mask)
+    }
+    fn stencil_mask_separate(&self, face: Culling, mask: u32) {
+        WebGL2RenderingContext::stencil_mask_separate(self, face, mask)
+    }
+    fn stencil_op(&self, fail: StencilAction, zfail: StencilAction, zpass: StencilAction) {
+        WebGL2RenderingContext::stencil_op(self, fail, zfail, zpass)
+    }
+    fn stencil_op_separate(
+        &self,
+        face: Culling,
+        fail: StencilAction,
+        zfail: StencilAction,
+        zpass: StencilAction,
+    ) {
+        WebGL2RenderingContext::stencil_op_separate(self, face, fail, zfail, zpass)
+    }
+    fn _get_buffer_parameter_i32(&self, target: BufferKind, pname: BufferParameter) -> i32 {
+        WebGL2RenderingContext::_get_buffer_parameter_i32(self, target, pname)
+    }
+    fn _get_buffer_parameter_enum(&self, target: BufferKind, pname: BufferParameter) -> DataHint {
+        WebGL2RenderingContext::_get_buffer_parameter_enum(self, target, pname)
+    }
+    fn check_framebuffer_status(&self, target: FramebufferKind) -> FramebufferStatus {
+        WebGL2RenderingContext::check_framebuffer_status(self, target)
+    }
+    fn _get_renderbuffer_parameter_i32(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> i32 {
+        WebGL2RenderingContext::_get_renderbuffer_parameter_i32(self, target, pname)
+    }
+    fn _get_renderbuffer_parameter_enum(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> InternalFormat {
+        WebGL2RenderingContext::_get_renderbuffer_parameter_enum(self, target, pname)
+    }
+    fn _renderbuffer_storage(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        WebGL2RenderingContext::renderbuffer_storage(self, target, internal_format, width, height)
+    }
+    fn copy_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: PixelCopyFormat,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        border: u32,
+    ) {
+        WebGL2RenderingContext::copy_tex_image_2d(
+            self,
+            target,
+            level,
+            internalformat,
+            x,
+            y,
+            width,
+            height,
+            border,
+        )
+    }
+    fn copy_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: i32,
+        yoffset: i32,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) {
+        WebGL2RenderingContext::copy_tex_sub_image_2d(
+            self, target, level, xoffset, yoffset, x, y, width, height,
+        )
+    }
+    fn generate_mipmap(&self, target: TextureKind) {
+        WebGL2RenderingContext::generate_mipmap(self, target)
+    }
+    fn _get_tex_parameter_i32(&self, target: TextureKind, pname: TextureParameter) -> i32 {
+        WebGL2RenderingContext::_get_tex_parameter_i32(self, target, pname)
+    }
+    fn _get_tex_parameter_u32(&self, target: TextureKind, pname: TextureParameter) -> u32 {
+        WebGL2RenderingContext::_get_tex_parameter_u32(self, target, pname)
+    }
+    fn _get_tex_parameter_f32(&self, target: TextureKind, pname: TextureParameter) -> f32 {
+        WebGL2RenderingContext::_get_tex_parameter_f32(self, target, pname)
+    }
+    fn _get_tex_parameter_bool(&self, target: TextureKind, pname: TextureParameter) -> bool {
+        WebGL2RenderingContext::_get_tex_parameter_bool(self, target, pname)
+    }
+    fn _get_tex_parameter_enum1(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMagFilter {
+        WebGL2RenderingContext::_get_tex_parameter_enum1(self, target, pname)
+    }
+    fn _get_tex_parameter_enum2(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMinFilter {
+        WebGL2RenderingContext::_get_tex_parameter_enum2(self, target, pname)
+    }
+    fn _get_tex_parameter_enum3(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureWrap {
+        WebGL2RenderingContext::_get_tex_parameter_enum3(self, target, pname)
+    }
+    fn _get_tex_parameter_enum4(&self, target: TextureKind, pname: TextureParameter) -> DepthTest {
+        WebGL2RenderingContext::_get_tex_parameter_enum4(self, target, pname)
+    }
+    fn _get_tex_parameter_enum5(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> CompareMode {
+        WebGL2RenderingContext::_get_tex_parameter_enum5(self, target, pname)
+    }
+    fn _get_parameter_bool(&self, pname: Parameter) -> bool {
+        WebGL2RenderingContext::_get_parameter_bool(self, pname)
+    }
+    fn _get_parameter_i32(&self, pname: Parameter) -> i32 {
+        WebGL2RenderingContext::_get_parameter_i32(self, pname)
+    }
+    fn _get_parameter_u32(&self, pname: Parameter) -> u32 {
+        WebGL2RenderingContext::_get_parameter_u32(self, pname)
+    }
+    fn _get_parameter_f32(&self, pname: Parameter) -> f32 {
+        WebGL2RenderingContext::_get_parameter_f32(self, pname)
+    }
+    fn _get_parameter_string(&self, pname: Parameter) -> String {
+        WebGL2RenderingContext::_get_parameter_string(self, pname)
+    }
+    fn _get_parameter_i32_array(&self, pname: Parameter) -> Vec<i32> {
+        WebGL2RenderingContext::_get_parameter_i32_array(self, pname)
+    }
+    fn _get_parameter_u32_array(&self, pname: Parameter) -> Vec<u32> {
+        WebGL2RenderingContext::_get_parameter_u32_array(self, pname)
+    }
+    fn _get_parameter_f32_array(&self, pname: Parameter) -> Vec<f32> {
+        WebGL2RenderingContext::_get_parameter_f32_array(self, pname)
+    }
+    fn _get_parameter_bool_array(&self, pname: Parameter) -> Vec<bool> {
+        WebGL2RenderingContext::_get_parameter_bool_array(self, pname)
+            .into_iter()
+            .map(|value| value != 0)
+            .collect()
+    }
+    fn _get_parameter_enum1(&self, pname: Parameter) -> BlendEquation {
+        WebGL2RenderingContext::_get_parameter_enum1(self, pname)
+    }
+    fn _get_parameter_enum2(&self, pname: Parameter) -> BlendMode {
+        WebGL2RenderingContext::_get_parameter_enum2(self, pname)
+    }
+    fn _get_parameter_enum3(&self, pname: Parameter) -> Culling {
+        WebGL2RenderingContext::_get_parameter_enum3(self, pname)
+    }
+    fn _get_parameter_enum4(&self, pname: Parameter) -> FrontFaceDirection {
+        WebGL2RenderingContext::_get_parameter_enum4(self, pname)
+    }
+    fn _get_parameter_enum5(&self, pname: Parameter) -> DepthTest {
+        WebGL2RenderingContext::_get_parameter_enum5(self, pname)
+    }
+    fn _get_parameter_enum6(&self, pname: Parameter) -> StencilTest {
+        WebGL2RenderingContext::_get_parameter_enum6(self, pname)
+    }
+    fn _get_parameter_enum7(&self, pname: Parameter) -> StencilAction {
+        WebGL2RenderingContext::_get_parameter_enum7(self, pname)
+    }
+    fn tex_parameter_f(&self, target: TextureKind, pname: TextureParameter, param: f32) {
+        WebGL2RenderingContext::tex_parameter_f(self, target, pname, param)
+    }
+    fn tex_parameter_i(&self, target: TextureKind, pname: TextureParameter, param: i32) {
+        WebGL2RenderingContext::tex_parameter_i(self, target, pname, param)
+    }
+    fn get_shader_precision_format(
+        &self,
+        shader_type: ShaderKind,
+        precision_type: ShaderPrecision,
+    ) -> WebGLRSShaderPrecisionFormat {
+        WebGL2RenderingContext::_get_shader_precision_format(self, shader_type, precision_type)
+            .into()
+    }
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        WebGL2RenderingContext::disable_vertex_attrib_array(self, index)
+    }
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        WebGL2RenderingContext::enable_vertex_attrib_array(self, index)
+    }
+    fn get_vertex_attrib_offset(&self, index: u32, pname: VertexAttrib) -> i64 {
+        WebGL2RenderingContext::get_vertex_attrib_offset(self, index, pname)
+    }
+    fn vertex_attrib_1f(&self, index: u32, v0: f32) {
+        WebGL2RenderingContext::vertex_attrib_1f(self, index, v0)
+    }
+    fn vertex_attrib_2f(&self, index: u32, v0: f32, v1: f32) {
+        WebGL2RenderingContext::vertex_attrib_2f(self, index, v0, v1)
+    }
+    fn vertex_attrib_3f(&self, index: u32, v0: f32, v1: f32, v2: f32) {
+        WebGL2RenderingContext::vertex_attrib_3f(self, index, v0, v1, v2)
+    }
+    fn vertex_attrib_4f(&self, index: u32, v0: f32, v1: f32, v2: f32, v3: f32) {
+        WebGL2RenderingContext::vertex_attrib_4f(self, index, v0, v1, v2, v3)
+    }
+    fn vertex_attrib_1fv(&self, index: u32, value: Vec<f32>) {
+        WebGL2RenderingContext::vertex_attrib_1fv(self, index, value)
+    }
+    fn vertex_attrib_2fv(&self, index: u32, value: Vec<f32>) {
+        WebGL2RenderingContext::vertex_attrib_2fv(self, index, value)
+    }
+    fn vertex_attrib_3fv(&self, index: u32, value: Vec<f32>) {
+        WebGL2RenderingContext::vertex_attrib_3fv(self, index, value)
+    }
+    fn vertex_attrib_4fv(&self, index: u32, value: Vec<f32>) {
+        WebGL2RenderingContext::vertex_attrib_4fv(self, index, value)
+    }
+    fn vertex_attrib_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        attribute_type: AttributeType,
+        normalized: bool,
+        stride: u8,
+        offset: i32,
+    ) {
+        WebGL2RenderingContext::vertex_attrib_pointer(
+            self,
+            index,
+            size,
+            attribute_type,
+            normalized,
+            stride,
+            offset,
+        )
+    }
+    fn clear(&self, mask: BufferBit) {
+        WebGL2RenderingContext::clear(self, mask)
+    }
+    fn draw_arrays(&self, mode: Primitives, first: u32, count: u32) {
+        WebGL2RenderingContext::draw_arrays(self, mode, first, count)
+    }
+    fn draw_elements(&self, mode: Primitives, count: u32, data_type: DataType, offset: i64) {
+        WebGL2RenderingContext::draw_elements(self, mode, count, data_type, offset)
+    }
+    fn finish(&self) {
+        WebGL2RenderingContext::finish(self)
+    }
+    fn flush(&self) {
+        WebGL2RenderingContext::flush(self)
+    }
+    fn _buffer_data(&self, target: BufferKind, src_data: ArrayData, usage: DataHint) {
+        match src_data {
+            ArrayData::I8(data) => self._buffer_data_i8(target, data, usage),
+            ArrayData::U8(data) => self._buffer_data_u8(target, data, usage),
+            ArrayData::I16(data) => self._buffer_data_i16(target, data, usage),
+            ArrayData::U16(data) => self._buffer_data_u16(target, data, usage),
+            ArrayData::I32(data) => self._buffer_data_i32(target, data, usage),
+            ArrayData::U32(data) => self._buffer_data_u32(target, data, usage),
+            ArrayData::F32(data) => self._buffer_data_f32(target, data, usage),
+            ArrayData::F64(data) => self._buffer_data_f64(target, data, usage),
+        }
+    }
+    fn _buffer_sub_data(&self, target: BufferKind, offset: i64, src_data: ArrayData) {
+        match src_data {
+            ArrayData::I8(data) => self._buffer_sub_data_i8(target, offset, data),
+            ArrayData::U8(data) => self._buffer_sub_data_u8(target, offset, data),
+            ArrayData::I16(data) => self._buffer_sub_data_i16(target, offset, data),
+            ArrayData::U16(data) => self._buffer_sub_data_u16(target, offset, data),
+            ArrayData::I32(data) => self._buffer_sub_data_i32(target, offset, data),
+            ArrayData::U32(data) => self._buffer_sub_data_u32(target, offset, data),
+            ArrayData::F32(data) => self._buffer_sub_data_f32(target, offset, data),
+            ArrayData::F64(data) => self._buffer_sub_data_f64(target, offset, data),
+        }
+    }
+    fn _tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        src_data: ArrayData,
+    ) -> Result<(), JsValue> {
+        match src_data {
+            ArrayData::I8(data) => self._tex_image_2d_i8(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::U8(data) => self._tex_image_2d_u8(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::I16(data) => self._tex_image_2d_i16(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::U16(data) => self._tex_image_2d_u16(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::I32(data) => self._tex_image_2d_i32(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::U32(data) => self._tex_image_2d_u32(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::F32(data) => self._tex_image_2d_f32(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel source",
+            )),
+        }
+    }
+    fn _tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue> {
+        match pixels {
+            ArrayData::I8(data) => self._tex_sub_image_2d_i8(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::U8(data) => self._tex_sub_image_2d_u8(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::I16(data) => self._tex_sub_image_2d_i16(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::U16(data) => self._tex_sub_image_2d_u16(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::I32(data) => self._tex_sub_image_2d_i32(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::U32(data) => self._tex_sub_image_2d_u32(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::F32(data) => self._tex_sub_image_2d_f32(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel source",
+            )),
+        }
+    }
+    fn _compressed_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_compressed_tex_image_2d(
+            self,
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            border,
+            data,
+        )
+    }
+    fn _compressed_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_compressed_tex_sub_image_2d(
+            self, target, level, xoffset, yoffset, width, height, format, data,
+        )
+    }
+    fn _tex_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_tex_image_2d_source(
+            self,
+            target,
+            level,
+            internalformat,
+            format,
+            pixel_type,
+            source.as_js_value(),
+        )
+    }
+    fn _tex_sub_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_tex_sub_image_2d_source(
+            self,
+            target,
+            level,
+            xoffset,
+            yoffset,
+            format,
+            pixel_type,
+            source.as_js_value(),
+        )
+    }
+    fn _read_pixels(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelReadFormat,
+        pixel_type: PixelType,
+        pixels: ArrayDataMut,
+    ) -> Result<(), JsValue> {
+        match pixels {
+            ArrayDataMut::I8(data) => {
+                self._read_pixels_i8(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::U8(data) => {
+                self._read_pixels_u8(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::I16(data) => {
+                self._read_pixels_i16(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::U16(data) => {
+                self._read_pixels_u16(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::I32(data) => {
+                self._read_pixels_i32(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::U32(data) => {
+                self._read_pixels_u32(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::F32(data) => {
+                self._read_pixels_f32(x, y, width, height, format, pixel_type, data)
+            }
+            ArrayDataMut::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel destination",
+            )),
+        }
+    }
+}
+
+/// Backend calls for instanced drawing.
+///
+/// Part of WebGL2, on a WebGL1 context these calls go through the `ANGLE_instanced_arrays` extension.
+pub trait InstancingBackend: Sized {
+    /// Binding for `WebGL2RenderingContext.vertexAttribDivisor()`
+    fn vertex_attrib_divisor(&self, index: u32, divisor: u32);
+    /// Binding for `WebGL2RenderingContext.drawArraysInstanced()`
+    fn draw_arrays_instanced(&self, mode: Primitives, first: i32, count: u32, instance_count: u32);
+    /// Binding for `WebGL2RenderingContext.drawElementsInstanced()`
+    fn draw_elements_instanced(
+        &self,
+        mode: Primitives,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+        instance_count: u32,
+    );
+}
+
+impl InstancingBackend for WebGL2RenderingContext {
+    fn vertex_attrib_divisor(&self, index: u32, divisor: u32) {
+        WebGL2RenderingContext::vertex_attrib_divisor(self, index, divisor)
+    }
+    fn draw_arrays_instanced(&self, mode: Primitives, first: i32, count: u32, instance_count: u32) {
+        WebGL2RenderingContext::draw_arrays_instanced(self, mode, first, count, instance_count)
+    }
+    fn draw_elements_instanced(
+        &self,
+        mode: Primitives,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+        instance_count: u32,
+    ) {
+        WebGL2RenderingContext::draw_elements_instanced(
+            self,
+            mode,
+            count,
+            data_type,
+            offset,
+            instance_count,
+        )
+    }
+}
+
+/// Backend calls for rendering to multiple color attachments at once.
+///
+/// Part of WebGL2, on a WebGL1 context these calls go through the `WEBGL_draw_buffers` extension.
+pub trait DrawBuffersBackend: Sized {
+    /// Binding for `WebGL2RenderingContext.drawBuffers()`
+    fn draw_buffers(&self, buffers: &[ColorBuffer]);
+}
+
+impl DrawBuffersBackend for WebGL2RenderingContext {
+    fn draw_buffers(&self, buffers: &[ColorBuffer]) {
+        let buffers: Vec<u32> = buffers.iter().map(|&buffer| buffer as u32).collect();
+        WebGL2RenderingContext::_draw_buffers(self, &buffers)
+    }
+}
+
+/// Backend calls for instanced drawing that starts at a base instance.
+///
+/// Provided by the `WEBGL_draw_instanced_base_vertex_base_instance` extension, which is only available on
+/// some WebGL2 contexts. The draw calls may only be made when `supports_base_instance` returns true.
+pub trait BaseInstanceBackend: Sized {
+    /// Returns true if the context supports the `WEBGL_draw_instanced_base_vertex_base_instance` extension.
+    fn supports_base_instance(&self) -> bool;
+    /// Binding for `WEBGL_draw_instanced_base_vertex_base_instance.drawArraysInstancedBaseInstanceWEBGL()`
+    fn draw_arrays_instanced_base_instance(
+        &self,
+        mode: Primitives,
+        first: i32,
+        count: u32,
+        instance_count: u32,
+        base_instance: u32,
+    );
+    /// Binding for
+    /// `WEBGL_draw_instanced_base_vertex_base_instance.drawElementsInstancedBaseVertexBaseInstanceWEBGL()`
+    #[allow(clippy::too_many_arguments)]
+    fn draw_elements_instanced_base_vertex_base_instance(
+        &self,
+        mode: Primitives,
+        count: u32,
+        index_type: IndexType,
+        offset: i64,
+        instance_count: u32,
+        base_vertex: i32,
+        base_instance: u32,
+    );
+}
+
+impl BaseInstanceBackend for WebGL2RenderingContext {
+    fn supports_base_instance(&self) -> bool {
+        self._get_base_instance_extension(BASE_INSTANCE_EXTENSION)
+            .is_some()
+    }
+    fn draw_arrays_instanced_base_instance(
+        &self,
+        mode: Primitives,
+        first: i32,
+        count: u32,
+        instance_count: u32,
+        base_instance: u32,
+    ) {
+        self._get_base_instance_extension(BASE_INSTANCE_EXTENSION)
+            .expect(
+                "WEBGL_draw_instanced_base_vertex_base_instance is not supported by this context",
+            )
+            .draw_arrays_instanced_base_instance_webgl(
+                mode,
+                first,
+                count,
+                instance_count,
+                base_instance,
+            )
+    }
+    fn draw_elements_instanced_base_vertex_base_instance(
+        &self,
+        mode: Primitives,
+        count: u32,
+        index_type: IndexType,
+        offset: i64,
+        instance_count: u32,
+        base_vertex: i32,
+        base_instance: u32,
+    ) {
+        self._get_base_instance_extension(BASE_INSTANCE_EXTENSION)
+            .expect(
+                "WEBGL_draw_instanced_base_vertex_base_instance is not supported by this context",
+            )
+            .draw_elements_instanced_base_vertex_base_instance_webgl(
+                mode,
+                count,
+                index_type,
+                offset,
+                instance_count,
+                base_vertex,
+                base_instance,
+            )
+    }
+}
+
+/// Name of the extension providing `BaseInstanceBackend`.
+const BASE_INSTANCE_EXTENSION: &str = "WEBGL_draw_instanced_base_vertex_base_instance";
+
+/// Backend calls for the context methods that were added in WebGL2.
+///
+/// Only the methods that can not be emulated on a WebGL1 context are part of this trait, instancing and
+/// multiple draw buffers are available through `InstancingBackend` and `DrawBuffersBackend`.
+pub trait WebGL2ContextBackend: ContextBackend {
+    /// Binding for `WebGL2RenderingContext.copyBufferSubData()`
+    fn copy_buffer_sub_data(
+        &self,
+        read_target: BufferKind,
+        write_target: BufferKind,
+        read_offset: i64,
+        write_offset: i64,
+        size: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.blitFramebuffer()`
+    #[allow(clippy::too_many_arguments)]
+    fn blit_framebuffer(
+        &self,
+        src_x0: i32,
+        src_y0: i32,
+        src_x1: i32,
+        src_y1: i32,
+        dst_x0: i32,
+        dst_y0: i32,
+        dst_x1: i32,
+        dst_y1: i32,
+        mask: BufferBit,
+        filter: TextureMagFilter,
+    );
+    /// Binding for `WebGL2RenderingContext.invalidateFramebuffer()`
+    fn invalidate_framebuffer(&self, target: FramebufferKind, attachments: &[Attachment]);
+    /// Binding for `WebGL2RenderingContext.readBuffer()`
+    fn read_buffer(&self, src: ColorBuffer);
+    /// Binding for `WebGL2RenderingContext.getInternalformatParameter()`
+    fn get_internal_format_parameter(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        pname: InformationType,
+    ) -> Vec<i32>;
+    /// Binding for `WebGL2RenderingContext.renderbufferStorageMultisample()`
+    fn _renderbuffer_storage_multisample(
+        &self,
+        target: RenderbufferKind,
+        samples: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.texStorage2D()`
+    fn _tex_storage_2d(
+        &self,
+        target: Texture2DKind,
+        levels: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.texStorage3D()`
+    fn _tex_storage_3d(
+        &self,
+        target: Texture3DKind,
+        levels: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+    );
+    /// Binding for `WebGLRenderingContext.texImage3D()`
+    #[allow(clippy::too_many_arguments)]
+    fn tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.texSubImage3D()`
+    #[allow(clippy::too_many_arguments)]
+    fn tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.texSubImage3D()` with a typed array as source
+    #[allow(clippy::too_many_arguments)]
+    fn _tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.compressedTexImage3D()`
+    #[allow(clippy::too_many_arguments)]
+    fn _compressed_tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.compressedTexSubImage3D()`
+    #[allow(clippy::too_many_arguments)]
+    fn _compressed_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.texImage3D()` with a `TexImageSource`
+    #[allow(clippy::too_many_arguments)]
+    fn _tex_image_3d_source(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.texSubImage3D()` with a `TexImageSource`
+    #[allow(clippy::too_many_arguments)]
+    fn _tex_sub_image_3d_source(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.copyTexSubImage3D()`
+    #[allow(clippy::too_many_arguments)]
+    fn copy_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.vertexAttribI4i()`
+    fn vertex_attrib_i_4i(&self, index: u32, v0: i32, v1: i32, v2: i32, v3: i32);
+    /// Binding for `WebGL2RenderingContext.vertexAttribI4ui()`
+    fn vertex_attrib_i_4ui(&self, index: u32, v0: u32, v1: u32, v2: u32, v3: u32);
+    /// Binding for `WebGL2RenderingContext.vertexAttribI4iv()`
+    fn vertex_attrib_i_4iv(&self, index: u32, value: Vec<i32>);
+    /// Binding for `WebGL2RenderingContext.vertexAttribI4uiv()`
+    fn vertex_attrib_i_4uiv(&self, index: u32, value: Vec<u32>);
+    /// Binding for `WebGL2RenderingContext.vertexAttribIPointer()`
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        data_type: AttributeType,
+        stride: u32,
+        offset: i64,
+    );
+    /// Binding for `WebGL2RenderingContext.drawRangeElements()`
+    fn draw_range_elements(
+        &self,
+        mode: Primitives,
+        start: u32,
+        end: u32,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+    );
+    /// Binding for `WebGL2RenderingContext.clearBufferfv()`
+    fn clear_buffer_fv(&self, buffer: BufferBit, drawbuffer: i32, values: Vec<u8>, src_offset: u32);
+    /// Binding for `WebGL2RenderingContext.clearBufferiv()`
+    fn clear_buffer_iv(&self, buffer: BufferBit, drawbuffer: i32, values: Vec<u8>, src_offset: u32);
+    /// Binding for `WebGL2RenderingContext.clearBufferuiv()`
+    fn clear_buffer_uiv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.clearBufferfv()`
+    fn clear_buffer_fi(&self, buffer: BufferBit, drawbuffer: i32, depth: f32, stencil: i32);
+    /// Binding for `WebGLRenderingContext.beginTransformFeedback()`
+    fn begin_transform_feedback(&self, primitive_mode: TransformFeedbackMode);
+    /// Binding for `WebGLRenderingContext.endTransformFeedback()`
+    fn end_transform_feedback(&self);
+    /// Binding for `WebGLRenderingContext.pauseTransformFeedback()`
+    fn pause_transform_feedback(&self);
+    /// Binding for `WebGLRenderingContext.resumeTransformFeedback()`
+    fn resume_transform_feedback(&self);
+    /// Binding for `WebGL2RenderingContext.endQuery()`
+    fn end_query(&self, target: QueryTarget);
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()`
+    fn _get_buffer_sub_data(
+        &self,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: ArrayDataMut,
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset and length
+    fn _buffer_data_range(
+        &self,
+        target: BufferKind,
+        src_data: ArrayData,
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset and length
+    fn _buffer_sub_data_range(
+        &self,
+        target: BufferKind,
+        dst_offset: i64,
+        src_data: ArrayData,
+        src_offset: u32,
+        length: u32,
+    );
+
+    /// Creates and initializes the multisampled data store of the currently bound renderbuffer.
+    ///
+    /// Fails without calling WebGL when the internal format is not renderable, see
+    /// `formats::validate_renderbuffer_storage`.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the renderbuffer.
+    /// * `samples` - specifying the number of samples of the renderbuffer.
+    /// * `internal_format` - specifying the internal format of the renderbuffer.
+    /// * `width` - specifying the width of the renderbuffer in pixels.
+    /// * `height` - specifying the height of the renderbuffer in pixels.
+    fn renderbuffer_storage_multisample(
+        &self,
+        target: RenderbufferKind,
+        samples: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<(), WebGLError> {
+        formats::validate_renderbuffer_storage(internal_format).map_err(|error| {
+            WebGLError::Format {
+                operation: "renderbuffer_storage_multisample",
+                error,
+            }
+        })?;
+        self._renderbuffer_storage_multisample(target, samples, internal_format, width, height);
+        Ok(())
+    }
+
+    /// Specifies all levels of a two-dimensional texture at once, the texture becomes immutable.
+    ///
+    /// Fails without calling WebGL when the internal format is not a sized texture format, see
+    /// `formats::validate_tex_storage`.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `levels` - specifying the number of texture levels.
+    /// * `internal_format` - specifying the internal format of the texture.
+    /// * `width` - specifying the width of the texture.
+    /// * `height` - specifying the height of the texture.
+    fn tex_storage_2d(
+        &self,
+        target: Texture2DKind,
+        levels: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<(), WebGLError> {
+        formats::validate_tex_storage(internal_format).map_err(|error| WebGLError::Format {
+            operation: "tex_storage_2d",
+            error,
+        })?;
+        self._tex_storage_2d(target, levels, internal_format, width, height);
+        Ok(())
+    }
+
+    /// Specifies all levels of a three-dimensional or array texture at once, the texture becomes immutable.
+    ///
+    /// Fails without calling WebGL when the internal format is not a sized texture format, see
+    /// `formats::validate_tex_storage`.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `levels` - specifying the number of texture levels.
+    /// * `internal_format` - specifying the internal format of the texture.
+    /// * `width` - specifying the width of the texture.
+    /// * `height` - specifying the height of the texture.
+    /// * `depth` - specifying the depth of the texture or the number of layers of an array texture.
+    fn tex_storage_3d(
+        &self,
+        target: Texture3DKind,
+        levels: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Result<(), WebGLError> {
+        formats::validate_tex_storage(internal_format).map_err(|error| WebGLError::Format {
+            operation: "tex_storage_3d",
+            error,
+        })?;
+        self._tex_storage_3d(target, levels, internal_format, width, height, depth);
+        Ok(())
+    }
+
+    /// Specifies a three-dimensional or array texture image in a compressed format.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail.
+    /// * `internalformat` - specifying the compressed format of the image.
+    /// * `width` - specifying the width of the texture.
+    /// * `height` - specifying the height of the texture.
+    /// * `depth` - specifying the depth of the texture or the number of layers of an array texture.
+    /// * `data` - the compressed image, layer after layer.
+    ///
+    /// Fails without calling WebGL when the length of the data is not the size of the compressed image, see
+    /// `formats::validate_compressed_data`.
+    #[allow(clippy::too_many_arguments)]
+    fn compressed_tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        depth: u32,
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        formats::validate_compressed_data(internalformat, width, height, depth, data.len())
+            .map_err(|error| WebGLError::Format {
+                operation: "compressed_tex_image_3d",
+                error,
+            })?;
+        self._compressed_tex_image_3d(target, level, internalformat, width, height, depth, 0, data)
+            .map_err(|exception| WebGLError::exception("compressed_tex_image_3d", exception))
+    }
+
+    /// Replaces a box of a three-dimensional or array texture with compressed data.
+    ///
+    /// Fails without calling WebGL like `compressed_tex_sub_image_2d`.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail.
+    /// * `xoffset` - specifying the x offset of the box, a multiple of the block width.
+    /// * `yoffset` - specifying the y offset of the box, a multiple of the block height.
+    /// * `zoffset` - specifying the z offset of the box.
+    /// * `width` - specifying the width of the box.
+    /// * `height` - specifying the height of the box.
+    /// * `depth` - specifying the depth of the box.
+    /// * `format` - specifying the compressed format of the texture.
+    /// * `data` - the compressed box, layer after layer.
+    #[allow(clippy::too_many_arguments)]
+    fn compressed_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), WebGLError> {
+        formats::validate_compressed_region(
+            format,
+            xoffset,
+            yoffset,
+            width,
+            height,
+            xoffset.saturating_add(width),
+            yoffset.saturating_add(height),
+        )
+        .and_then(|_| formats::validate_compressed_data(format, width, height, depth, data.len()))
+        .map_err(|error| WebGLError::Format {
+            operation: "compressed_tex_sub_image_3d",
+            error,
+        })?;
+        self._compressed_tex_sub_image_3d(
+            target, level, xoffset, yoffset, zoffset, width, height, depth, format, data,
+        )
+        .map_err(|exception| WebGLError::exception("compressed_tex_sub_image_3d", exception))
+    }
+
+    /// Specifies a three-dimensional texture image or a texture array from a DOM object.
+    ///
+    /// The slices or layers are stacked vertically in the source, its width is the width of the texture and
+    /// its height divided by `depth` the height. Rows that are left over are not uploaded.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target) of the active texture.
+    /// * `level` - specifying the level of detail. Level 0 is the base image level and level n is the nth
+    ///   mipmap reduction level.
+    /// * `internalformat` - specifying the color components in the texture.
+    /// * `depth` - specifying the depth of the texture or the number of layers.
+    /// * `format` - specifying the format of the texel data.
+    /// * `pixel_type` - specifying the data type of the texel data.
+    /// * `source` - the image, canvas, video frame, bitmap or image data that is uploaded. It is flipped
+    ///   and premultiplied according to `UNPACK_FLIP_Y_WEBGL` and `UNPACK_PREMULTIPLY_ALPHA_WEBGL`.
+    ///
+    /// Fails without calling WebGL when the format and type can not be uploaded to the internal format, see
+    /// `formats::validate_tex_image`.
+    #[allow(clippy::too_many_arguments)]
+    fn tex_image_3d_source<S: TexImageSource + ?Sized>(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &S,
+    ) -> Result<(), WebGLError> {
+        formats::validate_tex_image(internalformat, format, pixel_type).map_err(|error| {
+            WebGLError::Format {
+                operation: "tex_image_3d_source",
+                error,
+            }
+        })?;
+        self._tex_image_3d_source(
+            target,
+            level,
+            internalformat,
+            source.width(),
+            source.height() / depth.max(1),
+            depth,
+            0,
+            format,
+            pixel_type,
+            source.source(),
+        )
+        .map_err(|exception| WebGLError::exception("tex_image_3d_source", exception))
+    }
+
+    /// Returns the number of samples of the image of the currently bound renderbuffer.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the target renderbuffer object.
+    fn renderbuffer_samples(&self, target: RenderbufferKind) -> i32 {
+        self._get_renderbuffer_parameter_i32(target, RenderbufferParameter::Samples)
+    }
+
+    /// Returns the texture mipmap level
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_base_level(&self, target: TextureKind) -> i32 {
+        self._get_tex_parameter_i32(target, TextureParameter::BaseLevel)
+    }
+
+    /// Returns the texture comparison function
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_compare_func(&self, target: TextureKind) -> DepthTest {
+        self._get_tex_parameter_enum4(target, TextureParameter::CompareFunc)
+    }
+
+    /// Returns the texture comparison mode
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_compare_mode(&self, target: TextureKind) -> CompareMode {
+        self._get_tex_parameter_enum5(target, TextureParameter::CompareMode)
+    }
+
+    /// Returns whether the texture format and size is immutable
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_immutable_format(&self, target: TextureKind) -> bool {
+        self._get_tex_parameter_bool(target, TextureParameter::ImmutableFormat)
+    }
+
+    /// Returns the immutable levels for a texture
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_immutable_levels(&self, target: TextureKind) -> u32 {
+        self._get_tex_parameter_u32(target, TextureParameter::ImmutableLevels)
+    }
+
+    /// Returns the maximum texture mipmap array level
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_max_level(&self, target: TextureKind) -> i32 {
+        self._get_tex_parameter_i32(target, TextureParameter::MaxLevel)
+    }
+
+    /// Returns the texture maximum level-of-detail value
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_max_lod(&self, target: TextureKind) -> f32 {
+        self._get_tex_parameter_f32(target, TextureParameter::MaxLod)
+    }
+
+    /// Returns the texture minimum level-of-detail value
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_min_lod(&self, target: TextureKind) -> f32 {
+        self._get_tex_parameter_f32(target, TextureParameter::MinLod)
+    }
+
+    /// Returns the wrapping function for texture coordinate r
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target).
+    fn texture_wrap_r(&self, target: TextureKind) -> TextureWrap {
+        self._get_tex_parameter_enum3(target, TextureParameter::WrapS)
+    }
+
+    fn get_buffer_sub_data<B: BufferMut + ?Sized>(
+        &self,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut B,
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue> {
+        self._get_buffer_sub_data(target, src_offset, dst_data.data_mut(), dst_offset, length)
+    }
+
+    /// Initializes and creates the buffer object's data store from a range of the source data.
+    ///
+    /// The range is given in bytes, so it means the same for a slice of numbers and a slice of vertex
+    /// structs (which is viewed as bytes, see `Pod`).
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target)
+    /// * `src_data` - the source data to be stored in the buffer
+    /// * `usage` - specifying the usage pattern of the data store.
+    /// * `src_byte_offset` - specifying the byte offset in `src_data` to start reading from.
+    /// * `byte_length` - specifying the number of bytes to store, 0 stores everything after
+    ///   `src_byte_offset`.
+    ///
+    /// Fails without calling WebGL when the range does not fit in the data or does not start and end on
+    /// one of its elements.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let data = vec![0.0f32; 4];
+    /// gl.buffer_data_range(BufferKind::Array, &data, DataHint::StaticDraw, 4, 8)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     gl.take_calls()[0].to_string(),
+    ///     "bufferData(Array, [f32; 4], StaticDraw, 1, 2)"
+    /// );
+    ///
+    /// // past the end of the data and in the middle of an element
+    /// assert!(gl
+    ///     .buffer_data_range(BufferKind::Array, &data, DataHint::StaticDraw, 12, 8)
+    ///     .is_err());
+    /// assert!(gl
+    ///     .buffer_data_range(BufferKind::Array, &data, DataHint::StaticDraw, 6, 0)
+    ///     .is_err());
+    /// assert!(gl.calls().is_empty());
+    /// ```
+    fn buffer_data_range<B: Buffer + ?Sized>(
+        &self,
+        target: BufferKind,
+        src_data: &B,
+        usage: DataHint,
+        src_byte_offset: u32,
+        byte_length: u32,
+    ) -> Result<(), WebGLError> {
+        let data = src_data.data();
+        let (src_offset, length) =
+            element_range("buffer_data_range", &data, src_byte_offset, byte_length)?;
+        self._buffer_data_range(target, data, usage, src_offset, length);
+        Ok(())
+    }
+
+    /// Updates a subset of a buffer object's data store from a range of the source data.
+    ///
+    /// The range is given in bytes, see `buffer_data_range`.
+    ///
+    /// # Arguments
+    /// * `target` - specifying the binding point (target)
+    /// * `dst_offset` - specifying an offset in bytes where the data replacement will start.
+    /// * `src_data` - the source data to be stored in the buffer
+    /// * `src_byte_offset` - specifying the byte offset in `src_data` to start reading from.
+    /// * `byte_length` - specifying the number of bytes to store, 0 stores everything after
+    ///   `src_byte_offset`.
+    ///
+    /// Fails without calling WebGL when the range does not fit in the data or does not start and end on
+    /// one of its elements.
+    fn buffer_sub_data_range<B: Buffer + ?Sized>(
+        &self,
+        target: BufferKind,
+        dst_offset: i64,
+        src_data: &B,
+        src_byte_offset: u32,
+        byte_length: u32,
+    ) -> Result<(), WebGLError> {
+        let data = src_data.data();
+        let (src_offset, length) =
+            element_range("buffer_sub_data_range", &data, src_byte_offset, byte_length)?;
+        self._buffer_sub_data_range(target, dst_offset, data, src_offset, length);
+        Ok(())
+    }
+}
+
+impl WebGL2ContextBackend for WebGL2RenderingContext {
+    fn copy_buffer_sub_data(
+        &self,
+        read_target: BufferKind,
+        write_target: BufferKind,
+        read_offset: i64,
+        write_offset: i64,
+        size: u32,
+    ) {
+        WebGL2RenderingContext::copy_buffer_sub_data(
+            self,
+            read_target,
+            write_target,
+            read_offset,
+            write_offset,
+            size,
+        )
+    }
+    fn blit_framebuffer(
+        &self,
+        src_x0: i32,
+        src_y0: i32,
+        src_x1: i32,
+        src_y1: i32,
+        dst_x0: i32,
+        dst_y0: i32,
+        dst_x1: i32,
+        dst_y1: i32,
+        mask: BufferBit,
+        filter: TextureMagFilter,
+    ) {
+        WebGL2RenderingContext::blit_framebuffer(
+            self, src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+        )
+    }
+    fn invalidate_framebuffer(&self, target: FramebufferKind, attachments: &[Attachment]) {
+        let attachments: Vec<u32> = attachments
+            .iter()
+            .map(|&attachment| attachment as u32)
+            .collect();
+        WebGL2RenderingContext::_invalidate_framebuffer(self, target, &attachments)
+    }
+    fn read_buffer(&self, src: ColorBuffer) {
+        WebGL2RenderingContext::read_buffer(self, src)
+    }
+    fn get_internal_format_parameter(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        pname: InformationType,
+    ) -> Vec<i32> {
+        WebGL2RenderingContext::get_internal_format_parameter(self, target, internal_format, pname)
+    }
+    fn _renderbuffer_storage_multisample(
+        &self,
+        target: RenderbufferKind,
+        samples: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        WebGL2RenderingContext::renderbuffer_storage_multisample(
+            self,
+            target,
+            samples,
+            internal_format,
+            width,
+            height,
+        )
+    }
+    fn _tex_storage_2d(
+        &self,
+        target: Texture2DKind,
+        levels: u32,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        WebGL2RenderingContext::tex_storage_2d(self, target, levels, internal_format, width, height)
+    }
+    fn _tex_storage_3d(
+        &self,
+        target: Texture3DKind,
+        levels: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) {
+        WebGL2RenderingContext::tex_storage_3d(
+            self,
+            target,
+            levels,
+            internalformat,
+            width,
+            height,
+            depth,
+        )
+    }
+    fn tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    ) {
+        WebGL2RenderingContext::tex_image_3d(
+            self,
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            depth,
+            border,
+            format,
+            data_type,
+            src_data,
+            src_offset,
+        )
+    }
+    fn tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        data_type: PixelType,
+        src_data: Vec<u8>,
+        src_offset: u32,
+    ) {
+        WebGL2RenderingContext::tex_sub_image_3d(
+            self, target, level, xoffset, yoffset, zoffset, width, height, depth, format,
+            data_type, src_data, src_offset,
+        )
+    }
+    fn _tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue> {
+        match pixels {
+            ArrayData::I8(data) => self._tex_sub_image_3d_i8(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::U8(data) => self._tex_sub_image_3d_u8(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::I16(data) => self._tex_sub_image_3d_i16(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::U16(data) => self._tex_sub_image_3d_u16(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::I32(data) => self._tex_sub_image_3d_i32(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::U32(data) => self._tex_sub_image_3d_u32(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::F32(data) => self._tex_sub_image_3d_f32(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, pixel_type,
+                data,
+            ),
+            ArrayData::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel source",
+            )),
+        }
+    }
+    fn _compressed_tex_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_compressed_tex_image_3d(
+            self,
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            depth,
+            border,
+            data,
+        )
+    }
+    fn _compressed_tex_sub_image_3d(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_compressed_tex_sub_image_3d(
+            self, target, level, xoffset, yoffset, zoffset, width, height, depth, format, data,
+        )
+    }
+    fn _tex_image_3d_source(
+        &self,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        WebGL2RenderingContext::_tex_image_3d_source(
+            self,
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            depth,
+            border,
+            format,
+            pixel_type,
+            source.as_js_value(),
+        )
+    }
+    fn _tex_sub_image_3d_source(
         &self,
-        target: TextureBindPoint,
+        target: Texture3DKind,
         level: u32,
         xoffset: u32,
         yoffset: u32,
+        zoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        depth: u32,
+        format: PixelFormat,
         pixel_type: PixelType,
-        pixels: &I,
+        source: ImageSource,
     ) -> Result<(), JsValue> {
-        pixels.tex_sub_image_2d(
-            self, target, level, xoffset, yoffset, width, height, format, pixel_type,
+        WebGL2RenderingContext::_tex_sub_image_3d_source(
+            self,
+            target,
+            level,
+            xoffset,
+            yoffset,
+            zoffset,
+            width,
+            height,
+            depth,
+            format,
+            pixel_type,
+            source.as_js_value(),
         )
     }
-
-    /// Reads a block of pixels from a specified rectangle of the current color framebuffer into an array object.
-    ///
-    /// # Arguments
-    /// * `x` - specifying the first horizontal pixel that is read from the lower left corner of a rectangular block of pixels.
-    /// * `y` - specifying the first vertical pixel that is read from the lower left corner of a rectangular block of pixels.
-    /// * `width` - specifying the width of the rectangle.
-    /// * `height` - specifying the height of the rectangle.
-    /// * `format` - specifying the format of the pixel data.
-    /// * `pixel_type` - specifying the data type of the pixel data.
-    /// * `pixels` - An array object to read data into. The array type must match the type of the type parameter.
-    pub fn read_pixels<I: Image>(
+    fn copy_tex_sub_image_3d(
         &self,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
         x: u32,
         y: u32,
         width: u32,
         height: u32,
-        format: PixelReadFormat,
-        pixel_type: PixelType,
-        pixels: &mut I,
-    ) -> Result<(), JsValue> {
-        pixels.read_pixels(self, x, y, width, height, format, pixel_type)
+    ) {
+        WebGL2RenderingContext::copy_tex_sub_image_3d(
+            self, target, level, xoffset, yoffset, zoffset, x, y, width, height,
+        )
     }
-
-    pub fn get_buffer_sub_data<B: Buffer>(
+    fn vertex_attrib_i_4i(&self, index: u32, v0: i32, v1: i32, v2: i32, v3: i32) {
+        WebGL2RenderingContext::vertex_attrib_i_4i(self, index, v0, v1, v2, v3)
+    }
+    fn vertex_attrib_i_4ui(&self, index: u32, v0: u32, v1: u32, v2: u32, v3: u32) {
+        WebGL2RenderingContext::vertex_attrib_i_4ui(self, index, v0, v1, v2, v3)
+    }
+    fn vertex_attrib_i_4iv(&self, index: u32, value: Vec<i32>) {
+        WebGL2RenderingContext::vertex_attrib_i_4iv(self, index, value)
+    }
+    fn vertex_attrib_i_4uiv(&self, index: u32, value: Vec<u32>) {
+        WebGL2RenderingContext::vertex_attrib_i_4uiv(self, index, value)
+    }
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        data_type: AttributeType,
+        stride: u32,
+        offset: i64,
+    ) {
+        WebGL2RenderingContext::vertex_attrib_i_pointer(
+            self, index, size, data_type, stride, offset,
+        )
+    }
+    fn draw_range_elements(
+        &self,
+        mode: Primitives,
+        start: u32,
+        end: u32,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+    ) {
+        WebGL2RenderingContext::draw_range_elements(
+            self, mode, start, end, count, data_type, offset,
+        )
+    }
+    fn clear_buffer_fv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    ) {
+        WebGL2RenderingContext::clear_buffer_fv(self, buffer, drawbuffer, values, src_offset)
+    }
+    fn clear_buffer_iv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    ) {
+        WebGL2RenderingContext::clear_buffer_iv(self, buffer, drawbuffer, values, src_offset)
+    }
+    fn clear_buffer_uiv(
+        &self,
+        buffer: BufferBit,
+        drawbuffer: i32,
+        values: Vec<u8>,
+        src_offset: u32,
+    ) {
+        WebGL2RenderingContext::clear_buffer_uiv(self, buffer, drawbuffer, values, src_offset)
+    }
+    fn clear_buffer_fi(&self, buffer: BufferBit, drawbuffer: i32, depth: f32, stencil: i32) {
+        WebGL2RenderingContext::clear_buffer_fi(self, buffer, drawbuffer, depth, stencil)
+    }
+    fn begin_transform_feedback(&self, primitive_mode: TransformFeedbackMode) {
+        WebGL2RenderingContext::begin_transform_feedback(self, primitive_mode)
+    }
+    fn end_transform_feedback(&self) {
+        WebGL2RenderingContext::end_transform_feedback(self)
+    }
+    fn pause_transform_feedback(&self) {
+        WebGL2RenderingContext::pause_transform_feedback(self)
+    }
+    fn resume_transform_feedback(&self) {
+        WebGL2RenderingContext::resume_transform_feedback(self)
+    }
+    fn end_query(&self, target: QueryTarget) {
+        WebGL2RenderingContext::end_query(self, target)
+    }
+    fn _get_buffer_sub_data(
         &self,
         target: BufferKind,
         src_offset: i64,
-        dst_data: &mut B,
+        dst_data: ArrayDataMut,
         dst_offset: u32,
         length: u32,
     ) -> Result<(), JsValue> {
-        dst_data.get_buffer_sub_data(self, target, src_offset, dst_offset, length)
+        match dst_data {
+            ArrayDataMut::I8(data) => {
+                self._get_buffer_sub_data_i8(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::U8(data) => {
+                self._get_buffer_sub_data_u8(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::I16(data) => {
+                self._get_buffer_sub_data_i16(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::U16(data) => {
+                self._get_buffer_sub_data_u16(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::I32(data) => {
+                self._get_buffer_sub_data_i32(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::U32(data) => {
+                self._get_buffer_sub_data_u32(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::F32(data) => {
+                self._get_buffer_sub_data_f32(target, src_offset, data, dst_offset, length)
+            }
+            ArrayDataMut::F64(data) => {
+                self._get_buffer_sub_data_f64(target, src_offset, data, dst_offset, length)
+            }
+        }
+    }
+    fn _buffer_data_range(
+        &self,
+        target: BufferKind,
+        src_data: ArrayData,
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    ) {
+        match src_data {
+            ArrayData::I8(data) => {
+                self._buffer_data_range_i8(target, data, usage, src_offset, length)
+            }
+            ArrayData::U8(data) => {
+                self._buffer_data_range_u8(target, data, usage, src_offset, length)
+            }
+            ArrayData::I16(data) => {
+                self._buffer_data_range_i16(target, data, usage, src_offset, length)
+            }
+            ArrayData::U16(data) => {
+                self._buffer_data_range_u16(target, data, usage, src_offset, length)
+            }
+            ArrayData::I32(data) => {
+                self._buffer_data_range_i32(target, data, usage, src_offset, length)
+            }
+            ArrayData::U32(data) => {
+                self._buffer_data_range_u32(target, data, usage, src_offset, length)
+            }
+            ArrayData::F32(data) => {
+                self._buffer_data_range_f32(target, data, usage, src_offset, length)
+            }
+            ArrayData::F64(data) => {
+                self._buffer_data_range_f64(target, data, usage, src_offset, length)
+            }
+        }
+    }
+    fn _buffer_sub_data_range(
+        &self,
+        target: BufferKind,
+        dst_offset: i64,
+        src_data: ArrayData,
+        src_offset: u32,
+        length: u32,
+    ) {
+        match src_data {
+            ArrayData::I8(data) => {
+                self._buffer_sub_data_range_i8(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::U8(data) => {
+                self._buffer_sub_data_range_u8(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::I16(data) => {
+                self._buffer_sub_data_range_i16(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::U16(data) => {
+                self._buffer_sub_data_range_u16(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::I32(data) => {
+                self._buffer_sub_data_range_i32(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::U32(data) => {
+                self._buffer_sub_data_range_u32(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::F32(data) => {
+                self._buffer_sub_data_range_f32(target, dst_offset, data, src_offset, length)
+            }
+            ArrayData::F64(data) => {
+                self._buffer_sub_data_range_f64(target, dst_offset, data, src_offset, length)
+            }
+        }
     }
+}
 
-    // TODO loading -> tex_image_3d, tex_sub_image_3d, compressed_tex_image_2d, compressed_tex_sub_image_2d, compressed_tex_image_3d, compressed_tex_sub_image_3d, clear_buffer_uiv, clear_buffer_iv, clear_buffer_fv
+/// Describes the range and precision of a shader numeric format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WebGLRSShaderPrecisionFormat {
+    /// The base 2 log of the absolute value of the minimum value that can be represented.
+    pub range_min: u32,
+    /// The base 2 log of the absolute value of the maximum value that can be represented.
+    pub range_max: u32,
+    /// The number of bits of precision that can be represented. For integer formats this value is always 0.
+    pub precision: u32,
+}
+
+impl From<WebGLShaderPrecisionFormat> for WebGLRSShaderPrecisionFormat {
+    fn from(format: WebGLShaderPrecisionFormat) -> Self {
+        WebGLRSShaderPrecisionFormat {
+            range_min: format.range_min(),
+            range_max: format.range_max(),
+            precision: format.precision(),
+        }
+    }
 }
 
 /// WebGL2RenderingContext
@@ -405,6 +2955,10 @@ extern "C" {
     #[wasm_bindgen(method, js_name = isContextLost)]
     pub fn is_context_lost(this: &WebGL2RenderingContext) -> bool;
 
+    /// Binding for `WebGLRenderingContext.getSupportedExtensions()`, returns `None` if the context is lost
+    #[wasm_bindgen(method, js_name = getSupportedExtensions)]
+    fn _get_supported_extensions(this: &WebGL2RenderingContext) -> Option<StringArray>;
+
     /// The `WebGLRenderingContext.scissor()` method of the WebGL API sets a scissor box, which limits
     /// the drawing to a specified rectangle.
     #[wasm_bindgen(method)]
@@ -531,11 +3085,70 @@ extern "C" {
     #[wasm_bindgen(method, js_name = frontFace)]
     pub fn front_face(this: &WebGL2RenderingContext, mode: FrontFaceDirection);
 
-    /// The `WebGLRenderingContext.getParameter()` method of the WebGL API returns a value for the
-    /// passed parameter name.
-    //#[wasm_bindgen(method, js_name = getParameter)]
-    // TODO save for later, this is a very convoluted method
-    //pub fn get_parameter(this: &WebGL2RenderingContext, pname: )
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `bool`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_bool(this: &WebGL2RenderingContext, pname: Parameter) -> bool;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `i32`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_i32(this: &WebGL2RenderingContext, pname: Parameter) -> i32;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `u32`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_u32(this: &WebGL2RenderingContext, pname: Parameter) -> u32;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `f32`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_f32(this: &WebGL2RenderingContext, pname: Parameter) -> f32;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `String`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_string(this: &WebGL2RenderingContext, pname: Parameter) -> String;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<i32>`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_i32_array(this: &WebGL2RenderingContext, pname: Parameter) -> Vec<i32>;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<u32>`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_u32_array(this: &WebGL2RenderingContext, pname: Parameter) -> Vec<u32>;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<f32>`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_f32_array(this: &WebGL2RenderingContext, pname: Parameter) -> Vec<f32>;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<bool>`, the booleans
+    /// are returned as `0` or `1` because they are copied into a `Uint8Array`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_bool_array(this: &WebGL2RenderingContext, pname: Parameter) -> Vec<u8>;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `BlendEquation`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum1(this: &WebGL2RenderingContext, pname: Parameter) -> BlendEquation;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `BlendMode`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum2(this: &WebGL2RenderingContext, pname: Parameter) -> BlendMode;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Culling`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum3(this: &WebGL2RenderingContext, pname: Parameter) -> Culling;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `FrontFaceDirection`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum4(this: &WebGL2RenderingContext, pname: Parameter) -> FrontFaceDirection;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `DepthTest`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum5(this: &WebGL2RenderingContext, pname: Parameter) -> DepthTest;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `StencilTest`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum6(this: &WebGL2RenderingContext, pname: Parameter) -> StencilTest;
+
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `StencilAction`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_enum7(this: &WebGL2RenderingContext, pname: Parameter) -> StencilAction;
 
     /// The `WebGLRenderingContext.getError()` method of the WebGL API returns error information.
     #[wasm_bindgen(method, js_name = getError)]
@@ -551,7 +3164,7 @@ extern "C" {
     ///
     /// By default, all capabilities except `gl.DITHER` are disabled.
     #[wasm_bindgen(method, js_name = isEnabled)]
-    pub fn is_enabled(this: &WebGL2RenderingContext, cap: Flag);
+    pub fn is_enabled(this: &WebGL2RenderingContext, cap: Flag) -> bool;
 
     /// The `WebGLRenderingContext.lineWidth()` method of the WebGL API sets the line width of rasterized lines.
     #[wasm_bindgen(method, js_name = lineWidth)]
@@ -638,7 +3251,7 @@ extern "C" {
 
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[u8]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_u8(
+    fn _buffer_data_u8(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[u8],
@@ -646,7 +3259,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[i8]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_i8(
+    fn _buffer_data_i8(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[i8],
@@ -654,7 +3267,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[u16]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_u16(
+    fn _buffer_data_u16(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[u16],
@@ -662,7 +3275,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[i16]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_i16(
+    fn _buffer_data_i16(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[i16],
@@ -670,7 +3283,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[u32]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_u32(
+    fn _buffer_data_u32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[u32],
@@ -678,7 +3291,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[i32]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_i32(
+    fn _buffer_data_i32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[i32],
@@ -686,7 +3299,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[f32]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_f32(
+    fn _buffer_data_f32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[f32],
@@ -694,18 +3307,16 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferData()` when data has type `[f64]`
     #[wasm_bindgen(method, js_name = bufferData)]
-    pub(crate) fn _buffer_data_f64(
+    fn _buffer_data_f64(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         src_data: &[f64],
         usage: DataHint,
     );
 
-    //TODO buffer_data, buffer_sub_data with offset
-
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[u8]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_u8(
+    fn _buffer_sub_data_u8(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -713,7 +3324,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[i8]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_i8(
+    fn _buffer_sub_data_i8(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -721,7 +3332,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[u16]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_u16(
+    fn _buffer_sub_data_u16(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -729,7 +3340,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[i16]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_i16(
+    fn _buffer_sub_data_i16(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -737,7 +3348,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[u32]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_u32(
+    fn _buffer_sub_data_u32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -745,7 +3356,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[i32]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_i32(
+    fn _buffer_sub_data_i32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -753,7 +3364,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[f32]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_f32(
+    fn _buffer_sub_data_f32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -761,7 +3372,7 @@ extern "C" {
     );
     /// Binding for `WebGLRenderingContext.bufferSubData()` when data type is `[f64]`
     #[wasm_bindgen(method, js_name = bufferSubData)]
-    pub(crate) fn _buffer_sub_data_f64(
+    fn _buffer_sub_data_f64(
         this: &WebGL2RenderingContext,
         target: BufferKind,
         offset: i64,
@@ -786,15 +3397,14 @@ extern "C" {
     /// The `WebGLRenderingContext.checkFramebufferStatus()` method of the WebGL API returns the completeness
     /// status of the WebGLFramebuffer object.
     #[wasm_bindgen(method, js_name = checkFramebufferStatus)]
-    pub fn check_framebuffer_status(this: &WebGL2RenderingContext, target: FramebufferKind)
-        -> bool;
-
-    // TODO getFramebufferAttachmentParameter()
-    // later because of awful return structure
+    pub fn check_framebuffer_status(
+        this: &WebGL2RenderingContext,
+        target: FramebufferKind,
+    ) -> FramebufferStatus;
 
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[u8]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_u8(
+    fn _read_pixels_u8(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -806,7 +3416,7 @@ extern "C" {
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[i8]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_i8(
+    fn _read_pixels_i8(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -818,7 +3428,7 @@ extern "C" {
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[u16]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_u16(
+    fn _read_pixels_u16(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -830,7 +3440,7 @@ extern "C" {
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[i16]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_i16(
+    fn _read_pixels_i16(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -842,7 +3452,7 @@ extern "C" {
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[u32]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_u32(
+    fn _read_pixels_u32(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -854,7 +3464,7 @@ extern "C" {
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[i32]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_i32(
+    fn _read_pixels_i32(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -866,7 +3476,7 @@ extern "C" {
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.readPixels()` when type of data is `[f32]`
     #[wasm_bindgen(method, js_name = readPixels, catch)]
-    pub(crate) fn _read_pixels_f32(
+    fn _read_pixels_f32(
         this: &WebGL2RenderingContext,
         x: u32,
         y: u32,
@@ -890,7 +3500,7 @@ extern "C" {
         this: &WebGL2RenderingContext,
         target: RenderbufferKind,
         pname: RenderbufferParameter,
-    ) -> RenderbufferFormat;
+    ) -> InternalFormat;
 
     /// The `WebGLRenderingContext.renderbufferStorage()` method of the WebGL API creates and initializes
     /// a renderbuffer object's data store.
@@ -898,7 +3508,7 @@ extern "C" {
     pub fn renderbuffer_storage(
         this: &WebGL2RenderingContext,
         target: RenderbufferKind,
-        internalFormat: RenderbufferFormat,
+        internalFormat: InternalFormat,
         width: u32,
         height: u32,
     );
@@ -1008,106 +3618,106 @@ extern "C" {
 
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[u8]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_u8(
+    fn _tex_image_2d_u8(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[u8],
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[8]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_i8(
+    fn _tex_image_2d_i8(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[i8],
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[u16]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_u16(
+    fn _tex_image_2d_u16(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[u16],
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[i16]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_i16(
+    fn _tex_image_2d_i16(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[i16],
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[u32]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_u32(
+    fn _tex_image_2d_u32(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[u32],
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[i32]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_i32(
+    fn _tex_image_2d_i32(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[i32],
     ) -> Result<(), JsValue>;
     /// Binding for `WebGLRenderingContext.texImage2D()` if data has type `[f32]`.
     #[wasm_bindgen(method, js_name = texImage2D, catch)]
-    pub(crate) fn _tex_image_2d_f32(
+    fn _tex_image_2d_f32(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: PixelCopyFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         border: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         src_data: &[f32],
     ) -> Result<(), JsValue>;
 
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[u8]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_u8(
+    fn _tex_sub_image_2d_u8(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1115,13 +3725,13 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[u8],
     ) -> Result<(), JsValue>;
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[i8]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_i8(
+    fn _tex_sub_image_2d_i8(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1129,13 +3739,13 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[i8],
     ) -> Result<(), JsValue>;
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[u16]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_u16(
+    fn _tex_sub_image_2d_u16(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1143,13 +3753,13 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[u16],
     ) -> Result<(), JsValue>;
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[i16]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_i16(
+    fn _tex_sub_image_2d_i16(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1157,13 +3767,13 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[i16],
     ) -> Result<(), JsValue>;
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[u32]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_u32(
+    fn _tex_sub_image_2d_u32(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1171,13 +3781,13 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[u32],
     ) -> Result<(), JsValue>;
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[i32]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_i32(
+    fn _tex_sub_image_2d_i32(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1185,13 +3795,13 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[i32],
     ) -> Result<(), JsValue>;
     // Binding for `WebGLRenderingContext.texSubImage2D()` if data has type `[f32]`.
     #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
-    pub(crate) fn _tex_sub_image_2d_f32(
+    fn _tex_sub_image_2d_f32(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
@@ -1199,7 +3809,7 @@ extern "C" {
         yoffset: u32,
         width: u32,
         height: u32,
-        format: PixelCopyFormat,
+        format: PixelFormat,
         pixel_type: PixelType,
         pixels: &[f32],
     ) -> Result<(), JsValue>;
@@ -1215,7 +3825,7 @@ extern "C" {
     );
 
     /// The `WebGLRenderingContext.texParameter[fi]()` methods of the WebGL API set texture parameters.
-    #[wasm_bindgen(method, js_name = texParameterf)]
+    #[wasm_bindgen(method, js_name = texParameteri)]
     pub fn tex_parameter_i(
         this: &WebGL2RenderingContext,
         target: TextureKind,
@@ -1226,7 +3836,7 @@ extern "C" {
     /// The `WebGLRenderingContext.getShaderPrecisionFormat()` method of the WebGL API returns a new WebGLShaderPrecisionFormat
     /// object describing the range and precision for the specified shader numeric format.
     #[wasm_bindgen(method, js_name = getShaderPrecisionFormat)]
-    pub fn get_shader_precision_format(
+    fn _get_shader_precision_format(
         this: &WebGL2RenderingContext,
         shader_type: ShaderKind,
         precision_type: ShaderPrecision,
@@ -1360,87 +3970,248 @@ extern "C" {
         writeOffset: i64,
         size: u32,
     );
-
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[u8]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_u8(
+
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[u8]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_u8(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [u8],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[i8]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_i8(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [i8],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[u16]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_u16(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [u16],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[i16]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_i16(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [i16],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[u32]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_u32(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [u32],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[i32]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_i32(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [i32],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[f32]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_f32(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [f32],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[f64]`
+    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
+    fn _get_buffer_sub_data_f64(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_offset: i64,
+        dst_data: &mut [f64],
+        dst_offset: u32,
+        length: u32,
+    ) -> Result<(), JsValue>;
+
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[u8]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_u8(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[u8],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[i8]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_i8(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[i8],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[u16]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_u16(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[u16],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[i16]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_i16(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[i16],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[u32]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_u32(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[u32],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[i32]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_i32(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[i32],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[f32]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_f32(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[f32],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferData()` with a source offset when data type is `[f64]`
+    #[wasm_bindgen(method, js_name = bufferData)]
+    fn _buffer_data_range_f64(
+        this: &WebGL2RenderingContext,
+        target: BufferKind,
+        src_data: &[f64],
+        usage: DataHint,
+        src_offset: u32,
+        length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[u8]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_u8(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [u8],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[u8],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[i8]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_i8(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[i8]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_i8(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [i8],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[i8],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[u16]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_u16(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[u16]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_u16(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [u16],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[u16],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[i16]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_i16(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[i16]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_i16(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [i16],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[i16],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[u32]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_u32(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[u32]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_u32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [u32],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[u32],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[i32]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_i32(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[i32]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_i32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [i32],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[i32],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[f32]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_f32(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[f32]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_f32(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [f32],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[f32],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
-    /// Binding for `WebGL2RenderingContext.getBufferSubData()` when data type is `[f64]`
-    #[wasm_bindgen(method, js_name = getBufferSubData, catch)]
-    pub(crate) fn _get_buffer_sub_data_f64(
+    );
+    /// Binding for `WebGL2RenderingContext.bufferSubData()` with a source offset when data type is `[f64]`
+    #[wasm_bindgen(method, js_name = bufferSubData)]
+    fn _buffer_sub_data_range_f64(
         this: &WebGL2RenderingContext,
         target: BufferKind,
-        src_offset: i64,
-        dst_data: &mut [f64],
-        dst_offset: u32,
+        dst_offset: i64,
+        src_data: &[f64],
+        src_offset: u32,
         length: u32,
-    ) -> Result<(), JsValue>;
+    );
 
     /// The `WebGL2RenderingContext.blitFramebuffer()` method of the WebGL 2 API transfers a block of pixels from the
     /// read framebuffer to the draw framebuffer.
@@ -1461,15 +4232,14 @@ extern "C" {
 
     /// The `WebGL2RenderingContext.invalidateFramebuffer()` method of the WebGL 2 API invalidates the contents
     /// of attachments in a framebuffer.
-    /* FIXME: currently not supported by wasm_bindgen
     #[wasm_bindgen(method, js_name = invalidateFramebuffer)]
-    pub fn invalidate_framebuffer(
+    fn _invalidate_framebuffer(
         this: &WebGL2RenderingContext,
         target: FramebufferKind,
-        attachments: &[Attachment],
-    );*/
+        attachments: &[u32],
+    );
 
-    //FIXME: invalidateSubFramebuffer same issue as invalidateFramebuffer
+    //FIXME: invalidateSubFramebuffer, the attachments need the same conversion as invalidateFramebuffer
 
     /// The `WebGL2RenderingContext.readBuffer()` method of the WebGL 2 API selects a color buffer as the source
     /// for pixels for subsequent calls to copyTexImage2D, copyTexSubImage2D, copyTexSubImage3D or readPixels.
@@ -1483,7 +4253,7 @@ extern "C" {
     pub fn get_internal_format_parameter(
         this: &WebGL2RenderingContext,
         target: RenderbufferKind,
-        internal_format: RenderbufferFormat,
+        internal_format: InternalFormat,
         pname: InformationType,
     ) -> Vec<i32>;
 
@@ -1494,7 +4264,7 @@ extern "C" {
         this: &WebGL2RenderingContext,
         target: RenderbufferKind,
         samples: u32,
-        internal_format: RenderbufferFormat,
+        internal_format: InternalFormat,
         width: u32,
         height: u32,
     );
@@ -1507,7 +4277,7 @@ extern "C" {
         this: &WebGL2RenderingContext,
         target: Texture2DKind,
         levels: u32,
-        internal_format: RenderbufferFormat,
+        internal_format: InternalFormat,
         width: u32,
         height: u32,
     );
@@ -1520,7 +4290,7 @@ extern "C" {
         this: &WebGL2RenderingContext,
         target: Texture3DKind,
         levels: u32,
-        internalformat: RenderbufferFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         depth: u32,
@@ -1535,13 +4305,13 @@ extern "C" {
         this: &WebGL2RenderingContext,
         target: Texture3DKind,
         level: u32,
-        internalformat: RenderbufferFormat,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
         depth: u32,
         border: u32,
-        format: RenderbufferFormat,
-        data_type: RenderbufferFormat,
+        format: PixelFormat,
+        data_type: PixelType,
         srcData: Vec<u8>,
         srcOffset: u32,
     );
@@ -1560,12 +4330,125 @@ extern "C" {
         width: u32,
         height: u32,
         depth: u32,
-        format: RenderbufferFormat,
-        data_type: RenderbufferFormat,
+        format: PixelFormat,
+        data_type: PixelType,
         srcData: Vec<u8>,
         srcOffset: u32,
     );
 
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[u8]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_u8(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[u8],
+    ) -> Result<(), JsValue>;
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[i8]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_i8(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[i8],
+    ) -> Result<(), JsValue>;
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[u16]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_u16(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[u16],
+    ) -> Result<(), JsValue>;
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[i16]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_i16(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[i16],
+    ) -> Result<(), JsValue>;
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[u32]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_u32(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[u32],
+    ) -> Result<(), JsValue>;
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[i32]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_i32(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[i32],
+    ) -> Result<(), JsValue>;
+    // Binding for `WebGL2RenderingContext.texSubImage3D()` if data has type `[f32]`.
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_f32(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &[f32],
+    ) -> Result<(), JsValue>;
+
     /// The `WebGL2RenderingContext.copyTexSubImage3D()` method of the WebGL API copies pixels from the current WebGLFramebuffer
     /// into an existing 3D texture sub-image.
     #[wasm_bindgen(method, js_name = copyTexSubImage3D)]
@@ -1674,10 +4557,8 @@ extern "C" {
     /// The `WebGL2RenderingContext.drawBuffers()` method of the WebGL 2 API defines draw buffers to which fragment
     /// colors are written into. The draw buffer settings are part of the state of the currently bound framebuffer
     /// or the drawingbuffer if no framebuffer is bound.
-    /* FIXME: currently wasm_bindgen doesn not support Vec<T>
     #[wasm_bindgen(method, js_name = drawBuffers)]
-    pub fn draw_buffers(this: &WebGL2RenderingContext, buffers: Vec<ColorBuffer>);
-    */
+    fn _draw_buffers(this: &WebGL2RenderingContext, buffers: &[u32]);
 
     /// The `WebGL2RenderingContext.clearBuffer[fiuv]()` methods of the WebGL 2 API clear buffers from the currently
     /// bound framebuffer.
@@ -1738,17 +4619,6 @@ extern "C" {
     #[wasm_bindgen(method, js_name = endTransformFeedback)]
     pub fn end_transform_feedback(this: &WebGL2RenderingContext);
 
-    /// The `WebGL2RenderingContext.transformFeedbackVaryings()` method of the WebGL 2 API specifies values to record in
-    /// WebGLTransformFeedback buffers.
-    /* FIXME: Vec of string currently not supported by wasm_bindgen
-    #[wasm_bindgen(method, js_name = transformFeedbackVaryings)]
-    pub fn transform_feedback_varyings(
-        this: &WebGL2RenderingContext,
-        program: WebGLProgram,
-        varyings: Vec<String>,
-        buffer_mode: TransformFeedbackBufferMode,
-    );*/
-
     /// The WebGL2RenderingContext.pauseTransformFeedback() method of the WebGL 2 API pauses a transform feedback operation.
     #[wasm_bindgen(method, js_name = pauseTransformFeedback)]
     pub fn pause_transform_feedback(this: &WebGL2RenderingContext);
@@ -1769,50 +4639,121 @@ extern "C" {
     pub fn get_uniform_indices(this: &WebGL2RenderingContext, program: WebGLProgram, uniformNames: Vec<String>);
     */
 
-// TODO getActiveUniforms
+    /// Binding for `WebGLRenderingContext.compressedTexImage2D()`
+    #[wasm_bindgen(method, js_name = compressedTexImage2D, catch)]
+    fn _compressed_tex_image_2d(
+        this: &WebGL2RenderingContext,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
+
+    /// Binding for `WebGLRenderingContext.compressedTexSubImage2D()`
+    #[wasm_bindgen(method, js_name = compressedTexSubImage2D, catch)]
+    fn _compressed_tex_sub_image_2d(
+        this: &WebGL2RenderingContext,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
 
-// TODO getAcitveUniformBlockParameter
+    /// Binding for `WebGL2RenderingContext.compressedTexImage3D()`
+    #[wasm_bindgen(method, js_name = compressedTexImage3D, catch)]
+    fn _compressed_tex_image_3d(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        depth: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
 
-// Binding for `WebGLRenderingContext.compressedTexImage2D` when data type is `[u8]`
-    /* FIXME save for later
-    #[wasm_bindgen(method, js_name = compressedTexImage2D, catch)]
-    pub(crate) fn _compressed_tex_image_2d_u8(
+    /// Binding for `WebGL2RenderingContext.compressedTexSubImage3D()`
+    #[wasm_bindgen(method, js_name = compressedTexSubImage3D, catch)]
+    fn _compressed_tex_sub_image_3d(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue>;
+
+    /// Binding for `WebGLRenderingContext.texImage2D()` with a `TexImageSource`
+    #[wasm_bindgen(method, js_name = texImage2D, catch)]
+    fn _tex_image_2d_source(
+        this: &WebGL2RenderingContext,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &JsValue,
+    ) -> Result<(), JsValue>;
+
+    /// Binding for `WebGLRenderingContext.texSubImage2D()` with a `TexImageSource`
+    #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+    fn _tex_sub_image_2d_source(
         this: &WebGL2RenderingContext,
         target: TextureBindPoint,
         level: u32,
-        internalformat: CompressedFormat,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &JsValue,
+    ) -> Result<(), JsValue>;
+
+    /// Binding for `WebGL2RenderingContext.texImage3D()` with a `TexImageSource`
+    #[wasm_bindgen(method, js_name = texImage3D, catch)]
+    fn _tex_image_3d_source(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        internalformat: InternalFormat,
         width: u32,
         height: u32,
+        depth: u32,
         border: u32,
-        pixels: &[u8],
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &JsValue,
     ) -> Result<(), JsValue>;
-*/
-}
 
-// WebGLContextAttributes
-/* FIXME: not found when exported
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
-extern "C" {
-    pub type WebGLContextAttributes;
-    #[wasm_bindgen(method, getter)]
-    pub fn alpha(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter)]
-    pub fn antialias(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter)]
-    pub fn depth(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter = premultipliedAlpha)]
-    pub fn premultiplied_alpha(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter = preserveDrawingBuffer)]
-    pub fn preserve_drawing_buffer(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter)]
-    pub fn stencil(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter = failIfMajorPerformanceCaveat )]
-    pub fn fail_if_major_performance_caveat(this: &WebGLContextAttributes) -> bool;
-    #[wasm_bindgen(method, getter = powerPreference)]
-    pub fn power_preference(this: &WebGLContextAttributes) -> String;
+    /// Binding for `WebGL2RenderingContext.texSubImage3D()` with a `TexImageSource`
+    #[wasm_bindgen(method, js_name = texSubImage3D, catch)]
+    fn _tex_sub_image_3d_source(
+        this: &WebGL2RenderingContext,
+        target: Texture3DKind,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        zoffset: u32,
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &JsValue,
+    ) -> Result<(), JsValue>;
 }
-*/
 
 /// WebGLShaderPrecisionFormat;
 #[wasm_bindgen]
@@ -1832,3 +4773,44 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn precision(this: &WebGLShaderPrecisionFormat) -> u32;
 }
+
+/// Bindings for the `WEBGL_draw_instanced_base_vertex_base_instance` extension
+#[wasm_bindgen]
+extern "C" {
+    /// The `WEBGL_draw_instanced_base_vertex_base_instance` extension, allows offsetting the instance
+    /// and vertex numbers of instanced draws.
+    pub type WEBGLDrawInstancedBaseVertexBaseInstance;
+
+    /// Binding for `WebGL2RenderingContext.getExtension()` for
+    /// `WEBGL_draw_instanced_base_vertex_base_instance`
+    #[wasm_bindgen(method, js_name = getExtension)]
+    fn _get_base_instance_extension(
+        this: &WebGL2RenderingContext,
+        name: &str,
+    ) -> Option<WEBGLDrawInstancedBaseVertexBaseInstance>;
+
+    /// Binding for `WEBGL_draw_instanced_base_vertex_base_instance.drawArraysInstancedBaseInstanceWEBGL()`
+    #[wasm_bindgen(method, structural, js_name = drawArraysInstancedBaseInstanceWEBGL)]
+    fn draw_arrays_instanced_base_instance_webgl(
+        this: &WEBGLDrawInstancedBaseVertexBaseInstance,
+        mode: Primitives,
+        first: i32,
+        count: u32,
+        instance_count: u32,
+        base_instance: u32,
+    );
+
+    /// Binding for
+    /// `WEBGL_draw_instanced_base_vertex_base_instance.drawElementsInstancedBaseVertexBaseInstanceWEBGL()`
+    #[wasm_bindgen(method, structural, js_name = drawElementsInstancedBaseVertexBaseInstanceWEBGL)]
+    fn draw_elements_instanced_base_vertex_base_instance_webgl(
+        this: &WEBGLDrawInstancedBaseVertexBaseInstance,
+        mode: Primitives,
+        count: u32,
+        index_type: IndexType,
+        offset: i64,
+        instance_count: u32,
+        base_vertex: i32,
+        base_instance: u32,
+    );
+}
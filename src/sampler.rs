@@ -1,30 +1,222 @@
 //! WebGLSampler and methods
+use error::CheckedObject;
 use glenum::{
     CompareMode, DepthTest, TextureMagFilter, TextureMinFilter, TextureParameter, TextureWrap,
 };
-use rendering_context::WebGL2RenderingContext;
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing sampler objects.
+pub trait SamplerBackend: Sized {
+    /// Handle to a sampler object of this backend.
+    type Sampler: Clone;
+
+    /// Binding for `WebGL2RenderingContext.createSampler()`
+    fn _create_sampler(&self) -> Self::Sampler;
+    /// Binding for `WebGL2RenderingContext.deleteSampler()`.
+    fn _delete_sampler(&self, sampler: Self::Sampler);
+    /// Binding for `WebGL2RenderingContext.bindSampler()`
+    fn _bind_sampler(&self, unit: u32, sampler: &Self::Sampler);
+    /// Binding for `WebGL2RenderingContext.isSampler()`
+    fn _is_sampler(&self, sampler: &Self::Sampler) -> bool;
+    /// Binding for `WebGL2RenderingContext.getSamplerParameter()` when return type is `f32`
+    fn _get_sampler_parameter_f32(&self, sampler: &Self::Sampler, pname: TextureParameter) -> f32;
+    /// Binding for `WebGL2RenderingContext.getSamplerParameter()` when return type is `DepthTest`
+    fn _get_sampler_parameter_enum1(
+        &self,
+        sampler: &Self::Sampler,
+        pname: TextureParameter,
+    ) -> DepthTest;
+    /// Binding for `WebGL2RenderingContext.getSamplerParameter()` when return type is `CompareMode`
+    fn _get_sampler_parameter_enum2(
+        &self,
+        sampler: &Self::Sampler,
+        pname: TextureParameter,
+    ) -> CompareMode;
+    /// Binding for `WebGL2RenderingContext.getSamplerParameter()` when return type is `TextureMagFilter`
+    fn _get_sampler_parameter_enum3(
+        &self,
+        sampler: &Self::Sampler,
+        pname: TextureParameter,
+    ) -> TextureMagFilter;
+    /// Binding for `WebGL2RenderingContext.getSamplerParameter()` when return type is `TextureMinFilter`
+    fn _get_sampler_parameter_enum4(
+        &self,
+        sampler: &Self::Sampler,
+        pname: TextureParameter,
+    ) -> TextureMinFilter;
+    /// Binding for `WebGL2RenderingContext.getSamplerParameter()` when return type is `TextureWrap`
+    fn _get_sampler_parameter_enum5(
+        &self,
+        sampler: &Self::Sampler,
+        pname: TextureParameter,
+    ) -> TextureWrap;
+    /// Binding for `WebGL2RenderingContext.samplerParameteri()`
+    fn _sampler_parameter_i(&self, sampler: &Self::Sampler, pname: TextureParameter, param: i32);
+    /// Binding for `WebGL2RenderingContext.samplerParameterf()`
+    fn _sampler_parameter_f(&self, sampler: &Self::Sampler, pname: TextureParameter, param: f32);
+
     /// Creates and initializes a `WebGLRSSampler` object.
-    pub fn create_sampler(&self) -> WebGLRSSampler {
+    fn create_sampler(&self) -> WebGLRSSampler<'_, Self> {
         WebGLRSSampler {
             context: self,
             inner: self._create_sampler(),
+            leaked: false,
+        }
+    }
+}
+
+impl SamplerBackend for WebGL2RenderingContext {
+    type Sampler = WebGLSampler;
+
+    fn _create_sampler(&self) -> WebGLSampler {
+        WebGL2RenderingContext::_create_sampler(self)
+    }
+    fn _delete_sampler(&self, sampler: WebGLSampler) {
+        WebGL2RenderingContext::_delete_sampler(self, sampler)
+    }
+    fn _bind_sampler(&self, unit: u32, sampler: &WebGLSampler) {
+        WebGL2RenderingContext::_bind_sampler(self, unit, sampler)
+    }
+    fn _is_sampler(&self, sampler: &WebGLSampler) -> bool {
+        WebGL2RenderingContext::_is_sampler(self, sampler)
+    }
+    fn _get_sampler_parameter_f32(&self, sampler: &WebGLSampler, pname: TextureParameter) -> f32 {
+        WebGL2RenderingContext::_get_sampler_parameter_f32(self, sampler, pname)
+    }
+    fn _get_sampler_parameter_enum1(
+        &self,
+        sampler: &WebGLSampler,
+        pname: TextureParameter,
+    ) -> DepthTest {
+        WebGL2RenderingContext::_get_sampler_parameter_enum1(self, sampler, pname)
+    }
+    fn _get_sampler_parameter_enum2(
+        &self,
+        sampler: &WebGLSampler,
+        pname: TextureParameter,
+    ) -> CompareMode {
+        WebGL2RenderingContext::_get_sampler_parameter_enum2(self, sampler, pname)
+    }
+    fn _get_sampler_parameter_enum3(
+        &self,
+        sampler: &WebGLSampler,
+        pname: TextureParameter,
+    ) -> TextureMagFilter {
+        WebGL2RenderingContext::_get_sampler_parameter_enum3(self, sampler, pname)
+    }
+    fn _get_sampler_parameter_enum4(
+        &self,
+        sampler: &WebGLSampler,
+        pname: TextureParameter,
+    ) -> TextureMinFilter {
+        WebGL2RenderingContext::_get_sampler_parameter_enum4(self, sampler, pname)
+    }
+    fn _get_sampler_parameter_enum5(
+        &self,
+        sampler: &WebGLSampler,
+        pname: TextureParameter,
+    ) -> TextureWrap {
+        WebGL2RenderingContext::_get_sampler_parameter_enum5(self, sampler, pname)
+    }
+    fn _sampler_parameter_i(&self, sampler: &WebGLSampler, pname: TextureParameter, param: i32) {
+        WebGL2RenderingContext::_sampler_parameter_i(self, sampler, pname, param)
+    }
+    fn _sampler_parameter_f(&self, sampler: &WebGLSampler, pname: TextureParameter, param: f32) {
+        WebGL2RenderingContext::_sampler_parameter_f(self, sampler, pname, param)
+    }
+}
+
+/// Sampler object which stores the sampling parameters for texture access.
+///
+/// The object is deleted when the `WebGLRSSampler` is dropped.
+pub struct WebGLRSSampler<'ctx, B: 'ctx + SamplerBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Sampler,
+    leaked: bool,
+}
+
+impl<'ctx, B: SamplerBackend> Drop for WebGLRSSampler<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_sampler(self.inner.clone());
         }
     }
 }
 
-#[derive(Clone)]
-pub struct WebGLRSSampler<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLSampler,
+impl<'ctx, B: SamplerBackend + ContextBackend> WebGLRSSampler<'ctx, B> {
+    /// Returns a view on this sampler whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: SamplerBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSSampler<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSSampler "sampler";
+        fn bind(unit: u32);
+        fn is_valid() -> bool;
+        fn texture_compare_func() -> DepthTest;
+        fn set_texture_compare_func(value: DepthTest);
+        fn texture_compare_mode() -> CompareMode;
+        fn set_texture_compare_mode(value: CompareMode);
+        fn texture_mag_filter() -> TextureMagFilter;
+        fn set_texture_mag_filter(value: TextureMagFilter);
+        fn texture_max_lod() -> f32;
+        fn set_texture_max_lod(value: f32);
+        fn texture_min_filter() -> TextureMinFilter;
+        fn set_texture_min_filter(value: TextureMinFilter);
+        fn texture_min_lod() -> f32;
+        fn set_texture_min_lod(value: f32);
+        fn texture_wrap_r() -> TextureWrap;
+        fn set_texture_wrap_r(value: TextureWrap);
+        fn texture_wrap_s() -> TextureWrap;
+        fn set_texture_wrap_s(value: TextureWrap);
+        fn texture_wrap_t() -> TextureWrap;
+        fn set_texture_wrap_t(value: TextureWrap);
+    }
 }
 
-impl<'ctx> WebGLRSSampler<'ctx> {
+impl<'ctx, B: SamplerBackend> WebGLRSSampler<'ctx, B> {
     /// Deletes this `WebGLRSSampler` object.
+    ///
+    /// This is the same as dropping the `WebGLRSSampler`.
     pub fn delete(self) {
-        self.context._delete_sampler(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSSampler` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `sampler` - the raw handle.
+    pub fn from_raw(context: &'ctx B, sampler: B::Sampler) -> Self {
+        WebGLRSSampler {
+            context,
+            inner: sampler,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Sampler {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSSampler` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Binds this `WebGLRSSampler` object to the texture unit at the passed index.
@@ -145,7 +337,7 @@ impl<'ctx> WebGLRSSampler<'ctx> {
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLSampler;
+    pub type WebGLSampler;
 
     /// Binding for `WebGL2RenderingContext.createSampler()`
     #[wasm_bindgen(method, js_name = createSampler)]
@@ -1,7 +1,12 @@
 //! WebGLProgram and WebGLShader and methods
-use glenum::{ProgramParameter, ShaderKind, ShaderParameter, TransformFeedbackBufferMode};
-use rendering_context::WebGL2RenderingContext;
-use uniform_location::{WebGLRSUniformLocation, WebGLUniformLocation};
+use error::{CheckedObject, CHECKS_ENABLED};
+use glenum::{
+    ProgramParameter, ShaderKind, ShaderParameter, TransformFeedbackBufferMode,
+    UniformBlockParameter, UniformParameter, UniformType,
+};
+use rendering_context::{ContextBackend, StringArray, WebGL2RenderingContext};
+use shared::Shared;
+use uniform_location::{UniformBackend, WebGLRSUniformLocation, WebGLUniformLocation};
 use wasm_bindgen::prelude::*;
 
 // TODO WebGLRenderingContext.getAttachedShaders()
@@ -15,36 +20,583 @@ use wasm_bindgen::prelude::*;
 
 /// WebGLRSProgram
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing program and shader objects.
+pub trait ProgramBackend: UniformBackend {
+    /// Handle to a program object of this backend.
+    type Program: Clone;
+    /// Handle to a shader object of this backend.
+    type Shader: Clone;
+
+    /// Binding for `WebGLRenderingContext.createProgram()`
+    fn _create_program(&self) -> Self::Program;
+    /// Binding for `WebGLRenderingContext.deleteProgram()`.
+    fn _delete_program(&self, program: Self::Program);
+    /// Binding for `WebGLRenderingContext.detachShader()`.
+    fn _detach_shader(&self, program: &Self::Program, shader: &Self::Shader);
+    /// Binding for `WebGLRenderingContext.attachShader()`.
+    fn _attach_shader(&self, program: &Self::Program, shader: &Self::Shader);
+    /// Binding for `WebGLRenderingContext.bindAttribLocation()`.
+    fn _bind_attrib_location(&self, program: &Self::Program, index: u32, name: &str);
+    /// Binding for `WebGLRenderingContext.getProgramInfoLog`.
+    fn _get_program_info_log(&self, program: &Self::Program) -> String;
+    /// Binding for `WebGLRenderingContext.isProgram()`.
+    fn _is_program(&self, program: &Self::Program) -> bool;
+    /// Binding for `WebGLRenderingContext.linkProgram()`.
+    fn _link_program(&self, program: &Self::Program);
+    /// Binding for `WebGLRenderingContext.useProgram()`.
+    fn _use_program(&self, program: &Self::Program);
+    /// Binding for `WebGLRenderingContext.validateProgram()`.
+    fn _validate_program(&self, program: &Self::Program);
+    /// Binding for `WebGLRenderingContext.getActiveAttrib()`.
+    fn _get_active_attrib(&self, program: &Self::Program, index: u32) -> WebGLRSActiveInfo;
+    /// Binding for `WebGLRenderingContext.getActiveUniform()`.
+    fn _get_active_uniform(&self, program: &Self::Program, index: u32) -> WebGLRSActiveInfo;
+    /// Binding for `WebGLRenderingContext.getAttribLocation()`.
+    fn _get_attrib_location(&self, program: &Self::Program, name: &str) -> i32;
+    /// Binding for `WebGLRenderingContext.getUniformLocation()`.
+    fn _get_uniform_location(&self, program: &Self::Program, name: &str) -> Self::UniformLocation;
+    /// Binding for `WebGL2RenderingContext.getProgramParameter()` when return type is i32
+    fn _get_program_parameter_i32(&self, program: &Self::Program, pname: ProgramParameter) -> i32;
+    /// Binding for `WebGL2RenderingContext.getProgramParameter()` when return type is bool
+    fn _get_program_parameter_bool(&self, program: &Self::Program, pname: ProgramParameter)
+        -> bool;
+
+    /// Binding for `WebGLRenderingContext.createShader()`
+    fn _create_shader(&self, kind: ShaderKind) -> Self::Shader;
+    /// Binding for `WebGLRenderingContext.compileShader()`
+    fn _compile_shader(&self, shader: &Self::Shader);
+    /// Binding for `WebGLRenderingContext.deleteShader()`
+    fn _delete_shader(&self, shader: Self::Shader);
+    /// Binding for `WebGLRenderingContext.getShaderInfoLog`
+    fn _get_shader_info_log(&self, shader: &Self::Shader) -> String;
+    /// Binding for `WebGLRenderingContext.getShaderSource()`.
+    fn _get_shader_source(&self, shader: &Self::Shader) -> String;
+    /// Binding for `WebGLRenderingContext.isShader()`.
+    fn _is_shader(&self, shader: &Self::Shader) -> bool;
+    /// Binding for `WebGLRenderingContext.shaderSource()`.
+    fn _shader_source(&self, shader: &Self::Shader, source: &str);
+    /// Binding for `WebGLRenderingContext.getShaderParameter()` if return type is bool
+    fn _get_shader_parameter_bool(&self, shader: &Self::Shader, pname: ShaderParameter) -> bool;
+    /// Binding for `WebGLRenderingContext.getShaderParameter()` if return type is enum
+    fn _get_shader_parameter_enum(
+        &self,
+        shader: &Self::Shader,
+        pname: ShaderParameter,
+    ) -> ShaderKind;
+
     /// Creates and initializes a `WebGLRSProgram`
-    pub fn create_program(&self) -> WebGLRSProgram {
+    fn create_program(&self) -> WebGLRSProgram<'_, Self> {
         WebGLRSProgram {
             context: self,
             inner: self._create_program(),
+            leaked: false,
+        }
+    }
+
+    /// Creates a WebGLRSShader that can then be configured further using `shader_source()` and `compile_shader()`.
+    ///
+    /// # Arguments
+    /// * `kind` - Kind of the shader (Vertex or Fragment)
+    fn create_shader(&self, kind: ShaderKind) -> WebGLRSShader<'_, Self> {
+        WebGLRSShader {
+            context: self,
+            inner: self._create_shader(kind),
+            leaked: false,
+        }
+    }
+}
+
+impl ProgramBackend for WebGL2RenderingContext {
+    type Program = WebGLProgram;
+    type Shader = WebGLShader;
+
+    fn _create_program(&self) -> WebGLProgram {
+        WebGL2RenderingContext::_create_program(self)
+    }
+    fn _delete_program(&self, program: WebGLProgram) {
+        WebGL2RenderingContext::_delete_program(self, program)
+    }
+    fn _detach_shader(&self, program: &WebGLProgram, shader: &WebGLShader) {
+        WebGL2RenderingContext::_detach_shader(self, program, shader)
+    }
+    fn _attach_shader(&self, program: &WebGLProgram, shader: &WebGLShader) {
+        WebGL2RenderingContext::_attach_shader(self, program, shader)
+    }
+    fn _bind_attrib_location(&self, program: &WebGLProgram, index: u32, name: &str) {
+        WebGL2RenderingContext::_bind_attrib_location(self, program, index, name)
+    }
+    fn _get_program_info_log(&self, program: &WebGLProgram) -> String {
+        WebGL2RenderingContext::_get_program_info_log(self, program)
+    }
+    fn _is_program(&self, program: &WebGLProgram) -> bool {
+        WebGL2RenderingContext::_is_program(self, program)
+    }
+    fn _link_program(&self, program: &WebGLProgram) {
+        WebGL2RenderingContext::_link_program(self, program)
+    }
+    fn _use_program(&self, program: &WebGLProgram) {
+        WebGL2RenderingContext::_use_program(self, program)
+    }
+    fn _validate_program(&self, program: &WebGLProgram) {
+        WebGL2RenderingContext::_validate_program(self, program)
+    }
+    fn _get_active_attrib(&self, program: &WebGLProgram, index: u32) -> WebGLRSActiveInfo {
+        WebGL2RenderingContext::_get_active_attrib(self, program, index).into()
+    }
+    fn _get_active_uniform(&self, program: &WebGLProgram, index: u32) -> WebGLRSActiveInfo {
+        WebGL2RenderingContext::_get_active_uniform(self, program, index).into()
+    }
+    fn _get_attrib_location(&self, program: &WebGLProgram, name: &str) -> i32 {
+        WebGL2RenderingContext::_get_attrib_location(self, program, name)
+    }
+    fn _get_uniform_location(&self, program: &WebGLProgram, name: &str) -> WebGLUniformLocation {
+        WebGL2RenderingContext::_get_uniform_location(self, program, name)
+    }
+    fn _get_program_parameter_i32(&self, program: &WebGLProgram, pname: ProgramParameter) -> i32 {
+        WebGL2RenderingContext::_get_program_parameter_i32(self, program, pname)
+    }
+    fn _get_program_parameter_bool(&self, program: &WebGLProgram, pname: ProgramParameter) -> bool {
+        WebGL2RenderingContext::_get_program_parameter_bool(self, program, pname)
+    }
+    fn _create_shader(&self, kind: ShaderKind) -> WebGLShader {
+        WebGL2RenderingContext::_create_shader(self, kind)
+    }
+    fn _compile_shader(&self, shader: &WebGLShader) {
+        WebGL2RenderingContext::_compile_shader(self, shader)
+    }
+    fn _delete_shader(&self, shader: WebGLShader) {
+        WebGL2RenderingContext::_delete_shader(self, shader)
+    }
+    fn _get_shader_info_log(&self, shader: &WebGLShader) -> String {
+        WebGL2RenderingContext::_get_shader_info_log(self, shader)
+    }
+    fn _get_shader_source(&self, shader: &WebGLShader) -> String {
+        WebGL2RenderingContext::_get_shader_source(self, shader)
+    }
+    fn _is_shader(&self, shader: &WebGLShader) -> bool {
+        WebGL2RenderingContext::_is_shader(self, shader)
+    }
+    fn _shader_source(&self, shader: &WebGLShader, source: &str) {
+        WebGL2RenderingContext::_shader_source(self, shader, source)
+    }
+    fn _get_shader_parameter_bool(&self, shader: &WebGLShader, pname: ShaderParameter) -> bool {
+        WebGL2RenderingContext::_get_shader_parameter_bool(self, shader, pname)
+    }
+    fn _get_shader_parameter_enum(
+        &self,
+        shader: &WebGLShader,
+        pname: ShaderParameter,
+    ) -> ShaderKind {
+        WebGL2RenderingContext::_get_shader_parameter_enum(self, shader, pname)
+    }
+}
+
+/// Backend calls for the program methods that were added in WebGL2.
+pub trait WebGL2ProgramBackend: ProgramBackend {
+    /// Binding for `WebGL2RenderingContext.getFragDataLocation()`.
+    fn _get_frag_data_location(&self, program: &Self::Program, name: &str) -> i32;
+    /// Binding for `WebGL2RenderingContext.getTransformFeedbackVarying()`
+    fn _get_transform_feedback_varying(
+        &self,
+        program: &Self::Program,
+        index: u32,
+    ) -> WebGLRSActiveInfo;
+    /// Binding for `WebGL2RenderingContext.getUniformBlockIndex()`
+    fn _get_uniform_block_index(&self, program: &Self::Program, uniform_block_name: &str) -> u32;
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockName()`.
+    fn _get_active_uniform_block_name(
+        &self,
+        program: &Self::Program,
+        uniform_block_index: u32,
+    ) -> String;
+    /// Binding for `WebGL2RenderingContext.uniformBlockBinding()`.
+    fn _uniform_block_binding(
+        &self,
+        program: &Self::Program,
+        uniform_block_index: u32,
+        uniform_block_binding: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.getProgramParameter()` when return type is enum
+    fn _get_program_parameter_enum(
+        &self,
+        program: &Self::Program,
+        pname: ProgramParameter,
+    ) -> TransformFeedbackBufferMode;
+    /// Binding for `WebGL2RenderingContext.transformFeedbackVaryings()`
+    fn _transform_feedback_varyings(
+        &self,
+        program: &Self::Program,
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    );
+    /// Binding for `WebGL2RenderingContext.getActiveUniforms()` when return type is i32
+    fn _get_active_uniforms_i32(
+        &self,
+        program: &Self::Program,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<i32>;
+    /// Binding for `WebGL2RenderingContext.getActiveUniforms()` when return type is bool
+    fn _get_active_uniforms_bool(
+        &self,
+        program: &Self::Program,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<bool>;
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockParameter()` when return type is u32
+    fn _get_active_uniform_block_parameter_u32(
+        &self,
+        program: &Self::Program,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> u32;
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockParameter()` when return type is bool
+    fn _get_active_uniform_block_parameter_bool(
+        &self,
+        program: &Self::Program,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> bool;
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockParameter()` when return type is `Uint32Array`
+    fn _get_active_uniform_block_parameter_u32_array(
+        &self,
+        program: &Self::Program,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> Vec<u32>;
+}
+
+impl WebGL2ProgramBackend for WebGL2RenderingContext {
+    fn _get_frag_data_location(&self, program: &WebGLProgram, name: &str) -> i32 {
+        WebGL2RenderingContext::_get_frag_data_location(self, program, name)
+    }
+    fn _get_transform_feedback_varying(
+        &self,
+        program: &WebGLProgram,
+        index: u32,
+    ) -> WebGLRSActiveInfo {
+        WebGL2RenderingContext::_get_transform_feedback_varying(self, program, index).into()
+    }
+    fn _get_uniform_block_index(&self, program: &WebGLProgram, uniform_block_name: &str) -> u32 {
+        WebGL2RenderingContext::_get_uniform_block_index(self, program, uniform_block_name)
+    }
+    fn _get_active_uniform_block_name(
+        &self,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+    ) -> String {
+        WebGL2RenderingContext::_get_active_uniform_block_name(self, program, uniform_block_index)
+    }
+    fn _uniform_block_binding(
+        &self,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        uniform_block_binding: u32,
+    ) {
+        WebGL2RenderingContext::_uniform_block_binding(
+            self,
+            program,
+            uniform_block_index,
+            uniform_block_binding,
+        )
+    }
+    fn _get_program_parameter_enum(
+        &self,
+        program: &WebGLProgram,
+        pname: ProgramParameter,
+    ) -> TransformFeedbackBufferMode {
+        WebGL2RenderingContext::_get_program_parameter_enum(self, program, pname)
+    }
+    fn _transform_feedback_varyings(
+        &self,
+        program: &WebGLProgram,
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    ) {
+        let array = StringArray::new();
+        for varying in varyings {
+            array.push(varying);
+        }
+        WebGL2RenderingContext::_transform_feedback_varyings(self, program, &array, buffer_mode)
+    }
+    fn _get_active_uniforms_i32(
+        &self,
+        program: &WebGLProgram,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<i32> {
+        WebGL2RenderingContext::_get_active_uniforms_i32(self, program, uniform_indices, pname)
+    }
+    fn _get_active_uniforms_bool(
+        &self,
+        program: &WebGLProgram,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<bool> {
+        WebGL2RenderingContext::_get_active_uniforms_bool(self, program, uniform_indices, pname)
+            .into_iter()
+            .map(|value| value != 0)
+            .collect()
+    }
+    fn _get_active_uniform_block_parameter_u32(
+        &self,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> u32 {
+        WebGL2RenderingContext::_get_active_uniform_block_parameter_u32(
+            self,
+            program,
+            uniform_block_index,
+            pname,
+        )
+    }
+    fn _get_active_uniform_block_parameter_bool(
+        &self,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> bool {
+        WebGL2RenderingContext::_get_active_uniform_block_parameter_bool(
+            self,
+            program,
+            uniform_block_index,
+            pname,
+        )
+    }
+    fn _get_active_uniform_block_parameter_u32_array(
+        &self,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> Vec<u32> {
+        WebGL2RenderingContext::_get_active_uniform_block_parameter_u32_array(
+            self,
+            program,
+            uniform_block_index,
+            pname,
+        )
+    }
+}
+
+/// Information about an active attribute, uniform or transform feedback varying of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebGLRSActiveInfo {
+    /// The name of the requested variable.
+    pub name: String,
+    /// The size of the requested variable.
+    pub size: u32,
+    /// The type of the requested variable.
+    pub data_type: u32,
+}
+
+impl WebGLRSActiveInfo {
+    /// Returns the type of the variable, `None` if the type is not a WebGL2 type.
+    pub fn uniform_type(&self) -> Option<UniformType> {
+        let types = [
+            UniformType::Float,
+            UniformType::FloatVec2,
+            UniformType::FloatVec3,
+            UniformType::FloatVec4,
+            UniformType::Int,
+            UniformType::IntVec2,
+            UniformType::IntVec3,
+            UniformType::IntVec4,
+            UniformType::UnsignedInt,
+            UniformType::UnsignedIntVec2,
+            UniformType::UnsignedIntVec3,
+            UniformType::UnsignedIntVec4,
+            UniformType::Bool,
+            UniformType::BoolVec2,
+            UniformType::BoolVec3,
+            UniformType::BoolVec4,
+            UniformType::FloatMat2,
+            UniformType::FloatMat3,
+            UniformType::FloatMat4,
+            UniformType::FloatMat2x3,
+            UniformType::FloatMat2x4,
+            UniformType::FloatMat3x2,
+            UniformType::FloatMat3x4,
+            UniformType::FloatMat4x2,
+            UniformType::FloatMat4x3,
+            UniformType::Sampler2d,
+            UniformType::Sampler3d,
+            UniformType::SamplerCube,
+            UniformType::Sampler2dShadow,
+            UniformType::Sampler2dArray,
+            UniformType::Sampler2dArrayShadow,
+            UniformType::SamplerCubeShadow,
+            UniformType::IntSampler2d,
+            UniformType::IntSampler3d,
+            UniformType::IntSamplerCube,
+            UniformType::IntSampler2dArray,
+            UniformType::UnsignedIntSampler2d,
+            UniformType::UnsignedIntSampler3d,
+            UniformType::UnsignedIntSamplerCube,
+            UniformType::UnsignedIntSampler2dArray,
+        ];
+        types
+            .iter()
+            .find(|&&uniform_type| uniform_type as u32 == self.data_type)
+            .cloned()
+    }
+}
+
+impl From<WebGLActiveInfo> for WebGLRSActiveInfo {
+    fn from(info: WebGLActiveInfo) -> Self {
+        WebGLRSActiveInfo {
+            name: info.name(),
+            size: info.size(),
+            data_type: info.data_type(),
         }
     }
 }
 
+/// Returns whether `name` refers to the active uniform `active`, arrays are reported as their first element
+/// and can be looked up with or without an index.
+fn is_uniform_name(active: &str, name: &str) -> bool {
+    let base = match active.strip_suffix("[0]") {
+        Some(base) => base,
+        None => return active == name,
+    };
+    match name.strip_prefix(base) {
+        Some("") => true,
+        Some(index) => index
+            .strip_prefix('[')
+            .and_then(|index| index.strip_suffix(']'))
+            .is_some_and(|index| {
+                !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit())
+            }),
+        None => false,
+    }
+}
+
 /// The `WebGLRSProgram` is part of the WebGL API and is a combination of two compiled WebGLShaders
 /// consisting of a vertex shader and a fragment shader (both written in GLSL). These are then linked
 /// into a usable program.
-#[derive(Clone)]
-pub struct WebGLRSProgram<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLProgram,
+///
+/// The object is deleted when the `WebGLRSProgram` is dropped.
+pub struct WebGLRSProgram<'ctx, B: 'ctx + ProgramBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Program,
+    leaked: bool,
 }
 
-impl<'ctx> WebGLRSProgram<'ctx> {
+impl<'ctx, B: ProgramBackend> Drop for WebGLRSProgram<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_program(self.inner.clone());
+        }
+    }
+}
+
+impl<'ctx, B: ProgramBackend + ContextBackend> WebGLRSProgram<'ctx, B> {
+    /// Returns a view on this program whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: ProgramBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSProgram<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSProgram "program";
+        fn detach_shader(shader: &WebGLRSShader<B>);
+        fn attach_shader(shader: &WebGLRSShader<B>);
+        fn bind_attrib_location(index: u32, name: &str);
+        fn info_log() -> String;
+        fn is_valid() -> bool;
+        fn link();
+        fn enable();
+        fn validate();
+        fn active_attrib(index: u32) -> WebGLRSActiveInfo;
+        fn active_uniform(index: u32) -> WebGLRSActiveInfo;
+        fn attrib_location(name: &str) -> Option<u32>;
+        fn uniform_location(name: &str) -> WebGLRSUniformLocation<'a, B>;
+        fn delete_status() -> bool;
+        fn link_status() -> bool;
+        fn validate_status() -> bool;
+        fn attached_shaders() -> i32;
+        fn active_attributes() -> i32;
+        fn active_uniforms() -> i32;
+    }
+}
+
+impl<'a, 'ctx, B: WebGL2ProgramBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSProgram<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSProgram "program";
+        fn frag_data_location(name: &str) -> i32;
+        fn transform_feedback_varying(index: u32) -> WebGLRSActiveInfo;
+        fn uniform_block_index(uniform_block_name: &str) -> u32;
+        fn active_uniform_block_name(uniform_block_index: u32) -> String;
+        fn assign_uniform_block_binding(uniform_block_index: u32, uniform_block_binding: u32);
+        fn transform_feedback_buffer_mode() -> TransformFeedbackBufferMode;
+        fn transform_feedback_varyings() -> i32;
+        fn active_uniform_blocks() -> i32;
+        fn set_transform_feedback_varyings(
+            varyings: &[&str],
+            buffer_mode: TransformFeedbackBufferMode,
+        );
+        fn uniform_block_indices(uniform_indices: &[u32]) -> Vec<i32>;
+        fn uniform_offsets(uniform_indices: &[u32]) -> Vec<i32>;
+        fn uniform_array_strides(uniform_indices: &[u32]) -> Vec<i32>;
+        fn uniform_matrix_strides(uniform_indices: &[u32]) -> Vec<i32>;
+        fn uniform_is_row_major(uniform_indices: &[u32]) -> Vec<bool>;
+        fn uniform_block_binding(uniform_block_index: u32) -> u32;
+        fn uniform_block_data_size(uniform_block_index: u32) -> u32;
+        fn uniform_block_active_uniform_indices(uniform_block_index: u32) -> Vec<u32>;
+        fn uniform_block_referenced_by_vertex_shader(uniform_block_index: u32) -> bool;
+        fn uniform_block_referenced_by_fragment_shader(uniform_block_index: u32) -> bool;
+    }
+}
+
+impl<'ctx, B: ProgramBackend> WebGLRSProgram<'ctx, B> {
     /// Deletes this `WebGLRSProgram` object. This method has no effect if the program has already been deleted.
+    ///
+    /// This is the same as dropping the `WebGLRSProgram`.
     pub fn delete(self) {
-        self.context._delete_program(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSProgram` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `program` - the raw handle.
+    pub fn from_raw(context: &'ctx B, program: B::Program) -> Self {
+        WebGLRSProgram {
+            context,
+            inner: program,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Program {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSProgram` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Detaches a previously attached `WebGLRSShader` from this `WebGLRSProgram`.
     ///
     /// # Arguments
     /// * `shader` - shader to detach
-    pub fn detach_shader(&self, shader: &WebGLRSShader) {
+    pub fn detach_shader(&self, shader: &WebGLRSShader<B>) {
         self.context._detach_shader(&self.inner, &shader.inner);
     }
 
@@ -52,7 +604,7 @@ impl<'ctx> WebGLRSProgram<'ctx> {
     ///
     /// # Arguments
     /// * `shader` - shader to attach
-    pub fn attach_shader(&self, shader: &WebGLRSShader) {
+    pub fn attach_shader(&self, shader: &WebGLRSShader<B>) {
         self.context._attach_shader(&self.inner, &shader.inner)
     }
 
@@ -93,32 +645,39 @@ impl<'ctx> WebGLRSProgram<'ctx> {
         self.context._validate_program(&self.inner);
     }
 
-    /// Returns a `WebGLActiveInfo` object containing size, type, and name of a vertex attribute.
+    /// Returns a `WebGLRSActiveInfo` object containing size, type, and name of a vertex attribute.
     ///
     /// It is generally used when querying unknown uniforms either for debugging or generic library creation.
     ///
     /// # Arguments
     /// * `index` - specifying the index of the vertex attribute to get.
-    pub fn active_attrib(&self, index: u32) -> WebGLActiveInfo {
+    pub fn active_attrib(&self, index: u32) -> WebGLRSActiveInfo {
         self.context._get_active_attrib(&self.inner, index)
     }
 
-    /// Returns a WebGLActiveInfo object containing size, type, and name of a uniform attribute.
+    /// Returns a `WebGLRSActiveInfo` object containing size, type, and name of a uniform attribute.
     ///
     /// It is generally used when querying unknown uniforms either for debugging or generic library creation.
     ///
     /// # Arguments
     /// * `index` - specifying the index of the vertex attribute to get.
-    pub fn active_uniform(&self, index: u32) -> WebGLActiveInfo {
+    pub fn active_uniform(&self, index: u32) -> WebGLRSActiveInfo {
         self.context._get_active_uniform(&self.inner, index)
     }
 
     /// Returns the location of an attribute variable in this `WebGLRSProgram`.
     ///
     /// # Arguments
-    /// * `name` - specifying the name of the attribute variable whose location to get.  
-    pub fn attrib_location(&self, name: &str) -> u32 {
-        self.context._get_attrib_location(&self.inner, name)
+    /// * `name` - specifying the name of the attribute variable whose location to get.
+    ///
+    /// Returns `None` if the program has no active attribute with that name.
+    pub fn attrib_location(&self, name: &str) -> Option<u32> {
+        let location = self.context._get_attrib_location(&self.inner, name);
+        if location < 0 {
+            None
+        } else {
+            Some(location as u32)
+        }
     }
 
     /// Returns the location of a specific uniform variable which is part this `WebGLRSProgram`.
@@ -130,14 +689,99 @@ impl<'ctx> WebGLRSProgram<'ctx> {
     /// * `name`- specifying the name of the uniform variable whose location is to be returned. The name can't
     ///         have any whitespace in it, and you can't use this function to get the location of any uniforms
     ///         starting with the reserved string "gl_", since those are internal to the WebGL layer.
+    ///
+    /// In debug builds the type of the uniform is looked up with `getActiveUniform`, values passed to
+    /// `WebGLRSUniformLocation::set` are checked against it:
+    ///
+    /// ```
+    /// use webgl_rs::recording::{ActiveUniform, ProgramInterface};
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// gl.set_program_interface(ProgramInterface {
+    ///     uniforms: vec![
+    ///         ActiveUniform::new("color", UniformType::FloatVec3, 1),
+    ///         ActiveUniform::new("offsets[0]", UniformType::FloatVec2, 4),
+    ///     ],
+    ///     ..ProgramInterface::default()
+    /// });
+    /// let program = gl.create_program();
+    /// let color = program.uniform_location("color");
+    /// let offset = program.uniform_location("offsets[2]");
+    /// assert_eq!(program.uniform_location("missing").uniform_type(), None);
+    ///
+    /// if CHECKS_ENABLED {
+    ///     assert_eq!(color.uniform_type(), Some(UniformType::FloatVec3));
+    ///     assert_eq!(offset.uniform_type(), Some(UniformType::FloatVec2));
+    ///     assert!(color.set(1i32).is_err());
+    /// } else {
+    ///     assert_eq!(color.uniform_type(), None);
+    /// }
+    /// color.set([1.0, 0.5, 0.0]).unwrap();
+    /// ```
     // FIXME: can be null
-    pub fn uniform_location(&self, name: &str) -> WebGLRSUniformLocation {
+    pub fn uniform_location(&self, name: &str) -> WebGLRSUniformLocation<'_, B> {
+        let location = self.untyped_uniform_location(name);
+        if !CHECKS_ENABLED {
+            return location;
+        }
+        let uniform_type = (0..self.active_uniforms().max(0) as u32)
+            .map(|index| self.active_uniform(index))
+            .find(|info| is_uniform_name(&info.name, name))
+            .and_then(|info| info.uniform_type());
+        match uniform_type {
+            Some(uniform_type) => location.with_type(uniform_type),
+            None => location,
+        }
+    }
+
+    /// Returns the location of a uniform variable without looking up its type.
+    pub(crate) fn untyped_uniform_location(&self, name: &str) -> WebGLRSUniformLocation<'_, B> {
         WebGLRSUniformLocation {
-            context: &self.context,
+            context: self.context,
             inner: self.context._get_uniform_location(&self.inner, name),
+            uniform_type: None,
         }
     }
 
+    /// Indicates whether or not the program is flagged for deletion.
+    pub fn delete_status(&self) -> bool {
+        self.context
+            ._get_program_parameter_bool(&self.inner, ProgramParameter::DeleteStatus)
+    }
+
+    /// Indicates whether or not the last link operation was successful.
+    pub fn link_status(&self) -> bool {
+        self.context
+            ._get_program_parameter_bool(&self.inner, ProgramParameter::LinkStatus)
+    }
+
+    /// Indicates whether or not the last validation operation was successful.
+    pub fn validate_status(&self) -> bool {
+        self.context
+            ._get_program_parameter_bool(&self.inner, ProgramParameter::ValidateStatus)
+    }
+
+    /// Returns the number of attached shaders to a program.
+    pub fn attached_shaders(&self) -> i32 {
+        self.context
+            ._get_program_parameter_i32(&self.inner, ProgramParameter::AttachedShaders)
+    }
+
+    /// Returns the number of active attribute variables to a program.
+    pub fn active_attributes(&self) -> i32 {
+        self.context
+            ._get_program_parameter_i32(&self.inner, ProgramParameter::ActiveAttributes)
+    }
+
+    /// Returns the number of active uniform variables to a program.
+    pub fn active_uniforms(&self) -> i32 {
+        self.context
+            ._get_program_parameter_i32(&self.inner, ProgramParameter::ActiveUniforms)
+    }
+}
+
+impl<'ctx, B: WebGL2ProgramBackend> WebGLRSProgram<'ctx, B> {
     /// Returns the binding of color numbers to user-defined varying out variables.
     ///
     /// # Arguments
@@ -150,7 +794,7 @@ impl<'ctx> WebGLRSProgram<'ctx> {
     ///
     /// # Arguments
     /// * `index` -  specifying the index of the varying variable whose information to retrieve.
-    pub fn transform_feedback_varying(&self, index: u32) -> WebGLActiveInfo {
+    pub fn transform_feedback_varying(&self, index: u32) -> WebGLRSActiveInfo {
         self.context
             ._get_transform_feedback_varying(&self.inner, index)
     }
@@ -190,58 +834,161 @@ impl<'ctx> WebGLRSProgram<'ctx> {
         );
     }
 
-    /// Indicates whether or not the program is flagged for deletion.
-    pub fn delete_status(&self) -> bool {
+    /// Returns the buffer mode when transform feedback is active. May be `SeparateAttribs` or `InterleavedAttribs`.
+    pub fn transform_feedback_buffer_mode(&self) -> TransformFeedbackBufferMode {
         self.context
-            ._get_program_parameter_bool(&self.inner, ProgramParameter::DeleteStatus)
+            ._get_program_parameter_enum(&self.inner, ProgramParameter::TransformFeedbackBufferMode)
     }
 
-    /// Indicates whether or not the last link operation was successful.
-    pub fn link_status(&self) -> bool {
+    /// Returns the number of varying variables to capture in transform feedback mode.
+    pub fn transform_feedback_varyings(&self) -> i32 {
         self.context
-            ._get_program_parameter_bool(&self.inner, ProgramParameter::LinkStatus)
+            ._get_program_parameter_i32(&self.inner, ProgramParameter::TransformFeedbackVaryings)
     }
 
-    /// Indicates whether or not the last validation operation was successful.
-    pub fn validate_status(&self) -> bool {
+    // Returns the number of uniform blocks containing active uniforms.
+    pub fn active_uniform_blocks(&self) -> i32 {
         self.context
-            ._get_program_parameter_bool(&self.inner, ProgramParameter::ValidateStatus)
+            ._get_program_parameter_i32(&self.inner, ProgramParameter::ActiveUniformBlocks)
     }
 
-    /// Returns the number of attached shaders to a program.
-    pub fn attached_shaders(&self) -> i32 {
+    /// Specifies the varyings that are recorded in transform feedback buffers, takes effect on the next
+    /// `link`.
+    ///
+    /// # Arguments
+    /// * `varyings` - the names of the varyings to record.
+    /// * `buffer_mode` - whether the varyings are interleaved in one buffer or written to separate buffers.
+    pub fn set_transform_feedback_varyings(
+        &self,
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    ) {
         self.context
-            ._get_program_parameter_i32(&self.inner, ProgramParameter::AttachedShaders)
+            ._transform_feedback_varyings(&self.inner, varyings, buffer_mode);
     }
 
-    /// Returns the number of active attribute variables to a program.
-    pub fn active_attributes(&self) -> i32 {
-        self.context
-            ._get_program_parameter_i32(&self.inner, ProgramParameter::ActiveAttributes)
+    /// Returns the indices of the uniform blocks of the given uniforms, -1 for uniforms that are not part of
+    /// a block.
+    ///
+    /// # Arguments
+    /// * `uniform_indices` - the indices of the active uniforms to query.
+    pub fn uniform_block_indices(&self, uniform_indices: &[u32]) -> Vec<i32> {
+        self.context._get_active_uniforms_i32(
+            &self.inner,
+            uniform_indices,
+            UniformParameter::UniformBlockIndex,
+        )
     }
 
-    /// Returns the number of active uniform variables to a program.
-    pub fn active_uniforms(&self) -> i32 {
-        self.context
-            ._get_program_parameter_i32(&self.inner, ProgramParameter::ActiveUniforms)
+    /// Returns the byte offsets of the given uniforms in their uniform block, -1 for uniforms that are not
+    /// part of a block.
+    ///
+    /// # Arguments
+    /// * `uniform_indices` - the indices of the active uniforms to query.
+    pub fn uniform_offsets(&self, uniform_indices: &[u32]) -> Vec<i32> {
+        self.context._get_active_uniforms_i32(
+            &self.inner,
+            uniform_indices,
+            UniformParameter::UniformOffset,
+        )
     }
 
-    /// Returns the buffer mode when transform feedback is active. May be `SeparateAttribs` or `InterleavedAttribs`.
-    pub fn transform_feedback_buffer_mode(&self) -> TransformFeedbackBufferMode {
-        self.context
-            ._get_program_parameter_enum(&self.inner, ProgramParameter::TransformFeedbackBufferMode)
+    /// Returns the byte strides between the elements of the given array uniforms, 0 for uniforms that are
+    /// not arrays and -1 for uniforms that are not part of a block.
+    ///
+    /// # Arguments
+    /// * `uniform_indices` - the indices of the active uniforms to query.
+    pub fn uniform_array_strides(&self, uniform_indices: &[u32]) -> Vec<i32> {
+        self.context._get_active_uniforms_i32(
+            &self.inner,
+            uniform_indices,
+            UniformParameter::UniformArrayStride,
+        )
     }
 
-    /// Returns the number of varying variables to capture in transform feedback mode.
-    pub fn transform_feedback_varyings(&self) -> i32 {
-        self.context
-            ._get_program_parameter_i32(&self.inner, ProgramParameter::TransformFeedbackVaryings)
+    /// Returns the byte strides between the columns (or rows) of the given matrix uniforms, 0 for uniforms
+    /// that are not matrices and -1 for uniforms that are not part of a block.
+    ///
+    /// # Arguments
+    /// * `uniform_indices` - the indices of the active uniforms to query.
+    pub fn uniform_matrix_strides(&self, uniform_indices: &[u32]) -> Vec<i32> {
+        self.context._get_active_uniforms_i32(
+            &self.inner,
+            uniform_indices,
+            UniformParameter::UniformMatrixStride,
+        )
     }
 
-    // Returns the number of uniform blocks containing active uniforms.
-    pub fn active_uniform_blocks(&self) -> i32 {
-        self.context
-            ._get_program_parameter_i32(&self.inner, ProgramParameter::ActiveUniformBlocks)
+    /// Returns whether the given matrix uniforms are stored in row major order.
+    ///
+    /// # Arguments
+    /// * `uniform_indices` - the indices of the active uniforms to query.
+    pub fn uniform_is_row_major(&self, uniform_indices: &[u32]) -> Vec<bool> {
+        self.context._get_active_uniforms_bool(
+            &self.inner,
+            uniform_indices,
+            UniformParameter::UniformIsRowMajor,
+        )
+    }
+
+    /// Returns the binding point assigned to an active uniform block.
+    ///
+    /// # Arguments
+    /// * `uniform_block_index` - specifying the index of the active uniform block within the program.
+    pub fn uniform_block_binding(&self, uniform_block_index: u32) -> u32 {
+        self.context._get_active_uniform_block_parameter_u32(
+            &self.inner,
+            uniform_block_index,
+            UniformBlockParameter::UniformBlockBinding,
+        )
+    }
+
+    /// Returns the minimum size in bytes of the buffer that backs an active uniform block.
+    ///
+    /// # Arguments
+    /// * `uniform_block_index` - specifying the index of the active uniform block within the program.
+    pub fn uniform_block_data_size(&self, uniform_block_index: u32) -> u32 {
+        self.context._get_active_uniform_block_parameter_u32(
+            &self.inner,
+            uniform_block_index,
+            UniformBlockParameter::UniformBlockDataSize,
+        )
+    }
+
+    /// Returns the indices of the active uniforms in an active uniform block.
+    ///
+    /// # Arguments
+    /// * `uniform_block_index` - specifying the index of the active uniform block within the program.
+    pub fn uniform_block_active_uniform_indices(&self, uniform_block_index: u32) -> Vec<u32> {
+        self.context._get_active_uniform_block_parameter_u32_array(
+            &self.inner,
+            uniform_block_index,
+            UniformBlockParameter::UniformBlockActiveUniformIndices,
+        )
+    }
+
+    /// Returns whether an active uniform block is used by the vertex shader.
+    ///
+    /// # Arguments
+    /// * `uniform_block_index` - specifying the index of the active uniform block within the program.
+    pub fn uniform_block_referenced_by_vertex_shader(&self, uniform_block_index: u32) -> bool {
+        self.context._get_active_uniform_block_parameter_bool(
+            &self.inner,
+            uniform_block_index,
+            UniformBlockParameter::UniformBlockReferencedByVertexShader,
+        )
+    }
+
+    /// Returns whether an active uniform block is used by the fragment shader.
+    ///
+    /// # Arguments
+    /// * `uniform_block_index` - specifying the index of the active uniform block within the program.
+    pub fn uniform_block_referenced_by_fragment_shader(&self, uniform_block_index: u32) -> bool {
+        self.context._get_active_uniform_block_parameter_bool(
+            &self.inner,
+            uniform_block_index,
+            UniformBlockParameter::UniformBlockReferencedByFragmentShader,
+        )
     }
 }
 
@@ -251,7 +998,7 @@ impl<'ctx> WebGLRSProgram<'ctx> {
 extern "C" {
 
     #[derive(Clone)]
-    type WebGLProgram;
+    pub type WebGLProgram;
 
     /// Binding for `WebGLRenderingContext.createProgram()`
     #[wasm_bindgen(method, js_name = createProgram)]
@@ -320,7 +1067,7 @@ extern "C" {
         this: &WebGL2RenderingContext,
         program: &WebGLProgram,
         name: &str,
-    ) -> u32;
+    ) -> i32;
 
     /// Binding for `WebGLRenderingContext method getUniformLocation()`.
     #[wasm_bindgen(method, js_name = getUniformLocation)]
@@ -392,40 +1139,147 @@ extern "C" {
         program: &WebGLProgram,
         pname: ProgramParameter,
     ) -> TransformFeedbackBufferMode;
+    /// Binding for `WebGL2RenderingContext.transformFeedbackVaryings()`
+    #[wasm_bindgen(method, js_name = transformFeedbackVaryings)]
+    fn _transform_feedback_varyings(
+        this: &WebGL2RenderingContext,
+        program: &WebGLProgram,
+        varyings: &StringArray,
+        buffer_mode: TransformFeedbackBufferMode,
+    );
+
+    /// Binding for `WebGL2RenderingContext.getActiveUniforms()` when return type is i32
+    #[wasm_bindgen(method, js_name = getActiveUniforms)]
+    fn _get_active_uniforms_i32(
+        this: &WebGL2RenderingContext,
+        program: &WebGLProgram,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<i32>;
+
+    /// Binding for `WebGL2RenderingContext.getActiveUniforms()` when return type is bool
+    #[wasm_bindgen(method, js_name = getActiveUniforms)]
+    fn _get_active_uniforms_bool(
+        this: &WebGL2RenderingContext,
+        program: &WebGLProgram,
+        uniform_indices: &[u32],
+        pname: UniformParameter,
+    ) -> Vec<u8>;
+
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockParameter()` when return type is u32
+    #[wasm_bindgen(method, js_name = getActiveUniformBlockParameter)]
+    fn _get_active_uniform_block_parameter_u32(
+        this: &WebGL2RenderingContext,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> u32;
+
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockParameter()` when return type is bool
+    #[wasm_bindgen(method, js_name = getActiveUniformBlockParameter)]
+    fn _get_active_uniform_block_parameter_bool(
+        this: &WebGL2RenderingContext,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> bool;
+
+    /// Binding for `WebGL2RenderingContext.getActiveUniformBlockParameter()` when return type is
+    /// `Uint32Array`
+    #[wasm_bindgen(method, js_name = getActiveUniformBlockParameter)]
+    fn _get_active_uniform_block_parameter_u32_array(
+        this: &WebGL2RenderingContext,
+        program: &WebGLProgram,
+        uniform_block_index: u32,
+        pname: UniformBlockParameter,
+    ) -> Vec<u32>;
 }
 
 // WebGLRSShader
 
-impl WebGL2RenderingContext {
-    /// Creates a WebGLRSShader that can then be configured further using `shader_source()` and `compile_shader()`.
-    ///
-    /// # Arguments
-    /// * `kind` - Kind of the shader (Vertex or Fragment)
-    pub fn create_shader(&self, kind: ShaderKind) -> WebGLRSShader {
-        WebGLRSShader {
-            context: self,
-            inner: self._create_shader(kind),
+/// The `WebGLRSShader` is part of the WebGL API and can either be a vertex or a fragment shader.
+/// A `WebGLRSProgram` requires both types of shaders.
+///
+/// The object is deleted when the `WebGLRSShader` is dropped.
+pub struct WebGLRSShader<'ctx, B: 'ctx + ProgramBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Shader,
+    leaked: bool,
+}
+
+impl<'ctx, B: ProgramBackend> Drop for WebGLRSShader<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_shader(self.inner.clone());
         }
     }
 }
 
-/// The `WebGLRSShader` is part of the WebGL API and can either be a vertex or a fragment shader.
-/// A `WebGLRSProgram` requires both types of shaders.
-#[derive(Clone)]
-pub struct WebGLRSShader<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLShader,
+impl<'ctx, B: ProgramBackend + ContextBackend> WebGLRSShader<'ctx, B> {
+    /// Returns a view on this shader whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: ProgramBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSShader<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSShader "shader";
+        fn compile();
+        fn info_log() -> String;
+        fn shader_source() -> String;
+        fn is_valid() -> bool;
+        fn set_shader_source(source: &str);
+        fn delete_status() -> bool;
+        fn compile_status() -> bool;
+        fn kind() -> ShaderKind;
+    }
 }
 
-impl<'ctx> WebGLRSShader<'ctx> {
+impl<'ctx, B: ProgramBackend> WebGLRSShader<'ctx, B> {
     /// Compiles a GLSL shader into binary data so that it can be used by a `WebGLRSProgram`.
     pub fn compile(&self) {
         self.context._compile_shader(&self.inner);
     }
 
     /// Deletes this `WebGLRSShader` object.
+    ///
+    /// This is the same as dropping the `WebGLRSShader`.
     pub fn delete(self) {
-        self.context._delete_shader(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSShader` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `shader` - the raw handle.
+    pub fn from_raw(context: &'ctx B, shader: B::Shader) -> Self {
+        WebGLRSShader {
+            context,
+            inner: shader,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Shader {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSShader` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Returns the information log for the specified `WebGLRSShader` object. It contains warnings, debugging
@@ -473,7 +1327,7 @@ impl<'ctx> WebGLRSShader<'ctx> {
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLShader;
+    pub type WebGLShader;
 
     /// Binding for `WebGLRenderingContext.createShader()`
     #[wasm_bindgen(method, js_name = createShader)]
@@ -0,0 +1,70 @@
+//! Shared ownership of WebGL objects
+//!
+//! The `WebGLRS*` object wrappers delete their object when they are dropped, so they can not be cloned.
+//! When an object has to be used from multiple places it can be moved into a `Shared` handle instead.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let buffer = gl.create_buffer().share();
+//! let other = buffer.clone();
+//! drop(buffer);
+//! other.bind(BufferKind::Array);
+//! assert_eq!(Shared::handle_count(&other), 1);
+//! drop(other);
+//!
+//! let calls: Vec<String> = gl.calls().iter().map(|call| call.to_string()).collect();
+//! assert_eq!(
+//!     calls,
+//!     vec![
+//!         "createBuffer()",
+//!         "bindBuffer(Array, Handle(1))",
+//!         "deleteBuffer(Handle(1))",
+//!     ]
+//! );
+//! ```
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// Reference counted handle to a WebGL object wrapper.
+///
+/// The wrapped object is deleted when the last clone of the handle is dropped.
+pub struct Shared<T>(Rc<T>);
+
+impl<T> Shared<T> {
+    /// Moves an object wrapper into a new `Shared` handle.
+    pub fn new(object: T) -> Shared<T> {
+        Shared(Rc::new(object))
+    }
+
+    /// Returns the number of handles that share the object.
+    pub fn handle_count(this: &Shared<T>) -> usize {
+        Rc::strong_count(&this.0)
+    }
+
+    /// Returns the object wrapper if this is the only handle, otherwise the handle is returned again.
+    pub fn try_unwrap(this: Shared<T>) -> Result<T, Shared<T>> {
+        Rc::try_unwrap(this.0).map_err(Shared)
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Shared(Rc::clone(&self.0))
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Shared<T> {
+    fn from(object: T) -> Shared<T> {
+        Shared::new(object)
+    }
+}
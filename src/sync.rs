@@ -1,33 +1,137 @@
 //! WebGLSync and methods
+use error::CheckedObject;
 use glenum::{GPUState, SyncParameter, SyncStatus, WaitStatus};
-use rendering_context::WebGL2RenderingContext;
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing sync objects.
+pub trait SyncBackend: Sized {
+    /// Handle to a sync object of this backend.
+    type Sync: Clone;
+
+    /// Binding for `WebGL2RenderingContext.fenceSync()`
+    fn _fence_sync(&self, conditions: GPUState, flags: u32) -> Self::Sync;
+    /// Binding for `WebGL2RenderingContext.isSync()` method of the WebGL 2 API
+    fn _is_sync(&self, sync: &Self::Sync) -> bool;
+    /// Binding for `WebGL2RenderingContext.deleteSync()`
+    fn _delete_sync(&self, sync: Self::Sync);
+    /// Binding for `WebGL2RenderingContext.clientWaitSync()`
+    fn _client_wait_sync(&self, sync: &Self::Sync, flags: u32, timeout: i64) -> WaitStatus;
+    /// Binding for `WebGL2RenderingContext.waitSync()`
+    fn _wait_sync(&self, sync: &Self::Sync, flags: u32, timeout: i64);
+    /// Binding for `WebGL2RenderingContext.getSyncParameter()` when asking for status
+    fn _get_sync_parameter_status(&self, sync: &Self::Sync, pname: SyncParameter) -> SyncStatus;
+
     /// Creates a new `WebGLRSSync` object and inserts it into the GL command stream.
     ///
     /// # Arguments
     /// * `conditions` - specifying the condition that must be met to set the sync object's state to.
     /// * `flags` - specifying a bitwise combination of flags controlling the behavior of the sync object.
     ///         Must be 0 (exists for extensions only).
-    pub fn fence_sync(&self, conditions: GPUState, flags: u32) -> WebGLRSSync {
+    fn fence_sync(&self, conditions: GPUState, flags: u32) -> WebGLRSSync<'_, Self> {
         WebGLRSSync {
             context: self,
             inner: self._fence_sync(conditions, flags),
+            leaked: false,
+        }
+    }
+}
+
+impl SyncBackend for WebGL2RenderingContext {
+    type Sync = WebGLSync;
+
+    fn _fence_sync(&self, conditions: GPUState, flags: u32) -> WebGLSync {
+        WebGL2RenderingContext::_fence_sync(self, conditions, flags)
+    }
+    fn _is_sync(&self, sync: &WebGLSync) -> bool {
+        WebGL2RenderingContext::_is_sync(self, sync)
+    }
+    fn _delete_sync(&self, sync: WebGLSync) {
+        WebGL2RenderingContext::_delete_sync(self, sync)
+    }
+    fn _client_wait_sync(&self, sync: &WebGLSync, flags: u32, timeout: i64) -> WaitStatus {
+        WebGL2RenderingContext::_client_wait_sync(self, sync, flags, timeout)
+    }
+    fn _wait_sync(&self, sync: &WebGLSync, flags: u32, timeout: i64) {
+        WebGL2RenderingContext::_wait_sync(self, sync, flags, timeout)
+    }
+    fn _get_sync_parameter_status(&self, sync: &WebGLSync, pname: SyncParameter) -> SyncStatus {
+        WebGL2RenderingContext::_get_sync_parameter_status(self, sync, pname)
+    }
+}
+
+/// Sync object which is used to synchronize activities between the GPU and the application.
+///
+/// The object is deleted when the `WebGLRSSync` is dropped.
+pub struct WebGLRSSync<'ctx, B: 'ctx + SyncBackend = WebGL2RenderingContext> {
+    context: &'ctx B,
+    inner: B::Sync,
+    leaked: bool,
+}
+
+impl<'ctx, B: SyncBackend> Drop for WebGLRSSync<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_sync(self.inner.clone());
         }
     }
 }
 
-#[derive(Clone)]
-pub struct WebGLRSSync<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLSync,
+impl<'ctx, B: SyncBackend + ContextBackend> WebGLRSSync<'ctx, B> {
+    /// Returns a view on this sync whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: SyncBackend + ContextBackend> CheckedObject<'a, 'ctx, B, WebGLRSSync<'ctx, B>> {
+    checked_methods! {
+        object WebGLRSSync "sync";
+        fn is_valid() -> bool;
+        fn client_wait(flags: u32, timeout: i64) -> WaitStatus;
+        fn wait(flags: u32, timeout: i64);
+        fn status() -> SyncStatus;
+    }
 }
 
-impl<'ctx> WebGLRSSync<'ctx> {
+impl<'ctx, B: SyncBackend> WebGLRSSync<'ctx, B> {
     /// Deletes this `WebGLRSSync` object
+    ///
+    /// This is the same as dropping the `WebGLRSSync`.
     pub fn delete(self) {
-        self.context._delete_sync(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSSync` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `sync` - the raw handle.
+    pub fn from_raw(context: &'ctx B, sync: B::Sync) -> Self {
+        WebGLRSSync {
+            context,
+            inner: sync,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Sync {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSSync` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Returns true if this is a valid `WebGLRSSync` object.  
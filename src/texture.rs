@@ -1,30 +1,273 @@
 //! WebGLTexture and methods
-use glenum::{Attachment, FramebufferKind, TextureBindPoint, TextureKind};
-use rendering_context::WebGL2RenderingContext;
+use backend::{GlBackend, WebGL2Backend};
+use error::CheckedObject;
+use formats::format_info;
+use glenum::{
+    Attachment, FramebufferAttachmentParameter, FramebufferKind, InternalFormat, Parameter,
+    TextureBindPoint, TextureKind,
+};
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
+use texture_builder::TextureError;
+use texture_builder::TextureUpload;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing texture objects.
+pub trait TextureBackend: Sized {
+    /// Handle to a texture object of this backend.
+    type Texture: Clone;
+
+    /// Binding for `WebGLRenderingContext.createTexture()`.
+    fn _create_texture(&self) -> Self::Texture;
+    /// Binding for `WebGLRenderingContext.bindTexture()`
+    fn _bind_texture(&self, target: TextureKind, texture: &Self::Texture);
+    /// Binding for `WebGLRenderingContext.deleteTexture()`
+    fn _delete_texture(&self, texture: Self::Texture);
+    /// Binding for `WebGLRenderingContext.isTexture()`
+    fn _is_texture(&self, texture: &Self::Texture) -> bool;
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `WebGLTexture`
+    fn _get_parameter_texture(&self, pname: Parameter) -> Option<Self::Texture>;
+    /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is
+    /// `WebGLTexture`
+    fn _get_framebuffer_attachment_parameter_texture(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<Self::Texture>;
+    /// Binding for `WebGLRenderingContext.framebufferTexture2D()`
+    fn _framebuffer_texture_2d(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        textarget: TextureBindPoint,
+        texture: &Self::Texture,
+        level: i32,
+    );
+
     /// Creates and initializes a WebGLRSTexture
-    pub fn create_texture(&self) -> WebGLRSTexture {
+    fn create_texture(&self) -> WebGLRSTexture<'_, Self> {
         WebGLRSTexture {
             context: self,
             inner: self._create_texture(),
+            leaked: false,
+            storage: None,
         }
     }
 }
 
+impl TextureBackend for WebGL2RenderingContext {
+    type Texture = WebGLTexture;
+
+    fn _create_texture(&self) -> WebGLTexture {
+        WebGL2RenderingContext::_create_texture(self)
+    }
+    fn _bind_texture(&self, target: TextureKind, texture: &WebGLTexture) {
+        WebGL2RenderingContext::_bind_texture(self, target, texture)
+    }
+    fn _delete_texture(&self, texture: WebGLTexture) {
+        WebGL2RenderingContext::_delete_texture(self, texture)
+    }
+    fn _is_texture(&self, texture: &WebGLTexture) -> bool {
+        WebGL2RenderingContext::_is_texture(self, texture)
+    }
+    fn _get_parameter_texture(&self, pname: Parameter) -> Option<WebGLTexture> {
+        WebGL2RenderingContext::_get_parameter_texture(self, pname)
+    }
+    fn _get_framebuffer_attachment_parameter_texture(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<WebGLTexture> {
+        WebGL2RenderingContext::_get_framebuffer_attachment_parameter_texture(
+            self, target, attachment, pname,
+        )
+    }
+    fn _framebuffer_texture_2d(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        textarget: TextureBindPoint,
+        texture: &WebGLTexture,
+        level: i32,
+    ) {
+        WebGL2RenderingContext::_framebuffer_texture_2d(
+            self, target, attachment, textarget, texture, level,
+        )
+    }
+}
+
+/// Backend calls for the texture methods that were added in WebGL2.
+pub trait WebGL2TextureBackend: TextureBackend {
+    /// Binding for `WebGL2RenderingContext.framebufferTextureLayer()`
+    fn _framebuffer_texture_layer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        texture: &Self::Texture,
+        level: i32,
+        layer: i32,
+    );
+}
+
+impl WebGL2TextureBackend for WebGL2RenderingContext {
+    fn _framebuffer_texture_layer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        texture: &WebGLTexture,
+        level: i32,
+        layer: i32,
+    ) {
+        WebGL2RenderingContext::_framebuffer_texture_layer(
+            self, target, attachment, texture, level, layer,
+        )
+    }
+}
+
+/// The kind, format and size of the storage of a texture, known for textures created by a `TextureBuilder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureStorage {
+    /// The kind of the texture.
+    pub kind: TextureKind,
+    /// The sized internal format of the texture.
+    pub internal_format: InternalFormat,
+    /// The width of mipmap level 0.
+    pub width: u32,
+    /// The height of mipmap level 0.
+    pub height: u32,
+    /// The depth of mipmap level 0 of a 3D texture or the number of layers of an array texture, 1 for 2D
+    /// textures and cube maps.
+    pub depth: u32,
+    /// The number of mipmap levels.
+    pub levels: u32,
+}
+
+impl TextureStorage {
+    /// Returns the width, height and depth of a mipmap level, `None` if the texture does not have the level.
+    ///
+    /// The layers of an array texture are not reduced by the mipmap levels.
+    pub fn level_size(&self, level: u32) -> Option<(u32, u32, u32)> {
+        if level >= self.levels {
+            return None;
+        }
+        let depth = if self.kind == TextureKind::Texture3d {
+            (self.depth >> level).max(1)
+        } else {
+            self.depth
+        };
+        Some((
+            (self.width >> level).max(1),
+            (self.height >> level).max(1),
+            depth,
+        ))
+    }
+
+    /// Returns the number of mipmap levels of a complete mipmap chain for the size of the texture.
+    pub fn max_levels(&self) -> u32 {
+        let mut largest = self.width.max(self.height);
+        if self.kind == TextureKind::Texture3d {
+            largest = largest.max(self.depth);
+        }
+        32 - largest.leading_zeros()
+    }
+}
+
 /// The WebGLTexture interface is part of the WebGL API and represents an opaque texture object providing
 /// storage and state for texturing operations.
-#[derive(Clone)]
-pub struct WebGLRSTexture<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLTexture,
+///
+/// The object is deleted when the `WebGLRSTexture` is dropped.
+pub struct WebGLRSTexture<'ctx, B: 'ctx + TextureBackend = WebGL2RenderingContext> {
+    pub(crate) context: &'ctx B,
+    inner: B::Texture,
+    leaked: bool,
+    storage: Option<TextureStorage>,
+}
+
+impl<'ctx, B: TextureBackend> Drop for WebGLRSTexture<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_texture(self.inner.clone());
+        }
+    }
 }
 
-impl<'ctx> WebGLRSTexture<'ctx> {
+impl<'ctx, B: TextureBackend + ContextBackend> WebGLRSTexture<'ctx, B> {
+    /// Returns a view on this texture whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: TextureBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSTexture<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSTexture "texture";
+        fn bind(target: TextureKind);
+        fn is_valid() -> bool;
+        fn attach_framebuffer(
+            target: FramebufferKind,
+            attachment: Attachment,
+            tex_target: TextureBindPoint,
+            level: i32,
+        );
+    }
+}
+
+impl<'a, 'ctx, B: WebGL2TextureBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSTexture<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSTexture "texture";
+        fn attach_layer_framebuffer(
+            target: FramebufferKind,
+            attachment: Attachment,
+            level: i32,
+            layer: i32,
+        );
+    }
+}
+
+impl<'ctx, B: TextureBackend> WebGLRSTexture<'ctx, B> {
     /// Deletes the `WebGLRSTexture` object.
+    ///
+    /// This is the same as dropping the `WebGLRSTexture`.
     pub fn delete(self) {
-        self.context._delete_texture(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSTexture` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `texture` - the raw handle.
+    pub fn from_raw(context: &'ctx B, texture: B::Texture) -> Self {
+        WebGLRSTexture {
+            context,
+            inner: texture,
+            leaked: false,
+            storage: None,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::Texture {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSTexture` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Binds the `WebGLRSTexture` to a target
@@ -40,6 +283,16 @@ impl<'ctx> WebGLRSTexture<'ctx> {
         self.context._is_texture(&self.inner)
     }
 
+    /// Returns the kind, format and size of the texture, `None` if it was not created by a `TextureBuilder`.
+    pub fn storage(&self) -> Option<TextureStorage> {
+        self.storage
+    }
+
+    /// Remembers the storage that was allocated for the texture.
+    pub(crate) fn set_storage(&mut self, storage: TextureStorage) {
+        self.storage = Some(storage);
+    }
+
     /// Attaches this `WebGLRSTexture` object to a framebuffer.
     ///
     /// # Arguments
@@ -57,7 +310,9 @@ impl<'ctx> WebGLRSTexture<'ctx> {
         self.context
             ._framebuffer_texture_2d(target, attachment, tex_target, &self.inner, level);
     }
+}
 
+impl<'ctx, B: WebGL2TextureBackend> WebGLRSTexture<'ctx, B> {
     /// Attaches a single layer of this `WebGLRSTexture` object to a framebuffer.TextureBindPoint
     ///
     /// # Arguments
@@ -77,12 +332,40 @@ impl<'ctx> WebGLRSTexture<'ctx> {
     }
 }
 
+impl<'ctx, B: GlBackend> WebGLRSTexture<'ctx, B> {
+    /// Computes all mipmap levels of the texture from level 0.
+    ///
+    /// Fails without calling WebGL when the storage of the texture is not known or when its format is not
+    /// both color renderable and filterable, which `generateMipmap` requires.
+    pub fn generate_mipmaps(&self) -> Result<(), TextureError> {
+        let storage = self.storage.ok_or(TextureError::MissingStorage)?;
+        let info = format_info(storage.internal_format);
+        if !info.color_renderable || !info.filterable {
+            return Err(TextureError::NotMipmappable {
+                internal_format: storage.internal_format,
+            });
+        }
+        self.bind(storage.kind);
+        self.context.generate_mipmap(storage.kind);
+        Ok(())
+    }
+}
+
+impl<'ctx, B: WebGL2Backend> WebGLRSTexture<'ctx, B> {
+    /// Starts an upload of pixels to a mipmap level, see `TextureUpload`.
+    ///
+    /// The whole level is replaced unless a region is selected, cube maps also need a face.
+    pub fn upload(&self, level: u32) -> TextureUpload<'_, 'ctx, B> {
+        TextureUpload::new(self, level)
+    }
+}
+
 /// Bindings for WebGLTexture
 #[wasm_bindgen]
 #[derive(Clone, Copy)]
 extern "C" {
     #[derive(Clone)]
-    type WebGLTexture;
+    pub type WebGLTexture;
     /// Binding for `WebGLRenderingContext.createTexture()`.
     #[wasm_bindgen(method, js_name = createTexture)]
     fn _create_texture(this: &WebGL2RenderingContext) -> WebGLTexture;
@@ -99,6 +382,23 @@ extern "C" {
     #[wasm_bindgen(method, js_name = isTexture)]
     fn _is_texture(this: &WebGL2RenderingContext, texture: &WebGLTexture) -> bool;
 
+    /// Binding for `WebGLRenderingContext.getParameter()` when return type is `WebGLTexture`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_texture(
+        this: &WebGL2RenderingContext,
+        pname: Parameter,
+    ) -> Option<WebGLTexture>;
+
+    /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is
+    /// `WebGLTexture`
+    #[wasm_bindgen(method, js_name = getFramebufferAttachmentParameter)]
+    fn _get_framebuffer_attachment_parameter_texture(
+        this: &WebGL2RenderingContext,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<WebGLTexture>;
+
     /// Binding for `WebGLRenderingContext.framebufferTexture2D()`
     #[wasm_bindgen(method, js_name = framebufferTexture2D)]
     fn _framebuffer_texture_2d(
@@ -0,0 +1,849 @@
+//! Builder that creates a `WebGLRSTexture` with immutable storage, and uploads to its mipmap levels
+//!
+//! A texture created by a `TextureBuilder` remembers its kind, format and size, uploads and
+//! `generate_mipmaps` are checked against them before WebGL is called.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let texture = TextureBuilder::texture_2d(&gl, InternalFormat::Rgba8, 256, 128)
+//!     .mipmaps()
+//!     .wrap(TextureWrap::ClampToEdge)
+//!     .build()
+//!     .unwrap();
+//! let storage = texture.storage().unwrap();
+//! assert_eq!(storage.levels, 9);
+//! assert_eq!(storage.level_size(2), Some((64, 32, 1)));
+//!
+//! texture
+//!     .upload(0)
+//!     .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, &vec![0u8; 256 * 128 * 4])
+//!     .unwrap();
+//! texture.generate_mipmaps().unwrap();
+//!
+//! let error = texture
+//!     .upload(8)
+//!     .region(0, 0, 2, 2)
+//!     .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, &vec![0u8; 16])
+//!     .unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "the region of 2x2x1 at (0, 0, 0) does not fit in the mipmap level of 1x1x1"
+//! );
+//!
+//! let array = TextureBuilder::array_2d(&gl, InternalFormat::R32f, 16, 16, 4).build().unwrap();
+//! array
+//!     .upload(0)
+//!     .layer(3)
+//!     .pixels(PixelFormat::Red, PixelType::Float, &vec![0.0f32; 16 * 16])
+//!     .unwrap();
+//! assert_eq!(
+//!     gl.calls().last().unwrap().to_string(),
+//!     "texSubImage3D(Texture2dArray, 0, 0, 0, 3, 16, 16, 1, Red, Float, [f32; 256])"
+//! );
+//!
+//! let calls = gl.calls().len();
+//! texture
+//!     .upload(1)
+//!     .flip_y(true)
+//!     .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, &vec![0u8; 128 * 64 * 4])
+//!     .unwrap();
+//! let calls: Vec<String> = gl.calls()[calls..].iter().map(|call| call.to_string()).collect();
+//! assert_eq!(calls[calls.len() - 3], "pixelStorei(UnpackFlipYWebgl, 1)");
+//! assert_eq!(calls[calls.len() - 1], "pixelStorei(UnpackFlipYWebgl, 0)");
+//! assert!(!gl.parameters().unpack_flip_y());
+//! ```
+use backend::WebGL2Backend;
+use data_view::Image;
+use formats::FormatError;
+use formats::{format_info, validate_pixels, validate_tex_image, validate_tex_storage};
+use glenum::{
+    InternalFormat, Parameter, PixelFormat, PixelStorageMode, PixelType, Texture2DKind,
+    Texture3DKind, TextureBindPoint, TextureKind, TextureMagFilter, TextureMinFilter,
+    TextureParameter, TextureWrap,
+};
+use image_source::TexImageSource;
+use std::error;
+use std::fmt;
+use texture::{TextureStorage, WebGLRSTexture};
+use wasm_bindgen::JsValue;
+
+/// Creates a texture with immutable storage through `texStorage2D` or `texStorage3D`.
+///
+/// Unless they are set, the filters are chosen to make the texture complete: linear filtering for
+/// filterable formats, nearest filtering otherwise, and mipmap filtering when the texture has more than
+/// one level. The wrap modes keep their WebGL defaults unless they are set. The texture stays bound to its
+/// kind after `build`.
+pub struct TextureBuilder<'ctx, B: 'ctx + WebGL2Backend> {
+    context: &'ctx B,
+    storage: TextureStorage,
+    min_filter: Option<TextureMinFilter>,
+    mag_filter: Option<TextureMagFilter>,
+    wrap_s: Option<TextureWrap>,
+    wrap_t: Option<TextureWrap>,
+    wrap_r: Option<TextureWrap>,
+}
+
+impl<'ctx, B: WebGL2Backend> TextureBuilder<'ctx, B> {
+    fn new(
+        context: &'ctx B,
+        kind: TextureKind,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Self {
+        TextureBuilder {
+            context,
+            storage: TextureStorage {
+                kind,
+                internal_format,
+                width,
+                height,
+                depth,
+                levels: 1,
+            },
+            min_filter: None,
+            mag_filter: None,
+            wrap_s: None,
+            wrap_t: None,
+            wrap_r: None,
+        }
+    }
+
+    /// Creates a builder for a 2D texture.
+    ///
+    /// # Arguments
+    /// * `context` - the context to create the texture in.
+    /// * `internal_format` - the sized internal format of the texture.
+    /// * `width` - the width of mipmap level 0.
+    /// * `height` - the height of mipmap level 0.
+    pub fn texture_2d(
+        context: &'ctx B,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        TextureBuilder::new(
+            context,
+            TextureKind::Texture2d,
+            internal_format,
+            width,
+            height,
+            1,
+        )
+    }
+
+    /// Creates a builder for a cube map, every face is a square of `size` pixels.
+    pub fn cube_map(context: &'ctx B, internal_format: InternalFormat, size: u32) -> Self {
+        TextureBuilder::new(
+            context,
+            TextureKind::TextureCubeMap,
+            internal_format,
+            size,
+            size,
+            1,
+        )
+    }
+
+    /// Creates a builder for a 3D texture.
+    ///
+    /// # Arguments
+    /// * `context` - the context to create the texture in.
+    /// * `internal_format` - the sized internal format of the texture.
+    /// * `width` - the width of mipmap level 0.
+    /// * `height` - the height of mipmap level 0.
+    /// * `depth` - the depth of mipmap level 0.
+    pub fn texture_3d(
+        context: &'ctx B,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Self {
+        TextureBuilder::new(
+            context,
+            TextureKind::Texture3d,
+            internal_format,
+            width,
+            height,
+            depth,
+        )
+    }
+
+    /// Creates a builder for a 2D array texture.
+    ///
+    /// # Arguments
+    /// * `context` - the context to create the texture in.
+    /// * `internal_format` - the sized internal format of the texture.
+    /// * `width` - the width of mipmap level 0.
+    /// * `height` - the height of mipmap level 0.
+    /// * `layers` - the number of layers.
+    pub fn array_2d(
+        context: &'ctx B,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+        layers: u32,
+    ) -> Self {
+        TextureBuilder::new(
+            context,
+            TextureKind::Texture2dArray,
+            internal_format,
+            width,
+            height,
+            layers,
+        )
+    }
+
+    /// Sets the number of mipmap levels, 1 by default.
+    pub fn levels(mut self, levels: u32) -> Self {
+        self.storage.levels = levels;
+        self
+    }
+
+    /// Allocates a complete mipmap chain down to a size of 1 pixel.
+    pub fn mipmaps(mut self) -> Self {
+        self.storage.levels = self.storage.max_levels();
+        self
+    }
+
+    /// Sets the minification filter.
+    pub fn min_filter(mut self, filter: TextureMinFilter) -> Self {
+        self.min_filter = Some(filter);
+        self
+    }
+
+    /// Sets the magnification filter.
+    pub fn mag_filter(mut self, filter: TextureMagFilter) -> Self {
+        self.mag_filter = Some(filter);
+        self
+    }
+
+    /// Sets the wrap mode of all texture coordinates, the r coordinate only for 3D and array textures.
+    pub fn wrap(self, wrap: TextureWrap) -> Self {
+        self.wrap_s(wrap).wrap_t(wrap).wrap_r(wrap)
+    }
+
+    /// Sets the wrap mode of the s coordinate.
+    pub fn wrap_s(mut self, wrap: TextureWrap) -> Self {
+        self.wrap_s = Some(wrap);
+        self
+    }
+
+    /// Sets the wrap mode of the t coordinate.
+    pub fn wrap_t(mut self, wrap: TextureWrap) -> Self {
+        self.wrap_t = Some(wrap);
+        self
+    }
+
+    /// Sets the wrap mode of the r coordinate, ignored for 2D textures and cube maps.
+    pub fn wrap_r(mut self, wrap: TextureWrap) -> Self {
+        self.wrap_r = Some(wrap);
+        self
+    }
+
+    /// Creates the texture and allocates its storage.
+    ///
+    /// Fails without calling WebGL when the format is not a sized texture format, when the size is zero or
+    /// when the number of levels is zero or larger than the mipmap chain of the size.
+    pub fn build(self) -> Result<WebGLRSTexture<'ctx, B>, TextureError> {
+        let storage = self.storage;
+        validate_tex_storage(storage.internal_format)
+            .map_err(|error| TextureError::Format { error })?;
+        if storage.width == 0 || storage.height == 0 || storage.depth == 0 {
+            return Err(TextureError::InvalidSize {
+                width: storage.width,
+                height: storage.height,
+                depth: storage.depth,
+            });
+        }
+        if storage.levels == 0 || storage.levels > storage.max_levels() {
+            return Err(TextureError::InvalidLevels {
+                levels: storage.levels,
+                max: storage.max_levels(),
+            });
+        }
+
+        let context = self.context;
+        let mut texture = context.create_texture();
+        texture.bind(storage.kind);
+        match storage.kind {
+            TextureKind::Texture2d | TextureKind::TextureCubeMap => context._tex_storage_2d(
+                texture_2d_kind(storage.kind),
+                storage.levels,
+                storage.internal_format,
+                storage.width,
+                storage.height,
+            ),
+            TextureKind::Texture3d | TextureKind::Texture2dArray => context._tex_storage_3d(
+                texture_3d_kind(storage.kind),
+                storage.levels,
+                storage.internal_format,
+                storage.width,
+                storage.height,
+                storage.depth,
+            ),
+        }
+
+        let filterable = format_info(storage.internal_format).filterable;
+        let min_filter = self
+            .min_filter
+            .unwrap_or(match (filterable, storage.levels > 1) {
+                (true, true) => TextureMinFilter::LinearMipmapLinear,
+                (true, false) => TextureMinFilter::Linear,
+                (false, true) => TextureMinFilter::NearestMipmapNearest,
+                (false, false) => TextureMinFilter::Nearest,
+            });
+        let mag_filter = self.mag_filter.unwrap_or(if filterable {
+            TextureMagFilter::Linear
+        } else {
+            TextureMagFilter::Nearest
+        });
+        context.tex_parameter_i(storage.kind, TextureParameter::MinFilter, min_filter as i32);
+        context.tex_parameter_i(storage.kind, TextureParameter::MagFilter, mag_filter as i32);
+        let has_r =
+            storage.kind == TextureKind::Texture3d || storage.kind == TextureKind::Texture2dArray;
+        for &(pname, wrap) in &[
+            (TextureParameter::WrapS, self.wrap_s),
+            (TextureParameter::WrapT, self.wrap_t),
+            (TextureParameter::WrapR, self.wrap_r.filter(|_| has_r)),
+        ] {
+            if let Some(wrap) = wrap {
+                context.tex_parameter_i(storage.kind, pname, wrap as i32);
+            }
+        }
+
+        texture.set_storage(storage);
+        Ok(texture)
+    }
+}
+
+/// Converts the kind of a 2D texture or cube map to the target of `texStorage2D`.
+fn texture_2d_kind(kind: TextureKind) -> Texture2DKind {
+    match kind {
+        TextureKind::TextureCubeMap => Texture2DKind::TextureCubeMap,
+        _ => Texture2DKind::Texture2d,
+    }
+}
+
+/// Converts the kind of a 3D or array texture to the target of `texStorage3D` and `texSubImage3D`.
+pub(crate) fn texture_3d_kind(kind: TextureKind) -> Texture3DKind {
+    match kind {
+        TextureKind::Texture2dArray => Texture3DKind::Texture2dArray,
+        _ => Texture3DKind::Texture3d,
+    }
+}
+
+/// Offset of a region of a texture.
+type Offset3 = (u32, u32, u32);
+/// Size of a region of a texture.
+type Size3 = (u32, u32, u32);
+
+/// Builder for an upload of pixels to a mipmap level of a texture, created with `WebGLRSTexture::upload`.
+///
+/// The upload is checked against the storage the texture was created with: the level, face and region have
+/// to exist, the pixel format and type have to match the internal format and the data has to hold the
+/// whole region. The texture stays bound to its kind afterwards.
+pub struct TextureUpload<'a, 'ctx: 'a, B: 'ctx + WebGL2Backend> {
+    texture: &'a WebGLRSTexture<'ctx, B>,
+    level: u32,
+    face: Option<TextureBindPoint>,
+    region: Option<(u32, u32, u32, u32)>,
+    layers: Option<(u32, u32)>,
+    flip_y: Option<bool>,
+    premultiply_alpha: Option<bool>,
+}
+
+impl<'a, 'ctx, B: WebGL2Backend> TextureUpload<'a, 'ctx, B> {
+    /// Creates an upload that replaces a whole mipmap level of the texture.
+    pub fn new(texture: &'a WebGLRSTexture<'ctx, B>, level: u32) -> Self {
+        TextureUpload {
+            texture,
+            level,
+            face: None,
+            region: None,
+            layers: None,
+            flip_y: None,
+            premultiply_alpha: None,
+        }
+    }
+
+    /// Uploads to a face of a cube map, one of the `TextureBindPoint::TextureCubeMap*` targets.
+    pub fn face(mut self, face: TextureBindPoint) -> Self {
+        self.face = Some(face);
+        self
+    }
+
+    /// Uploads to a rectangle of the level instead of the whole level.
+    ///
+    /// # Arguments
+    /// * `x` - the x offset of the rectangle.
+    /// * `y` - the y offset of the rectangle.
+    /// * `width` - the width of the rectangle.
+    /// * `height` - the height of the rectangle.
+    pub fn region(mut self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.region = Some((x, y, width, height));
+        self
+    }
+
+    /// Uploads to a single layer of an array texture or a single slice of a 3D texture.
+    pub fn layer(self, layer: u32) -> Self {
+        self.layers(layer, 1)
+    }
+
+    /// Uploads to consecutive layers of an array texture or slices of a 3D texture, all of them by default.
+    ///
+    /// # Arguments
+    /// * `first` - the first layer.
+    /// * `count` - the number of layers.
+    pub fn layers(mut self, first: u32, count: u32) -> Self {
+        self.layers = Some((first, count));
+        self
+    }
+
+    /// Sets `UNPACK_FLIP_Y_WEBGL` for this upload, the previous value is restored afterwards.
+    ///
+    /// WebGL2 only allows flipping pixels that are uploaded to 3D and array textures when they come from a
+    /// DOM source, `pixels` returns `TextureError::UnpackModeNotSupported` otherwise. When neither mode is
+    /// set, `pixels` turns both off for such uploads in case they were enabled on the context.
+    pub fn flip_y(mut self, flip_y: bool) -> Self {
+        self.flip_y = Some(flip_y);
+        self
+    }
+
+    /// Sets `UNPACK_PREMULTIPLY_ALPHA_WEBGL` for this upload, the previous value is restored afterwards.
+    ///
+    /// Like `flip_y` it can only be enabled for uploads to 3D and array textures from a DOM source.
+    pub fn premultiply_alpha(mut self, premultiply_alpha: bool) -> Self {
+        self.premultiply_alpha = Some(premultiply_alpha);
+        self
+    }
+
+    /// Checks the upload and replaces the selected pixels with `texSubImage2D` or `texSubImage3D`.
+    ///
+    /// # Arguments
+    /// * `format` - the format of the pixel data.
+    /// * `pixel_type` - the data type of the pixel data.
+    /// * `pixels` - the pixels, rows of the region from bottom to top, layer after layer.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let texture = TextureBuilder::array_2d(&gl, InternalFormat::Rgba8, 2, 2, 2).build().unwrap();
+    /// let pixels = vec![0u8; 2 * 2 * 2 * 4];
+    /// let error = texture
+    ///     .upload(0)
+    ///     .flip_y(true)
+    ///     .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, &pixels)
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     TextureError::UnpackModeNotSupported { kind: TextureKind::Texture2dArray }
+    /// );
+    /// assert!(!gl.calls().iter().any(|call| call.name == "texSubImage3D"));
+    ///
+    /// texture
+    ///     .upload(0)
+    ///     .flip_y(false)
+    ///     .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, &pixels)
+    ///     .unwrap();
+    ///
+    /// // modes enabled on the context are turned off for the upload and restored afterwards
+    /// gl.pixel_storei(PixelStorageMode::UnpackFlipYWebgl, 1);
+    /// gl.take_calls();
+    /// texture
+    ///     .upload(0)
+    ///     .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, &pixels)
+    ///     .unwrap();
+    /// let calls: Vec<String> = gl
+    ///     .calls()
+    ///     .iter()
+    ///     .filter(|call| call.name == "pixelStorei" || call.name == "texSubImage3D")
+    ///     .map(|call| call.to_string())
+    ///     .collect();
+    /// assert_eq!(calls.len(), 3);
+    /// assert_eq!(calls[0], "pixelStorei(UnpackFlipYWebgl, 0)");
+    /// assert!(calls[1].starts_with("texSubImage3D("));
+    /// assert_eq!(calls[2], "pixelStorei(UnpackFlipYWebgl, 1)");
+    /// ```
+    pub fn pixels<I: Image + ?Sized>(
+        self,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: &I,
+    ) -> Result<(), TextureError> {
+        let texture = self.texture;
+        let storage = texture.storage().ok_or(TextureError::MissingStorage)?;
+        let (target, level_size) = self.target(&storage)?;
+        let unpack_mode = self.flip_y == Some(true) || self.premultiply_alpha == Some(true);
+        let layered =
+            storage.kind == TextureKind::Texture3d || storage.kind == TextureKind::Texture2dArray;
+        if unpack_mode && layered {
+            return Err(TextureError::UnpackModeNotSupported { kind: storage.kind });
+        }
+        let ((x, y, z), (width, height, depth)) =
+            self.checked_region(level_size, (level_size.0, level_size.1))?;
+
+        let context = texture.context;
+        validate_tex_image(storage.internal_format, format, pixel_type)
+            .and_then(|_| {
+                let alignment = context._get_parameter_i32(Parameter::UnpackAlignment) as usize;
+                validate_pixels::<I::Component>(
+                    pixels.data().len(),
+                    width,
+                    height.saturating_mul(depth),
+                    format,
+                    pixel_type,
+                    alignment,
+                )
+            })
+            .map_err(|error| TextureError::Format { error })?;
+
+        texture.bind(storage.kind);
+        let modes = if layered {
+            (Some(false), Some(false))
+        } else {
+            (self.flip_y, self.premultiply_alpha)
+        };
+        self.with_unpack_modes(modes, || match storage.kind {
+            TextureKind::Texture2d | TextureKind::TextureCubeMap => context._tex_sub_image_2d(
+                target,
+                self.level,
+                x,
+                y,
+                width,
+                height,
+                format,
+                pixel_type,
+                pixels.data(),
+            ),
+            TextureKind::Texture3d | TextureKind::Texture2dArray => context._tex_sub_image_3d(
+                texture_3d_kind(storage.kind),
+                self.level,
+                x,
+                y,
+                z,
+                width,
+                height,
+                depth,
+                format,
+                pixel_type,
+                pixels.data(),
+            ),
+        })
+        .map_err(TextureError::exception)
+    }
+
+    /// Checks the upload and replaces the selected pixels with a DOM source, through `texSubImage2D` or
+    /// `texSubImage3D`.
+    ///
+    /// The size of the region is inferred from the source: its width, and its height divided by the number
+    /// of layers, which are stacked vertically in the source. The height has to be a multiple of the number
+    /// of layers. A `region` that was selected has to have that
+    /// size, only its offset is free.
+    ///
+    /// # Arguments
+    /// * `format` - the format of the pixel data.
+    /// * `pixel_type` - the data type of the pixel data.
+    /// * `source` - the image, canvas, video frame, bitmap or image data that is uploaded.
+    pub fn source<S: TexImageSource + ?Sized>(
+        self,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: &S,
+    ) -> Result<(), TextureError> {
+        let texture = self.texture;
+        let storage = texture.storage().ok_or(TextureError::MissingStorage)?;
+        let (target, level_size) = self.target(&storage)?;
+        let layers = self.layers.map_or(level_size.2, |layers| layers.1).max(1);
+        if source.height() % layers != 0 {
+            return Err(TextureError::SourceLayersMismatch {
+                height: source.height(),
+                layers,
+            });
+        }
+        let source_size = (source.width(), source.height() / layers);
+        if let Some((_, _, width, height)) = self.region {
+            if (width, height) != source_size {
+                return Err(TextureError::SourceSizeMismatch {
+                    region: (width, height),
+                    source: source_size,
+                });
+            }
+        }
+        let ((x, y, z), (width, height, depth)) = self.checked_region(level_size, source_size)?;
+        validate_tex_image(storage.internal_format, format, pixel_type)
+            .map_err(|error| TextureError::Format { error })?;
+
+        let context = texture.context;
+        texture.bind(storage.kind);
+        self.with_unpack_modes((self.flip_y, self.premultiply_alpha), || {
+            match storage.kind {
+                TextureKind::Texture2d | TextureKind::TextureCubeMap => context
+                    ._tex_sub_image_2d_source(
+                        target,
+                        self.level,
+                        x,
+                        y,
+                        format,
+                        pixel_type,
+                        source.source(),
+                    ),
+                TextureKind::Texture3d | TextureKind::Texture2dArray => context
+                    ._tex_sub_image_3d_source(
+                        texture_3d_kind(storage.kind),
+                        self.level,
+                        x,
+                        y,
+                        z,
+                        width,
+                        height,
+                        depth,
+                        format,
+                        pixel_type,
+                        source.source(),
+                    ),
+            }
+        })
+        .map_err(TextureError::exception)
+    }
+
+    /// Returns the target of the upload and the size of its mipmap level, checking that both exist.
+    fn target(
+        &self,
+        storage: &TextureStorage,
+    ) -> Result<(TextureBindPoint, (u32, u32, u32)), TextureError> {
+        let level_size = storage
+            .level_size(self.level)
+            .ok_or(TextureError::LevelOutOfRange {
+                level: self.level,
+                levels: storage.levels,
+            })?;
+        let target = match (storage.kind, self.face) {
+            (TextureKind::TextureCubeMap, Some(face)) if face != TextureBindPoint::Texture2d => {
+                face
+            }
+            (TextureKind::TextureCubeMap, _) => return Err(TextureError::MissingFace),
+            (kind, Some(_)) => return Err(TextureError::NotACubeMap { kind }),
+            (_, None) => TextureBindPoint::Texture2d,
+        };
+        Ok((target, level_size))
+    }
+
+    /// Returns the offset and size of the uploaded region, `size` is the width and height when no region
+    /// was selected. Fails when the region does not lie within the mipmap level.
+    fn checked_region(
+        &self,
+        level_size: (u32, u32, u32),
+        size: (u32, u32),
+    ) -> Result<(Offset3, Size3), TextureError> {
+        let (x, y, width, height) = self.region.unwrap_or((0, 0, size.0, size.1));
+        let (z, depth) = self.layers.unwrap_or((0, level_size.2));
+        let offset = (x, y, z);
+        let size = (width, height, depth);
+        if x.saturating_add(width) > level_size.0
+            || y.saturating_add(height) > level_size.1
+            || z.saturating_add(depth) > level_size.2
+        {
+            return Err(TextureError::RegionOutOfRange {
+                offset,
+                size,
+                level_size,
+            });
+        }
+        Ok((offset, size))
+    }
+
+    /// Runs an upload with the given `UNPACK_FLIP_Y_WEBGL` and `UNPACK_PREMULTIPLY_ALPHA_WEBGL` modes set,
+    /// modes that are `None` are left alone. The previous modes are restored afterwards.
+    fn with_unpack_modes<T, F: FnOnce() -> T>(
+        &self,
+        (flip_y, premultiply_alpha): (Option<bool>, Option<bool>),
+        upload: F,
+    ) -> T {
+        let context = self.texture.context;
+        let mut restore = Vec::new();
+        for &(mode, parameter, value) in &[
+            (
+                PixelStorageMode::UnpackFlipYWebgl,
+                Parameter::UnpackFlipYWebgl,
+                flip_y,
+            ),
+            (
+                PixelStorageMode::UnpackPremultiplyAlphaWebgl,
+                Parameter::UnpackPremultiplyAlphaWebgl,
+                premultiply_alpha,
+            ),
+        ] {
+            if let Some(value) = value {
+                let previous = context._get_parameter_bool(parameter);
+                if previous != value {
+                    context.pixel_storei(mode, value as i32);
+                    restore.push((mode, previous));
+                }
+            }
+        }
+        let result = upload();
+        for (mode, previous) in restore {
+            context.pixel_storei(mode, previous as i32);
+        }
+        result
+    }
+}
+
+/// Error returned by `TextureBuilder` and the upload methods of a texture created by it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextureError {
+    /// The texture was not created by a `TextureBuilder`, so its storage is not known.
+    MissingStorage,
+    /// The texture would have a width, height or depth of zero.
+    InvalidSize {
+        /// The requested width.
+        width: u32,
+        /// The requested height.
+        height: u32,
+        /// The requested depth or number of layers.
+        depth: u32,
+    },
+    /// The number of mipmap levels is zero or larger than the mipmap chain of the size.
+    InvalidLevels {
+        /// The requested number of levels.
+        levels: u32,
+        /// The number of levels of a complete mipmap chain.
+        max: u32,
+    },
+    /// The mipmap level does not exist.
+    LevelOutOfRange {
+        /// The requested level.
+        level: u32,
+        /// The number of levels of the texture.
+        levels: u32,
+    },
+    /// An upload to a cube map did not select one of its faces.
+    MissingFace,
+    /// A face was selected for a texture that is not a cube map.
+    NotACubeMap {
+        /// The kind of the texture.
+        kind: TextureKind,
+    },
+    /// The uploaded region does not lie within the mipmap level.
+    RegionOutOfRange {
+        /// The x, y and z offset of the region.
+        offset: (u32, u32, u32),
+        /// The width, height and depth of the region.
+        size: (u32, u32, u32),
+        /// The width, height and depth of the mipmap level.
+        level_size: (u32, u32, u32),
+    },
+    /// The size of a DOM source does not match the uploaded region, see `TextureUpload::source`.
+    SourceSizeMismatch {
+        /// The width and height of one layer of the region.
+        region: (u32, u32),
+        /// The width and height the source provides for one layer.
+        source: (u32, u32),
+    },
+    /// The height of a DOM source is not a multiple of the number of layers stacked in it.
+    SourceLayersMismatch {
+        /// The height of the source.
+        height: u32,
+        /// The number of uploaded layers.
+        layers: u32,
+    },
+    /// `flip_y` or `premultiply_alpha` was enabled for an upload of pixels to a 3D or array texture, which
+    /// WebGL2 only allows for DOM sources.
+    UnpackModeNotSupported {
+        /// The kind of the texture.
+        kind: TextureKind,
+    },
+    /// `generateMipmap` requires a color renderable and filterable format.
+    NotMipmappable {
+        /// The internal format of the texture.
+        internal_format: InternalFormat,
+    },
+    /// The internal format can not be allocated, or the pixels can not be uploaded to it.
+    Format {
+        /// Why the format or the pixels were rejected.
+        error: FormatError,
+    },
+    /// WebGL threw an exception.
+    Exception {
+        /// The message of the exception.
+        message: String,
+    },
+}
+
+impl TextureError {
+    /// Wraps a javascript exception thrown by an upload.
+    pub(crate) fn exception(exception: JsValue) -> TextureError {
+        TextureError::Exception {
+            message: exception
+                .as_string()
+                .unwrap_or_else(|| format!("{:?}", exception)),
+        }
+    }
+}
+
+impl fmt::Display for TextureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextureError::MissingStorage => {
+                f.write_str("the storage of the texture is not known, create it with a TextureBuilder")
+            }
+            TextureError::InvalidSize {
+                width,
+                height,
+                depth,
+            } => write!(f, "a texture can not be {}x{}x{}", width, height, depth),
+            TextureError::InvalidLevels { levels, max } => write!(
+                f,
+                "a texture of this size has 1 to {} mipmap levels, not {}",
+                max, levels
+            ),
+            TextureError::LevelOutOfRange { level, levels } => write!(
+                f,
+                "mipmap level {} does not exist, the texture has {} levels",
+                level, levels
+            ),
+            TextureError::MissingFace => f.write_str("an upload to a cube map needs a face"),
+            TextureError::NotACubeMap { kind } => {
+                write!(f, "a {:?} texture does not have faces", kind)
+            }
+            TextureError::RegionOutOfRange {
+                offset,
+                size,
+                level_size,
+            } => write!(
+                f,
+                "the region of {}x{}x{} at ({}, {}, {}) does not fit in the mipmap level of {}x{}x{}",
+                size.0, size.1, size.2, offset.0, offset.1, offset.2, level_size.0, level_size.1, level_size.2
+            ),
+            TextureError::SourceSizeMismatch { region, source } => write!(
+                f,
+                "the region of {}x{} does not match the source of {}x{}",
+                region.0, region.1, source.0, source.1
+            ),
+            TextureError::SourceLayersMismatch { height, layers } => write!(
+                f,
+                "the source of height {} can not be split into {} layers",
+                height, layers
+            ),
+            TextureError::UnpackModeNotSupported { kind } => write!(
+                f,
+                "pixels uploaded to a {:?} texture can not be flipped or premultiplied, only DOM sources can",
+                kind
+            ),
+            TextureError::NotMipmappable { internal_format } => write!(
+                f,
+                "mipmaps can not be generated for {:?}, it is not color renderable and filterable",
+                internal_format
+            ),
+            TextureError::Format { ref error } => write!(f, "{}", error),
+            TextureError::Exception { ref message } => f.write_str(message),
+        }
+    }
+}
+
+impl error::Error for TextureError {}
@@ -0,0 +1,760 @@
+//! Texture images read from a file, ready to be uploaded level by level
+//!
+//! The loaders for KTX (`TextureData::from_ktx`) and DDS (`TextureData::from_dds`) files both produce a
+//! `TextureData`: the format, kind and size of the texture together with its mipmap levels. The images of
+//! the levels can be uploaded by hand with `images`, or `create_texture` creates a texture of the right
+//! kind and fills it.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let data = TextureData::from_ktx(include_bytes!("../fixtures/ktx/rgba8_mipmaps.ktx")).unwrap();
+//! let gl = RecordingBackend::new();
+//! let texture = TextureBuilder::texture_2d(&gl, InternalFormat::Rgba8, data.width(), data.height())
+//!     .levels(data.levels())
+//!     .build()
+//!     .unwrap();
+//! for (level, _layer, _face, pixels) in data.images() {
+//!     texture
+//!         .upload(level)
+//!         .pixels(PixelFormat::Rgba, PixelType::UnsignedByte, pixels)
+//!         .unwrap();
+//! }
+//! assert_eq!(data.level_size(2), Some((1, 1, 1)));
+//! ```
+use backend::WebGL2Backend;
+use data_view::PixelComponent;
+use formats::{bytes_per_pixel, compressed_format_info};
+use glenum::PixelType as T;
+use glenum::{
+    InternalFormat, Parameter, PixelFormat, PixelStorageMode, PixelType, TextureBindPoint,
+    TextureCompression, TextureKind, TextureMagFilter, TextureMinFilter, TextureParameter,
+};
+use std::error;
+use std::fmt;
+use std::mem;
+use std::ptr;
+use texture::WebGLRSTexture;
+use texture_builder::TextureError;
+use texture_builder::{texture_3d_kind, TextureBuilder, TextureUpload};
+
+/// The faces of a cube map in the order texture files store them.
+const CUBE_FACES: [TextureBindPoint; 6] = [
+    TextureBindPoint::TextureCubeMapPositiveX,
+    TextureBindPoint::TextureCubeMapNegativeX,
+    TextureBindPoint::TextureCubeMapPositiveY,
+    TextureBindPoint::TextureCubeMapNegativeY,
+    TextureBindPoint::TextureCubeMapPositiveZ,
+    TextureBindPoint::TextureCubeMapNegativeZ,
+];
+
+/// The format of the images of a `TextureData`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureDataFormat {
+    /// Pixels that are uploaded with `texSubImage2D` or `texSubImage3D`.
+    Uncompressed {
+        /// The internal format of the texture.
+        internal_format: InternalFormat,
+        /// The format of the pixels.
+        format: PixelFormat,
+        /// The data type of the pixels.
+        pixel_type: PixelType,
+    },
+    /// Blocks of a compressed format that are uploaded with `compressedTexImage2D` or
+    /// `compressedTexImage3D`.
+    Compressed(TextureCompression),
+}
+
+/// Reads the fields of a texture file in the byte order of the file.
+pub(crate) struct Reader<'a> {
+    pub(crate) data: &'a [u8],
+    pub(crate) big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn bytes(&self, offset: usize, length: usize) -> Result<&'a [u8], TextureFileError> {
+        let end = offset.saturating_add(length);
+        self.data
+            .get(offset..end)
+            .ok_or(TextureFileError::UnexpectedEnd {
+                required: end,
+                actual: self.data.len(),
+            })
+    }
+
+    pub(crate) fn u32(&self, offset: usize) -> Result<u32, TextureFileError> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.bytes(offset, 4)?);
+        Ok(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    /// Reads a 64 bit offset or length, values that do not fit in a `usize` can not lie within the file.
+    pub(crate) fn usize(&self, offset: usize) -> Result<usize, TextureFileError> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.bytes(offset, 8)?);
+        let value = u64::from_le_bytes(bytes);
+        Ok(if value > usize::MAX as u64 {
+            usize::MAX
+        } else {
+            value as usize
+        })
+    }
+}
+
+/// A texture read from a file, split into its mipmap levels.
+///
+/// The images of a level are stored layer after layer and face after face. An image holds all slices of a
+/// 3D texture. One dimensional textures become 2D textures with a height of 1.
+#[derive(Debug, Clone)]
+pub struct TextureData {
+    format: TextureDataFormat,
+    kind: TextureKind,
+    width: u32,
+    height: u32,
+    depth: u32,
+    layers: u32,
+    faces: u32,
+    row_alignment: u32,
+    generate_mipmaps: bool,
+    levels: Vec<Vec<u8>>,
+}
+
+impl TextureData {
+    /// Checks the dimensions of a texture and decides its kind, the levels are added with `push_level`.
+    ///
+    /// # Arguments
+    /// * `format` - the format of the images.
+    /// * `width` - the width of mipmap level 0.
+    /// * `height` - the height of mipmap level 0, 0 for one dimensional textures.
+    /// * `depth` - the depth of mipmap level 0, 0 for textures that are not 3D.
+    /// * `layers` - the number of layers, 0 for textures that are not arrays.
+    /// * `faces` - the number of faces, 6 for cube maps and 1 otherwise.
+    /// * `levels` - the number of mipmap levels, 0 if only level 0 is stored and the mipmaps have to be
+    ///   generated.
+    /// * `row_alignment` - the alignment of the rows of uncompressed images.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        format: TextureDataFormat,
+        width: u32,
+        height: u32,
+        depth: u32,
+        layers: u32,
+        faces: u32,
+        levels: u32,
+        row_alignment: u32,
+    ) -> Result<TextureData, TextureFileError> {
+        let unsupported = TextureFileError::UnsupportedDimensions {
+            width,
+            height,
+            depth,
+            layers,
+            faces,
+        };
+        let kind = match (depth, layers, faces) {
+            _ if width == 0 || (height == 0 && depth > 0) => return Err(unsupported),
+            (0, 0, 1) => TextureKind::Texture2d,
+            (0, 0, 6) if width == height => TextureKind::TextureCubeMap,
+            (0, _, 1) => TextureKind::Texture2dArray,
+            (_, 0, 1) => TextureKind::Texture3d,
+            _ => return Err(unsupported),
+        };
+        let mut data = TextureData {
+            format,
+            kind,
+            width,
+            height: height.max(1),
+            depth: depth.max(1),
+            layers: layers.max(1),
+            faces,
+            row_alignment,
+            generate_mipmaps: levels == 0,
+            levels: Vec::new(),
+        };
+        // level 0 is the largest, when its length fits in a usize the lengths of all levels do
+        if data.checked_level_length(0).is_none() {
+            return Err(unsupported);
+        }
+        let max = data.max_levels();
+        if levels > max {
+            return Err(TextureFileError::TooManyLevels { levels, max });
+        }
+        data.levels.reserve(levels.max(1) as usize);
+        Ok(data)
+    }
+
+    /// Adds the next mipmap level, fails when it does not have the size that follows from the dimensions
+    /// and format.
+    pub(crate) fn push_level(&mut self, level_data: Vec<u8>) -> Result<(), TextureFileError> {
+        let level = self.levels();
+        let expected = self.level_length(level);
+        if level_data.len() != expected {
+            return Err(TextureFileError::LevelSizeMismatch {
+                level,
+                expected,
+                actual: level_data.len(),
+            });
+        }
+        self.levels.push(level_data);
+        Ok(())
+    }
+
+    /// Returns the format of the images.
+    pub fn format(&self) -> TextureDataFormat {
+        self.format
+    }
+
+    /// Returns the kind of texture the file describes.
+    pub fn kind(&self) -> TextureKind {
+        self.kind
+    }
+
+    /// Returns the width of mipmap level 0.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of mipmap level 0.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the depth of mipmap level 0, 1 for textures that are not 3D.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Returns the number of layers, 1 for textures that are not arrays.
+    pub fn layers(&self) -> u32 {
+        self.layers
+    }
+
+    /// Returns the number of faces, 6 for cube maps and 1 otherwise.
+    pub fn faces(&self) -> u32 {
+        self.faces
+    }
+
+    /// Returns the number of mipmap levels in the file.
+    pub fn levels(&self) -> u32 {
+        self.levels.len() as u32
+    }
+
+    /// Returns whether the file asks for the mipmaps to be generated, it then only contains level 0.
+    pub fn generate_mipmaps(&self) -> bool {
+        self.generate_mipmaps
+    }
+
+    /// Returns the alignment of the rows of uncompressed images, the `UNPACK_ALIGNMENT` they have to be
+    /// uploaded with.
+    pub fn row_alignment(&self) -> u32 {
+        self.row_alignment
+    }
+
+    /// Returns the width, height and depth of a mipmap level, `None` if the file does not contain the level.
+    pub fn level_size(&self, level: u32) -> Option<(u32, u32, u32)> {
+        if level >= self.levels() {
+            return None;
+        }
+        Some(self.mipmap_size(level))
+    }
+
+    /// Returns the data of a mipmap level, the images of all layers and faces.
+    pub fn level_data(&self, level: u32) -> Option<&[u8]> {
+        self.levels.get(level as usize).map(|data| &data[..])
+    }
+
+    /// Returns an iterator over the images of all mipmap levels as `(level, layer, face, bytes)`.
+    ///
+    /// Images are returned level by level, then layer by layer and face by face. Use `level_size` for the
+    /// size of the images.
+    pub fn images(&self) -> TextureImages<'_> {
+        TextureImages {
+            data: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the WebGL extension that has to be enabled to upload the images, `None` for uncompressed
+    /// formats.
+    pub fn required_extension(&self) -> Option<&'static str> {
+        match self.format {
+            TextureDataFormat::Compressed(format) => Some(compressed_format_info(format).extension),
+            TextureDataFormat::Uncompressed { .. } => None,
+        }
+    }
+
+    fn mipmap_size(&self, level: u32) -> (u32, u32, u32) {
+        (
+            (self.width >> level).max(1),
+            (self.height >> level).max(1),
+            (self.depth >> level).max(1),
+        )
+    }
+
+    fn max_levels(&self) -> u32 {
+        32 - self.width.max(self.height).max(self.depth).leading_zeros()
+    }
+
+    /// Returns the number of bytes of the image of one layer and face of a mipmap level, `None` when it
+    /// does not fit in a `usize`.
+    fn checked_image_length(&self, level: u32) -> Option<usize> {
+        let (width, height, depth) = self.mipmap_size(level);
+        let (row, rows) = match self.format {
+            TextureDataFormat::Compressed(format) => {
+                let info = compressed_format_info(format);
+                let columns = width.div_ceil(info.block_width).max(info.min_blocks) as usize;
+                let rows = height.div_ceil(info.block_height).max(info.min_blocks) as usize;
+                (columns.checked_mul(info.bytes_per_block as usize)?, rows)
+            }
+            TextureDataFormat::Uncompressed {
+                format, pixel_type, ..
+            } => {
+                let alignment = self.row_alignment as usize;
+                let row = (width as usize).checked_mul(bytes_per_pixel(format, pixel_type))?;
+                let padded = row.checked_add(alignment - 1)? / alignment * alignment;
+                (padded, height as usize)
+            }
+        };
+        row.checked_mul(rows)?.checked_mul(depth as usize)
+    }
+
+    /// Returns the number of bytes of a mipmap level, `None` when it does not fit in a `usize`.
+    fn checked_level_length(&self, level: u32) -> Option<usize> {
+        self.checked_image_length(level)?
+            .checked_mul(self.layers as usize)?
+            .checked_mul(self.faces as usize)
+    }
+
+    /// Returns the number of bytes of the image of one layer and face of a mipmap level.
+    pub(crate) fn image_length(&self, level: u32) -> usize {
+        self.checked_image_length(level)
+            .expect("the length of level 0 is checked by TextureData::new")
+    }
+
+    /// Returns the number of bytes of a mipmap level.
+    pub(crate) fn level_length(&self, level: u32) -> usize {
+        self.checked_level_length(level)
+            .expect("the length of level 0 is checked by TextureData::new")
+    }
+
+    /// Creates a texture and uploads all mipmap levels to it.
+    ///
+    /// Uncompressed textures are created by a `TextureBuilder` and filled with `texSubImage2D` or
+    /// `texSubImage3D`, with the `UNPACK_ALIGNMENT` set to the row alignment of the file for the duration of
+    /// the upload. When the file asks for it the mipmaps are generated afterwards.
+    ///
+    /// Compressed textures are filled level by level with `compressedTexImage2D` or `compressedTexImage3D`,
+    /// which also works for the formats that can not be updated in parts, so their storage is not known to
+    /// the texture. `TEXTURE_MAX_LEVEL` is set to the last level of the file, mipmaps can not be generated
+    /// for compressed formats. Fails without creating a texture when the context does not support the
+    /// extension of the format, the extension also has to be enabled with `getExtension`.
+    ///
+    /// The texture stays bound to its kind afterwards.
+    pub fn create_texture<'ctx, B: WebGL2Backend>(
+        &self,
+        context: &'ctx B,
+    ) -> Result<WebGLRSTexture<'ctx, B>, TextureFileError> {
+        match self.format {
+            TextureDataFormat::Uncompressed {
+                internal_format,
+                format,
+                pixel_type,
+            } => self.create_uncompressed(context, internal_format, format, pixel_type),
+            TextureDataFormat::Compressed(format) => {
+                let extension = compressed_format_info(format).extension;
+                if !context
+                    .get_supported_extensions()
+                    .iter()
+                    .any(|name| name == extension)
+                {
+                    return Err(TextureFileError::MissingExtension { extension });
+                }
+                self.create_compressed(context, format)
+            }
+        }
+        .map_err(|error| TextureFileError::Texture { error })
+    }
+
+    fn create_uncompressed<'ctx, B: WebGL2Backend>(
+        &self,
+        context: &'ctx B,
+        internal_format: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+    ) -> Result<WebGLRSTexture<'ctx, B>, TextureError> {
+        let builder = match self.kind {
+            TextureKind::Texture2d => {
+                TextureBuilder::texture_2d(context, internal_format, self.width, self.height)
+            }
+            TextureKind::TextureCubeMap => {
+                TextureBuilder::cube_map(context, internal_format, self.width)
+            }
+            TextureKind::Texture3d => TextureBuilder::texture_3d(
+                context,
+                internal_format,
+                self.width,
+                self.height,
+                self.depth,
+            ),
+            TextureKind::Texture2dArray => TextureBuilder::array_2d(
+                context,
+                internal_format,
+                self.width,
+                self.height,
+                self.layers,
+            ),
+        };
+        let builder = if self.generate_mipmaps {
+            builder.mipmaps()
+        } else {
+            builder.levels(self.levels())
+        };
+        let texture = builder.build()?;
+
+        let alignment = context._get_parameter_i32(Parameter::UnpackAlignment);
+        let row_alignment = self.row_alignment as i32;
+        if alignment != row_alignment {
+            context.pixel_storei(PixelStorageMode::UnpackAlignment, row_alignment);
+        }
+        let result = self.images().try_for_each(|(level, layer, face, data)| {
+            let upload = match self.kind {
+                TextureKind::TextureCubeMap => {
+                    texture.upload(level).face(CUBE_FACES[face as usize])
+                }
+                TextureKind::Texture2dArray => texture.upload(level).layer(layer),
+                _ => texture.upload(level),
+            };
+            upload_pixels(upload, format, pixel_type, data)
+        });
+        if alignment != row_alignment {
+            context.pixel_storei(PixelStorageMode::UnpackAlignment, alignment);
+        }
+        result?;
+
+        if self.generate_mipmaps {
+            texture.generate_mipmaps()?;
+        }
+        Ok(texture)
+    }
+
+    fn create_compressed<'ctx, B: WebGL2Backend>(
+        &self,
+        context: &'ctx B,
+        format: TextureCompression,
+    ) -> Result<WebGLRSTexture<'ctx, B>, TextureError> {
+        let texture = context.create_texture();
+        texture.bind(self.kind);
+        for (level, data) in self.levels.iter().enumerate() {
+            let level = level as u32;
+            let (width, height, depth) = self.mipmap_size(level);
+            match self.kind {
+                TextureKind::Texture2d => context._compressed_tex_image_2d(
+                    TextureBindPoint::Texture2d,
+                    level,
+                    format,
+                    width,
+                    height,
+                    0,
+                    data,
+                ),
+                TextureKind::TextureCubeMap => data
+                    .chunks(self.image_length(level))
+                    .zip(CUBE_FACES.iter())
+                    .try_for_each(|(face_data, &face)| {
+                        context._compressed_tex_image_2d(
+                            face, level, format, width, height, 0, face_data,
+                        )
+                    }),
+                TextureKind::Texture3d | TextureKind::Texture2dArray => {
+                    let depth = if self.kind == TextureKind::Texture3d {
+                        depth
+                    } else {
+                        self.layers
+                    };
+                    context._compressed_tex_image_3d(
+                        texture_3d_kind(self.kind),
+                        level,
+                        format,
+                        width,
+                        height,
+                        depth,
+                        0,
+                        data,
+                    )
+                }
+            }
+            .map_err(TextureError::exception)?;
+        }
+
+        let min_filter = if self.levels.len() > 1 {
+            TextureMinFilter::LinearMipmapLinear
+        } else {
+            TextureMinFilter::Linear
+        };
+        context.tex_parameter_i(
+            self.kind,
+            TextureParameter::MaxLevel,
+            self.levels.len() as i32 - 1,
+        );
+        context.tex_parameter_i(self.kind, TextureParameter::MinFilter, min_filter as i32);
+        context.tex_parameter_i(
+            self.kind,
+            TextureParameter::MagFilter,
+            TextureMagFilter::Linear as i32,
+        );
+        Ok(texture)
+    }
+}
+
+/// Iterator over the images of a `TextureData`, created with `TextureData::images`.
+pub struct TextureImages<'a> {
+    data: &'a TextureData,
+    index: usize,
+}
+
+impl<'a> Iterator for TextureImages<'a> {
+    type Item = (u32, u32, u32, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data;
+        let images_per_level = (data.layers * data.faces) as usize;
+        let level = self.index / images_per_level;
+        let image = self.index % images_per_level;
+        let level_data = data.levels.get(level)?;
+        let length = data.image_length(level as u32);
+        self.index += 1;
+        Some((
+            level as u32,
+            image as u32 / data.faces,
+            image as u32 % data.faces,
+            &level_data[image * length..(image + 1) * length],
+        ))
+    }
+}
+
+/// Uploads little endian bytes as the typed array that matches the pixel type.
+fn upload_pixels<B: WebGL2Backend>(
+    upload: TextureUpload<'_, '_, B>,
+    format: PixelFormat,
+    pixel_type: PixelType,
+    data: &[u8],
+) -> Result<(), TextureError> {
+    match pixel_type {
+        T::UnsignedByte => upload.pixels(format, pixel_type, data),
+        T::Byte => upload.pixels(format, pixel_type, &components::<i8>(data)),
+        T::Short => upload.pixels(format, pixel_type, &components::<i16>(data)),
+        T::Int => upload.pixels(format, pixel_type, &components::<i32>(data)),
+        T::Float => upload.pixels(format, pixel_type, &components::<f32>(data)),
+        T::UnsignedShort
+        | T::UnsignedShort565
+        | T::UnsignedShort5551
+        | T::UnsignedShort4444
+        | T::HalfFloat => upload.pixels(format, pixel_type, &components::<u16>(data)),
+        _ => upload.pixels(format, pixel_type, &components::<u32>(data)),
+    }
+}
+
+/// Copies bytes into a vector of components, the data of a file is not aligned for them.
+fn components<C: PixelComponent + Default>(data: &[u8]) -> Vec<C> {
+    let mut components = vec![C::default(); data.len() / mem::size_of::<C>()];
+    // every bit pattern is a valid `Pod` value
+    unsafe {
+        ptr::copy_nonoverlapping(
+            data.as_ptr(),
+            components.as_mut_ptr() as *mut u8,
+            components.len() * mem::size_of::<C>(),
+        );
+    }
+    components
+}
+
+/// Error returned when a KTX or DDS file can not be parsed or turned into a texture, see `TextureData`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextureFileError {
+    /// The data does not start with the identifier or magic number of the file format.
+    InvalidIdentifier {
+        /// The name of the file format, `"KTX"` or `"DDS"`.
+        container: &'static str,
+    },
+    /// The file ends before a part of it that is read.
+    UnexpectedEnd {
+        /// The number of bytes the part requires the file to have.
+        required: usize,
+        /// The number of bytes of the file.
+        actual: usize,
+    },
+    /// The endianness field of a KTX 1 file is neither little nor big endian.
+    InvalidEndianness {
+        /// The value of the field.
+        value: u32,
+    },
+    /// The format of a KTX 1 file is not one of the formats of this crate.
+    UnsupportedGlFormat {
+        /// The `glInternalFormat` field.
+        internal_format: u32,
+        /// The `glFormat` field.
+        format: u32,
+        /// The `glType` field.
+        pixel_type: u32,
+    },
+    /// The format of a KTX 2 file does not correspond with one of the formats of this crate.
+    UnsupportedVkFormat {
+        /// The `vkFormat` field.
+        vk_format: u32,
+    },
+    /// The pixel format of a DDS file without a DX10 header does not correspond with one of the formats of
+    /// this crate.
+    UnsupportedDdsFormat {
+        /// The `dwFlags` field of the pixel format.
+        flags: u32,
+        /// The `dwFourCC` field of the pixel format.
+        four_cc: u32,
+        /// The `dwRGBBitCount` field of the pixel format.
+        bit_count: u32,
+        /// The red, green, blue and alpha masks of the pixel format.
+        masks: [u32; 4],
+    },
+    /// The format of a DDS file with a DX10 header does not correspond with one of the formats of this crate.
+    UnsupportedDxgiFormat {
+        /// The `dxgiFormat` field.
+        dxgi_format: u32,
+    },
+    /// The levels of a KTX 2 file are supercompressed with a scheme that can not be decoded, BasisLZ,
+    /// zlib, or zstd without the `zstd` feature.
+    UnsupportedSupercompression {
+        /// The `supercompressionScheme` field.
+        scheme: u32,
+    },
+    /// A supercompressed mipmap level could not be decompressed.
+    Decompression {
+        /// The mipmap level.
+        level: u32,
+        /// Why decompressing failed.
+        message: String,
+    },
+    /// WebGL has no texture of the given size, e.g. an array of cube maps or cube map faces that are not
+    /// square.
+    UnsupportedDimensions {
+        /// The width of the texture.
+        width: u32,
+        /// The height of the texture.
+        height: u32,
+        /// The depth of the texture, 0 for textures that are not 3D.
+        depth: u32,
+        /// The number of layers, 0 for textures that are not arrays.
+        layers: u32,
+        /// The number of faces.
+        faces: u32,
+    },
+    /// The file has more mipmap levels than the mipmap chain of its size.
+    TooManyLevels {
+        /// The number of levels of the file.
+        levels: u32,
+        /// The number of levels of a complete mipmap chain.
+        max: u32,
+    },
+    /// A mipmap level does not have the size that follows from the size and format of the texture.
+    LevelSizeMismatch {
+        /// The mipmap level.
+        level: u32,
+        /// The number of bytes the level should have.
+        expected: usize,
+        /// The number of bytes of the level in the file.
+        actual: usize,
+    },
+    /// The compressed format of the file needs an extension the context does not support.
+    MissingExtension {
+        /// The name of the extension.
+        extension: &'static str,
+    },
+    /// The texture could not be created or filled.
+    Texture {
+        /// Why creating or filling the texture failed.
+        error: TextureError,
+    },
+}
+
+impl fmt::Display for TextureFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextureFileError::InvalidIdentifier { container } => {
+                write!(f, "the data is not a {} file", container)
+            }
+            TextureFileError::UnexpectedEnd { required, actual } => write!(
+                f,
+                "the file is truncated, {} bytes are required but it has {}",
+                required, actual
+            ),
+            TextureFileError::InvalidEndianness { value } => {
+                write!(f, "{:#010x} is not a valid KTX endianness", value)
+            }
+            TextureFileError::UnsupportedGlFormat {
+                internal_format,
+                format,
+                pixel_type,
+            } => write!(
+                f,
+                "internal format {:#06x} with format {:#06x} and type {:#06x} is not supported",
+                internal_format, format, pixel_type
+            ),
+            TextureFileError::UnsupportedVkFormat { vk_format } => {
+                write!(f, "vkFormat {} is not supported", vk_format)
+            }
+            TextureFileError::UnsupportedDdsFormat {
+                flags,
+                four_cc,
+                bit_count,
+                masks,
+            } => write!(
+                f,
+                "DDS pixel format with flags {:#x}, FourCC {:#010x}, {} bits and masks {:08x?} is not supported",
+                flags, four_cc, bit_count, masks
+            ),
+            TextureFileError::UnsupportedDxgiFormat { dxgi_format } => {
+                write!(f, "DXGI format {} is not supported", dxgi_format)
+            }
+            TextureFileError::UnsupportedSupercompression { scheme } => {
+                write!(f, "supercompression scheme {} is not supported", scheme)
+            }
+            TextureFileError::Decompression { level, ref message } => write!(
+                f,
+                "mipmap level {} could not be decompressed: {}",
+                level, message
+            ),
+            TextureFileError::UnsupportedDimensions {
+                width,
+                height,
+                depth,
+                layers,
+                faces,
+            } => write!(
+                f,
+                "a texture of {}x{}x{} with {} layers and {} faces is not supported",
+                width, height, depth, layers, faces
+            ),
+            TextureFileError::TooManyLevels { levels, max } => write!(
+                f,
+                "the file has {} mipmap levels but its size allows at most {}",
+                levels, max
+            ),
+            TextureFileError::LevelSizeMismatch {
+                level,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "mipmap level {} has {} bytes instead of {}",
+                level, actual, expected
+            ),
+            TextureFileError::MissingExtension { extension } => {
+                write!(f, "the format of the file needs the {} extension", extension)
+            }
+            TextureFileError::Texture { ref error } => write!(f, "{}", error),
+        }
+    }
+}
+
+impl error::Error for TextureFileError {}
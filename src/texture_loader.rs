@@ -0,0 +1,678 @@
+//! Asynchronous loading of textures from URLs and blobs
+//!
+//! `TextureLoader::load` fetches a file and returns a future that resolves to a `WebGLRSTexture` once the
+//! file is decoded and uploaded. KTX and DDS files are recognized by their identifier and read with
+//! `TextureData`. Other files are decoded by the browser with `createImageBitmap`, or with an `<img>`
+//! element when the browser does not support it, and uploaded as a 2D texture without copying their
+//! pixels into wasm memory. `LoadOptions` choose whether mipmaps are generated, whether the texture is
+//! sRGB and how the image is unpacked, they only apply to images decoded by the browser: KTX and DDS files
+//! are uploaded as the file describes them.
+//!
+//! The futures do not depend on an executor, in the browser they can be driven by e.g.
+//! `wasm_bindgen_futures::spawn_local`. Fetching is done by a `Fetch` implementation, `MemoryFetch`
+//! serves files from memory so loading can be tested outside of the browser together with a
+//! `RecordingBackend`.
+//!
+//! ```
+//! use std::future::Future;
+//! use std::sync::Arc;
+//! use std::task::{Context, Poll, Wake, Waker};
+//! use webgl_rs::*;
+//!
+//! // memory files never wake the task, a waker that does nothing is enough to poll them
+//! struct NoopWaker;
+//!
+//! impl Wake for NoopWaker {
+//!     fn wake(self: Arc<Self>) {}
+//! }
+//!
+//! let mut files = MemoryFetch::new();
+//! files.insert("ground.ktx", &include_bytes!("../fixtures/ktx/rgba8_mipmaps.ktx")[..]);
+//! let loader = TextureLoader::with_fetch(files);
+//! let gl = RecordingBackend::new();
+//!
+//! // files from memory are available right away, the future is ready when it is polled the first time
+//! let waker = Waker::from(Arc::new(NoopWaker));
+//! let mut context = Context::from_waker(&waker);
+//! let mut ground = Box::pin(loader.load(&gl, "ground.ktx", LoadOptions::new()));
+//! let texture = match ground.as_mut().poll(&mut context) {
+//!     Poll::Ready(texture) => texture.unwrap(),
+//!     Poll::Pending => unreachable!(),
+//! };
+//! assert_eq!(texture.storage().unwrap().levels, 3);
+//!
+//! let mut missing = Box::pin(loader.load(&gl, "grass.png", LoadOptions::new()));
+//! let error = match missing.as_mut().poll(&mut context) {
+//!     Poll::Ready(result) => result.err().unwrap(),
+//!     Poll::Pending => unreachable!(),
+//! };
+//! assert_eq!(error.to_string(), "grass.png could not be fetched, the server answered 404");
+//! ```
+use backend::WebGL2Backend;
+use glenum::{InternalFormat, PixelFormat, PixelType};
+use image_source::{HTMLImageElement, ImageBitmap, TexImageSource};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::future::{self, Future};
+use std::mem;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{self, Poll, Waker};
+use texture::WebGLRSTexture;
+use texture_builder::TextureBuilder;
+use texture_builder::TextureError;
+use texture_data::TextureData;
+use texture_data::TextureFileError;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Loader bindings
+#[wasm_bindgen]
+extern "C" {
+    type Promise;
+    /// Binding for `Promise.then()`
+    #[wasm_bindgen(method, js_name = then)]
+    fn then(this: &Promise, resolve: &Settle, reject: &Settle);
+
+    /// Binding for the global `fetch()`
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch(url: &str) -> Promise;
+
+    type Response;
+    /// Binding for `Response.ok`
+    #[wasm_bindgen(method, getter)]
+    fn ok(this: &Response) -> bool;
+    /// Binding for `Response.status`
+    #[wasm_bindgen(method, getter)]
+    fn status(this: &Response) -> u16;
+    /// Binding for `Response.arrayBuffer()`
+    #[wasm_bindgen(method, js_name = arrayBuffer)]
+    fn array_buffer(this: &Response) -> Promise;
+
+    /// Binary data, e.g. a `File` picked by the user.
+    pub type Blob;
+    /// Binding for `new Blob()`
+    #[wasm_bindgen(constructor)]
+    fn new(parts: &JsValue) -> Blob;
+    /// Binding for `Blob.arrayBuffer()`
+    #[wasm_bindgen(method, js_name = arrayBuffer)]
+    fn array_buffer(this: &Blob) -> Promise;
+
+    type Uint8Array;
+    /// Binding for `new Uint8Array()` with an `ArrayBuffer`
+    #[wasm_bindgen(constructor)]
+    fn new(buffer: &JsValue) -> Uint8Array;
+    /// Binding for `new Uint8Array()` with a copy of `data`
+    #[wasm_bindgen(constructor, js_class = "Uint8Array")]
+    fn from_bytes(data: &[u8]) -> Uint8Array;
+    /// Binding for `Uint8Array.slice()`, which copies the array into wasm memory
+    #[wasm_bindgen(method, js_name = slice)]
+    fn to_vec(this: &Uint8Array) -> Vec<u8>;
+
+    /// Binding for `Array.of()` with a single element
+    #[wasm_bindgen(js_namespace = Array, js_name = of)]
+    fn array_of(element: &JsValue) -> JsValue;
+
+    type ImageBitmapOptions;
+    #[wasm_bindgen(constructor, js_class = "Object")]
+    fn new() -> ImageBitmapOptions;
+    #[wasm_bindgen(method, structural, setter = imageOrientation)]
+    fn set_image_orientation(this: &ImageBitmapOptions, image_orientation: &str);
+    #[wasm_bindgen(method, structural, setter = premultiplyAlpha)]
+    fn set_premultiply_alpha(this: &ImageBitmapOptions, premultiply_alpha: &str);
+    #[wasm_bindgen(method, structural, setter = colorSpaceConversion)]
+    fn set_color_space_conversion(this: &ImageBitmapOptions, color_space_conversion: &str);
+
+    /// Binding for the global `createImageBitmap()`, throws when the browser does not support it
+    #[wasm_bindgen(js_name = createImageBitmap, catch)]
+    fn create_image_bitmap(blob: &Blob, options: &ImageBitmapOptions) -> Result<Promise, JsValue>;
+    /// Binding for `ImageBitmap.close()`
+    #[wasm_bindgen(method)]
+    fn close(this: &ImageBitmap);
+
+    /// Binding for `new Image()`
+    #[wasm_bindgen(constructor, js_class = "Image")]
+    fn new_image() -> HTMLImageElement;
+    /// Binding for `HTMLImageElement.src`
+    #[wasm_bindgen(method, setter = src)]
+    fn set_src(this: &HTMLImageElement, src: &str);
+    /// Binding for `HTMLImageElement.decode()`
+    #[wasm_bindgen(method)]
+    fn decode(this: &HTMLImageElement) -> Promise;
+
+    /// Binding for `URL.createObjectURL()`
+    #[wasm_bindgen(js_namespace = URL, js_name = createObjectURL)]
+    fn create_object_url(blob: &Blob) -> String;
+    /// Binding for `URL.revokeObjectURL()`
+    #[wasm_bindgen(js_namespace = URL, js_name = revokeObjectURL)]
+    fn revoke_object_url(url: &str);
+}
+
+/// Callback that settles a `PromiseFuture`.
+type Settle = Closure<dyn FnMut(JsValue)>;
+
+/// Future that resolves when a javascript promise settles.
+struct PromiseFuture {
+    state: Rc<RefCell<PromiseState>>,
+    callbacks: Option<(Settle, Settle)>,
+}
+
+#[derive(Default)]
+struct PromiseState {
+    result: Option<Result<JsValue, JsValue>>,
+    waker: Option<Waker>,
+}
+
+impl PromiseFuture {
+    fn new(promise: &Promise) -> PromiseFuture {
+        let state = Rc::new(RefCell::new(PromiseState::default()));
+        let settle = |ok: bool| {
+            let state = state.clone();
+            Closure::wrap(Box::new(move |value: JsValue| {
+                let waker = {
+                    let mut state = state.borrow_mut();
+                    state.result = Some(if ok { Ok(value) } else { Err(value) });
+                    state.waker.take()
+                };
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }) as Box<dyn FnMut(JsValue)>)
+        };
+        let callbacks = (settle(true), settle(false));
+        promise.then(&callbacks.0, &callbacks.1);
+        PromiseFuture {
+            state,
+            callbacks: Some(callbacks),
+        }
+    }
+}
+
+impl Future for PromiseFuture {
+    type Output = Result<JsValue, JsValue>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for PromiseFuture {
+    fn drop(&mut self) {
+        // the promise can still settle after the future is dropped, calling a dropped closure throws
+        if let Some((resolve, reject)) = self.callbacks.take() {
+            if self.state.borrow().result.is_none() {
+                resolve.forget();
+                reject.forget();
+            }
+        }
+    }
+}
+
+/// Fetches the contents of a file for a `TextureLoader`.
+pub trait Fetch {
+    /// The future that resolves to the contents of the file.
+    type Future: Future<Output = Result<Vec<u8>, LoadError>>;
+
+    /// Starts fetching the file at `url`.
+    fn fetch(&self, url: &str) -> Self::Future;
+}
+
+/// Fetches files with the `fetch` API of the browser.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BrowserFetch;
+
+impl Fetch for BrowserFetch {
+    type Future = FetchFuture;
+
+    fn fetch(&self, url: &str) -> FetchFuture {
+        FetchFuture {
+            url: url.to_string(),
+            response: Some(PromiseFuture::new(&fetch(url))),
+            body: None,
+        }
+    }
+}
+
+/// Future returned by `BrowserFetch`, resolves to the body of the response.
+pub struct FetchFuture {
+    url: String,
+    response: Option<PromiseFuture>,
+    body: Option<PromiseFuture>,
+}
+
+impl FetchFuture {
+    fn error(&self, exception: JsValue) -> LoadError {
+        LoadError::Fetch {
+            url: self.url.clone(),
+            message: LoadError::exception_message(exception),
+        }
+    }
+}
+
+impl Future for FetchFuture {
+    type Output = Result<Vec<u8>, LoadError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if let Some(mut response) = this.response.take() {
+            let response = match Pin::new(&mut response).poll(cx) {
+                Poll::Ready(Ok(response)) => response.unchecked_into::<Response>(),
+                Poll::Ready(Err(exception)) => return Poll::Ready(Err(this.error(exception))),
+                Poll::Pending => {
+                    this.response = Some(response);
+                    return Poll::Pending;
+                }
+            };
+            if !response.ok() {
+                return Poll::Ready(Err(LoadError::Status {
+                    url: this.url.clone(),
+                    status: response.status(),
+                }));
+            }
+            this.body = Some(PromiseFuture::new(&response.array_buffer()));
+        }
+        match this.body.as_mut().map(|body| Pin::new(body).poll(cx)) {
+            Some(Poll::Ready(Ok(buffer))) => Poll::Ready(Ok(Uint8Array::new(&buffer).to_vec())),
+            Some(Poll::Ready(Err(exception))) => Poll::Ready(Err(this.error(exception))),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+/// Serves files from memory, a stand-in for `BrowserFetch` outside of the browser.
+///
+/// Fetching a URL that was not inserted fails with a `LoadError::Status` of 404.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFetch {
+    files: HashMap<String, Vec<u8>>,
+}
+
+impl MemoryFetch {
+    /// Creates a stand-in without files.
+    pub fn new() -> MemoryFetch {
+        MemoryFetch::default()
+    }
+
+    /// Adds a file, or replaces the file that was served at `url`.
+    pub fn insert(&mut self, url: &str, data: impl Into<Vec<u8>>) {
+        self.files.insert(url.to_string(), data.into());
+    }
+}
+
+impl Fetch for MemoryFetch {
+    type Future = future::Ready<Result<Vec<u8>, LoadError>>;
+
+    fn fetch(&self, url: &str) -> Self::Future {
+        future::ready(self.files.get(url).cloned().ok_or(LoadError::Status {
+            url: url.to_string(),
+            status: 404,
+        }))
+    }
+}
+
+/// Reads the contents of a blob.
+struct ReadBlob(PromiseFuture);
+
+impl Future for ReadBlob {
+    type Output = Result<Vec<u8>, LoadError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|result| {
+            result
+                .map(|buffer| Uint8Array::new(&buffer).to_vec())
+                .map_err(|exception| LoadError::Read {
+                    message: LoadError::exception_message(exception),
+                })
+        })
+    }
+}
+
+/// How a `TextureLoader` creates the texture of an image that is decoded by the browser.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    mipmaps: bool,
+    srgb: bool,
+    flip_y: bool,
+    premultiply_alpha: bool,
+}
+
+impl LoadOptions {
+    /// Creates options for a texture without mipmaps that holds the image as it is stored.
+    pub fn new() -> LoadOptions {
+        LoadOptions::default()
+    }
+
+    /// Allocates the whole mipmap chain and generates the mipmaps after the upload.
+    pub fn mipmaps(mut self, mipmaps: bool) -> Self {
+        self.mipmaps = mipmaps;
+        self
+    }
+
+    /// Creates the texture with `SRGB8_ALPHA8` instead of `RGBA8`, so it is sampled in linear color space.
+    pub fn srgb(mut self, srgb: bool) -> Self {
+        self.srgb = srgb;
+        self
+    }
+
+    /// Flips the image vertically, so its first row ends up at the top of the texture coordinates.
+    pub fn flip_y(mut self, flip_y: bool) -> Self {
+        self.flip_y = flip_y;
+        self
+    }
+
+    /// Multiplies the colors of the image by their alpha.
+    pub fn premultiply_alpha(mut self, premultiply_alpha: bool) -> Self {
+        self.premultiply_alpha = premultiply_alpha;
+        self
+    }
+
+    fn image_bitmap_options(&self) -> ImageBitmapOptions {
+        let options = ImageBitmapOptions::new();
+        if self.flip_y {
+            options.set_image_orientation("flipY");
+        }
+        options.set_premultiply_alpha(if self.premultiply_alpha {
+            "premultiply"
+        } else {
+            "none"
+        });
+        options.set_color_space_conversion("none");
+        options
+    }
+}
+
+/// Loads textures from URLs and blobs, see the module documentation.
+pub struct TextureLoader<F: Fetch = BrowserFetch> {
+    fetch: F,
+}
+
+impl TextureLoader<BrowserFetch> {
+    /// Creates a loader that fetches files with the `fetch` API of the browser.
+    pub fn new() -> TextureLoader<BrowserFetch> {
+        TextureLoader::with_fetch(BrowserFetch)
+    }
+}
+
+impl Default for TextureLoader<BrowserFetch> {
+    fn default() -> Self {
+        TextureLoader::new()
+    }
+}
+
+impl<F: Fetch> TextureLoader<F> {
+    /// Creates a loader that fetches files with `fetch`.
+    pub fn with_fetch(fetch: F) -> TextureLoader<F> {
+        TextureLoader { fetch }
+    }
+
+    /// Fetches the file at `url` and creates a texture from it.
+    ///
+    /// # Arguments
+    /// * `context` - the context to create the texture in.
+    /// * `url` - the URL of an image, a KTX or a DDS file.
+    /// * `options` - how the texture of an image is created.
+    pub fn load<'ctx, B: WebGL2Backend>(
+        &self,
+        context: &'ctx B,
+        url: &str,
+        options: LoadOptions,
+    ) -> impl Future<Output = Result<WebGLRSTexture<'ctx, B>, LoadError>> {
+        LoadTexture {
+            context,
+            options,
+            state: LoadState::Reading(Box::pin(self.fetch.fetch(url))),
+        }
+    }
+
+    /// Reads a blob, e.g. a `File` picked by the user, and creates a texture from it.
+    ///
+    /// # Arguments
+    /// * `context` - the context to create the texture in.
+    /// * `blob` - the contents of an image, a KTX or a DDS file.
+    /// * `options` - how the texture of an image is created.
+    pub fn load_blob<'ctx, B: WebGL2Backend>(
+        &self,
+        context: &'ctx B,
+        blob: &Blob,
+        options: LoadOptions,
+    ) -> impl Future<Output = Result<WebGLRSTexture<'ctx, B>, LoadError>> {
+        LoadTexture {
+            context,
+            options,
+            state: LoadState::Reading(Box::pin(ReadBlob(PromiseFuture::new(&blob.array_buffer())))),
+        }
+    }
+}
+
+/// Future returned by `TextureLoader::load` and `TextureLoader::load_blob`.
+struct LoadTexture<'ctx, B: 'ctx + WebGL2Backend, T> {
+    context: &'ctx B,
+    options: LoadOptions,
+    state: LoadState<T>,
+}
+
+enum LoadState<T> {
+    /// Waiting for the contents of the file.
+    Reading(Pin<Box<T>>),
+    /// Waiting for `createImageBitmap`.
+    DecodingBitmap(PromiseFuture),
+    /// Waiting for an `<img>` element that shows an object URL of the file.
+    DecodingImage(HTMLImageElement, String, PromiseFuture),
+    Done,
+}
+
+impl<'ctx, B, T> Future for LoadTexture<'ctx, B, T>
+where
+    B: WebGL2Backend,
+    T: Future<Output = Result<Vec<u8>, LoadError>>,
+{
+    type Output = Result<WebGLRSTexture<'ctx, B>, LoadError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        match mem::replace(&mut this.state, LoadState::Done) {
+            LoadState::Reading(mut reading) => {
+                let data = match reading.as_mut().poll(cx) {
+                    Poll::Ready(result) => result?,
+                    Poll::Pending => {
+                        this.state = LoadState::Reading(reading);
+                        return Poll::Pending;
+                    }
+                };
+                if let Some(file) = texture_file(&data) {
+                    return Poll::Ready(
+                        file.and_then(|file| file.create_texture(this.context))
+                            .map_err(|error| LoadError::File { error }),
+                    );
+                }
+                this.state = decode(&data, &this.options);
+                // the promise has to be polled once to register the waker
+                Pin::new(this).poll(cx)
+            }
+            LoadState::DecodingBitmap(mut decoding) => match Pin::new(&mut decoding).poll(cx) {
+                Poll::Ready(result) => {
+                    let bitmap = result
+                        .map_err(decode_error)?
+                        .unchecked_into::<ImageBitmap>();
+                    let texture = create_texture(this.context, &this.options, &bitmap, false);
+                    bitmap.close();
+                    Poll::Ready(texture.map_err(|error| LoadError::Texture { error }))
+                }
+                Poll::Pending => {
+                    this.state = LoadState::DecodingBitmap(decoding);
+                    Poll::Pending
+                }
+            },
+            LoadState::DecodingImage(image, url, mut decoding) => {
+                match Pin::new(&mut decoding).poll(cx) {
+                    Poll::Ready(result) => {
+                        revoke_object_url(&url);
+                        result.map_err(decode_error)?;
+                        Poll::Ready(
+                            create_texture(this.context, &this.options, &image, true)
+                                .map_err(|error| LoadError::Texture { error }),
+                        )
+                    }
+                    Poll::Pending => {
+                        this.state = LoadState::DecodingImage(image, url, decoding);
+                        Poll::Pending
+                    }
+                }
+            }
+            LoadState::Done => panic!("TextureLoader future polled after completion"),
+        }
+    }
+}
+
+/// Reads the file if it is a KTX or DDS file.
+fn texture_file(data: &[u8]) -> Option<Result<TextureData, TextureFileError>> {
+    type Read = fn(&[u8]) -> Result<TextureData, TextureFileError>;
+    for read in &[TextureData::from_ktx as Read, TextureData::from_dds] {
+        match read(data) {
+            Err(TextureFileError::InvalidIdentifier { .. }) => {}
+            result => return Some(result),
+        }
+    }
+    None
+}
+
+/// Starts decoding an image with `createImageBitmap`, or with an `<img>` element when that throws.
+fn decode<T>(data: &[u8], options: &LoadOptions) -> LoadState<T> {
+    let blob = Blob::new(&array_of(&Uint8Array::from_bytes(data)));
+    match create_image_bitmap(&blob, &options.image_bitmap_options()) {
+        Ok(promise) => LoadState::DecodingBitmap(PromiseFuture::new(&promise)),
+        Err(_) => {
+            let url = create_object_url(&blob);
+            let image = HTMLImageElement::new_image();
+            image.set_src(&url);
+            let decoding = PromiseFuture::new(&image.decode());
+            LoadState::DecodingImage(image, url, decoding)
+        }
+    }
+}
+
+fn decode_error(exception: JsValue) -> LoadError {
+    LoadError::Decode {
+        message: LoadError::exception_message(exception),
+    }
+}
+
+/// Creates a 2D texture for a decoded image and uploads it, `unpack` sets the pixel storage modes for the
+/// upload as `createImageBitmap` already flipped and premultiplied the pixels of an `ImageBitmap`.
+fn create_texture<'ctx, B: WebGL2Backend, S: TexImageSource>(
+    context: &'ctx B,
+    options: &LoadOptions,
+    source: &S,
+    unpack: bool,
+) -> Result<WebGLRSTexture<'ctx, B>, TextureError> {
+    let internal_format = if options.srgb {
+        InternalFormat::Srgb8Alpha8
+    } else {
+        InternalFormat::Rgba8
+    };
+    let builder =
+        TextureBuilder::texture_2d(context, internal_format, source.width(), source.height());
+    let texture = if options.mipmaps {
+        builder.mipmaps()
+    } else {
+        builder
+    }
+    .build()?;
+    let upload = texture.upload(0);
+    let upload = if unpack {
+        upload
+            .flip_y(options.flip_y)
+            .premultiply_alpha(options.premultiply_alpha)
+    } else {
+        upload
+    };
+    upload.source(PixelFormat::Rgba, PixelType::UnsignedByte, source)?;
+    if options.mipmaps {
+        texture.generate_mipmaps()?;
+    }
+    Ok(texture)
+}
+
+/// Error returned by the futures of a `TextureLoader`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /// The file could not be fetched, e.g. because of a network error or a blocked cross-origin request.
+    Fetch {
+        /// The URL of the file.
+        url: String,
+        /// The message of the exception.
+        message: String,
+    },
+    /// The server answered with a status that is not successful.
+    Status {
+        /// The URL of the file.
+        url: String,
+        /// The HTTP status code.
+        status: u16,
+    },
+    /// The contents of a blob could not be read.
+    Read {
+        /// The message of the exception.
+        message: String,
+    },
+    /// The browser could not decode the image, e.g. because the format is not supported or the file is
+    /// corrupt.
+    Decode {
+        /// The message of the exception.
+        message: String,
+    },
+    /// The file is a KTX or DDS file that could not be read or uploaded.
+    File {
+        /// Why reading or uploading the file failed.
+        error: TextureFileError,
+    },
+    /// The texture for a decoded image could not be created or filled.
+    Texture {
+        /// Why creating or filling the texture failed.
+        error: TextureError,
+    },
+}
+
+impl LoadError {
+    /// Returns the message of a javascript exception that made loading fail.
+    pub(crate) fn exception_message(exception: JsValue) -> String {
+        exception
+            .as_string()
+            .unwrap_or_else(|| format!("{:?}", exception))
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadError::Fetch {
+                ref url,
+                ref message,
+            } => write!(f, "{} could not be fetched: {}", url, message),
+            LoadError::Status { ref url, status } => {
+                write!(
+                    f,
+                    "{} could not be fetched, the server answered {}",
+                    url, status
+                )
+            }
+            LoadError::Read { ref message } => write!(f, "the blob could not be read: {}", message),
+            LoadError::Decode { ref message } => {
+                write!(f, "the image could not be decoded: {}", message)
+            }
+            LoadError::File { ref error } => write!(f, "{}", error),
+            LoadError::Texture { ref error } => write!(f, "{}", error),
+        }
+    }
+}
+
+impl error::Error for LoadError {}
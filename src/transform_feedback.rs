@@ -1,28 +1,133 @@
 //! WebGLTransformFeedback and methods
+use error::CheckedObject;
 use glenum::TransformFeedback;
-use rendering_context::WebGL2RenderingContext;
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing transform feedback objects.
+pub trait TransformFeedbackBackend: Sized {
+    /// Handle to a transform feedback object of this backend.
+    type TransformFeedback: Clone;
+
+    /// Binding for `WebGL2RenderingContext.createTransformFeedback()`
+    fn _create_transform_feedback(&self) -> Self::TransformFeedback;
+    /// Binding for `WebGL2RenderingContext.deleteTransformFeedback()`
+    fn _delete_transform_feedback(&self, transform_feedback: Self::TransformFeedback);
+    /// Binding for `WebGL2RenderingContext.isTransformFeedback()`
+    fn _is_transform_feedback(&self, transform_feedback: &Self::TransformFeedback) -> bool;
+    /// Binding for `WebGL2RenderingContext.bindTransformFeedback()`
+    fn _bind_transform_feedback(
+        &self,
+        target: TransformFeedback,
+        transform_feedback: &Self::TransformFeedback,
+    );
+
     /// Creates and initializes a `WebGLRSTransformFeedback` object.
-    pub fn create_transform_feedback(&self) -> WebGLRSTransformFeedback {
+    fn create_transform_feedback(&self) -> WebGLRSTransformFeedback<'_, Self> {
         WebGLRSTransformFeedback {
             context: self,
             inner: self._create_transform_feedback(),
+            leaked: false,
+        }
+    }
+}
+
+impl TransformFeedbackBackend for WebGL2RenderingContext {
+    type TransformFeedback = WebGLTransformFeedback;
+
+    fn _create_transform_feedback(&self) -> WebGLTransformFeedback {
+        WebGL2RenderingContext::_create_transform_feedback(self)
+    }
+    fn _delete_transform_feedback(&self, transform_feedback: WebGLTransformFeedback) {
+        WebGL2RenderingContext::_delete_transform_feedback(self, transform_feedback)
+    }
+    fn _is_transform_feedback(&self, transform_feedback: &WebGLTransformFeedback) -> bool {
+        WebGL2RenderingContext::_is_transform_feedback(self, transform_feedback)
+    }
+    fn _bind_transform_feedback(
+        &self,
+        target: TransformFeedback,
+        transform_feedback: &WebGLTransformFeedback,
+    ) {
+        WebGL2RenderingContext::_bind_transform_feedback(self, target, transform_feedback)
+    }
+}
+
+/// Transform feedback object which captures the output of the vertex shader.
+///
+/// The object is deleted when the `WebGLRSTransformFeedback` is dropped.
+pub struct WebGLRSTransformFeedback<
+    'ctx,
+    B: 'ctx + TransformFeedbackBackend = WebGL2RenderingContext,
+> {
+    context: &'ctx B,
+    inner: B::TransformFeedback,
+    leaked: bool,
+}
+
+impl<'ctx, B: TransformFeedbackBackend> Drop for WebGLRSTransformFeedback<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_transform_feedback(self.inner.clone());
         }
     }
 }
 
-#[derive(Clone)]
-pub struct WebGLRSTransformFeedback<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLTransformFeedback,
+impl<'ctx, B: TransformFeedbackBackend + ContextBackend> WebGLRSTransformFeedback<'ctx, B> {
+    /// Returns a view on this transform feedback whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
 }
 
-impl<'ctx> WebGLRSTransformFeedback<'ctx> {
+impl<'a, 'ctx, B: TransformFeedbackBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSTransformFeedback<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSTransformFeedback "transform_feedback";
+        fn is_valid() -> bool;
+        fn bind(target: TransformFeedback);
+    }
+}
+
+impl<'ctx, B: TransformFeedbackBackend> WebGLRSTransformFeedback<'ctx, B> {
     /// Deletes this `WebGLRSTransformFeedback` object.
+    ///
+    /// This is the same as dropping the `WebGLRSTransformFeedback`.
     pub fn delete(self) {
-        self.context._delete_transform_feedback(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSTransformFeedback` is dropped.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `transform_feedback` - the raw handle.
+    pub fn from_raw(context: &'ctx B, transform_feedback: B::TransformFeedback) -> Self {
+        WebGLRSTransformFeedback {
+            context,
+            inner: transform_feedback,
+            leaked: false,
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::TransformFeedback {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSTransformFeedback` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Returns true if this is a valid `WebGLRSTransformFeedback` object.
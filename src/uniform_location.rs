@@ -1,14 +1,766 @@
 //! WebGLUniformLocation and methods
-use rendering_context::WebGL2RenderingContext;
+use error::{CheckedObject, WebGLError};
+use glenum::UniformType;
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
 use wasm_bindgen::prelude::*;
 
-#[derive(Clone)]
-pub struct WebGLRSUniformLocation<'ctx> {
-    pub(crate) context: &'ctx WebGL2RenderingContext,
-    pub(crate) inner: WebGLUniformLocation,
+/// Backend calls for setting the values of uniform variables.
+pub trait UniformBackend: Sized {
+    /// Handle to a uniform location of this backend.
+    type UniformLocation: Clone;
+
+    /// Binding for `WebGL2RenderingContext.uniform1f()`
+    fn uniform_1f(&self, location: &Self::UniformLocation, v0: f32);
+    /// Binding for `WebGL2RenderingContext.uniform2f()`
+    fn uniform_2f(&self, location: &Self::UniformLocation, v0: f32, v1: f32);
+    /// Binding for `WebGL2RenderingContext.uniform3f()`
+    fn uniform_3f(&self, location: &Self::UniformLocation, v0: f32, v1: f32, v2: f32);
+    /// Binding for `WebGL2RenderingContext.uniform4f()`
+    fn uniform_4f(&self, location: &Self::UniformLocation, v0: f32, v1: f32, v2: f32, v3: f32);
+    /// Binding for `WebGL2RenderingContext.uniform1i()`
+    fn uniform_1i(&self, location: &Self::UniformLocation, v0: i32);
+    /// Binding for `WebGL2RenderingContext.uniform2i()`
+    fn uniform_2i(&self, location: &Self::UniformLocation, v0: i32, v1: i32);
+    /// Binding for `WebGL2RenderingContext.uniform3i()`
+    fn uniform_3i(&self, location: &Self::UniformLocation, v0: i32, v1: i32, v2: i32);
+    /// Binding for `WebGL2RenderingContext.uniform4i()`
+    fn uniform_4i(&self, location: &Self::UniformLocation, v0: i32, v1: i32, v2: i32, v3: i32);
+    /// Binding for `WebGL2RenderingContext.uniform1fv()`
+    fn uniform_1fv(&self, location: &Self::UniformLocation, value: Vec<f32>);
+    /// Binding for `WebGL2RenderingContext.uniform2fv()`
+    fn uniform_2fv(&self, location: &Self::UniformLocation, value: Vec<f32>);
+    /// Binding for `WebGL2RenderingContext.uniform3fv()`
+    fn uniform_3fv(&self, location: &Self::UniformLocation, value: Vec<f32>);
+    /// Binding for `WebGL2RenderingContext.uniform4fv()`
+    fn uniform_4fv(&self, location: &Self::UniformLocation, value: Vec<f32>);
+    /// Binding for `WebGL2RenderingContext.uniform1iv()`
+    fn uniform_1iv(&self, location: &Self::UniformLocation, value: Vec<i32>);
+    /// Binding for `WebGL2RenderingContext.uniform2iv()`
+    fn uniform_2iv(&self, location: &Self::UniformLocation, value: Vec<i32>);
+    /// Binding for `WebGL2RenderingContext.uniform3iv()`
+    fn uniform_3iv(&self, location: &Self::UniformLocation, value: Vec<i32>);
+    /// Binding for `WebGL2RenderingContext.uniform4iv()`
+    fn uniform_4iv(&self, location: &Self::UniformLocation, value: Vec<i32>);
+    /// Binding for `WebGL2RenderingContext.uniformMatrix2fv()`
+    fn uniform_matrix_2fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix3fv()`
+    fn uniform_matrix_3fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix4fv()`
+    fn uniform_matrix_4fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    );
+}
+
+impl UniformBackend for WebGL2RenderingContext {
+    type UniformLocation = WebGLUniformLocation;
+
+    fn uniform_1f(&self, location: &WebGLUniformLocation, v0: f32) {
+        WebGL2RenderingContext::uniform_1f(self, location, v0)
+    }
+    fn uniform_2f(&self, location: &WebGLUniformLocation, v0: f32, v1: f32) {
+        WebGL2RenderingContext::uniform_2f(self, location, v0, v1)
+    }
+    fn uniform_3f(&self, location: &WebGLUniformLocation, v0: f32, v1: f32, v2: f32) {
+        WebGL2RenderingContext::uniform_3f(self, location, v0, v1, v2)
+    }
+    fn uniform_4f(&self, location: &WebGLUniformLocation, v0: f32, v1: f32, v2: f32, v3: f32) {
+        WebGL2RenderingContext::uniform_4f(self, location, v0, v1, v2, v3)
+    }
+    fn uniform_1i(&self, location: &WebGLUniformLocation, v0: i32) {
+        WebGL2RenderingContext::uniform_1i(self, location, v0)
+    }
+    fn uniform_2i(&self, location: &WebGLUniformLocation, v0: i32, v1: i32) {
+        WebGL2RenderingContext::uniform_2i(self, location, v0, v1)
+    }
+    fn uniform_3i(&self, location: &WebGLUniformLocation, v0: i32, v1: i32, v2: i32) {
+        WebGL2RenderingContext::uniform_3i(self, location, v0, v1, v2)
+    }
+    fn uniform_4i(&self, location: &WebGLUniformLocation, v0: i32, v1: i32, v2: i32, v3: i32) {
+        WebGL2RenderingContext::uniform_4i(self, location, v0, v1, v2, v3)
+    }
+    fn uniform_1fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        WebGL2RenderingContext::uniform_1fv(self, location, value)
+    }
+    fn uniform_2fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        WebGL2RenderingContext::uniform_2fv(self, location, value)
+    }
+    fn uniform_3fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        WebGL2RenderingContext::uniform_3fv(self, location, value)
+    }
+    fn uniform_4fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        WebGL2RenderingContext::uniform_4fv(self, location, value)
+    }
+    fn uniform_1iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        WebGL2RenderingContext::uniform_1iv(self, location, value)
+    }
+    fn uniform_2iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        WebGL2RenderingContext::uniform_2iv(self, location, value)
+    }
+    fn uniform_3iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        WebGL2RenderingContext::uniform_3iv(self, location, value)
+    }
+    fn uniform_4iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        WebGL2RenderingContext::uniform_4iv(self, location, value)
+    }
+    fn uniform_matrix_2fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_2fv(self, location, transpose, value)
+    }
+    fn uniform_matrix_3fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_3fv(self, location, transpose, value)
+    }
+    fn uniform_matrix_4fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_4fv(self, location, transpose, value)
+    }
+}
+
+/// Backend calls for the unsigned integer and non-square matrix uniforms that were added in WebGL2.
+pub trait WebGL2UniformBackend: UniformBackend {
+    /// Binding for `WebGL2RenderingContext.uniform1ui()`
+    fn uniform_1ui(&self, location: &Self::UniformLocation, v0: u32);
+    /// Binding for `WebGL2RenderingContext.uniform2ui()`
+    fn uniform_2ui(&self, location: &Self::UniformLocation, v0: u32, v1: u32);
+    /// Binding for `WebGL2RenderingContext.uniform3ui()`
+    fn uniform_3ui(&self, location: &Self::UniformLocation, v0: u32, v1: u32, v2: u32);
+    /// Binding for `WebGL2RenderingContext.uniform4ui()`
+    fn uniform_4ui(&self, location: &Self::UniformLocation, v0: u32, v1: u32, v2: u32, v3: u32);
+    /// Binding for `WebGL2RenderingContext.uniform1uiv()`
+    fn uniform_1uiv(&self, location: &Self::UniformLocation, value: Vec<u32>);
+    /// Binding for `WebGL2RenderingContext.uniform2uiv()`
+    fn uniform_2uiv(&self, location: &Self::UniformLocation, value: Vec<u32>);
+    /// Binding for `WebGL2RenderingContext.uniform3uiv()`
+    fn uniform_3uiv(&self, location: &Self::UniformLocation, value: Vec<u32>);
+    /// Binding for `WebGL2RenderingContext.uniform4uiv()`
+    fn uniform_4uiv(&self, location: &Self::UniformLocation, value: Vec<u32>);
+    /// Binding for `WebGL2RenderingContext.uniformMatrix2x3fv()`
+    fn uniform_matrix_2x3fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix2x4fv()`
+    fn uniform_matrix_2x4fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix3x2fv()`
+    fn uniform_matrix_3x2fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix3x4fv()`
+    fn uniform_matrix_3x4fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix4x2fv()`
+    fn uniform_matrix_4x2fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    );
+    /// Binding for `WebGL2RenderingContext.uniformMatrix4x3fv()`
+    fn uniform_matrix_4x3fv(
+        &self,
+        location: &Self::UniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    );
+}
+
+impl WebGL2UniformBackend for WebGL2RenderingContext {
+    fn uniform_1ui(&self, location: &WebGLUniformLocation, v0: u32) {
+        WebGL2RenderingContext::uniform_1ui(self, location, v0)
+    }
+    fn uniform_2ui(&self, location: &WebGLUniformLocation, v0: u32, v1: u32) {
+        WebGL2RenderingContext::uniform_2ui(self, location, v0, v1)
+    }
+    fn uniform_3ui(&self, location: &WebGLUniformLocation, v0: u32, v1: u32, v2: u32) {
+        WebGL2RenderingContext::uniform_3ui(self, location, v0, v1, v2)
+    }
+    fn uniform_4ui(&self, location: &WebGLUniformLocation, v0: u32, v1: u32, v2: u32, v3: u32) {
+        WebGL2RenderingContext::uniform_4ui(self, location, v0, v1, v2, v3)
+    }
+    fn uniform_1uiv(&self, location: &WebGLUniformLocation, value: Vec<u32>) {
+        WebGL2RenderingContext::uniform_1uiv(self, location, value)
+    }
+    fn uniform_2uiv(&self, location: &WebGLUniformLocation, value: Vec<u32>) {
+        WebGL2RenderingContext::uniform_2uiv(self, location, value)
+    }
+    fn uniform_3uiv(&self, location: &WebGLUniformLocation, value: Vec<u32>) {
+        WebGL2RenderingContext::uniform_3uiv(self, location, value)
+    }
+    fn uniform_4uiv(&self, location: &WebGLUniformLocation, value: Vec<u32>) {
+        WebGL2RenderingContext::uniform_4uiv(self, location, value)
+    }
+    fn uniform_matrix_2x3fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_2x3fv(
+            self, location, transpose, data, src_offset, src_length,
+        )
+    }
+    fn uniform_matrix_2x4fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_2x4fv(
+            self, location, transpose, data, src_offset, src_length,
+        )
+    }
+    fn uniform_matrix_3x2fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_3x2fv(
+            self, location, transpose, data, src_offset, src_length,
+        )
+    }
+    fn uniform_matrix_3x4fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_3x4fv(
+            self, location, transpose, data, src_offset, src_length,
+        )
+    }
+    fn uniform_matrix_4x2fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_4x2fv(
+            self, location, transpose, data, src_offset, src_length,
+        )
+    }
+    fn uniform_matrix_4x3fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        data: Vec<f32>,
+        src_offset: u32,
+        src_length: u32,
+    ) {
+        WebGL2RenderingContext::uniform_matrix_4x3fv(
+            self, location, transpose, data, src_offset, src_length,
+        )
+    }
+}
+/// The location of a uniform variable of a `WebGLRSProgram`.
+///
+/// A location that knows the type of its uniform checks in debug builds that the values passed to `set` match
+/// that type. The type is set with `with_type` or `ProgramReflection::uniform_location`, in debug builds
+/// `WebGLRSProgram::uniform_location` looks it up as well.
+pub struct WebGLRSUniformLocation<'ctx, B: 'ctx + UniformBackend = WebGL2RenderingContext> {
+    pub(crate) context: &'ctx B,
+    pub(crate) inner: B::UniformLocation,
+    pub(crate) uniform_type: Option<UniformType>,
+}
+
+impl<'ctx, B: UniformBackend> Clone for WebGLRSUniformLocation<'ctx, B> {
+    fn clone(&self) -> Self {
+        WebGLRSUniformLocation {
+            context: self.context,
+            inner: self.inner.clone(),
+            uniform_type: self.uniform_type,
+        }
+    }
+}
+
+impl<'ctx, B: UniformBackend + ContextBackend> WebGLRSUniformLocation<'ctx, B> {
+    /// Returns a view on this uniform location whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: UniformBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSUniformLocation<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSUniformLocation "uniform";
+        #[validated]
+        fn set[V: Uniform<B>](value: V);
+    }
+}
+
+impl<'ctx, B: UniformBackend> WebGLRSUniformLocation<'ctx, B> {
+    /// Sets the type of the uniform, values passed to `set` are checked against it.
+    pub fn with_type(mut self, uniform_type: UniformType) -> Self {
+        self.uniform_type = Some(uniform_type);
+        self
+    }
+
+    /// Returns the type of the uniform, if it is known.
+    pub fn uniform_type(&self) -> Option<UniformType> {
+        self.uniform_type
+    }
+
+    /// Sets the value of the uniform.
+    ///
+    /// Returns an error without calling WebGL if the type of the uniform is known and the value can not be
+    /// assigned to it.
+    ///
+    /// # Arguments
+    /// * `value` - the new value, a scalar, vector, matrix or a slice of those for uniform arrays.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let program = gl.create_program();
+    /// let color = program.uniform_location("color").with_type(UniformType::FloatVec3);
+    /// color.set([1.0, 0.5, 0.0]).unwrap();
+    /// let offsets = program.uniform_location("offsets");
+    /// offsets.set(&[[0.0, 1.0], [1.0, 0.0]][..]).unwrap();
+    /// assert_eq!(gl.calls().last().unwrap().name, "uniform2fv");
+    ///
+    /// let calls = gl.calls().len();
+    /// match color.set(1i32) {
+    ///     Err(WebGLError::UniformType {
+    ///         value,
+    ///         uniform_type: UniformType::FloatVec3,
+    ///         ..
+    ///     }) => assert_eq!(value, "int"),
+    ///     other => panic!("unexpected result {:?}", other),
+    /// }
+    /// assert_eq!(gl.calls().len(), calls);
+    /// ```
+    pub fn set<V: Uniform<B>>(&self, value: V) -> Result<(), WebGLError> {
+        if let Some(uniform_type) = self.uniform_type {
+            if !V::accepts(uniform_type) {
+                return Err(WebGLError::UniformType {
+                    operation: "uniform.set",
+                    value: V::glsl_name(),
+                    uniform_type,
+                });
+            }
+        }
+        value.set_uniform(self.context, &self.inner);
+        Ok(())
+    }
+}
+
+/// A value that can be assigned to a uniform variable.
+///
+/// Vectors are fixed size arrays, matrices are arrays of columns (`[[f32; 3]; 2]` is a `mat2x3`) and
+/// slices of either set uniform arrays. Unsigned integers and non-square matrices need a WebGL2 backend.
+pub trait Uniform<B: UniformBackend> {
+    /// Returns true if the value can be assigned to a uniform of the given type.
+    fn accepts(uniform_type: UniformType) -> bool;
+    /// Returns the GLSL name of the type of the value, used in error messages.
+    fn glsl_name() -> &'static str;
+    /// Sets the value of the uniform at the given location.
+    fn set_uniform(&self, context: &B, location: &B::UniformLocation);
+}
+
+/// Returns true if the type is a sampler, samplers are set with integer values.
+fn is_sampler(uniform_type: UniformType) -> bool {
+    let samplers = [
+        UniformType::Sampler2d,
+        UniformType::Sampler3d,
+        UniformType::SamplerCube,
+        UniformType::Sampler2dShadow,
+        UniformType::Sampler2dArray,
+        UniformType::Sampler2dArrayShadow,
+        UniformType::SamplerCubeShadow,
+        UniformType::IntSampler2d,
+        UniformType::IntSampler3d,
+        UniformType::IntSamplerCube,
+        UniformType::IntSampler2dArray,
+        UniformType::UnsignedIntSampler2d,
+        UniformType::UnsignedIntSampler3d,
+        UniformType::UnsignedIntSamplerCube,
+        UniformType::UnsignedIntSampler2dArray,
+    ];
+    samplers.contains(&uniform_type)
+}
+
+// Implements `Uniform` for a scalar or vector, its slice and the slices of its slices, the bool types
+// accept every component type
+macro_rules! uniform_vector {
+    ($backend:ident, $value:ty, $name:expr, [$($accepted:pat)|+], |$this:ident| $components:expr,
+     $set:ident($($component:expr),+), $set_v:ident) => {
+        impl<B: $backend> Uniform<B> for $value {
+            fn accepts(uniform_type: UniformType) -> bool {
+                match uniform_type {
+                    $($accepted)|+ => true,
+                    _ => false,
+                }
+            }
+            fn glsl_name() -> &'static str {
+                $name
+            }
+            fn set_uniform(&self, context: &B, location: &B::UniformLocation) {
+                let $this = self;
+                context.$set(location, $($component),+);
+            }
+        }
+
+        impl<'a, B: $backend> Uniform<B> for &'a [$value] {
+            fn accepts(uniform_type: UniformType) -> bool {
+                <$value as Uniform<B>>::accepts(uniform_type)
+            }
+            fn glsl_name() -> &'static str {
+                concat!($name, "[]")
+            }
+            fn set_uniform(&self, context: &B, location: &B::UniformLocation) {
+                let data = self
+                    .iter()
+                    .flat_map(|$this| $components)
+                    .collect();
+                context.$set_v(location, data);
+            }
+        }
+    };
+}
+
+uniform_vector!(
+    UniformBackend,
+    f32,
+    "float",
+    [UniformType::Float | UniformType::Bool],
+    |v| vec![*v],
+    uniform_1f(*v),
+    uniform_1fv
+);
+uniform_vector!(
+    UniformBackend,
+    [f32; 2],
+    "vec2",
+    [UniformType::FloatVec2 | UniformType::BoolVec2],
+    |v| v.to_vec(),
+    uniform_2f(v[0], v[1]),
+    uniform_2fv
+);
+uniform_vector!(
+    UniformBackend,
+    [f32; 3],
+    "vec3",
+    [UniformType::FloatVec3 | UniformType::BoolVec3],
+    |v| v.to_vec(),
+    uniform_3f(v[0], v[1], v[2]),
+    uniform_3fv
+);
+uniform_vector!(
+    UniformBackend,
+    [f32; 4],
+    "vec4",
+    [UniformType::FloatVec4 | UniformType::BoolVec4],
+    |v| v.to_vec(),
+    uniform_4f(v[0], v[1], v[2], v[3]),
+    uniform_4fv
+);
+uniform_vector!(
+    UniformBackend,
+    [i32; 2],
+    "ivec2",
+    [UniformType::IntVec2 | UniformType::BoolVec2],
+    |v| v.to_vec(),
+    uniform_2i(v[0], v[1]),
+    uniform_2iv
+);
+uniform_vector!(
+    UniformBackend,
+    [i32; 3],
+    "ivec3",
+    [UniformType::IntVec3 | UniformType::BoolVec3],
+    |v| v.to_vec(),
+    uniform_3i(v[0], v[1], v[2]),
+    uniform_3iv
+);
+uniform_vector!(
+    UniformBackend,
+    [i32; 4],
+    "ivec4",
+    [UniformType::IntVec4 | UniformType::BoolVec4],
+    |v| v.to_vec(),
+    uniform_4i(v[0], v[1], v[2], v[3]),
+    uniform_4iv
+);
+uniform_vector!(
+    UniformBackend,
+    bool,
+    "bool",
+    [UniformType::Bool],
+    |v| vec![*v as i32],
+    uniform_1i(*v as i32),
+    uniform_1iv
+);
+uniform_vector!(
+    UniformBackend,
+    [bool; 2],
+    "bvec2",
+    [UniformType::BoolVec2],
+    |v| v.iter().map(|&c| c as i32).collect::<Vec<_>>(),
+    uniform_2i(v[0] as i32, v[1] as i32),
+    uniform_2iv
+);
+uniform_vector!(
+    UniformBackend,
+    [bool; 3],
+    "bvec3",
+    [UniformType::BoolVec3],
+    |v| v.iter().map(|&c| c as i32).collect::<Vec<_>>(),
+    uniform_3i(v[0] as i32, v[1] as i32, v[2] as i32),
+    uniform_3iv
+);
+uniform_vector!(
+    UniformBackend,
+    [bool; 4],
+    "bvec4",
+    [UniformType::BoolVec4],
+    |v| v.iter().map(|&c| c as i32).collect::<Vec<_>>(),
+    uniform_4i(v[0] as i32, v[1] as i32, v[2] as i32, v[3] as i32),
+    uniform_4iv
+);
+uniform_vector!(
+    WebGL2UniformBackend,
+    u32,
+    "uint",
+    [UniformType::UnsignedInt | UniformType::Bool],
+    |v| vec![*v],
+    uniform_1ui(*v),
+    uniform_1uiv
+);
+uniform_vector!(
+    WebGL2UniformBackend,
+    [u32; 2],
+    "uvec2",
+    [UniformType::UnsignedIntVec2 | UniformType::BoolVec2],
+    |v| v.to_vec(),
+    uniform_2ui(v[0], v[1]),
+    uniform_2uiv
+);
+uniform_vector!(
+    WebGL2UniformBackend,
+    [u32; 3],
+    "uvec3",
+    [UniformType::UnsignedIntVec3 | UniformType::BoolVec3],
+    |v| v.to_vec(),
+    uniform_3ui(v[0], v[1], v[2]),
+    uniform_3uiv
+);
+uniform_vector!(
+    WebGL2UniformBackend,
+    [u32; 4],
+    "uvec4",
+    [UniformType::UnsignedIntVec4 | UniformType::BoolVec4],
+    |v| v.to_vec(),
+    uniform_4ui(v[0], v[1], v[2], v[3]),
+    uniform_4uiv
+);
+
+// `int` is also used for the texture units of samplers, so it can't go through `uniform_vector`
+impl<B: UniformBackend> Uniform<B> for i32 {
+    fn accepts(uniform_type: UniformType) -> bool {
+        match uniform_type {
+            UniformType::Int | UniformType::Bool => true,
+            _ => is_sampler(uniform_type),
+        }
+    }
+    fn glsl_name() -> &'static str {
+        "int"
+    }
+    fn set_uniform(&self, context: &B, location: &B::UniformLocation) {
+        context.uniform_1i(location, *self);
+    }
+}
+
+impl<B: UniformBackend> Uniform<B> for &[i32] {
+    fn accepts(uniform_type: UniformType) -> bool {
+        <i32 as Uniform<B>>::accepts(uniform_type)
+    }
+    fn glsl_name() -> &'static str {
+        "int[]"
+    }
+    fn set_uniform(&self, context: &B, location: &B::UniformLocation) {
+        context.uniform_1iv(location, self.to_vec());
+    }
+}
+
+// Implements `Uniform` for a column major matrix and its slice, `$set` is called as
+// `$set(location, transpose, data $(, extra)*)`
+macro_rules! uniform_matrix {
+    ($backend:ident, $value:ty, $name:expr, $accepted:path, $set:ident $(, $extra:expr)*) => {
+        impl<B: $backend> Uniform<B> for $value {
+            fn accepts(uniform_type: UniformType) -> bool {
+                match uniform_type {
+                    $accepted => true,
+                    _ => false,
+                }
+            }
+            fn glsl_name() -> &'static str {
+                $name
+            }
+            fn set_uniform(&self, context: &B, location: &B::UniformLocation) {
+                let data = self.iter().flat_map(|column| column.iter().cloned()).collect();
+                context.$set(location, false, data $(, $extra)*);
+            }
+        }
+
+        impl<'a, B: $backend> Uniform<B> for &'a [$value] {
+            fn accepts(uniform_type: UniformType) -> bool {
+                <$value as Uniform<B>>::accepts(uniform_type)
+            }
+            fn glsl_name() -> &'static str {
+                concat!($name, "[]")
+            }
+            fn set_uniform(&self, context: &B, location: &B::UniformLocation) {
+                let data = self
+                    .iter()
+                    .flat_map(|matrix| matrix.iter())
+                    .flat_map(|column| column.iter().cloned())
+                    .collect();
+                context.$set(location, false, data $(, $extra)*);
+            }
+        }
+    };
 }
 
-impl<'ctx> WebGLRSUniformLocation<'ctx> {}
+uniform_matrix!(
+    UniformBackend,
+    [[f32; 2]; 2],
+    "mat2",
+    UniformType::FloatMat2,
+    uniform_matrix_2fv
+);
+uniform_matrix!(
+    UniformBackend,
+    [[f32; 3]; 3],
+    "mat3",
+    UniformType::FloatMat3,
+    uniform_matrix_3fv
+);
+uniform_matrix!(
+    UniformBackend,
+    [[f32; 4]; 4],
+    "mat4",
+    UniformType::FloatMat4,
+    uniform_matrix_4fv
+);
+uniform_matrix!(
+    WebGL2UniformBackend,
+    [[f32; 3]; 2],
+    "mat2x3",
+    UniformType::FloatMat2x3,
+    uniform_matrix_2x3fv,
+    0,
+    0
+);
+uniform_matrix!(
+    WebGL2UniformBackend,
+    [[f32; 4]; 2],
+    "mat2x4",
+    UniformType::FloatMat2x4,
+    uniform_matrix_2x4fv,
+    0,
+    0
+);
+uniform_matrix!(
+    WebGL2UniformBackend,
+    [[f32; 2]; 3],
+    "mat3x2",
+    UniformType::FloatMat3x2,
+    uniform_matrix_3x2fv,
+    0,
+    0
+);
+uniform_matrix!(
+    WebGL2UniformBackend,
+    [[f32; 4]; 3],
+    "mat3x4",
+    UniformType::FloatMat3x4,
+    uniform_matrix_3x4fv,
+    0,
+    0
+);
+uniform_matrix!(
+    WebGL2UniformBackend,
+    [[f32; 2]; 4],
+    "mat4x2",
+    UniformType::FloatMat4x2,
+    uniform_matrix_4x2fv,
+    0,
+    0
+);
+uniform_matrix!(
+    WebGL2UniformBackend,
+    [[f32; 3]; 4],
+    "mat4x3",
+    UniformType::FloatMat4x3,
+    uniform_matrix_4x3fv,
+    0,
+    0
+);
 
 /// WebGLUniformLocation
 #[wasm_bindgen]
@@ -0,0 +1,155 @@
+//! Description of the memory layout of vertex types
+//!
+//! A `Vertex` lists the attributes stored in every vertex of a buffer, with everything
+//! `vertexAttribPointer` needs to know about them. `WebGLRSVertexArrayObject::configure` applies such a
+//! layout in one call, or `configure_integer` on WebGL2 for layouts with integer attributes. The layout itself is plain data, so it can be inspected without a context.
+//!
+//! With the `derive` feature the trait can be derived for `#[repr(C)]` structs, see the `webgl-rs-derive`
+//! crate. Otherwise it is implemented by hand:
+//!
+//! ```
+//! use webgl_rs::*;
+//! use std::mem;
+//!
+//! #[derive(Clone, Copy)]
+//! #[repr(C)]
+//! struct Vertex2d {
+//!     position: [f32; 2],
+//!     color: [u8; 4],
+//! }
+//!
+//! unsafe impl Pod for Vertex2d {}
+//!
+//! impl Vertex for Vertex2d {
+//!     const ATTRIBUTES: &'static [VertexAttribute] = &[
+//!         VertexAttribute::of::<[f32; 2]>("position", mem::offset_of!(Vertex2d, position)),
+//!         VertexAttribute::of::<[u8; 4]>("color", mem::offset_of!(Vertex2d, color)).normalized(),
+//!     ];
+//! }
+//!
+//! assert_eq!(Vertex2d::stride(), 12);
+//! assert_eq!(Vertex2d::ATTRIBUTES[1].offset, 8);
+//! assert_eq!(Vertex2d::ATTRIBUTES[1].attribute_type, AttributeType::UnsignedByte);
+//!
+//! let gl = RecordingBackend::new();
+//! let buffer = gl.create_buffer();
+//! let vertex_array = gl.create_vertex_array();
+//! vertex_array.configure::<Vertex2d>(&buffer, &[0, 1]).unwrap();
+//! assert!(gl.calls().iter().any(|call| call.to_string()
+//!     == "vertexAttribPointer(1, Four, UnsignedByte, true, 12, 8)"));
+//! ```
+use data_view::Pod;
+use glenum::{AttributeSize, AttributeType};
+use std::mem;
+
+/// One attribute of a vertex type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttribute {
+    /// Name of the attribute, the field name for derived layouts.
+    pub name: &'static str,
+    /// Number of components of the attribute.
+    pub size: AttributeSize,
+    /// Type of the components of the attribute.
+    pub attribute_type: AttributeType,
+    /// Whether integer components are normalized to [0, 1] or [-1, 1] when they are converted to floats.
+    pub normalized: bool,
+    /// Whether the attribute is read as an integer (`vertexAttribIPointer`), requires WebGL2.
+    pub integer: bool,
+    /// Number of instances drawn before the attribute advances, 0 advances it every vertex.
+    pub divisor: u32,
+    /// Byte offset of the attribute from the start of the vertex.
+    pub offset: u32,
+}
+
+impl VertexAttribute {
+    /// Describes a field of type `F` at the given byte offset, not normalized, read as floats and
+    /// advanced every vertex.
+    pub const fn of<F: VertexField>(name: &'static str, offset: usize) -> VertexAttribute {
+        VertexAttribute {
+            name,
+            size: F::SIZE,
+            attribute_type: F::TYPE,
+            normalized: false,
+            integer: false,
+            divisor: 0,
+            offset: offset as u32,
+        }
+    }
+
+    /// Normalizes the integer components of the attribute.
+    pub const fn normalized(mut self) -> VertexAttribute {
+        self.normalized = true;
+        self
+    }
+
+    /// Reads the attribute as integers instead of floats.
+    pub const fn integer(mut self) -> VertexAttribute {
+        self.integer = true;
+        self
+    }
+
+    /// Advances the attribute once every `divisor` instances.
+    pub const fn divisor(mut self, divisor: u32) -> VertexAttribute {
+        self.divisor = divisor;
+        self
+    }
+}
+
+/// A field type that can be used as vertex attribute: a scalar or an array of 2 to 4 scalars.
+pub trait VertexField: Pod {
+    /// Number of components.
+    const SIZE: AttributeSize;
+    /// Type of the components.
+    const TYPE: AttributeType;
+}
+
+macro_rules! vertex_field {
+    ($component:ty, $attribute_type:ident) => {
+        impl VertexField for $component {
+            const SIZE: AttributeSize = AttributeSize::One;
+            const TYPE: AttributeType = AttributeType::$attribute_type;
+        }
+        impl VertexField for [$component; 1] {
+            const SIZE: AttributeSize = AttributeSize::One;
+            const TYPE: AttributeType = AttributeType::$attribute_type;
+        }
+        impl VertexField for [$component; 2] {
+            const SIZE: AttributeSize = AttributeSize::Two;
+            const TYPE: AttributeType = AttributeType::$attribute_type;
+        }
+        impl VertexField for [$component; 3] {
+            const SIZE: AttributeSize = AttributeSize::Three;
+            const TYPE: AttributeType = AttributeType::$attribute_type;
+        }
+        impl VertexField for [$component; 4] {
+            const SIZE: AttributeSize = AttributeSize::Four;
+            const TYPE: AttributeType = AttributeType::$attribute_type;
+        }
+    };
+}
+
+vertex_field!(i8, Byte);
+vertex_field!(u8, UnsignedByte);
+vertex_field!(i16, Short);
+vertex_field!(u16, UnsignedShort);
+vertex_field!(i32, Int);
+vertex_field!(u32, UnsignedInt);
+vertex_field!(f32, Float);
+
+/// A type whose values are stored as vertices in a buffer.
+pub trait Vertex: Pod {
+    /// The attributes of the vertex, in the order their locations are passed to `configure`.
+    const ATTRIBUTES: &'static [VertexAttribute];
+
+    /// Returns the number of bytes between the starts of two consecutive vertices.
+    fn stride() -> u32 {
+        mem::size_of::<Self>() as u32
+    }
+
+    /// Returns the attribute with the given name.
+    fn attribute(name: &str) -> Option<&'static VertexAttribute> {
+        Self::ATTRIBUTES
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+}
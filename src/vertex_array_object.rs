@@ -1,32 +1,464 @@
 //! VertextArrayObject and methods
-use rendering_context::WebGL2RenderingContext;
+//!
+//! A `WebGLRSVertexArrayObject` keeps a description of the buffers its attributes and indices point at, so
+//! draw calls can be checked against it and it can be inspected with `Debug`.
+//!
+//! ```
+//! use webgl_rs::*;
+//!
+//! let gl = RecordingBackend::new();
+//! let vertices = gl.create_buffer();
+//! let indices = gl.create_buffer();
+//! let vertex_array = gl.create_vertex_array();
+//! vertex_array
+//!     .attribute(0, &vertices, AttributeLayout::new(AttributeSize::Three, AttributeType::Float))
+//!     .and_then(|vertex_array| {
+//!         vertex_array.instanced_attribute(
+//!             1,
+//!             &vertices,
+//!             AttributeLayout::new(AttributeSize::Four, AttributeType::UnsignedByte).normalized(),
+//!             1,
+//!         )
+//!     })
+//!     .unwrap()
+//!     .index_buffer(&indices, IndexType::UnsignedShort);
+//!
+//! assert_eq!(vertex_array.index_type(), Some(IndexType::UnsignedShort));
+//! assert_eq!(vertex_array.bindings().attributes.len(), 2);
+//!
+//! let debug = format!("{:?}", vertex_array);
+//! assert!(debug.starts_with(
+//!     "WebGLRSVertexArrayObject { attributes: [AttributeBinding { location: 0, buffer: Handle(1), "
+//! ));
+//! assert!(debug.contains("normalized: true, stride: 0, offset: 0 }, integer: false, divisor: 1 }"));
+//! assert!(debug.ends_with(
+//!     "index_buffer: Some(IndexBinding { buffer: Handle(2), buffer_size: 0, index_type: UnsignedShort }) }"
+//! ));
+//!
+//! let mut bindings = vertex_array.bindings().clone();
+//! bindings.attributes[0].buffer_size = 120;
+//! assert_eq!(bindings.max_vertex_count(), Some(10));
+//! ```
+use backend::{GlBackend, WebGL2Backend};
+use buffer::{BufferBackend, WebGLRSBuffer};
+use draw::DrawCommand;
+use error::{CheckedObject, WebGLError};
+use glenum::{AttributeSize, AttributeType, BufferKind, IndexType, Parameter, Primitives};
+use rendering_context::{ContextBackend, WebGL2RenderingContext};
+use shared::Shared;
+use std::cell::{Ref, RefCell};
+use std::error;
+use std::fmt;
+use vertex::{Vertex, VertexAttribute};
 use wasm_bindgen::prelude::*;
 
-impl WebGL2RenderingContext {
+/// Backend calls for creating and managing vertex array objects.
+///
+/// Vertex arrays record the buffers they point at, so every vertex array backend is a buffer backend.
+pub trait VertexArrayBackend: BufferBackend {
+    /// Handle to a vertex array object of this backend.
+    type VertexArray: Clone;
+
+    /// Binding for `WebGL2RenderingContext.createVertexArray()`
+    fn _create_vertex_array(&self) -> Self::VertexArray;
+    /// Binding for `WebGL2RenderingContext.deleteVertexArray()`
+    fn _delete_vertex_array(&self, vertex_array: Self::VertexArray);
+    /// Binding for `WebGL2RenderingContext.isVertexArray()`
+    fn _is_vertex_array(&self, vertex_array: &Self::VertexArray) -> bool;
+    /// Binding for `WebGL2RenderingContext.bindVertexArray()`
+    fn _bind_vertex_array(&self, vertex_array: &Self::VertexArray);
+    /// Binding for `WebGL2RenderingContext.bindVertexArray()` with null, binds the default vertex array
+    fn _unbind_vertex_array(&self);
+    /// Binding for `WebGL2RenderingContext.getParameter()` when return type is `WebGLVertexArrayObject`
+    fn _get_parameter_vertex_array(&self, pname: Parameter) -> Option<Self::VertexArray>;
+
     /// Creates and initializes a WebGLRSVertexArrayObject object that represents a vertex array object
     /// (VAO) pointing to vertex array data and which provides names for different sets of vertex data.
-    pub fn create_vertex_array(&self) -> WebGLRSVertexArrayObject {
+    fn create_vertex_array(&self) -> WebGLRSVertexArrayObject<'_, Self> {
         WebGLRSVertexArrayObject {
             context: self,
             inner: self._create_vertex_array(),
+            leaked: false,
+            bindings: RefCell::new(VertexArrayBindings::new()),
+        }
+    }
+}
+
+impl VertexArrayBackend for WebGL2RenderingContext {
+    type VertexArray = WebGLVertexArrayObject;
+
+    fn _create_vertex_array(&self) -> WebGLVertexArrayObject {
+        WebGL2RenderingContext::_create_vertex_array(self)
+    }
+    fn _delete_vertex_array(&self, vertex_array: WebGLVertexArrayObject) {
+        WebGL2RenderingContext::_delete_vertex_array(self, vertex_array)
+    }
+    fn _is_vertex_array(&self, vertex_array: &WebGLVertexArrayObject) -> bool {
+        WebGL2RenderingContext::_is_vertex_array(self, vertex_array)
+    }
+    fn _bind_vertex_array(&self, vertex_array: &WebGLVertexArrayObject) {
+        WebGL2RenderingContext::_bind_vertex_array(self, vertex_array)
+    }
+    fn _unbind_vertex_array(&self) {
+        WebGL2RenderingContext::_bind_vertex_array_option(self, None)
+    }
+    fn _get_parameter_vertex_array(&self, pname: Parameter) -> Option<WebGLVertexArrayObject> {
+        WebGL2RenderingContext::_get_parameter_vertex_array(self, pname)
+    }
+}
+
+/// Returns the size in bytes of one component of an attribute type.
+fn component_size(attribute_type: AttributeType) -> u32 {
+    match attribute_type {
+        AttributeType::Byte | AttributeType::UnsignedByte => 1,
+        AttributeType::Short | AttributeType::UnsignedShort | AttributeType::HalfFloat => 2,
+        AttributeType::Int | AttributeType::UnsignedInt | AttributeType::Float => 4,
+    }
+}
+
+/// Returns the size in bytes of one index.
+pub(crate) fn index_size(index_type: IndexType) -> u32 {
+    match index_type {
+        IndexType::UnsignedByte => 1,
+        IndexType::UnsignedShort => 2,
+        IndexType::UnsignedInt => 4,
+    }
+}
+
+/// Where the elements of an attribute are stored in a buffer, the arguments of `vertexAttribPointer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeLayout {
+    /// Number of components of an element.
+    pub size: AttributeSize,
+    /// Type of the components.
+    pub attribute_type: AttributeType,
+    /// Whether integer components are normalized when they are converted to floats.
+    pub normalized: bool,
+    /// Bytes between the starts of consecutive elements, 0 if the elements are tightly packed.
+    pub stride: u32,
+    /// Byte offset of the first element in the buffer.
+    pub offset: u32,
+}
+
+impl AttributeLayout {
+    /// Describes tightly packed, not normalized elements starting at the beginning of the buffer.
+    pub const fn new(size: AttributeSize, attribute_type: AttributeType) -> AttributeLayout {
+        AttributeLayout {
+            size,
+            attribute_type,
+            normalized: false,
+            stride: 0,
+            offset: 0,
+        }
+    }
+
+    /// Describes an attribute of a vertex type stored in a buffer of vertices.
+    pub fn of_vertex<V: Vertex>(attribute: &VertexAttribute) -> AttributeLayout {
+        AttributeLayout {
+            size: attribute.size,
+            attribute_type: attribute.attribute_type,
+            normalized: attribute.normalized,
+            stride: V::stride(),
+            offset: attribute.offset,
+        }
+    }
+
+    /// Normalizes the integer components of the elements.
+    pub const fn normalized(mut self) -> AttributeLayout {
+        self.normalized = true;
+        self
+    }
+
+    /// Sets the number of bytes between the starts of consecutive elements.
+    pub const fn stride(mut self, stride: u32) -> AttributeLayout {
+        self.stride = stride;
+        self
+    }
+
+    /// Sets the byte offset of the first element.
+    pub const fn offset(mut self, offset: u32) -> AttributeLayout {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the size in bytes of one element.
+    pub fn element_size(&self) -> u32 {
+        self.size as u32 * component_size(self.attribute_type)
+    }
+
+    /// Returns the number of bytes between the starts of consecutive elements, also for tightly packed
+    /// elements.
+    pub fn effective_stride(&self) -> u32 {
+        if self.stride == 0 {
+            self.element_size()
+        } else {
+            self.stride
+        }
+    }
+
+    /// Returns the number of complete elements a buffer of the given size holds.
+    pub fn element_count(&self, buffer_size: u32) -> u32 {
+        let end = self.offset + self.element_size();
+        if buffer_size < end {
+            0
+        } else {
+            (buffer_size - end) / self.effective_stride() + 1
+        }
+    }
+}
+
+/// An attribute of a vertex array, as set by `WebGLRSVertexArrayObject::attribute` and friends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeBinding<Handle> {
+    /// Location of the attribute.
+    pub location: u32,
+    /// The buffer the attribute reads from.
+    pub buffer: Handle,
+    /// Size in bytes of the buffer when the attribute was set or the sizes were last updated.
+    pub buffer_size: u32,
+    /// Where the elements are stored in the buffer.
+    pub layout: AttributeLayout,
+    /// Whether the attribute is read as integers.
+    pub integer: bool,
+    /// Number of instances drawn before the attribute advances, 0 advances it every vertex.
+    pub divisor: u32,
+}
+
+/// The element array buffer of a vertex array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexBinding<Handle> {
+    /// The buffer holding the indices.
+    pub buffer: Handle,
+    /// Size in bytes of the buffer when it was set or the sizes were last updated.
+    pub buffer_size: u32,
+    /// Type of the indices.
+    pub index_type: IndexType,
+}
+
+impl<Handle> IndexBinding<Handle> {
+    /// Returns the number of indices in the buffer.
+    pub fn index_count(&self) -> u32 {
+        self.buffer_size / index_size(self.index_type)
+    }
+}
+
+/// Description of what a vertex array points at.
+///
+/// Buffer sizes are read when a binding is set and again by `WebGLRSVertexArrayObject::update_buffer_sizes`,
+/// which `DrawCommand::execute` calls before checking a draw.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexArrayBindings<Handle> {
+    /// The attributes, ordered by the time they were first set.
+    pub attributes: Vec<AttributeBinding<Handle>>,
+    /// The element array buffer, if one was set.
+    pub index_buffer: Option<IndexBinding<Handle>>,
+}
+
+impl<Handle> VertexArrayBindings<Handle> {
+    /// Creates an empty description.
+    pub fn new() -> VertexArrayBindings<Handle> {
+        VertexArrayBindings {
+            attributes: Vec::new(),
+            index_buffer: None,
+        }
+    }
+
+    /// Returns the attribute at the given location.
+    pub fn attribute(&self, location: u32) -> Option<&AttributeBinding<Handle>> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.location == location)
+    }
+
+    /// Returns the number of vertices that can be drawn before an attribute reads past the end of its
+    /// buffer, `None` if no attribute advances per vertex.
+    pub fn max_vertex_count(&self) -> Option<u32> {
+        self.attributes
+            .iter()
+            .filter(|attribute| attribute.divisor == 0)
+            .map(|attribute| attribute.layout.element_count(attribute.buffer_size))
+            .min()
+    }
+
+    /// Returns the number of instances that can be drawn before an instanced attribute reads past the end
+    /// of its buffer, `None` if there are no instanced attributes.
+    pub fn max_instance_count(&self) -> Option<u32> {
+        self.attributes
+            .iter()
+            .filter(|attribute| attribute.divisor != 0)
+            .map(|attribute| {
+                attribute
+                    .layout
+                    .element_count(attribute.buffer_size)
+                    .saturating_mul(attribute.divisor)
+            })
+            .min()
+    }
+
+    /// Records an attribute, replacing the one at the same location.
+    fn set_attribute(&mut self, binding: AttributeBinding<Handle>) {
+        match self
+            .attributes
+            .iter_mut()
+            .find(|attribute| attribute.location == binding.location)
+        {
+            Some(attribute) => *attribute = binding,
+            None => self.attributes.push(binding),
         }
     }
 }
 
+impl<Handle> Default for VertexArrayBindings<Handle> {
+    fn default() -> Self {
+        VertexArrayBindings::new()
+    }
+}
+
 /// VertexArrayObject
 ///
 /// The WebGLVertexArrayObject interface is part of the WebGL 2 API, represents vertex array objects (VAOs)
 /// pointing to vertex array data, and provides names for different sets of vertex data.
-#[derive(Clone)]
-pub struct WebGLRSVertexArrayObject<'ctx> {
-    context: &'ctx WebGL2RenderingContext,
-    inner: WebGLVertexArrayObject,
+///
+/// The object is deleted when the `WebGLRSVertexArrayObject` is dropped.
+pub struct WebGLRSVertexArrayObject<'ctx, B: 'ctx + VertexArrayBackend = WebGL2RenderingContext> {
+    pub(crate) context: &'ctx B,
+    inner: B::VertexArray,
+    leaked: bool,
+    bindings: RefCell<VertexArrayBindings<B::Buffer>>,
+}
+
+impl<'ctx, B: VertexArrayBackend> Drop for WebGLRSVertexArrayObject<'ctx, B> {
+    fn drop(&mut self) {
+        if !self.leaked {
+            self.context._delete_vertex_array(self.inner.clone());
+        }
+    }
 }
 
-impl<'ctx> WebGLRSVertexArrayObject<'ctx> {
+impl<'ctx, B: VertexArrayBackend + ContextBackend> WebGLRSVertexArrayObject<'ctx, B> {
+    /// Returns a view on this vertex array whose methods check the WebGL error flag after the call, see
+    /// `CheckedObject`.
+    pub fn checked(&self) -> CheckedObject<'_, 'ctx, B, Self> {
+        CheckedObject::new(self.context, self)
+    }
+}
+
+impl<'a, 'ctx, B: VertexArrayBackend + ContextBackend>
+    CheckedObject<'a, 'ctx, B, WebGLRSVertexArrayObject<'ctx, B>>
+{
+    checked_methods! {
+        object WebGLRSVertexArrayObject "vertex_array";
+        fn is_valid() -> bool;
+        fn bind();
+    }
+}
+
+impl<'a, 'ctx, B: GlBackend> CheckedObject<'a, 'ctx, B, WebGLRSVertexArrayObject<'ctx, B>> {
+    checked_methods! {
+        object WebGLRSVertexArrayObject "vertex_array";
+        #[rejecting]
+        fn attribute(location: u32, buffer: &WebGLRSBuffer<B>, layout: AttributeLayout);
+        #[rejecting]
+        fn instanced_attribute(
+            location: u32,
+            buffer: &WebGLRSBuffer<B>,
+            layout: AttributeLayout,
+            divisor: u32,
+        );
+        #[chained]
+        fn index_buffer(buffer: &WebGLRSBuffer<B>, index_type: IndexType);
+    }
+
+    /// Checked version of `WebGLRSVertexArrayObject::configure`.
+    pub fn configure<V: Vertex>(
+        &self,
+        buffer: &WebGLRSBuffer<B>,
+        locations: &[u32],
+    ) -> Result<&Self, WebGLError> {
+        let result = self.object.configure::<V>(buffer, locations).map(|_| ());
+        self.check_rejected("vertex_array.configure", result)
+    }
+
+    /// Checks the error flag after an attribute was set, or wraps the error that kept it from being set.
+    fn check_rejected(
+        &self,
+        operation: &'static str,
+        result: Result<(), VertexArrayError>,
+    ) -> Result<&Self, WebGLError> {
+        match result {
+            Ok(()) => self.check(operation, self),
+            Err(error) => Err(WebGLError::VertexArray { operation, error }),
+        }
+    }
+}
+
+impl<'a, 'ctx, B: WebGL2Backend> CheckedObject<'a, 'ctx, B, WebGLRSVertexArrayObject<'ctx, B>> {
+    checked_methods! {
+        object WebGLRSVertexArrayObject "vertex_array";
+        #[rejecting]
+        fn integer_attribute(location: u32, buffer: &WebGLRSBuffer<B>, layout: AttributeLayout);
+        #[rejecting]
+        fn instanced_integer_attribute(
+            location: u32,
+            buffer: &WebGLRSBuffer<B>,
+            layout: AttributeLayout,
+            divisor: u32,
+        );
+    }
+
+    /// Checked version of `WebGLRSVertexArrayObject::configure_integer`.
+    pub fn configure_integer<V: Vertex>(
+        &self,
+        buffer: &WebGLRSBuffer<B>,
+        locations: &[u32],
+    ) -> Result<&Self, WebGLError> {
+        let result = self
+            .object
+            .configure_integer::<V>(buffer, locations)
+            .map(|_| ());
+        self.check_rejected("vertex_array.configure_integer", result)
+    }
+}
+
+impl<'ctx, B: VertexArrayBackend> WebGLRSVertexArrayObject<'ctx, B> {
     /// Deletes the `WebGLRSVertexArrayObject` on the gpu and consumes itself.
+    ///
+    /// This is the same as dropping the `WebGLRSVertexArrayObject`.
     pub fn delete(self) {
-        self.context._delete_vertex_array(self.inner);
+        drop(self);
+    }
+
+    /// Takes ownership of a raw handle, the object is deleted when the returned `WebGLRSVertexArrayObject` is dropped.
+    ///
+    /// Bindings made before are not known, the description of the returned object starts out empty.
+    ///
+    /// # Arguments
+    /// * `context` - the context that created the object.
+    /// * `vertex_array` - the raw handle.
+    pub fn from_raw(context: &'ctx B, vertex_array: B::VertexArray) -> Self {
+        WebGLRSVertexArrayObject {
+            context,
+            inner: vertex_array,
+            leaked: false,
+            bindings: RefCell::new(VertexArrayBindings::new()),
+        }
+    }
+
+    /// Returns the raw handle without deleting the object, it has to be deleted manually afterwards.
+    pub fn into_raw(mut self) -> B::VertexArray {
+        self.leaked = true;
+        self.inner.clone()
+    }
+
+    /// Gives up ownership without deleting the object, it stays alive until the context is lost.
+    pub fn leak(self) {
+        self.into_raw();
+    }
+
+    /// Moves the `WebGLRSVertexArrayObject` into a reference counted handle, the object is deleted when the last
+    /// clone of the handle is dropped.
+    pub fn share(self) -> Shared<Self> {
+        Shared::new(self)
     }
 
     /// Return true if this is a valid `WebGLRSVertexArrayObject` object.
@@ -38,13 +470,476 @@ impl<'ctx> WebGLRSVertexArrayObject<'ctx> {
     pub fn bind(&self) {
         self.context._bind_vertex_array(&self.inner);
     }
+
+    /// Returns the description of what this vertex array points at.
+    pub fn bindings(&self) -> Ref<'_, VertexArrayBindings<B::Buffer>> {
+        self.bindings.borrow()
+    }
+
+    /// Returns the type of the indices in the element array buffer, `None` if no index buffer was set.
+    pub fn index_type(&self) -> Option<IndexType> {
+        self.bindings()
+            .index_buffer
+            .as_ref()
+            .map(|index_buffer| index_buffer.index_type)
+    }
+
+    /// Returns the number of vertices that can be drawn, see `VertexArrayBindings::max_vertex_count`.
+    pub fn max_vertex_count(&self) -> Option<u32> {
+        self.bindings().max_vertex_count()
+    }
+
+    /// Binds this vertex array for the duration of `f`, the previously bound vertex array is bound again
+    /// afterwards.
+    fn edit<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        let previous = self
+            .context
+            ._get_parameter_vertex_array(Parameter::VertexArrayBinding);
+        self.bind();
+        let result = f();
+        match previous {
+            Some(ref previous) => self.context._bind_vertex_array(previous),
+            None => self.context._unbind_vertex_array(),
+        }
+        result
+    }
+}
+
+impl<'ctx, B: GlBackend> WebGLRSVertexArrayObject<'ctx, B> {
+    /// Starts a draw call from this vertex array that is checked against its bindings, see `DrawCommand`.
+    ///
+    /// # Arguments
+    /// * `mode` - the primitives to assemble from the vertices.
+    pub fn draw(&self, mode: Primitives) -> DrawCommand<'_, 'ctx, B> {
+        DrawCommand::new(self, mode)
+    }
+
+    /// Reads the sizes of the buffers this vertex array points at again, for data that was uploaded or
+    /// respecified after the bindings were set.
+    ///
+    /// The vertex array stays bound afterwards, the buffer of the last attribute stays bound to
+    /// `BufferKind::Array`.
+    pub fn update_buffer_sizes(&self) {
+        self.bind();
+        let mut bindings = self.bindings.borrow_mut();
+        for attribute in &mut bindings.attributes {
+            self.context
+                ._bind_buffer(BufferKind::Array, &attribute.buffer);
+            attribute.buffer_size = self.context.buffer_size(BufferKind::Array).max(0) as u32;
+        }
+        if let Some(ref mut index_buffer) = bindings.index_buffer {
+            self.context
+                ._bind_buffer(BufferKind::ElementArray, &index_buffer.buffer);
+            index_buffer.buffer_size =
+                self.context.buffer_size(BufferKind::ElementArray).max(0) as u32;
+        }
+    }
+
+    /// Points an attribute at the elements stored in a buffer, the attribute advances every vertex.
+    ///
+    /// The vertex array is bound while the attribute is set and the previous vertex array is bound again
+    /// afterwards. The buffer stays bound to `BufferKind::Array`.
+    ///
+    /// # Arguments
+    /// * `location` - the location of the attribute.
+    /// * `buffer` - the buffer holding the elements.
+    /// * `layout` - where the elements are stored in the buffer.
+    ///
+    /// Fails without calling WebGL when the stride exceeds the WebGL maximum of 255 bytes.
+    pub fn attribute(
+        &self,
+        location: u32,
+        buffer: &WebGLRSBuffer<B>,
+        layout: AttributeLayout,
+    ) -> Result<&Self, VertexArrayError> {
+        self.instanced_attribute(location, buffer, layout, 0)
+    }
+
+    /// Points an attribute at the elements stored in a buffer, the attribute advances once every
+    /// `divisor` instances.
+    ///
+    /// # Arguments
+    /// * `location` - the location of the attribute.
+    /// * `buffer` - the buffer holding the elements.
+    /// * `layout` - where the elements are stored in the buffer.
+    /// * `divisor` - the number of instances drawn before the attribute advances, 0 advances it every vertex.
+    ///
+    /// Fails without calling WebGL when the stride exceeds the WebGL maximum of 255 bytes.
+    pub fn instanced_attribute(
+        &self,
+        location: u32,
+        buffer: &WebGLRSBuffer<B>,
+        layout: AttributeLayout,
+        divisor: u32,
+    ) -> Result<&Self, VertexArrayError> {
+        self.set_attribute(location, buffer, layout, false, divisor, |context| {
+            context.vertex_attrib_pointer(
+                location,
+                layout.size,
+                layout.attribute_type,
+                layout.normalized,
+                layout.stride as u8,
+                layout.offset as i32,
+            )
+        })
+    }
+
+    /// Points the attributes of this vertex array at the vertices stored in a buffer, following the layout
+    /// of `V`.
+    ///
+    /// Every attribute is set with `instanced_attribute`. Integer attributes need WebGL2, use
+    /// `configure_integer` for vertex types that have them.
+    ///
+    /// # Arguments
+    /// * `buffer` - the buffer holding the vertices.
+    /// * `locations` - the location of every attribute of `V`, in the order of `V::ATTRIBUTES`.
+    ///
+    /// Fails without calling WebGL when the number of locations differs from the number of attributes, when
+    /// `V` has an integer attribute or when the stride of `V` exceeds the WebGL maximum of 255 bytes.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    /// use std::mem;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// #[repr(C)]
+    /// struct Particle {
+    ///     position: [f32; 3],
+    ///     id: u32,
+    /// }
+    ///
+    /// unsafe impl Pod for Particle {}
+    ///
+    /// impl Vertex for Particle {
+    ///     const ATTRIBUTES: &'static [VertexAttribute] = &[
+    ///         VertexAttribute::of::<[f32; 3]>("position", mem::offset_of!(Particle, position)),
+    ///         VertexAttribute::of::<u32>("id", mem::offset_of!(Particle, id)).integer(),
+    ///     ];
+    /// }
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let buffer = gl.create_buffer();
+    /// let vertex_array = gl.create_vertex_array();
+    /// let calls = gl.calls().len();
+    /// assert_eq!(
+    ///     vertex_array.configure::<Particle>(&buffer, &[0]).err(),
+    ///     Some(VertexArrayError::LocationCount { expected: 2, actual: 1 })
+    /// );
+    /// assert_eq!(
+    ///     vertex_array.configure::<Particle>(&buffer, &[0, 1]).err(),
+    ///     Some(VertexArrayError::IntegerAttribute { name: "id", location: 1 })
+    /// );
+    /// assert_eq!(gl.calls().len(), calls);
+    /// assert!(vertex_array.bindings().attributes.is_empty());
+    /// ```
+    pub fn configure<V: Vertex>(
+        &self,
+        buffer: &WebGLRSBuffer<B>,
+        locations: &[u32],
+    ) -> Result<&Self, VertexArrayError> {
+        self.configure_with::<V, fn(u32, AttributeLayout, u32) -> Result<(), VertexArrayError>>(
+            buffer, locations, None,
+        )
+    }
+
+    /// Sets the element array buffer that indexed draw calls read their indices from.
+    ///
+    /// # Arguments
+    /// * `buffer` - the buffer holding the indices.
+    /// * `index_type` - the type of the indices.
+    pub fn index_buffer(&self, buffer: &WebGLRSBuffer<B>, index_type: IndexType) -> &Self {
+        let buffer_size = self.edit(|| {
+            buffer.bind(BufferKind::ElementArray);
+            self.context.buffer_size(BufferKind::ElementArray).max(0) as u32
+        });
+        self.bindings.borrow_mut().index_buffer = Some(IndexBinding {
+            buffer: buffer.inner.clone(),
+            buffer_size,
+            index_type,
+        });
+        self
+    }
+
+    /// Sets the attributes of `V` like `configure`, integer attributes are set with `integer` which is called
+    /// with their location, layout and divisor.
+    ///
+    /// The locations, the stride and whether integer attributes can be set are checked before any attribute
+    /// is set.
+    fn configure_with<
+        V: Vertex,
+        F: FnMut(u32, AttributeLayout, u32) -> Result<(), VertexArrayError>,
+    >(
+        &self,
+        buffer: &WebGLRSBuffer<B>,
+        locations: &[u32],
+        mut integer: Option<F>,
+    ) -> Result<&Self, VertexArrayError> {
+        if locations.len() != V::ATTRIBUTES.len() {
+            return Err(VertexArrayError::LocationCount {
+                expected: V::ATTRIBUTES.len(),
+                actual: locations.len(),
+            });
+        }
+        for (attribute, &location) in V::ATTRIBUTES.iter().zip(locations) {
+            if V::stride() > 255 {
+                return Err(VertexArrayError::StrideTooLarge {
+                    location,
+                    stride: V::stride(),
+                });
+            }
+            if attribute.integer && integer.is_none() {
+                return Err(VertexArrayError::IntegerAttribute {
+                    name: attribute.name,
+                    location,
+                });
+            }
+        }
+        for (attribute, &location) in V::ATTRIBUTES.iter().zip(locations) {
+            let layout = AttributeLayout::of_vertex::<V>(attribute);
+            match integer {
+                Some(ref mut integer) if attribute.integer => {
+                    integer(location, layout, attribute.divisor)?;
+                }
+                _ => {
+                    self.instanced_attribute(location, buffer, layout, attribute.divisor)?;
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Enables an attribute, sets its pointer with `pointer` and records the binding.
+    ///
+    /// Fails without calling WebGL when the stride exceeds the WebGL maximum of 255 bytes.
+    fn set_attribute<F: FnOnce(&B)>(
+        &self,
+        location: u32,
+        buffer: &WebGLRSBuffer<B>,
+        layout: AttributeLayout,
+        integer: bool,
+        divisor: u32,
+        pointer: F,
+    ) -> Result<&Self, VertexArrayError> {
+        if layout.stride > 255 {
+            return Err(VertexArrayError::StrideTooLarge {
+                location,
+                stride: layout.stride,
+            });
+        }
+        let previous_divisor = self
+            .bindings()
+            .attribute(location)
+            .map_or(0, |attribute| attribute.divisor);
+        let buffer_size = self.edit(|| {
+            buffer.bind(BufferKind::Array);
+            self.context.enable_vertex_attrib_array(location);
+            pointer(self.context);
+            if divisor != previous_divisor {
+                self.context.vertex_attrib_divisor(location, divisor);
+            }
+            self.context.buffer_size(BufferKind::Array).max(0) as u32
+        });
+        self.bindings.borrow_mut().set_attribute(AttributeBinding {
+            location,
+            buffer: buffer.inner.clone(),
+            buffer_size,
+            layout,
+            integer,
+            divisor,
+        });
+        Ok(self)
+    }
+}
+
+impl<'ctx, B: WebGL2Backend> WebGLRSVertexArrayObject<'ctx, B> {
+    /// Points an integer attribute at the elements stored in a buffer, see `attribute`.
+    ///
+    /// The components are read as integers with `vertexAttribIPointer`, `normalized` is ignored.
+    ///
+    /// # Arguments
+    /// * `location` - the location of the attribute.
+    /// * `buffer` - the buffer holding the elements.
+    /// * `layout` - where the elements are stored in the buffer.
+    ///
+    /// Fails without calling WebGL when the stride exceeds the WebGL maximum of 255 bytes.
+    pub fn integer_attribute(
+        &self,
+        location: u32,
+        buffer: &WebGLRSBuffer<B>,
+        layout: AttributeLayout,
+    ) -> Result<&Self, VertexArrayError> {
+        self.instanced_integer_attribute(location, buffer, layout, 0)
+    }
+
+    /// Points an integer attribute at the elements stored in a buffer, the attribute advances once every
+    /// `divisor` instances.
+    ///
+    /// # Arguments
+    /// * `location` - the location of the attribute.
+    /// * `buffer` - the buffer holding the elements.
+    /// * `layout` - where the elements are stored in the buffer.
+    /// * `divisor` - the number of instances drawn before the attribute advances, 0 advances it every vertex.
+    ///
+    /// Fails without calling WebGL when the stride exceeds the WebGL maximum of 255 bytes.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let buffer = gl.create_buffer();
+    /// let layout = AttributeLayout {
+    ///     stride: 256,
+    ///     ..AttributeLayout::new(AttributeSize::Four, AttributeType::Int)
+    /// };
+    /// let vertex_array = gl.create_vertex_array();
+    /// let calls = gl.calls().len();
+    /// assert_eq!(
+    ///     vertex_array.instanced_integer_attribute(3, &buffer, layout, 1).err(),
+    ///     Some(VertexArrayError::StrideTooLarge { location: 3, stride: 256 })
+    /// );
+    /// assert_eq!(gl.calls().len(), calls);
+    /// ```
+    pub fn instanced_integer_attribute(
+        &self,
+        location: u32,
+        buffer: &WebGLRSBuffer<B>,
+        layout: AttributeLayout,
+        divisor: u32,
+    ) -> Result<&Self, VertexArrayError> {
+        self.set_attribute(location, buffer, layout, true, divisor, |context| {
+            context.vertex_attrib_i_pointer(
+                location,
+                layout.size,
+                layout.attribute_type,
+                layout.stride,
+                i64::from(layout.offset),
+            )
+        })
+    }
+
+    /// Points the attributes of this vertex array at the vertices stored in a buffer like `configure`,
+    /// integer attributes are set with `instanced_integer_attribute`.
+    ///
+    /// # Arguments
+    /// * `buffer` - the buffer holding the vertices.
+    /// * `locations` - the location of every attribute of `V`, in the order of `V::ATTRIBUTES`.
+    ///
+    /// Fails without calling WebGL when the number of locations differs from the number of attributes or
+    /// when the stride of `V` exceeds the WebGL maximum of 255 bytes.
+    ///
+    /// ```
+    /// use webgl_rs::*;
+    /// use std::mem;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// #[repr(C)]
+    /// struct Particle {
+    ///     position: [f32; 3],
+    ///     id: u32,
+    /// }
+    ///
+    /// unsafe impl Pod for Particle {}
+    ///
+    /// impl Vertex for Particle {
+    ///     const ATTRIBUTES: &'static [VertexAttribute] = &[
+    ///         VertexAttribute::of::<[f32; 3]>("position", mem::offset_of!(Particle, position)),
+    ///         VertexAttribute::of::<u32>("id", mem::offset_of!(Particle, id)).integer(),
+    ///     ];
+    /// }
+    ///
+    /// let gl = RecordingBackend::new();
+    /// let buffer = gl.create_buffer();
+    /// let vertex_array = gl.create_vertex_array();
+    /// vertex_array.configure_integer::<Particle>(&buffer, &[0, 1]).unwrap();
+    /// let calls: Vec<String> = gl.calls().iter().map(|call| call.to_string()).collect();
+    /// assert!(calls.contains(&"vertexAttribPointer(0, Three, Float, false, 16, 0)".to_string()));
+    /// assert!(calls.contains(&"vertexAttribIPointer(1, One, UnsignedInt, 16, 12)".to_string()));
+    /// ```
+    pub fn configure_integer<V: Vertex>(
+        &self,
+        buffer: &WebGLRSBuffer<B>,
+        locations: &[u32],
+    ) -> Result<&Self, VertexArrayError> {
+        self.configure_with::<V, _>(
+            buffer,
+            locations,
+            Some(|location, layout, divisor| {
+                self.instanced_integer_attribute(location, buffer, layout, divisor)
+                    .map(|_| ())
+            }),
+        )
+    }
+}
+
+/// Shows the attributes and index buffer the vertex array points at.
+impl<'ctx, B: VertexArrayBackend> fmt::Debug for WebGLRSVertexArrayObject<'ctx, B>
+where
+    B::Buffer: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bindings = self.bindings();
+        f.debug_struct("WebGLRSVertexArrayObject")
+            .field("attributes", &bindings.attributes)
+            .field("index_buffer", &bindings.index_buffer)
+            .finish()
+    }
 }
 
 /// WebGLVerterArrayObject bindings
+/// Error returned when an attribute can not be pointed at a buffer, nothing is set when it is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexArrayError {
+    /// The stride of an attribute exceeds the WebGL maximum of 255 bytes.
+    StrideTooLarge {
+        /// Location of the attribute.
+        location: u32,
+        /// The stride in bytes.
+        stride: u32,
+    },
+    /// The number of locations passed to `configure` differs from the number of attributes of the vertex.
+    LocationCount {
+        /// The number of attributes of the vertex.
+        expected: usize,
+        /// The number of locations passed.
+        actual: usize,
+    },
+    /// `configure` was used for a vertex with an integer attribute, which needs `configure_integer`.
+    IntegerAttribute {
+        /// Name of the attribute.
+        name: &'static str,
+        /// Location of the attribute.
+        location: u32,
+    },
+}
+
+impl fmt::Display for VertexArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VertexArrayError::StrideTooLarge { location, stride } => write!(
+                f,
+                "the stride of attribute {} is {} bytes, at most 255 are allowed",
+                location, stride
+            ),
+            VertexArrayError::LocationCount { expected, actual } => write!(
+                f,
+                "expected a location for each of the {} attributes of the vertex, got {}",
+                expected, actual
+            ),
+            VertexArrayError::IntegerAttribute { name, location } => write!(
+                f,
+                "attribute {} at location {} is an integer attribute, use configure_integer",
+                name, location
+            ),
+        }
+    }
+}
+
+impl error::Error for VertexArrayError {}
+
 #[wasm_bindgen]
 extern "C" {
     #[derive(Clone)]
-    type WebGLVertexArrayObject;
+    pub type WebGLVertexArrayObject;
     /// Binding for `WebGL2RenderingContext.createVertexArray()`
     #[wasm_bindgen(method, js_name = createVertexArray)]
     fn _create_vertex_array(this: &WebGL2RenderingContext) -> WebGLVertexArrayObject;
@@ -63,4 +958,18 @@ extern "C" {
     /// Binding for `WebGL2RenderingContext.bindVertexArray()`
     #[wasm_bindgen(method, js_name = bindVertexArray)]
     fn _bind_vertex_array(this: &WebGL2RenderingContext, vertex_array: &WebGLVertexArrayObject);
+
+    /// Binding for `WebGL2RenderingContext.bindVertexArray()` when the vertex array can be null
+    #[wasm_bindgen(method, js_name = bindVertexArray)]
+    fn _bind_vertex_array_option(
+        this: &WebGL2RenderingContext,
+        vertex_array: Option<&WebGLVertexArrayObject>,
+    );
+
+    /// Binding for `WebGL2RenderingContext.getParameter()` when return type is `WebGLVertexArrayObject`
+    #[wasm_bindgen(method, js_name = getParameter)]
+    fn _get_parameter_vertex_array(
+        this: &WebGL2RenderingContext,
+        pname: Parameter,
+    ) -> Option<WebGLVertexArrayObject>;
 }
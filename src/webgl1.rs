@@ -0,0 +1,2858 @@
+//! WebGL1 rendering context
+//!
+//! `WebGLRenderingContext` implements the backend traits that are shared with `WebGL2RenderingContext`.
+//! Functionality that became part of the core API in WebGL2 is provided through the extensions that were
+//! promoted, these are enabled when the context is created:
+//!
+//! * instancing (`InstancingBackend`) through `ANGLE_instanced_arrays`
+//! * vertex array objects (`VertexArrayBackend`) through `OES_vertex_array_object`
+//! * multiple draw buffers (`DrawBuffersBackend`) through `WEBGL_draw_buffers`
+//! * float and half float textures through `OES_texture_float`, `OES_texture_half_float` and the
+//!   related `*_linear` and color buffer extensions
+//!
+//! Not every device supports all of these extensions, use `WebGLRenderingContext::supports` to check
+//! before relying on one of them. Calls that need a missing extension are not made and record an
+//! `INVALID_OPERATION` error instead, which is reported by `get_error` and by the checked context. `EXT_disjoint_timer_query` is not exposed, timer queries are only
+//! available on a WebGL2 context.
+use buffer::{BufferBackend, WebGLBuffer};
+use context_builder::{BuildContext, ContextBuilder, OffscreenCanvas, WebGLContextAttributes};
+use data_view::{ArrayData, ArrayDataMut};
+use formats;
+use formats::FormatError;
+use framebuffer::{FramebufferBackend, WebGLFramebuffer};
+use glenum::*;
+use image_source::ImageSource;
+use renderbuffer::{RenderbufferBackend, WebGLRenderbuffer};
+use rendering_context::{
+    string_list, ContextBackend, DrawBuffersBackend, HTMLCanvasElement, InstancingBackend,
+    WebGLRSShaderPrecisionFormat,
+};
+use shader_program::{ProgramBackend, WebGLProgram, WebGLRSActiveInfo, WebGLShader};
+use std::cell::Cell;
+use texture::{TextureBackend, WebGLTexture};
+use uniform_location::{UniformBackend, WebGLUniformLocation};
+use vertex_array_object::VertexArrayBackend;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Value of `HALF_FLOAT_OES`, which differs from the `HALF_FLOAT` constant of WebGL2.
+const HALF_FLOAT_OES: u32 = 0x8D61;
+
+/// Extensions that are enabled by `WebGLRenderingContext::new` when they are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extension {
+    /// `ANGLE_instanced_arrays`
+    AngleInstancedArrays,
+    /// `EXT_blend_minmax`
+    ExtBlendMinmax,
+    /// `EXT_frag_depth`
+    ExtFragDepth,
+    /// `EXT_shader_texture_lod`
+    ExtShaderTextureLod,
+    /// `EXT_sRGB`
+    ExtSrgb,
+    /// `OES_element_index_uint`
+    OesElementIndexUint,
+    /// `OES_standard_derivatives`
+    OesStandardDerivatives,
+    /// `OES_texture_float`
+    OesTextureFloat,
+    /// `OES_texture_half_float`
+    OesTextureHalfFloat,
+    /// `OES_texture_half_float_linear`
+    OesTextureHalfFloatLinear,
+    /// `OES_vertex_array_object`
+    OesVertexArrayObject,
+    /// `WEBGL_depth_texture`
+    WebglDepthTexture,
+    /// `WEBGL_draw_buffers`
+    WebglDrawBuffers,
+    /// `OES_texture_float_linear`, not promoted in WebGL2 but needed to filter float textures
+    OesTextureFloatLinear,
+    /// `WEBGL_color_buffer_float`, allows rendering to float textures
+    WebglColorBufferFloat,
+    /// `EXT_color_buffer_half_float`, allows rendering to half float textures
+    ExtColorBufferHalfFloat,
+}
+
+impl Extension {
+    /// All extensions that are requested when a context is created.
+    pub const ALL: [Extension; 16] = [
+        Extension::AngleInstancedArrays,
+        Extension::ExtBlendMinmax,
+        Extension::ExtFragDepth,
+        Extension::ExtShaderTextureLod,
+        Extension::ExtSrgb,
+        Extension::OesElementIndexUint,
+        Extension::OesStandardDerivatives,
+        Extension::OesTextureFloat,
+        Extension::OesTextureHalfFloat,
+        Extension::OesTextureHalfFloatLinear,
+        Extension::OesVertexArrayObject,
+        Extension::WebglDepthTexture,
+        Extension::WebglDrawBuffers,
+        Extension::OesTextureFloatLinear,
+        Extension::WebglColorBufferFloat,
+        Extension::ExtColorBufferHalfFloat,
+    ];
+
+    /// Returns the name that is passed to `getExtension`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Extension::AngleInstancedArrays => "ANGLE_instanced_arrays",
+            Extension::ExtBlendMinmax => "EXT_blend_minmax",
+            Extension::ExtFragDepth => "EXT_frag_depth",
+            Extension::ExtShaderTextureLod => "EXT_shader_texture_lod",
+            Extension::ExtSrgb => "EXT_sRGB",
+            Extension::OesElementIndexUint => "OES_element_index_uint",
+            Extension::OesStandardDerivatives => "OES_standard_derivatives",
+            Extension::OesTextureFloat => "OES_texture_float",
+            Extension::OesTextureHalfFloat => "OES_texture_half_float",
+            Extension::OesTextureHalfFloatLinear => "OES_texture_half_float_linear",
+            Extension::OesVertexArrayObject => "OES_vertex_array_object",
+            Extension::WebglDepthTexture => "WEBGL_depth_texture",
+            Extension::WebglDrawBuffers => "WEBGL_draw_buffers",
+            Extension::OesTextureFloatLinear => "OES_texture_float_linear",
+            Extension::WebglColorBufferFloat => "WEBGL_color_buffer_float",
+            Extension::ExtColorBufferHalfFloat => "EXT_color_buffer_half_float",
+        }
+    }
+}
+
+/// WebGL1 rendering context
+///
+/// The `WebGLRenderingContext` interface provides the OpenGL ES 2.0 rendering context for the drawing
+/// surface of an HTML `<canvas>` element.
+///
+/// The methods that go through an extension record an `INVALID_OPERATION` error when the extension is not
+/// supported by the context:
+///
+/// ```no_run
+/// use webgl_rs::*;
+///
+/// let gl = WebGLRenderingContext::new("canvas");
+/// if !gl.supports(Extension::WebglDrawBuffers) {
+///     let result = gl
+///         .checked()
+///         .draw_buffers(&[ColorBuffer::ColorAttachment0, ColorBuffer::ColorAttachment1]);
+///     assert!(result.is_err() || !CHECKS_ENABLED);
+/// }
+/// ```
+pub struct WebGLRenderingContext {
+    inner: sys::WebGLRenderingContext,
+    extensions: Vec<Extension>,
+    missing_extension: Cell<bool>,
+    instanced_arrays: Option<ANGLEInstancedArrays>,
+    vertex_array_object: Option<OESVertexArrayObject>,
+    draw_buffers: Option<WEBGLDrawBuffers>,
+}
+
+impl WebGLRenderingContext {
+    /// Creates a WebGL1 context with the default attributes on the canvas with the given id and enables all
+    /// supported extensions.
+    ///
+    /// # Panics
+    /// Panics if the canvas does not exist or the context could not be created, use `ContextBuilder` to
+    /// handle these errors.
+    pub fn new(id: &str) -> WebGLRenderingContext {
+        ContextBuilder::new()
+            .build(id)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub(crate) fn create_on_canvas(
+        canvas: &HTMLCanvasElement,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<WebGLRenderingContext> {
+        canvas
+            ._get_webgl_context(<Self as BuildContext>::CONTEXT_TYPE, attributes)
+            .map(WebGLRenderingContext::from_context)
+    }
+
+    pub(crate) fn create_on_offscreen_canvas(
+        canvas: &OffscreenCanvas,
+        attributes: &WebGLContextAttributes,
+    ) -> Option<WebGLRenderingContext> {
+        canvas
+            ._get_webgl_context(<Self as BuildContext>::CONTEXT_TYPE, attributes)
+            .map(WebGLRenderingContext::from_context)
+    }
+
+    fn from_context(inner: sys::WebGLRenderingContext) -> WebGLRenderingContext {
+        let extensions = Extension::ALL
+            .iter()
+            .cloned()
+            .filter(|extension| !inner._get_extension(extension.name()).is_null())
+            .collect();
+        let instanced_arrays = inner._get_instanced_arrays(Extension::AngleInstancedArrays.name());
+        let vertex_array_object =
+            inner._get_vertex_array_object(Extension::OesVertexArrayObject.name());
+        let draw_buffers = inner._get_draw_buffers(Extension::WebglDrawBuffers.name());
+        WebGLRenderingContext {
+            inner,
+            extensions,
+            missing_extension: Cell::new(false),
+            instanced_arrays,
+            vertex_array_object,
+            draw_buffers,
+        }
+    }
+
+    /// Returns true if the extension is supported and enabled on this context.
+    pub fn supports(&self, extension: Extension) -> bool {
+        self.extensions.contains(&extension)
+    }
+
+    /// Returns all extensions that are enabled on this context.
+    pub fn extensions(&self) -> &[Extension] {
+        &self.extensions
+    }
+
+    /// Returns the extension object if it is supported, otherwise records an `INVALID_OPERATION` error that
+    /// is returned by the next call to `get_error`.
+    fn extension<'a, T>(&self, extension: &'a Option<T>) -> Option<&'a T> {
+        if extension.is_none() {
+            self.missing_extension.set(true);
+        }
+        extension.as_ref()
+    }
+
+    fn instanced_arrays(&self) -> Option<&ANGLEInstancedArrays> {
+        self.extension(&self.instanced_arrays)
+    }
+
+    fn vertex_array_object(&self) -> Option<&OESVertexArrayObject> {
+        self.extension(&self.vertex_array_object)
+    }
+
+    fn draw_buffers_extension(&self) -> Option<&WEBGLDrawBuffers> {
+        self.extension(&self.draw_buffers)
+    }
+}
+
+/// Converts a pixel type to the value accepted by a WebGL1 context.
+fn pixel_type_value(pixel_type: PixelType) -> u32 {
+    match pixel_type {
+        PixelType::HalfFloat => HALF_FLOAT_OES,
+        pixel_type => pixel_type as u32,
+    }
+}
+
+impl InstancingBackend for WebGLRenderingContext {
+    fn vertex_attrib_divisor(&self, index: u32, divisor: u32) {
+        if let Some(extension) = self.instanced_arrays() {
+            extension.vertex_attrib_divisor_angle(index, divisor)
+        }
+    }
+    fn draw_arrays_instanced(&self, mode: Primitives, first: i32, count: u32, instance_count: u32) {
+        if let Some(extension) = self.instanced_arrays() {
+            extension.draw_arrays_instanced_angle(mode, first, count, instance_count)
+        }
+    }
+    fn draw_elements_instanced(
+        &self,
+        mode: Primitives,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+        instance_count: u32,
+    ) {
+        if let Some(extension) = self.instanced_arrays() {
+            extension.draw_elements_instanced_angle(mode, count, data_type, offset, instance_count)
+        }
+    }
+}
+
+impl VertexArrayBackend for WebGLRenderingContext {
+    type VertexArray = WebGLVertexArrayObjectOES;
+
+    fn _create_vertex_array(&self) -> WebGLVertexArrayObjectOES {
+        // without the extension the handle is null, like the result of a create call on a lost context
+        match self.vertex_array_object() {
+            Some(extension) => extension.create_vertex_array_oes(),
+            None => JsValue::NULL.unchecked_into(),
+        }
+    }
+    fn _delete_vertex_array(&self, vertex_array: WebGLVertexArrayObjectOES) {
+        if let Some(extension) = self.vertex_array_object() {
+            extension.delete_vertex_array_oes(vertex_array)
+        }
+    }
+    fn _is_vertex_array(&self, vertex_array: &WebGLVertexArrayObjectOES) -> bool {
+        match self.vertex_array_object() {
+            Some(extension) => extension.is_vertex_array_oes(vertex_array),
+            None => false,
+        }
+    }
+    fn _bind_vertex_array(&self, vertex_array: &WebGLVertexArrayObjectOES) {
+        if let Some(extension) = self.vertex_array_object() {
+            extension.bind_vertex_array_oes(vertex_array)
+        }
+    }
+    fn _unbind_vertex_array(&self) {
+        if let Some(extension) = self.vertex_array_object() {
+            extension.bind_vertex_array_oes_option(None)
+        }
+    }
+    fn _get_parameter_vertex_array(&self, pname: Parameter) -> Option<WebGLVertexArrayObjectOES> {
+        self.inner._get_parameter_vertex_array(pname)
+    }
+}
+
+impl DrawBuffersBackend for WebGLRenderingContext {
+    fn draw_buffers(&self, buffers: &[ColorBuffer]) {
+        let buffers: Vec<u32> = buffers.iter().map(|&buffer| buffer as u32).collect();
+        if let Some(extension) = self.draw_buffers_extension() {
+            extension.draw_buffers_webgl(&buffers)
+        }
+    }
+}
+
+impl ContextBackend for WebGLRenderingContext {
+    fn validate_tex_image(
+        &self,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+    ) -> Result<(), FormatError> {
+        formats::validate_tex_image_webgl1(internalformat, format, pixel_type)
+    }
+    fn drawing_buffer_width(&self) -> u32 {
+        self.inner.drawing_buffer_width()
+    }
+    fn drawing_buffer_height(&self) -> u32 {
+        self.inner.drawing_buffer_height()
+    }
+    fn is_context_lost(&self) -> bool {
+        self.inner.is_context_lost()
+    }
+    fn get_supported_extensions(&self) -> Vec<String> {
+        string_list(self.inner._get_supported_extensions())
+    }
+    fn scissor(&self, x: i32, y: i32, width: u32, height: u32) {
+        self.inner.scissor(x, y, width, height)
+    }
+    fn viewport(&self, x: i32, y: i32, width: u32, height: u32) {
+        self.inner.viewport(x, y, width, height)
+    }
+    fn active_texture(&self, texture: TextureUnit) {
+        self.inner.active_texture(texture)
+    }
+    fn blend_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        self.inner.blend_color(red, green, blue, alpha)
+    }
+    fn blend_equation(&self, mode: BlendEquation) {
+        self.inner.blend_equation(mode)
+    }
+    fn blend_equation_separate(&self, mode_rgb: BlendEquation, mode_alpha: BlendEquation) {
+        self.inner.blend_equation_separate(mode_rgb, mode_alpha)
+    }
+    fn blend_func(&self, sfactor: BlendMode, dfactor: BlendMode) {
+        self.inner.blend_func(sfactor, dfactor)
+    }
+    fn blend_func_separate(
+        &self,
+        src_rgb: BlendMode,
+        dst_rgb: BlendMode,
+        src_alpha: BlendMode,
+        dst_alpha: BlendMode,
+    ) {
+        self.inner
+            .blend_func_separate(src_rgb, dst_rgb, src_alpha, dst_alpha)
+    }
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        self.inner.clear_color(red, green, blue, alpha)
+    }
+    fn clear_depth(&self, depth: f32) {
+        self.inner.clear_depth(depth)
+    }
+    fn clear_stencil(&self, s: i32) {
+        self.inner.clear_stencil(s)
+    }
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        self.inner.color_mask(red, green, blue, alpha)
+    }
+    fn cull_face(&self, mode: Culling) {
+        self.inner.cull_face(mode)
+    }
+    fn depth_func(&self, func: DepthTest) {
+        self.inner.depth_func(func)
+    }
+    fn depth_mask(&self, flag: bool) {
+        self.inner.depth_mask(flag)
+    }
+    fn depth_range(&self, z_near: f32, z_far: f32) {
+        self.inner.depth_range(z_near, z_far)
+    }
+    fn disable(&self, cap: Flag) {
+        self.inner.disable(cap)
+    }
+    fn enable(&self, cap: Flag) {
+        self.inner.enable(cap)
+    }
+    fn front_face(&self, mode: FrontFaceDirection) {
+        self.inner.front_face(mode)
+    }
+    fn get_error(&self) -> Error {
+        if self.missing_extension.replace(false) {
+            return Error::InvalidOperation;
+        }
+        self.inner.get_error()
+    }
+    fn hint(&self, target: HintTarget, mode: HintMode) {
+        self.inner.hint(target, mode)
+    }
+    fn is_enabled(&self, cap: Flag) -> bool {
+        self.inner.is_enabled(cap)
+    }
+    fn line_width(&self, width: f32) {
+        self.inner.line_width(width)
+    }
+    fn pixel_storei(&self, pname: PixelStorageMode, param: i32) {
+        self.inner.pixel_storei(pname, param)
+    }
+    fn polygon_offset(&self, factor: f32, units: f32) {
+        self.inner.polygon_offset(factor, units)
+    }
+    fn sample_coverage(&self, value: f32, invert: bool) {
+        self.inner.sample_coverage(value, invert)
+    }
+    fn stencil_func(&self, func: StencilTest, reference: i32, mask: u32) {
+        self.inner.stencil_func(func, reference, mask)
+    }
+    fn stencil_func_separate(&self, face: Culling, func: StencilTest, reference: i32, mask: u32) {
+        self.inner
+            .stencil_func_separate(face, func, reference, mask)
+    }
+    fn stencil_mask(&self, mask: u32) {
+        self.inner.stencil_mask(mask)
+    }
+    fn stencil_mask_separate(&self, face: Culling, mask: u32) {
+        self.inner.stencil_mask_separate(face, mask)
+    }
+    fn stencil_op(&self, fail: StencilAction, zfail: StencilAction, zpass: StencilAction) {
+        self.inner.stencil_op(fail, zfail, zpass)
+    }
+    fn stencil_op_separate(
+        &self,
+        face: Culling,
+        fail: StencilAction,
+        zfail: StencilAction,
+        zpass: StencilAction,
+    ) {
+        self.inner.stencil_op_separate(face, fail, zfail, zpass)
+    }
+    fn _get_buffer_parameter_i32(&self, target: BufferKind, pname: BufferParameter) -> i32 {
+        self.inner._get_buffer_parameter_i32(target, pname)
+    }
+    fn _get_buffer_parameter_enum(&self, target: BufferKind, pname: BufferParameter) -> DataHint {
+        self.inner._get_buffer_parameter_enum(target, pname)
+    }
+    fn check_framebuffer_status(&self, target: FramebufferKind) -> FramebufferStatus {
+        self.inner.check_framebuffer_status(target)
+    }
+    fn _get_renderbuffer_parameter_i32(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> i32 {
+        self.inner._get_renderbuffer_parameter_i32(target, pname)
+    }
+    fn _get_renderbuffer_parameter_enum(
+        &self,
+        target: RenderbufferKind,
+        pname: RenderbufferParameter,
+    ) -> InternalFormat {
+        self.inner._get_renderbuffer_parameter_enum(target, pname)
+    }
+    fn _renderbuffer_storage(
+        &self,
+        target: RenderbufferKind,
+        internal_format: InternalFormat,
+        width: u32,
+        height: u32,
+    ) {
+        self.inner
+            .renderbuffer_storage(target, internal_format, width, height)
+    }
+    fn copy_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: PixelCopyFormat,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        border: u32,
+    ) {
+        self.inner
+            .copy_tex_image_2d(target, level, internalformat, x, y, width, height, border)
+    }
+    fn copy_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: i32,
+        yoffset: i32,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) {
+        self.inner
+            .copy_tex_sub_image_2d(target, level, xoffset, yoffset, x, y, width, height)
+    }
+    fn generate_mipmap(&self, target: TextureKind) {
+        self.inner.generate_mipmap(target)
+    }
+    fn _get_tex_parameter_i32(&self, target: TextureKind, pname: TextureParameter) -> i32 {
+        self.inner._get_tex_parameter_i32(target, pname)
+    }
+    fn _get_tex_parameter_u32(&self, target: TextureKind, pname: TextureParameter) -> u32 {
+        self.inner._get_tex_parameter_u32(target, pname)
+    }
+    fn _get_tex_parameter_f32(&self, target: TextureKind, pname: TextureParameter) -> f32 {
+        self.inner._get_tex_parameter_f32(target, pname)
+    }
+    fn _get_tex_parameter_bool(&self, target: TextureKind, pname: TextureParameter) -> bool {
+        self.inner._get_tex_parameter_bool(target, pname)
+    }
+    fn _get_tex_parameter_enum1(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMagFilter {
+        self.inner._get_tex_parameter_enum1(target, pname)
+    }
+    fn _get_tex_parameter_enum2(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureMinFilter {
+        self.inner._get_tex_parameter_enum2(target, pname)
+    }
+    fn _get_tex_parameter_enum3(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> TextureWrap {
+        self.inner._get_tex_parameter_enum3(target, pname)
+    }
+    fn _get_tex_parameter_enum4(&self, target: TextureKind, pname: TextureParameter) -> DepthTest {
+        self.inner._get_tex_parameter_enum4(target, pname)
+    }
+    fn _get_tex_parameter_enum5(
+        &self,
+        target: TextureKind,
+        pname: TextureParameter,
+    ) -> CompareMode {
+        self.inner._get_tex_parameter_enum5(target, pname)
+    }
+    fn _get_parameter_bool(&self, pname: Parameter) -> bool {
+        self.inner._get_parameter_bool(pname)
+    }
+    fn _get_parameter_i32(&self, pname: Parameter) -> i32 {
+        self.inner._get_parameter_i32(pname)
+    }
+    fn _get_parameter_u32(&self, pname: Parameter) -> u32 {
+        self.inner._get_parameter_u32(pname)
+    }
+    fn _get_parameter_f32(&self, pname: Parameter) -> f32 {
+        self.inner._get_parameter_f32(pname)
+    }
+    fn _get_parameter_string(&self, pname: Parameter) -> String {
+        self.inner._get_parameter_string(pname)
+    }
+    fn _get_parameter_i32_array(&self, pname: Parameter) -> Vec<i32> {
+        self.inner._get_parameter_i32_array(pname)
+    }
+    fn _get_parameter_u32_array(&self, pname: Parameter) -> Vec<u32> {
+        self.inner._get_parameter_u32_array(pname)
+    }
+    fn _get_parameter_f32_array(&self, pname: Parameter) -> Vec<f32> {
+        self.inner._get_parameter_f32_array(pname)
+    }
+    fn _get_parameter_bool_array(&self, pname: Parameter) -> Vec<bool> {
+        self.inner
+            ._get_parameter_bool_array(pname)
+            .into_iter()
+            .map(|value| value != 0)
+            .collect()
+    }
+    fn _get_parameter_enum1(&self, pname: Parameter) -> BlendEquation {
+        self.inner._get_parameter_enum1(pname)
+    }
+    fn _get_parameter_enum2(&self, pname: Parameter) -> BlendMode {
+        self.inner._get_parameter_enum2(pname)
+    }
+    fn _get_parameter_enum3(&self, pname: Parameter) -> Culling {
+        self.inner._get_parameter_enum3(pname)
+    }
+    fn _get_parameter_enum4(&self, pname: Parameter) -> FrontFaceDirection {
+        self.inner._get_parameter_enum4(pname)
+    }
+    fn _get_parameter_enum5(&self, pname: Parameter) -> DepthTest {
+        self.inner._get_parameter_enum5(pname)
+    }
+    fn _get_parameter_enum6(&self, pname: Parameter) -> StencilTest {
+        self.inner._get_parameter_enum6(pname)
+    }
+    fn _get_parameter_enum7(&self, pname: Parameter) -> StencilAction {
+        self.inner._get_parameter_enum7(pname)
+    }
+    fn tex_parameter_f(&self, target: TextureKind, pname: TextureParameter, param: f32) {
+        self.inner.tex_parameter_f(target, pname, param)
+    }
+    fn tex_parameter_i(&self, target: TextureKind, pname: TextureParameter, param: i32) {
+        self.inner.tex_parameter_i(target, pname, param)
+    }
+    fn get_shader_precision_format(
+        &self,
+        shader_type: ShaderKind,
+        precision_type: ShaderPrecision,
+    ) -> WebGLRSShaderPrecisionFormat {
+        self.inner
+            ._get_shader_precision_format(shader_type, precision_type)
+            .into()
+    }
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        self.inner.disable_vertex_attrib_array(index)
+    }
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        self.inner.enable_vertex_attrib_array(index)
+    }
+    fn get_vertex_attrib_offset(&self, index: u32, pname: VertexAttrib) -> i64 {
+        self.inner.get_vertex_attrib_offset(index, pname)
+    }
+    fn vertex_attrib_1f(&self, index: u32, v0: f32) {
+        self.inner.vertex_attrib_1f(index, v0)
+    }
+    fn vertex_attrib_2f(&self, index: u32, v0: f32, v1: f32) {
+        self.inner.vertex_attrib_2f(index, v0, v1)
+    }
+    fn vertex_attrib_3f(&self, index: u32, v0: f32, v1: f32, v2: f32) {
+        self.inner.vertex_attrib_3f(index, v0, v1, v2)
+    }
+    fn vertex_attrib_4f(&self, index: u32, v0: f32, v1: f32, v2: f32, v3: f32) {
+        self.inner.vertex_attrib_4f(index, v0, v1, v2, v3)
+    }
+    fn vertex_attrib_1fv(&self, index: u32, value: Vec<f32>) {
+        self.inner.vertex_attrib_1fv(index, value)
+    }
+    fn vertex_attrib_2fv(&self, index: u32, value: Vec<f32>) {
+        self.inner.vertex_attrib_2fv(index, value)
+    }
+    fn vertex_attrib_3fv(&self, index: u32, value: Vec<f32>) {
+        self.inner.vertex_attrib_3fv(index, value)
+    }
+    fn vertex_attrib_4fv(&self, index: u32, value: Vec<f32>) {
+        self.inner.vertex_attrib_4fv(index, value)
+    }
+    fn vertex_attrib_pointer(
+        &self,
+        index: u32,
+        size: AttributeSize,
+        attribute_type: AttributeType,
+        normalized: bool,
+        stride: u8,
+        offset: i32,
+    ) {
+        self.inner
+            .vertex_attrib_pointer(index, size, attribute_type, normalized, stride, offset)
+    }
+    fn clear(&self, mask: BufferBit) {
+        self.inner.clear(mask)
+    }
+    fn draw_arrays(&self, mode: Primitives, first: u32, count: u32) {
+        self.inner.draw_arrays(mode, first, count)
+    }
+    fn draw_elements(&self, mode: Primitives, count: u32, data_type: DataType, offset: i64) {
+        self.inner.draw_elements(mode, count, data_type, offset)
+    }
+    fn finish(&self) {
+        self.inner.finish()
+    }
+    fn flush(&self) {
+        self.inner.flush()
+    }
+    fn _buffer_data(&self, target: BufferKind, src_data: ArrayData, usage: DataHint) {
+        match src_data {
+            ArrayData::I8(data) => self.inner._buffer_data_i8(target, data, usage),
+            ArrayData::U8(data) => self.inner._buffer_data_u8(target, data, usage),
+            ArrayData::I16(data) => self.inner._buffer_data_i16(target, data, usage),
+            ArrayData::U16(data) => self.inner._buffer_data_u16(target, data, usage),
+            ArrayData::I32(data) => self.inner._buffer_data_i32(target, data, usage),
+            ArrayData::U32(data) => self.inner._buffer_data_u32(target, data, usage),
+            ArrayData::F32(data) => self.inner._buffer_data_f32(target, data, usage),
+            ArrayData::F64(data) => self.inner._buffer_data_f64(target, data, usage),
+        }
+    }
+    fn _buffer_sub_data(&self, target: BufferKind, offset: i64, src_data: ArrayData) {
+        match src_data {
+            ArrayData::I8(data) => self.inner._buffer_sub_data_i8(target, offset, data),
+            ArrayData::U8(data) => self.inner._buffer_sub_data_u8(target, offset, data),
+            ArrayData::I16(data) => self.inner._buffer_sub_data_i16(target, offset, data),
+            ArrayData::U16(data) => self.inner._buffer_sub_data_u16(target, offset, data),
+            ArrayData::I32(data) => self.inner._buffer_sub_data_i32(target, offset, data),
+            ArrayData::U32(data) => self.inner._buffer_sub_data_u32(target, offset, data),
+            ArrayData::F32(data) => self.inner._buffer_sub_data_f32(target, offset, data),
+            ArrayData::F64(data) => self.inner._buffer_sub_data_f64(target, offset, data),
+        }
+    }
+    fn _tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        width: u32,
+        height: u32,
+        border: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        src_data: ArrayData,
+    ) -> Result<(), JsValue> {
+        let pixel_type = pixel_type_value(pixel_type);
+        match src_data {
+            ArrayData::I8(data) => self.inner._tex_image_2d_i8(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::U8(data) => self.inner._tex_image_2d_u8(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::I16(data) => self.inner._tex_image_2d_i16(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::U16(data) => self.inner._tex_image_2d_u16(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::I32(data) => self.inner._tex_image_2d_i32(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::U32(data) => self.inner._tex_image_2d_u32(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::F32(data) => self.inner._tex_image_2d_f32(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                pixel_type,
+                data,
+            ),
+            ArrayData::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel source",
+            )),
+        }
+    }
+    fn _tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        pixels: ArrayData,
+    ) -> Result<(), JsValue> {
+        let pixel_type = pixel_type_value(pixel_type);
+        match pixels {
+            ArrayData::I8(data) => self.inner._tex_sub_image_2d_i8(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::U8(data) => self.inner._tex_sub_image_2d_u8(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::I16(data) => self.inner._tex_sub_image_2d_i16(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::U16(data) => self.inner._tex_sub_image_2d_u16(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::I32(data) => self.inner._tex_sub_image_2d_i32(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::U32(data) => self.inner._tex_sub_image_2d_u32(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::F32(data) => self.inner._tex_sub_image_2d_f32(
+                target, level, xoffset, yoffset, width, height, format, pixel_type, data,
+            ),
+            ArrayData::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel source",
+            )),
+        }
+    }
+    fn _compressed_tex_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: TextureCompression,
+        width: u32,
+        height: u32,
+        border: u32,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        self.inner._compressed_tex_image_2d(
+            target,
+            level,
+            internalformat,
+            width,
+            height,
+            border,
+            data,
+        )
+    }
+    fn _compressed_tex_sub_image_2d(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        width: u32,
+        height: u32,
+        format: TextureCompression,
+        data: &[u8],
+    ) -> Result<(), JsValue> {
+        self.inner._compressed_tex_sub_image_2d(
+            target, level, xoffset, yoffset, width, height, format, data,
+        )
+    }
+    fn _tex_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        internalformat: InternalFormat,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        self.inner._tex_image_2d_source(
+            target,
+            level,
+            internalformat,
+            format,
+            pixel_type_value(pixel_type),
+            source.as_js_value(),
+        )
+    }
+    fn _tex_sub_image_2d_source(
+        &self,
+        target: TextureBindPoint,
+        level: u32,
+        xoffset: u32,
+        yoffset: u32,
+        format: PixelFormat,
+        pixel_type: PixelType,
+        source: ImageSource,
+    ) -> Result<(), JsValue> {
+        self.inner._tex_sub_image_2d_source(
+            target,
+            level,
+            xoffset,
+            yoffset,
+            format,
+            pixel_type_value(pixel_type),
+            source.as_js_value(),
+        )
+    }
+    fn _read_pixels(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelReadFormat,
+        pixel_type: PixelType,
+        pixels: ArrayDataMut,
+    ) -> Result<(), JsValue> {
+        let pixel_type = pixel_type_value(pixel_type);
+        match pixels {
+            ArrayDataMut::I8(data) => self
+                .inner
+                ._read_pixels_i8(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::U8(data) => self
+                .inner
+                ._read_pixels_u8(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::I16(data) => self
+                .inner
+                ._read_pixels_i16(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::U16(data) => self
+                .inner
+                ._read_pixels_u16(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::I32(data) => self
+                .inner
+                ._read_pixels_i32(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::U32(data) => self
+                .inner
+                ._read_pixels_u32(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::F32(data) => self
+                .inner
+                ._read_pixels_f32(x, y, width, height, format, pixel_type, data),
+            ArrayDataMut::F64(_) => Err(JsValue::from_str(
+                "f64 data can not be used as pixel destination",
+            )),
+        }
+    }
+}
+
+impl BufferBackend for WebGLRenderingContext {
+    type Buffer = WebGLBuffer;
+
+    fn _create_buffer(&self) -> WebGLBuffer {
+        self.inner._create_buffer()
+    }
+    fn _delete_buffer(&self, buffer: WebGLBuffer) {
+        self.inner._delete_buffer(buffer)
+    }
+    fn _is_buffer(&self, buffer: &WebGLBuffer) -> bool {
+        self.inner._is_buffer(buffer)
+    }
+    fn _bind_buffer(&self, target: BufferKind, buffer: &WebGLBuffer) {
+        self.inner._bind_buffer(target, buffer)
+    }
+    fn _get_parameter_buffer(&self, pname: Parameter) -> Option<WebGLBuffer> {
+        self.inner._get_parameter_buffer(pname)
+    }
+}
+
+impl FramebufferBackend for WebGLRenderingContext {
+    type Framebuffer = WebGLFramebuffer;
+
+    fn _create_framebuffer(&self) -> WebGLFramebuffer {
+        self.inner._create_framebuffer()
+    }
+    fn _bind_framebuffer(&self, target: FramebufferKind, framebuffer: &WebGLFramebuffer) {
+        self.inner._bind_framebuffer(target, framebuffer)
+    }
+    fn _delete_framebuffer(&self, framebuffer: WebGLFramebuffer) {
+        self.inner._delete_framebuffer(framebuffer)
+    }
+    fn _is_framebuffer(&self, framebuffer: &WebGLFramebuffer) -> bool {
+        self.inner._is_framebuffer(framebuffer)
+    }
+    fn _get_framebuffer_attachment_parameter_i32(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> i32 {
+        self.inner
+            ._get_framebuffer_attachment_parameter_i32(target, attachment, pname)
+    }
+}
+
+impl RenderbufferBackend for WebGLRenderingContext {
+    type Renderbuffer = WebGLRenderbuffer;
+
+    fn _create_renderbuffer(&self) -> WebGLRenderbuffer {
+        self.inner._create_renderbuffer()
+    }
+    fn _framebuffer_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        renderbuffertarget: RenderbufferKind,
+        renderbuffer: &WebGLRenderbuffer,
+    ) {
+        self.inner
+            ._framebuffer_renderbuffer(target, attachment, renderbuffertarget, renderbuffer)
+    }
+    fn _bind_renderbuffer(&self, target: RenderbufferKind, renderbuffer: &WebGLRenderbuffer) {
+        self.inner._bind_renderbuffer(target, renderbuffer)
+    }
+    fn _delete_renderbuffer(&self, renderbuffer: WebGLRenderbuffer) {
+        self.inner._delete_renderbuffer(renderbuffer)
+    }
+    fn _is_renderbuffer(&self, renderbuffer: &WebGLRenderbuffer) -> bool {
+        self.inner._is_renderbuffer(renderbuffer)
+    }
+    fn _get_framebuffer_attachment_parameter_renderbuffer(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<WebGLRenderbuffer> {
+        self.inner
+            ._get_framebuffer_attachment_parameter_renderbuffer(target, attachment, pname)
+    }
+}
+
+impl TextureBackend for WebGLRenderingContext {
+    type Texture = WebGLTexture;
+
+    fn _create_texture(&self) -> WebGLTexture {
+        self.inner._create_texture()
+    }
+    fn _bind_texture(&self, target: TextureKind, texture: &WebGLTexture) {
+        self.inner._bind_texture(target, texture)
+    }
+    fn _delete_texture(&self, texture: WebGLTexture) {
+        self.inner._delete_texture(texture)
+    }
+    fn _is_texture(&self, texture: &WebGLTexture) -> bool {
+        self.inner._is_texture(texture)
+    }
+    fn _get_parameter_texture(&self, pname: Parameter) -> Option<WebGLTexture> {
+        self.inner._get_parameter_texture(pname)
+    }
+    fn _get_framebuffer_attachment_parameter_texture(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        pname: FramebufferAttachmentParameter,
+    ) -> Option<WebGLTexture> {
+        self.inner
+            ._get_framebuffer_attachment_parameter_texture(target, attachment, pname)
+    }
+    fn _framebuffer_texture_2d(
+        &self,
+        target: FramebufferKind,
+        attachment: Attachment,
+        textarget: TextureBindPoint,
+        texture: &WebGLTexture,
+        level: i32,
+    ) {
+        self.inner
+            ._framebuffer_texture_2d(target, attachment, textarget, texture, level)
+    }
+}
+
+impl ProgramBackend for WebGLRenderingContext {
+    type Program = WebGLProgram;
+    type Shader = WebGLShader;
+
+    fn _create_program(&self) -> WebGLProgram {
+        self.inner._create_program()
+    }
+    fn _delete_program(&self, program: WebGLProgram) {
+        self.inner._delete_program(program)
+    }
+    fn _detach_shader(&self, program: &WebGLProgram, shader: &WebGLShader) {
+        self.inner._detach_shader(program, shader)
+    }
+    fn _attach_shader(&self, program: &WebGLProgram, shader: &WebGLShader) {
+        self.inner._attach_shader(program, shader)
+    }
+    fn _bind_attrib_location(&self, program: &WebGLProgram, index: u32, name: &str) {
+        self.inner._bind_attrib_location(program, index, name)
+    }
+    fn _get_program_info_log(&self, program: &WebGLProgram) -> String {
+        self.inner._get_program_info_log(program)
+    }
+    fn _is_program(&self, program: &WebGLProgram) -> bool {
+        self.inner._is_program(program)
+    }
+    fn _link_program(&self, program: &WebGLProgram) {
+        self.inner._link_program(program)
+    }
+    fn _use_program(&self, program: &WebGLProgram) {
+        self.inner._use_program(program)
+    }
+    fn _validate_program(&self, program: &WebGLProgram) {
+        self.inner._validate_program(program)
+    }
+    fn _get_active_attrib(&self, program: &WebGLProgram, index: u32) -> WebGLRSActiveInfo {
+        self.inner._get_active_attrib(program, index).into()
+    }
+    fn _get_active_uniform(&self, program: &WebGLProgram, index: u32) -> WebGLRSActiveInfo {
+        self.inner._get_active_uniform(program, index).into()
+    }
+    fn _get_attrib_location(&self, program: &WebGLProgram, name: &str) -> i32 {
+        self.inner._get_attrib_location(program, name)
+    }
+    fn _get_uniform_location(&self, program: &WebGLProgram, name: &str) -> WebGLUniformLocation {
+        self.inner._get_uniform_location(program, name)
+    }
+    fn _get_program_parameter_i32(&self, program: &WebGLProgram, pname: ProgramParameter) -> i32 {
+        self.inner._get_program_parameter_i32(program, pname)
+    }
+    fn _get_program_parameter_bool(&self, program: &WebGLProgram, pname: ProgramParameter) -> bool {
+        self.inner._get_program_parameter_bool(program, pname)
+    }
+    fn _create_shader(&self, kind: ShaderKind) -> WebGLShader {
+        self.inner._create_shader(kind)
+    }
+    fn _compile_shader(&self, shader: &WebGLShader) {
+        self.inner._compile_shader(shader)
+    }
+    fn _delete_shader(&self, shader: WebGLShader) {
+        self.inner._delete_shader(shader)
+    }
+    fn _get_shader_info_log(&self, shader: &WebGLShader) -> String {
+        self.inner._get_shader_info_log(shader)
+    }
+    fn _get_shader_source(&self, shader: &WebGLShader) -> String {
+        self.inner._get_shader_source(shader)
+    }
+    fn _is_shader(&self, shader: &WebGLShader) -> bool {
+        self.inner._is_shader(shader)
+    }
+    fn _shader_source(&self, shader: &WebGLShader, source: &str) {
+        self.inner._shader_source(shader, source)
+    }
+    fn _get_shader_parameter_bool(&self, shader: &WebGLShader, pname: ShaderParameter) -> bool {
+        self.inner._get_shader_parameter_bool(shader, pname)
+    }
+    fn _get_shader_parameter_enum(
+        &self,
+        shader: &WebGLShader,
+        pname: ShaderParameter,
+    ) -> ShaderKind {
+        self.inner._get_shader_parameter_enum(shader, pname)
+    }
+}
+
+impl UniformBackend for WebGLRenderingContext {
+    type UniformLocation = WebGLUniformLocation;
+
+    fn uniform_1f(&self, location: &WebGLUniformLocation, v0: f32) {
+        self.inner.uniform_1f(location, v0)
+    }
+    fn uniform_2f(&self, location: &WebGLUniformLocation, v0: f32, v1: f32) {
+        self.inner.uniform_2f(location, v0, v1)
+    }
+    fn uniform_3f(&self, location: &WebGLUniformLocation, v0: f32, v1: f32, v2: f32) {
+        self.inner.uniform_3f(location, v0, v1, v2)
+    }
+    fn uniform_4f(&self, location: &WebGLUniformLocation, v0: f32, v1: f32, v2: f32, v3: f32) {
+        self.inner.uniform_4f(location, v0, v1, v2, v3)
+    }
+    fn uniform_1i(&self, location: &WebGLUniformLocation, v0: i32) {
+        self.inner.uniform_1i(location, v0)
+    }
+    fn uniform_2i(&self, location: &WebGLUniformLocation, v0: i32, v1: i32) {
+        self.inner.uniform_2i(location, v0, v1)
+    }
+    fn uniform_3i(&self, location: &WebGLUniformLocation, v0: i32, v1: i32, v2: i32) {
+        self.inner.uniform_3i(location, v0, v1, v2)
+    }
+    fn uniform_4i(&self, location: &WebGLUniformLocation, v0: i32, v1: i32, v2: i32, v3: i32) {
+        self.inner.uniform_4i(location, v0, v1, v2, v3)
+    }
+    fn uniform_1fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        self.inner.uniform_1fv(location, value)
+    }
+    fn uniform_2fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        self.inner.uniform_2fv(location, value)
+    }
+    fn uniform_3fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        self.inner.uniform_3fv(location, value)
+    }
+    fn uniform_4fv(&self, location: &WebGLUniformLocation, value: Vec<f32>) {
+        self.inner.uniform_4fv(location, value)
+    }
+    fn uniform_1iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        self.inner.uniform_1iv(location, value)
+    }
+    fn uniform_2iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        self.inner.uniform_2iv(location, value)
+    }
+    fn uniform_3iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        self.inner.uniform_3iv(location, value)
+    }
+    fn uniform_4iv(&self, location: &WebGLUniformLocation, value: Vec<i32>) {
+        self.inner.uniform_4iv(location, value)
+    }
+    fn uniform_matrix_2fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    ) {
+        self.inner.uniform_matrix_2fv(location, transpose, value)
+    }
+    fn uniform_matrix_3fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    ) {
+        self.inner.uniform_matrix_3fv(location, transpose, value)
+    }
+    fn uniform_matrix_4fv(
+        &self,
+        location: &WebGLUniformLocation,
+        transpose: bool,
+        value: Vec<f32>,
+    ) {
+        self.inner.uniform_matrix_4fv(location, transpose, value)
+    }
+}
+
+/// Bindings for the extension objects
+#[wasm_bindgen]
+extern "C" {
+    /// The `ANGLE_instanced_arrays` extension, allows drawing the same object multiple times.
+    pub type ANGLEInstancedArrays;
+    /// Binding for `ANGLE_instanced_arrays.vertexAttribDivisorANGLE()`
+    #[wasm_bindgen(method, structural, js_name = vertexAttribDivisorANGLE)]
+    fn vertex_attrib_divisor_angle(this: &ANGLEInstancedArrays, index: u32, divisor: u32);
+    /// Binding for `ANGLE_instanced_arrays.drawArraysInstancedANGLE()`
+    #[wasm_bindgen(method, structural, js_name = drawArraysInstancedANGLE)]
+    fn draw_arrays_instanced_angle(
+        this: &ANGLEInstancedArrays,
+        mode: Primitives,
+        first: i32,
+        count: u32,
+        primcount: u32,
+    );
+    /// Binding for `ANGLE_instanced_arrays.drawElementsInstancedANGLE()`
+    #[wasm_bindgen(method, structural, js_name = drawElementsInstancedANGLE)]
+    fn draw_elements_instanced_angle(
+        this: &ANGLEInstancedArrays,
+        mode: Primitives,
+        count: u32,
+        data_type: AttributeType,
+        offset: i64,
+        primcount: u32,
+    );
+
+    /// The `OES_vertex_array_object` extension, provides vertex array objects.
+    pub type OESVertexArrayObject;
+    /// Vertex array object created by the `OES_vertex_array_object` extension.
+    #[derive(Clone)]
+    pub type WebGLVertexArrayObjectOES;
+    /// Binding for `OES_vertex_array_object.createVertexArrayOES()`
+    #[wasm_bindgen(method, structural, js_name = createVertexArrayOES)]
+    fn create_vertex_array_oes(this: &OESVertexArrayObject) -> WebGLVertexArrayObjectOES;
+    /// Binding for `OES_vertex_array_object.deleteVertexArrayOES()`
+    #[wasm_bindgen(method, structural, js_name = deleteVertexArrayOES)]
+    fn delete_vertex_array_oes(
+        this: &OESVertexArrayObject,
+        vertex_array: WebGLVertexArrayObjectOES,
+    );
+    /// Binding for `OES_vertex_array_object.isVertexArrayOES()`
+    #[wasm_bindgen(method, structural, js_name = isVertexArrayOES)]
+    fn is_vertex_array_oes(
+        this: &OESVertexArrayObject,
+        vertex_array: &WebGLVertexArrayObjectOES,
+    ) -> bool;
+    /// Binding for `OES_vertex_array_object.bindVertexArrayOES()`
+    #[wasm_bindgen(method, structural, js_name = bindVertexArrayOES)]
+    fn bind_vertex_array_oes(this: &OESVertexArrayObject, vertex_array: &WebGLVertexArrayObjectOES);
+    /// Binding for `OES_vertex_array_object.bindVertexArrayOES()` when the vertex array can be null
+    #[wasm_bindgen(method, structural, js_name = bindVertexArrayOES)]
+    fn bind_vertex_array_oes_option(
+        this: &OESVertexArrayObject,
+        vertex_array: Option<&WebGLVertexArrayObjectOES>,
+    );
+
+    /// The `WEBGL_draw_buffers` extension, allows rendering to multiple color attachments.
+    pub type WEBGLDrawBuffers;
+    /// Binding for `WEBGL_draw_buffers.drawBuffersWEBGL()`
+    #[wasm_bindgen(method, structural, js_name = drawBuffersWEBGL)]
+    fn draw_buffers_webgl(this: &WEBGLDrawBuffers, buffers: &[u32]);
+}
+
+/// Bindings for the javascript `WebGLRenderingContext`
+///
+/// Kept in a separate module because the javascript type has the same name as the wrapper.
+mod sys {
+    use super::*;
+    use rendering_context::{StringArray, WebGLShaderPrecisionFormat};
+    use shader_program::WebGLActiveInfo;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type WebGLRenderingContext;
+
+        /// Binding for `HTMLCanvasElement.getContext()` with context type `"webgl"`
+        #[wasm_bindgen(method, js_name = getContext)]
+        pub fn _get_webgl_context(
+            this: &HTMLCanvasElement,
+            context_type: &str,
+            attributes: &WebGLContextAttributes,
+        ) -> Option<WebGLRenderingContext>;
+        /// Binding for `OffscreenCanvas.getContext()` with context type `"webgl"`
+        #[wasm_bindgen(method, js_name = getContext)]
+        pub fn _get_webgl_context(
+            this: &OffscreenCanvas,
+            context_type: &str,
+            attributes: &WebGLContextAttributes,
+        ) -> Option<WebGLRenderingContext>;
+
+        /// Binding for `WebGLRenderingContext.getExtension()`
+        #[wasm_bindgen(method, js_name = getExtension)]
+        pub fn _get_extension(this: &WebGLRenderingContext, name: &str) -> JsValue;
+        /// Binding for `WebGLRenderingContext.getExtension()` for `ANGLE_instanced_arrays`
+        #[wasm_bindgen(method, js_name = getExtension)]
+        pub fn _get_instanced_arrays(
+            this: &WebGLRenderingContext,
+            name: &str,
+        ) -> Option<ANGLEInstancedArrays>;
+        /// Binding for `WebGLRenderingContext.getExtension()` for `OES_vertex_array_object`
+        #[wasm_bindgen(method, js_name = getExtension)]
+        pub fn _get_vertex_array_object(
+            this: &WebGLRenderingContext,
+            name: &str,
+        ) -> Option<OESVertexArrayObject>;
+        /// Binding for `WebGLRenderingContext.getExtension()` for `WEBGL_draw_buffers`
+        #[wasm_bindgen(method, js_name = getExtension)]
+        pub fn _get_draw_buffers(
+            this: &WebGLRenderingContext,
+            name: &str,
+        ) -> Option<WEBGLDrawBuffers>;
+
+        /// Binding for `WebGLRenderingContext.drawingBufferWidth`
+        #[wasm_bindgen(method, getter = drawingBufferWidth)]
+        pub fn drawing_buffer_width(this: &WebGLRenderingContext) -> u32;
+
+        /// Binding for `WebGLRenderingContext.drawingBufferHeight`
+        #[wasm_bindgen(method, getter = drawingBufferHeight)]
+        pub fn drawing_buffer_height(this: &WebGLRenderingContext) -> u32;
+
+        /// Binding for `WebGLRenderingContext.isContextLost()`
+        #[wasm_bindgen(method, js_name = isContextLost)]
+        pub fn is_context_lost(this: &WebGLRenderingContext) -> bool;
+
+        /// Binding for `WebGLRenderingContext.getSupportedExtensions()`
+        #[wasm_bindgen(method, js_name = getSupportedExtensions)]
+        pub fn _get_supported_extensions(this: &WebGLRenderingContext) -> Option<StringArray>;
+
+        /// Binding for `WebGLRenderingContext.scissor()`
+        #[wasm_bindgen(method)]
+        pub fn scissor(this: &WebGLRenderingContext, x: i32, y: i32, width: u32, height: u32);
+
+        /// Binding for `WebGLRenderingContext.viewport()`
+        #[wasm_bindgen(method)]
+        pub fn viewport(this: &WebGLRenderingContext, x: i32, y: i32, width: u32, height: u32);
+
+        /// Binding for `WebGLRenderingContext.activeTexture()`
+        #[wasm_bindgen(method, js_name = activeTexture)]
+        pub fn active_texture(this: &WebGLRenderingContext, texture: TextureUnit);
+
+        /// Binding for `WebGLRenderingContext.blendColor()`
+        #[wasm_bindgen(method, js_name = blendColor)]
+        pub fn blend_color(
+            this: &WebGLRenderingContext,
+            red: f32,
+            green: f32,
+            blue: f32,
+            alpha: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.blendEquation()`
+        #[wasm_bindgen(method, js_name = blendEquation)]
+        pub fn blend_equation(this: &WebGLRenderingContext, mode: BlendEquation);
+
+        /// Binding for `WebGLRenderingContext.blendEquationSeparate()`
+        #[wasm_bindgen(method, js_name = blendEquationSeparate)]
+        pub fn blend_equation_separate(
+            this: &WebGLRenderingContext,
+            mode_rgb: BlendEquation,
+            mode_alpha: BlendEquation,
+        );
+
+        /// Binding for `WebGLRenderingContext.blendFunc()`
+        #[wasm_bindgen(method, js_name = blendFunc)]
+        pub fn blend_func(this: &WebGLRenderingContext, sfactor: BlendMode, dfactor: BlendMode);
+
+        /// Binding for `WebGLRenderingContext.blendFuncSeparate()`
+        #[wasm_bindgen(method, js_name = blendFuncSeparate)]
+        pub fn blend_func_separate(
+            this: &WebGLRenderingContext,
+            src_rgb: BlendMode,
+            dst_rgb: BlendMode,
+            src_alpha: BlendMode,
+            dst_alpha: BlendMode,
+        );
+
+        /// Binding for `WebGLRenderingContext.clearColor()`
+        #[wasm_bindgen(method, js_name = clearColor)]
+        pub fn clear_color(
+            this: &WebGLRenderingContext,
+            red: f32,
+            green: f32,
+            blue: f32,
+            alpha: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.clearDepth()`
+        #[wasm_bindgen(method, js_name = clearDepth)]
+        pub fn clear_depth(this: &WebGLRenderingContext, depth: f32);
+
+        /// Binding for `WebGLRenderingContext.clearStencil()`
+        #[wasm_bindgen(method, js_name = clearStencil)]
+        pub fn clear_stencil(this: &WebGLRenderingContext, s: i32);
+
+        /// Binding for `WebGLRenderingContext.colorMask()`
+        #[wasm_bindgen(method, js_name = colorMask)]
+        pub fn color_mask(
+            this: &WebGLRenderingContext,
+            red: bool,
+            green: bool,
+            blue: bool,
+            alpha: bool,
+        );
+
+        /// Binding for `WebGLRenderingContext.cullFace()`
+        #[wasm_bindgen(method, js_name = cullFace)]
+        pub fn cull_face(this: &WebGLRenderingContext, mode: Culling);
+
+        /// Binding for `WebGLRenderingContext.depthFunc()`
+        #[wasm_bindgen(method, js_name = depthFunc)]
+        pub fn depth_func(this: &WebGLRenderingContext, func: DepthTest);
+
+        /// Binding for `WebGLRenderingContext.depthMask()`
+        #[wasm_bindgen(method, js_name = depthMask)]
+        pub fn depth_mask(this: &WebGLRenderingContext, flag: bool);
+
+        /// Binding for `WebGLRenderingContext.depthRange()`
+        #[wasm_bindgen(method, js_name = depthRange)]
+        pub fn depth_range(this: &WebGLRenderingContext, z_near: f32, z_far: f32);
+
+        /// Binding for `WebGLRenderingContext.disable()`
+        #[wasm_bindgen(method)]
+        pub fn disable(this: &WebGLRenderingContext, cap: Flag);
+
+        /// Binding for `WebGLRenderingContext.enable()`
+        #[wasm_bindgen(method)]
+        pub fn enable(this: &WebGLRenderingContext, cap: Flag);
+
+        /// Binding for `WebGLRenderingContext.frontFace()`
+        #[wasm_bindgen(method, js_name = frontFace)]
+        pub fn front_face(this: &WebGLRenderingContext, mode: FrontFaceDirection);
+
+        /// Binding for `WebGLRenderingContext.getError()`
+        #[wasm_bindgen(method, js_name = getError)]
+        pub fn get_error(this: &WebGLRenderingContext) -> Error;
+
+        /// Binding for `WebGLRenderingContext.hint()`
+        #[wasm_bindgen(method)]
+        pub fn hint(this: &WebGLRenderingContext, target: HintTarget, mode: HintMode);
+
+        /// Binding for `WebGLRenderingContext.isEnabled()`
+        #[wasm_bindgen(method, js_name = isEnabled)]
+        pub fn is_enabled(this: &WebGLRenderingContext, cap: Flag) -> bool;
+
+        /// Binding for `WebGLRenderingContext.lineWidth()`
+        #[wasm_bindgen(method, js_name = lineWidth)]
+        pub fn line_width(this: &WebGLRenderingContext, width: f32);
+
+        /// Binding for `WebGLRenderingContext.pixelStorei()`
+        #[wasm_bindgen(method, js_name = pixelStorei)]
+        pub fn pixel_storei(this: &WebGLRenderingContext, pname: PixelStorageMode, param: i32);
+
+        /// Binding for `WebGLRenderingContext.polygonOffset()`
+        #[wasm_bindgen(method, js_name = polygonOffset)]
+        pub fn polygon_offset(this: &WebGLRenderingContext, factor: f32, units: f32);
+
+        /// Binding for `WebGLRenderingContext.sampleCoverage()`
+        #[wasm_bindgen(method, js_name = sampleCoverage)]
+        pub fn sample_coverage(this: &WebGLRenderingContext, value: f32, invert: bool);
+
+        /// Binding for `WebGLRenderingContext.stencilFunc()`
+        #[wasm_bindgen(method, js_name = stencilFunc)]
+        pub fn stencil_func(
+            this: &WebGLRenderingContext,
+            func: StencilTest,
+            reference: i32,
+            mask: u32,
+        );
+
+        /// Binding for `WebGLRenderingContext.stencilFuncSeparate()`
+        #[wasm_bindgen(method, js_name = stencilFuncSeparate)]
+        pub fn stencil_func_separate(
+            this: &WebGLRenderingContext,
+            face: Culling,
+            func: StencilTest,
+            reference: i32,
+            mask: u32,
+        );
+
+        /// Binding for `WebGLRenderingContext.stencilMask()`
+        #[wasm_bindgen(method, js_name = stencilMask)]
+        pub fn stencil_mask(this: &WebGLRenderingContext, mask: u32);
+
+        /// Binding for `WebGLRenderingContext.stencilMaskSeparate()`
+        #[wasm_bindgen(method, js_name = stencilMaskSeparate)]
+        pub fn stencil_mask_separate(this: &WebGLRenderingContext, face: Culling, mask: u32);
+
+        /// Binding for `WebGLRenderingContext.stencilOp()`
+        #[wasm_bindgen(method, js_name = stencilOp)]
+        pub fn stencil_op(
+            this: &WebGLRenderingContext,
+            fail: StencilAction,
+            zfail: StencilAction,
+            zpass: StencilAction,
+        );
+
+        /// Binding for `WebGLRenderingContext.stencilOpSeparate()`
+        #[wasm_bindgen(method, js_name = stencilOpSeparate)]
+        pub fn stencil_op_separate(
+            this: &WebGLRenderingContext,
+            face: Culling,
+            fail: StencilAction,
+            zfail: StencilAction,
+            zpass: StencilAction,
+        );
+
+        /// Binding for `WebGLRenderingContext.getBufferParameter()`
+        #[wasm_bindgen(method, js_name = getBufferParameter)]
+        pub fn _get_buffer_parameter_i32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            pname: BufferParameter,
+        ) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getBufferParameter()`
+        #[wasm_bindgen(method, js_name = getBufferParameter)]
+        pub fn _get_buffer_parameter_enum(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            pname: BufferParameter,
+        ) -> DataHint;
+
+        /// Binding for `WebGLRenderingContext.checkFramebufferStatus()`
+        #[wasm_bindgen(method, js_name = checkFramebufferStatus)]
+        pub fn check_framebuffer_status(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+        ) -> FramebufferStatus;
+
+        /// Binding for `WebGLRenderingContext.getRenderbufferParameter()`
+        #[wasm_bindgen(method, js_name = getRenderbufferParameter)]
+        pub fn _get_renderbuffer_parameter_i32(
+            this: &WebGLRenderingContext,
+            target: RenderbufferKind,
+            pname: RenderbufferParameter,
+        ) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getRenderbufferParameter()`
+        #[wasm_bindgen(method, js_name = getRenderbufferParameter)]
+        pub fn _get_renderbuffer_parameter_enum(
+            this: &WebGLRenderingContext,
+            target: RenderbufferKind,
+            pname: RenderbufferParameter,
+        ) -> InternalFormat;
+
+        /// Binding for `WebGLRenderingContext.renderbufferStorage()`
+        #[wasm_bindgen(method, js_name = renderbufferStorage)]
+        pub fn renderbuffer_storage(
+            this: &WebGLRenderingContext,
+            target: RenderbufferKind,
+            internalFormat: InternalFormat,
+            width: u32,
+            height: u32,
+        );
+
+        /// Binding for `WebGLRenderingContext.copyTexImage2D()`
+        #[wasm_bindgen(method, js_name = copyTexImage2D)]
+        pub fn copy_tex_image_2d(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: PixelCopyFormat,
+            x: i32,
+            y: i32,
+            width: u32,
+            height: u32,
+            border: u32,
+        );
+
+        /// Binding for `WebGLRenderingContext.copyTexSubImage2D()`
+        #[wasm_bindgen(method, js_name = copyTexSubImage2D)]
+        pub fn copy_tex_sub_image_2d(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: i32,
+            yoffset: i32,
+            x: i32,
+            y: i32,
+            width: u32,
+            height: u32,
+        );
+
+        /// Binding for `WebGLRenderingContext.generateMipmap()`
+        #[wasm_bindgen(method, js_name = generateMipmap)]
+        pub fn generate_mipmap(this: &WebGLRenderingContext, target: TextureKind);
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_i32(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_u32(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> u32;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_f32(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> f32;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_bool(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> bool;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_enum1(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> TextureMagFilter;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_enum2(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> TextureMinFilter;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_enum3(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> TextureWrap;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_enum4(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> DepthTest;
+
+        /// Binding for `WebGLRenderingContext.getTexParameter()`
+        #[wasm_bindgen(method, js_name = getTexParameter)]
+        pub fn _get_tex_parameter_enum5(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+        ) -> CompareMode;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `bool`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_bool(this: &WebGLRenderingContext, pname: Parameter) -> bool;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `i32`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_i32(this: &WebGLRenderingContext, pname: Parameter) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `u32`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_u32(this: &WebGLRenderingContext, pname: Parameter) -> u32;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `f32`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_f32(this: &WebGLRenderingContext, pname: Parameter) -> f32;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `String`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_string(this: &WebGLRenderingContext, pname: Parameter) -> String;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<i32>`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_i32_array(this: &WebGLRenderingContext, pname: Parameter)
+            -> Vec<i32>;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<u32>`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_u32_array(this: &WebGLRenderingContext, pname: Parameter)
+            -> Vec<u32>;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<f32>`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_f32_array(this: &WebGLRenderingContext, pname: Parameter)
+            -> Vec<f32>;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Vec<bool>`, the booleans
+        /// are returned as `0` or `1` because they are copied into a `Uint8Array`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_bool_array(this: &WebGLRenderingContext, pname: Parameter)
+            -> Vec<u8>;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `BlendEquation`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum1(
+            this: &WebGLRenderingContext,
+            pname: Parameter,
+        ) -> BlendEquation;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `BlendMode`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum2(this: &WebGLRenderingContext, pname: Parameter) -> BlendMode;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `Culling`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum3(this: &WebGLRenderingContext, pname: Parameter) -> Culling;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `FrontFaceDirection`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum4(
+            this: &WebGLRenderingContext,
+            pname: Parameter,
+        ) -> FrontFaceDirection;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `DepthTest`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum5(this: &WebGLRenderingContext, pname: Parameter) -> DepthTest;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `StencilTest`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum6(this: &WebGLRenderingContext, pname: Parameter) -> StencilTest;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `StencilAction`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_enum7(
+            this: &WebGLRenderingContext,
+            pname: Parameter,
+        ) -> StencilAction;
+
+        /// Binding for `WebGLRenderingContext.texParameterf()`
+        #[wasm_bindgen(method, js_name = texParameterf)]
+        pub fn tex_parameter_f(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+            param: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.texParameteri()`
+        #[wasm_bindgen(method, js_name = texParameteri)]
+        pub fn tex_parameter_i(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            pname: TextureParameter,
+            param: i32,
+        );
+
+        /// Binding for `WebGLRenderingContext.getShaderPrecisionFormat()`
+        #[wasm_bindgen(method, js_name = getShaderPrecisionFormat)]
+        pub fn _get_shader_precision_format(
+            this: &WebGLRenderingContext,
+            shader_type: ShaderKind,
+            precision_type: ShaderPrecision,
+        ) -> WebGLShaderPrecisionFormat;
+
+        /// Binding for `WebGLRenderingContext.disableVertexAttribArray()`
+        #[wasm_bindgen(method, js_name = disableVertexAttribArray)]
+        pub fn disable_vertex_attrib_array(this: &WebGLRenderingContext, index: u32);
+
+        /// Binding for `WebGLRenderingContext.enableVertexAttribArray()`
+        #[wasm_bindgen(method, js_name = enableVertexAttribArray)]
+        pub fn enable_vertex_attrib_array(this: &WebGLRenderingContext, index: u32);
+
+        /// Binding for `WebGLRenderingContext.getVertexAttribOffset()`
+        #[wasm_bindgen(method, js_name = getVertexAttribOffset)]
+        pub fn get_vertex_attrib_offset(
+            this: &WebGLRenderingContext,
+            index: u32,
+            pname: VertexAttrib,
+        ) -> i64;
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib1f()`
+        #[wasm_bindgen(method, js_name = vertexAttrib1f)]
+        pub fn vertex_attrib_1f(this: &WebGLRenderingContext, index: u32, v0: f32);
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib2f()`
+        #[wasm_bindgen(method, js_name = vertexAttrib2f)]
+        pub fn vertex_attrib_2f(this: &WebGLRenderingContext, index: u32, v0: f32, v1: f32);
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib3f()`
+        #[wasm_bindgen(method, js_name = vertexAttrib3f)]
+        pub fn vertex_attrib_3f(
+            this: &WebGLRenderingContext,
+            index: u32,
+            v0: f32,
+            v1: f32,
+            v2: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib4f()`
+        #[wasm_bindgen(method, js_name = vertexAttrib4f)]
+        pub fn vertex_attrib_4f(
+            this: &WebGLRenderingContext,
+            index: u32,
+            v0: f32,
+            v1: f32,
+            v2: f32,
+            v3: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib1fv()`
+        #[wasm_bindgen(method, js_name = vertexAttrib1fv)]
+        pub fn vertex_attrib_1fv(this: &WebGLRenderingContext, index: u32, value: Vec<f32>);
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib2fv()`
+        #[wasm_bindgen(method, js_name = vertexAttrib2fv)]
+        pub fn vertex_attrib_2fv(this: &WebGLRenderingContext, index: u32, value: Vec<f32>);
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib3fv()`
+        #[wasm_bindgen(method, js_name = vertexAttrib3fv)]
+        pub fn vertex_attrib_3fv(this: &WebGLRenderingContext, index: u32, value: Vec<f32>);
+
+        /// Binding for `WebGLRenderingContext.vertexAttrib4fv()`
+        #[wasm_bindgen(method, js_name = vertexAttrib4fv)]
+        pub fn vertex_attrib_4fv(this: &WebGLRenderingContext, index: u32, value: Vec<f32>);
+
+        /// Binding for `WebGLRenderingContext.vertexAttribPointer()`
+        #[wasm_bindgen(method, js_name = vertexAttribPointer)]
+        pub fn vertex_attrib_pointer(
+            this: &WebGLRenderingContext,
+            index: u32,
+            size: AttributeSize,
+            attribute_type: AttributeType,
+            normalized: bool,
+            stride: u8,
+            offset: i32,
+        );
+
+        /// Binding for `WebGLRenderingContext.clear()`
+        #[wasm_bindgen(method)]
+        pub fn clear(this: &WebGLRenderingContext, mask: BufferBit);
+
+        /// Binding for `WebGLRenderingContext.drawArrays()`
+        #[wasm_bindgen(method, js_name = drawArrays)]
+        pub fn draw_arrays(this: &WebGLRenderingContext, mode: Primitives, first: u32, count: u32);
+
+        /// Binding for `WebGLRenderingContext.drawElements()`
+        #[wasm_bindgen(method, js_name = drawElements)]
+        pub fn draw_elements(
+            this: &WebGLRenderingContext,
+            mode: Primitives,
+            count: u32,
+            data_type: DataType,
+            offset: i64,
+        );
+
+        /// Binding for `WebGLRenderingContext.finish()`
+        #[wasm_bindgen(method)]
+        pub fn finish(this: &WebGLRenderingContext);
+
+        /// Binding for `WebGLRenderingContext.flush()`
+        #[wasm_bindgen(method)]
+        pub fn flush(this: &WebGLRenderingContext);
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[i8]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_i8(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[i8],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[u8]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_u8(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[u8],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[i16]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_i16(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[i16],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[u16]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_u16(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[u16],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[i32]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_i32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[i32],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[u32]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_u32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[u32],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[f32]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_f32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[f32],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferData()` with `[f64]` data
+        #[wasm_bindgen(method, js_name = bufferData)]
+        pub fn _buffer_data_f64(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            src_data: &[f64],
+            usage: DataHint,
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[i8]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_i8(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[i8],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[u8]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_u8(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[u8],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[i16]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_i16(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[i16],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[u16]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_u16(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[u16],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[i32]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_i32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[i32],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[u32]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_u32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[u32],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[f32]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_f32(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[f32],
+        );
+
+        /// Binding for `WebGLRenderingContext.bufferSubData()` with `[f64]` data
+        #[wasm_bindgen(method, js_name = bufferSubData)]
+        pub fn _buffer_sub_data_f64(
+            this: &WebGLRenderingContext,
+            target: BufferKind,
+            offset: i64,
+            srcData: &[f64],
+        );
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[i8]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_i8(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[i8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[u8]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_u8(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[u8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[i16]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_i16(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[i16],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[u16]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_u16(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[u16],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[i32]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_i32(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[i32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[u32]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_u32(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[u32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with `[f32]` data
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_f32(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            width: u32,
+            height: u32,
+            border: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            src_data: &[f32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[i8]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_i8(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[i8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[u8]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_u8(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[u8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[i16]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_i16(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[i16],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[u16]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_u16(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[u16],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[i32]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_i32(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[i32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[u32]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_u32(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[u32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with `[f32]` data
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_f32(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            pixels: &[f32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.compressedTexImage2D()`
+        #[wasm_bindgen(method, js_name = compressedTexImage2D, catch)]
+        pub fn _compressed_tex_image_2d(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: TextureCompression,
+            width: u32,
+            height: u32,
+            border: u32,
+            data: &[u8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.compressedTexSubImage2D()`
+        #[wasm_bindgen(method, js_name = compressedTexSubImage2D, catch)]
+        pub fn _compressed_tex_sub_image_2d(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            width: u32,
+            height: u32,
+            format: TextureCompression,
+            data: &[u8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texImage2D()` with a `TexImageSource`
+        #[wasm_bindgen(method, js_name = texImage2D, catch)]
+        pub fn _tex_image_2d_source(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            internalformat: InternalFormat,
+            format: PixelFormat,
+            pixel_type: u32,
+            source: &JsValue,
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.texSubImage2D()` with a `TexImageSource`
+        #[wasm_bindgen(method, js_name = texSubImage2D, catch)]
+        pub fn _tex_sub_image_2d_source(
+            this: &WebGLRenderingContext,
+            target: TextureBindPoint,
+            level: u32,
+            xoffset: u32,
+            yoffset: u32,
+            format: PixelFormat,
+            pixel_type: u32,
+            source: &JsValue,
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[i8]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_i8(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [i8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[u8]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_u8(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [u8],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[i16]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_i16(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [i16],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[u16]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_u16(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [u16],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[i32]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_i32(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [i32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[u32]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_u32(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [u32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.readPixels()` with `[f32]` data
+        #[wasm_bindgen(method, js_name = readPixels, catch)]
+        pub fn _read_pixels_f32(
+            this: &WebGLRenderingContext,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            format: PixelReadFormat,
+            pixel_type: u32,
+            pixels: &mut [f32],
+        ) -> Result<(), JsValue>;
+
+        /// Binding for `WebGLRenderingContext.createBuffer()`
+        #[wasm_bindgen(method, js_name = createBuffer)]
+        pub fn _create_buffer(this: &WebGLRenderingContext) -> WebGLBuffer;
+
+        /// Binding for `WebGLRenderingContext.deleteBuffer()`
+        #[wasm_bindgen(method, js_name = deleteBuffer)]
+        pub fn _delete_buffer(this: &WebGLRenderingContext, buffer: WebGLBuffer);
+
+        /// Binding for `WebGLRenderingContext.isBuffer()`
+        #[wasm_bindgen(method, js_name = isBuffer)]
+        pub fn _is_buffer(this: &WebGLRenderingContext, buffer: &WebGLBuffer) -> bool;
+
+        /// Binding for `WebGLRenderingContext.bindBuffer()`
+        #[wasm_bindgen(method, js_name = bindBuffer)]
+        pub fn _bind_buffer(this: &WebGLRenderingContext, target: BufferKind, buffer: &WebGLBuffer);
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `WebGLBuffer`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_buffer(
+            this: &WebGLRenderingContext,
+            pname: Parameter,
+        ) -> Option<WebGLBuffer>;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is
+        /// `WebGLVertexArrayObjectOES`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_vertex_array(
+            this: &WebGLRenderingContext,
+            pname: Parameter,
+        ) -> Option<WebGLVertexArrayObjectOES>;
+
+        /// Binding for `WebGLRenderingContext.createFramebuffer()`
+        #[wasm_bindgen(method, js_name = createFramebuffer)]
+        pub fn _create_framebuffer(this: &WebGLRenderingContext) -> WebGLFramebuffer;
+
+        /// Binding for `WebGLRenderingContext.bindFramebuffer()`
+        #[wasm_bindgen(method, js_name = bindFramebuffer)]
+        pub fn _bind_framebuffer(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+            framebuffer: &WebGLFramebuffer,
+        );
+
+        /// Binding for `WebGLRenderingContext.deleteFramebuffer()`
+        #[wasm_bindgen(method, js_name = deleteFramebuffer)]
+        pub fn _delete_framebuffer(this: &WebGLRenderingContext, framebuffer: WebGLFramebuffer);
+
+        /// Binding for `WebGLRenderingContext.isFramebuffer()`
+        #[wasm_bindgen(method, js_name = isFramebuffer)]
+        pub fn _is_framebuffer(
+            this: &WebGLRenderingContext,
+            framebuffer: &WebGLFramebuffer,
+        ) -> bool;
+
+        /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is `i32`
+        #[wasm_bindgen(method, js_name = getFramebufferAttachmentParameter)]
+        pub fn _get_framebuffer_attachment_parameter_i32(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+            attachment: Attachment,
+            pname: FramebufferAttachmentParameter,
+        ) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is
+        /// `WebGLRenderbuffer`
+        #[wasm_bindgen(method, js_name = getFramebufferAttachmentParameter)]
+        pub fn _get_framebuffer_attachment_parameter_renderbuffer(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+            attachment: Attachment,
+            pname: FramebufferAttachmentParameter,
+        ) -> Option<WebGLRenderbuffer>;
+
+        /// Binding for `WebGLRenderingContext.getFramebufferAttachmentParameter()` when return type is
+        /// `WebGLTexture`
+        #[wasm_bindgen(method, js_name = getFramebufferAttachmentParameter)]
+        pub fn _get_framebuffer_attachment_parameter_texture(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+            attachment: Attachment,
+            pname: FramebufferAttachmentParameter,
+        ) -> Option<WebGLTexture>;
+
+        /// Binding for `WebGLRenderingContext.createRenderbuffer()`
+        #[wasm_bindgen(method, js_name = createRenderbuffer)]
+        pub fn _create_renderbuffer(this: &WebGLRenderingContext) -> WebGLRenderbuffer;
+
+        /// Binding for `WebGLRenderingContext.framebufferRenderbuffer()`
+        #[wasm_bindgen(method, js_name = framebufferRenderbuffer)]
+        pub fn _framebuffer_renderbuffer(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+            attachment: Attachment,
+            renderbuffertarget: RenderbufferKind,
+            renderbuffer: &WebGLRenderbuffer,
+        );
+
+        /// Binding for `WebGLRenderingContext.bindRenderbuffer()`
+        #[wasm_bindgen(method, js_name = bindRenderbuffer)]
+        pub fn _bind_renderbuffer(
+            this: &WebGLRenderingContext,
+            target: RenderbufferKind,
+            renderbuffer: &WebGLRenderbuffer,
+        );
+
+        /// Binding for `WebGLRenderingContext.deleteRenderbuffer()`
+        #[wasm_bindgen(method, js_name = deleteRenderbuffer)]
+        pub fn _delete_renderbuffer(this: &WebGLRenderingContext, renderbuffer: WebGLRenderbuffer);
+
+        /// Binding for `WebGLRenderingContext.isRenderbuffer()`
+        #[wasm_bindgen(method, js_name = isRenderbuffer)]
+        pub fn _is_renderbuffer(
+            this: &WebGLRenderingContext,
+            renderbuffer: &WebGLRenderbuffer,
+        ) -> bool;
+
+        /// Binding for `WebGLRenderingContext.createTexture()`
+        #[wasm_bindgen(method, js_name = createTexture)]
+        pub fn _create_texture(this: &WebGLRenderingContext) -> WebGLTexture;
+
+        /// Binding for `WebGLRenderingContext.bindTexture()`
+        #[wasm_bindgen(method, js_name = bindTexture)]
+        pub fn _bind_texture(
+            this: &WebGLRenderingContext,
+            target: TextureKind,
+            texture: &WebGLTexture,
+        );
+
+        /// Binding for `WebGLRenderingContext.deleteTexture()`
+        #[wasm_bindgen(method, js_name = deleteTexture)]
+        pub fn _delete_texture(this: &WebGLRenderingContext, texture: WebGLTexture);
+
+        /// Binding for `WebGLRenderingContext.isTexture()`
+        #[wasm_bindgen(method, js_name = isTexture)]
+        pub fn _is_texture(this: &WebGLRenderingContext, texture: &WebGLTexture) -> bool;
+
+        /// Binding for `WebGLRenderingContext.getParameter()` when return type is `WebGLTexture`
+        #[wasm_bindgen(method, js_name = getParameter)]
+        pub fn _get_parameter_texture(
+            this: &WebGLRenderingContext,
+            pname: Parameter,
+        ) -> Option<WebGLTexture>;
+
+        /// Binding for `WebGLRenderingContext.framebufferTexture2D()`
+        #[wasm_bindgen(method, js_name = framebufferTexture2D)]
+        pub fn _framebuffer_texture_2d(
+            this: &WebGLRenderingContext,
+            target: FramebufferKind,
+            attachment: Attachment,
+            textarget: TextureBindPoint,
+            texture: &WebGLTexture,
+            level: i32,
+        );
+
+        /// Binding for `WebGLRenderingContext.createProgram()`
+        #[wasm_bindgen(method, js_name = createProgram)]
+        pub fn _create_program(this: &WebGLRenderingContext) -> WebGLProgram;
+
+        /// Binding for `WebGLRenderingContext.deleteProgram()`
+        #[wasm_bindgen(method, js_name = deleteProgram)]
+        pub fn _delete_program(this: &WebGLRenderingContext, program: WebGLProgram);
+
+        /// Binding for `WebGLRenderingContext.detachShader()`
+        #[wasm_bindgen(method, js_name = detachShader)]
+        pub fn _detach_shader(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            shader: &WebGLShader,
+        );
+
+        /// Binding for `WebGLRenderingContext.attachShader()`
+        #[wasm_bindgen(method, js_name = attachShader)]
+        pub fn _attach_shader(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            shader: &WebGLShader,
+        );
+
+        /// Binding for `WebGLRenderingContext.bindAttribLocation()`
+        #[wasm_bindgen(method, js_name = bindAttribLocation)]
+        pub fn _bind_attrib_location(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            index: u32,
+            name: &str,
+        );
+
+        /// Binding for `WebGLRenderingContext.getProgramInfoLog()`
+        #[wasm_bindgen(method, js_name = getProgramInfoLog)]
+        pub fn _get_program_info_log(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+        ) -> String;
+
+        /// Binding for `WebGLRenderingContext.isProgram()`
+        #[wasm_bindgen(method, js_name = isProgram)]
+        pub fn _is_program(this: &WebGLRenderingContext, program: &WebGLProgram) -> bool;
+
+        /// Binding for `WebGLRenderingContext.linkProgram()`
+        #[wasm_bindgen(method, js_name = linkProgram)]
+        pub fn _link_program(this: &WebGLRenderingContext, program: &WebGLProgram);
+
+        /// Binding for `WebGLRenderingContext.useProgram()`
+        #[wasm_bindgen(method, js_name = useProgram)]
+        pub fn _use_program(this: &WebGLRenderingContext, program: &WebGLProgram);
+
+        /// Binding for `WebGLRenderingContext.validateProgram()`
+        #[wasm_bindgen(method, js_name = validateProgram)]
+        pub fn _validate_program(this: &WebGLRenderingContext, program: &WebGLProgram);
+
+        /// Binding for `WebGLRenderingContext.getActiveAttrib()`
+        #[wasm_bindgen(method, js_name = getActiveAttrib)]
+        pub fn _get_active_attrib(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            index: u32,
+        ) -> WebGLActiveInfo;
+
+        /// Binding for `WebGLRenderingContext.getActiveUniform()`
+        #[wasm_bindgen(method, js_name = getActiveUniform)]
+        pub fn _get_active_uniform(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            index: u32,
+        ) -> WebGLActiveInfo;
+
+        /// Binding for `WebGLRenderingContext.getAttribLocation()`
+        #[wasm_bindgen(method, js_name = getAttribLocation)]
+        pub fn _get_attrib_location(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            name: &str,
+        ) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getUniformLocation()`
+        #[wasm_bindgen(method, js_name = getUniformLocation)]
+        pub fn _get_uniform_location(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            name: &str,
+        ) -> WebGLUniformLocation;
+
+        /// Binding for `WebGLRenderingContext.getProgramParameter()` with `[i32]` data
+        #[wasm_bindgen(method, js_name = getProgramParameter)]
+        pub fn _get_program_parameter_i32(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            pname: ProgramParameter,
+        ) -> i32;
+
+        /// Binding for `WebGLRenderingContext.getProgramParameter()`
+        #[wasm_bindgen(method, js_name = getProgramParameter)]
+        pub fn _get_program_parameter_bool(
+            this: &WebGLRenderingContext,
+            program: &WebGLProgram,
+            pname: ProgramParameter,
+        ) -> bool;
+
+        /// Binding for `WebGLRenderingContext.createShader()`
+        #[wasm_bindgen(method, js_name = createShader)]
+        pub fn _create_shader(this: &WebGLRenderingContext, kind: ShaderKind) -> WebGLShader;
+
+        /// Binding for `WebGLRenderingContext.compileShader()`
+        #[wasm_bindgen(method, js_name = compileShader)]
+        pub fn _compile_shader(this: &WebGLRenderingContext, shader: &WebGLShader);
+
+        /// Binding for `WebGLRenderingContext.deleteShader()`
+        #[wasm_bindgen(method, js_name = deleteShader)]
+        pub fn _delete_shader(this: &WebGLRenderingContext, shader: WebGLShader);
+
+        /// Binding for `WebGLRenderingContext.getShaderInfoLog()`
+        #[wasm_bindgen(method, js_name = getShaderInfoLog)]
+        pub fn _get_shader_info_log(this: &WebGLRenderingContext, shader: &WebGLShader) -> String;
+
+        /// Binding for `WebGLRenderingContext.getShaderSource()`
+        #[wasm_bindgen(method, js_name = getShaderSource)]
+        pub fn _get_shader_source(this: &WebGLRenderingContext, shader: &WebGLShader) -> String;
+
+        /// Binding for `WebGLRenderingContext.isShader()`
+        #[wasm_bindgen(method, js_name = isShader)]
+        pub fn _is_shader(this: &WebGLRenderingContext, shader: &WebGLShader) -> bool;
+
+        /// Binding for `WebGLRenderingContext.shaderSource()`
+        #[wasm_bindgen(method, js_name = shaderSource)]
+        pub fn _shader_source(this: &WebGLRenderingContext, shader: &WebGLShader, source: &str);
+
+        /// Binding for `WebGLRenderingContext.getShaderParameter()`
+        #[wasm_bindgen(method, js_name = getShaderParameter)]
+        pub fn _get_shader_parameter_bool(
+            this: &WebGLRenderingContext,
+            shader: &WebGLShader,
+            pname: ShaderParameter,
+        ) -> bool;
+
+        /// Binding for `WebGLRenderingContext.getShaderParameter()`
+        #[wasm_bindgen(method, js_name = getShaderParameter)]
+        pub fn _get_shader_parameter_enum(
+            this: &WebGLRenderingContext,
+            shader: &WebGLShader,
+            pname: ShaderParameter,
+        ) -> ShaderKind;
+
+        /// Binding for `WebGLRenderingContext.uniform1f()`
+        #[wasm_bindgen(method, js_name = uniform1f)]
+        pub fn uniform_1f(this: &WebGLRenderingContext, location: &WebGLUniformLocation, v0: f32);
+
+        /// Binding for `WebGLRenderingContext.uniform2f()`
+        #[wasm_bindgen(method, js_name = uniform2f)]
+        pub fn uniform_2f(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            v0: f32,
+            v1: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform3f()`
+        #[wasm_bindgen(method, js_name = uniform3f)]
+        pub fn uniform_3f(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            v0: f32,
+            v1: f32,
+            v2: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform4f()`
+        #[wasm_bindgen(method, js_name = uniform4f)]
+        pub fn uniform_4f(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            v0: f32,
+            v1: f32,
+            v2: f32,
+            v3: f32,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform1i()`
+        #[wasm_bindgen(method, js_name = uniform1i)]
+        pub fn uniform_1i(this: &WebGLRenderingContext, location: &WebGLUniformLocation, v0: i32);
+
+        /// Binding for `WebGLRenderingContext.uniform2i()`
+        #[wasm_bindgen(method, js_name = uniform2i)]
+        pub fn uniform_2i(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            v0: i32,
+            v1: i32,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform3i()`
+        #[wasm_bindgen(method, js_name = uniform3i)]
+        pub fn uniform_3i(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            v0: i32,
+            v1: i32,
+            v2: i32,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform4i()`
+        #[wasm_bindgen(method, js_name = uniform4i)]
+        pub fn uniform_4i(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            v0: i32,
+            v1: i32,
+            v2: i32,
+            v3: i32,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform1fv()`
+        #[wasm_bindgen(method, js_name = uniform1fv)]
+        pub fn uniform_1fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<f32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform2fv()`
+        #[wasm_bindgen(method, js_name = uniform2fv)]
+        pub fn uniform_2fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<f32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform3fv()`
+        #[wasm_bindgen(method, js_name = uniform3fv)]
+        pub fn uniform_3fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<f32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform4fv()`
+        #[wasm_bindgen(method, js_name = uniform4fv)]
+        pub fn uniform_4fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<f32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform1iv()`
+        #[wasm_bindgen(method, js_name = uniform1iv)]
+        pub fn uniform_1iv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<i32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform2iv()`
+        #[wasm_bindgen(method, js_name = uniform2iv)]
+        pub fn uniform_2iv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<i32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform3iv()`
+        #[wasm_bindgen(method, js_name = uniform3iv)]
+        pub fn uniform_3iv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<i32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniform4iv()`
+        #[wasm_bindgen(method, js_name = uniform4iv)]
+        pub fn uniform_4iv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            value: Vec<i32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniformMatrix2fv()`
+        #[wasm_bindgen(method, js_name = uniformMatrix2fv)]
+        pub fn uniform_matrix_2fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            transpose: bool,
+            value: Vec<f32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniformMatrix3fv()`
+        #[wasm_bindgen(method, js_name = uniformMatrix3fv)]
+        pub fn uniform_matrix_3fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            transpose: bool,
+            value: Vec<f32>,
+        );
+
+        /// Binding for `WebGLRenderingContext.uniformMatrix4fv()`
+        #[wasm_bindgen(method, js_name = uniformMatrix4fv)]
+        pub fn uniform_matrix_4fv(
+            this: &WebGLRenderingContext,
+            location: &WebGLUniformLocation,
+            transpose: bool,
+            value: Vec<f32>,
+        );
+    }
+}
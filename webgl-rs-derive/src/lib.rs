@@ -0,0 +1,196 @@
+//! Derive macros for `webgl-rs`, enabled through its `derive` feature.
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, LitInt, LitStr};
+
+/// Derives `webgl_rs::Vertex` and `webgl_rs::Pod` for a `#[repr(C)]` struct with named fields.
+///
+/// Every field becomes an attribute named after the field, its size and type follow from the field type
+/// (`f32`, `u8`, ..., or an array of 2 to 4 of them). The struct may not contain padding, which is checked
+/// at compile time with `core::mem::offset_of!`, so the derive needs Rust 1.77 or newer. Fields accept a `#[vertex(...)]` attribute with:
+/// * `normalized` - normalize integer components when they are converted to floats.
+/// * `integer` - read the attribute as integers, requires WebGL2.
+/// * `divisor = n` - advance the attribute once every `n` instances.
+/// * `name = "..."` - use a different attribute name than the field name.
+/// * `skip` - do not turn the field into an attribute, e.g. for explicit padding.
+///
+/// ```
+/// extern crate webgl_rs;
+/// use webgl_rs::Vertex;
+///
+/// #[derive(Clone, Copy, Vertex)]
+/// #[repr(C)]
+/// struct Particle {
+///     position: [f32; 3],
+///     #[vertex(normalized)]
+///     color: [u8; 4],
+///     #[vertex(integer, divisor = 1, name = "particle_id")]
+///     id: u32,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Particle::stride(), 20);
+///     let id = Particle::attribute("particle_id").unwrap();
+///     assert_eq!((id.offset, id.integer, id.divisor), (16, true, 1));
+/// }
+/// ```
+#[proc_macro_derive(Vertex, attributes(vertex))]
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_vertex(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Options of a field, parsed from its `#[vertex(...)]` attribute.
+struct FieldOptions {
+    name: Option<LitStr>,
+    normalized: bool,
+    integer: bool,
+    divisor: Option<LitInt>,
+    skip: bool,
+}
+
+fn field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions {
+        name: None,
+        normalized: false,
+        integer: false,
+        divisor: None,
+        skip: false,
+    };
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("vertex"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("normalized") {
+                options.normalized = true;
+            } else if meta.path.is_ident("integer") {
+                options.integer = true;
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("divisor") {
+                options.divisor = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("name") {
+                options.name = Some(meta.value()?.parse()?);
+            } else {
+                return Err(
+                    meta.error("expected `normalized`, `integer`, `skip`, `divisor` or `name`")
+                );
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+/// Returns true if the type has a `#[repr(C)]` or `#[repr(transparent)]` attribute.
+fn has_c_layout(input: &DeriveInput) -> syn::Result<bool> {
+    let mut c_layout = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                c_layout = true;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(c_layout)
+}
+
+fn expand_vertex(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "Vertex can not be derived for generic types",
+        ));
+    }
+    if !has_c_layout(input)? {
+        return Err(syn::Error::new(
+            ident.span(),
+            "Vertex can only be derived for #[repr(C)] structs",
+        ));
+    }
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "Vertex can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Vertex can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut attributes = Vec::new();
+    for field in fields {
+        let options = field_options(field)?;
+        if options.skip {
+            continue;
+        }
+        let field_ident = field
+            .ident
+            .as_ref()
+            .expect("named fields have an identifier");
+        let field_type = &field.ty;
+        let name = match options.name {
+            Some(name) => name,
+            None => LitStr::new(&field_ident.to_string(), field_ident.span()),
+        };
+        let mut attribute = quote! {
+            ::webgl_rs::VertexAttribute::of::<#field_type>(
+                #name,
+                ::std::mem::offset_of!(#ident, #field_ident),
+            )
+        };
+        if options.normalized {
+            attribute = quote!(#attribute.normalized());
+        }
+        if options.integer {
+            attribute = quote!(#attribute.integer());
+        }
+        if let Some(divisor) = options.divisor {
+            attribute = quote!(#attribute.divisor(#divisor));
+        }
+        attributes.push(attribute);
+    }
+    let field_types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    Ok(quote! {
+        const _: fn() = || {
+            fn assert_pod<T: ::webgl_rs::Pod>() {}
+            #(assert_pod::<#field_types>();)*
+        };
+        const _: () = assert!(
+            ::std::mem::size_of::<#ident>() == 0 #(+ ::std::mem::size_of::<#field_types>())*,
+            "Vertex types can not contain padding"
+        );
+
+        unsafe impl ::webgl_rs::Pod for #ident {}
+
+        impl ::webgl_rs::Vertex for #ident {
+            const ATTRIBUTES: &'static [::webgl_rs::VertexAttribute] = &[#(#attributes),*];
+        }
+    })
+}